@@ -2,9 +2,18 @@ use axum::{http::StatusCode, response::IntoResponse, Json};
 use domain::{
     repository::repository_error::RepositoryError,
     service::service_error::{
-        auth_service_error::AuthServiceError,
+        achievement_service_error::AchievementServiceError, admin_service_error::AdminServiceError,
+        auth_service_error::AuthServiceError, calendar_service_error::CalendarServiceError,
         daily_mission_service_error::DailyMissionServiceError, exp_error::ExpServiceError,
+        friend_service_error::FriendServiceError, group_service_error::GroupServiceError,
+        leaderboard_service_error::LeaderboardServiceError,
+        live_event_service_error::LiveEventServiceError, mission_codec_error::MissionCodecError,
+        mission_transfer_service_error::MissionTransferServiceError,
+        push_service_error::PushServiceError, quest_service_error::QuestServiceError,
+        reminder_service_error::ReminderServiceError, stats_service_error::StatsServiceError,
+        streak_service_error::StreakServiceError, template_service_error::TemplateServiceError,
         token_service_error::TokenServiceError, user_service_error::UserServiceError,
+        webhook_service_error::WebhookServiceError,
     },
 };
 use serde::Serialize;
@@ -119,7 +128,7 @@ pub(crate) enum ExpError {
     Server,
     TokenExpired,
     NotFound,
-    ExpOverflow,
+    InvalidQuery(String),
 }
 
 impl From<ExpServiceError> for ExpError {
@@ -136,7 +145,8 @@ impl From<ExpServiceError> for ExpError {
                 RepositoryError::InvalidData(_) => ExpError::InvalidData,
                 RepositoryError::DatabaseError(_) => ExpError::Server,
             },
-            ExpServiceError::DetectedExpOverflow(_) => ExpError::ExpOverflow,
+            ExpServiceError::InvalidQuery(e) => ExpError::InvalidQuery(e),
+            ExpServiceError::LevelCurve(_) => ExpError::Server,
         }
     }
 }
@@ -186,11 +196,11 @@ impl IntoResponse for ExpError {
                 )),
             )
                 .into_response(),
-            Self::ExpOverflow => (
-                ErrorRes::EXP_OVERFLOW.0,
+            Self::InvalidQuery(e) => (
+                ErrorRes::INVALID_QUERY.0,
                 Json(Error::new(
-                    ErrorRes::EXP_OVERFLOW.1,
-                    ErrorRes::EXP_OVERFLOW.2,
+                    ErrorRes::INVALID_QUERY.1,
+                    &format!("{}:{}", ErrorRes::INVALID_QUERY.2, e),
                 )),
             )
                 .into_response(),
@@ -202,11 +212,16 @@ pub(crate) enum DailyError {
     DataMismatch,
     InvalidData,
     InvalidToken,
-    OverCap,
+    OverCap(u32),
+    NotDue,
+    NotQuantitative,
+    ProgressRequired,
+    AlreadyCompleted,
     Server,
     TokenExpired,
     EntityNotFound,
     Validate(String),
+    InvalidQuery(String),
 }
 
 impl From<DailyMissionServiceError> for DailyError {
@@ -223,8 +238,13 @@ impl From<DailyMissionServiceError> for DailyError {
                 RepositoryError::InvalidData(_) => DailyError::InvalidData,
                 RepositoryError::DatabaseError(_) => DailyError::Server,
             },
-            DailyMissionServiceError::OverCapacity => DailyError::OverCap,
+            DailyMissionServiceError::OverCapacity(limit) => DailyError::OverCap(limit),
+            DailyMissionServiceError::NotDue => DailyError::NotDue,
+            DailyMissionServiceError::NotQuantitative => DailyError::NotQuantitative,
+            DailyMissionServiceError::ProgressRequired => DailyError::ProgressRequired,
+            DailyMissionServiceError::AlreadyCompleted => DailyError::AlreadyCompleted,
             DailyMissionServiceError::Validate(e) => DailyError::Validate(e.to_string()),
+            DailyMissionServiceError::InvalidQuery(e) => DailyError::InvalidQuery(e),
             DailyMissionServiceError::UnknownError(_) => DailyError::Server,
         }
     }
@@ -275,19 +295,1528 @@ impl IntoResponse for DailyError {
                 )),
             )
                 .into_response(),
-            Self::OverCap => (
+            Self::OverCap(limit) => (
                 ErrorRes::DAILY_OVER_CAP.0,
                 Json(Error::new(
                     ErrorRes::DAILY_OVER_CAP.1,
-                    ErrorRes::DAILY_OVER_CAP.2,
+                    &format!("{} (limit: {})", ErrorRes::DAILY_OVER_CAP.2, limit),
+                )),
+            )
+                .into_response(),
+            Self::NotDue => (
+                ErrorRes::DAILY_NOT_DUE.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_NOT_DUE.1,
+                    ErrorRes::DAILY_NOT_DUE.2,
+                )),
+            )
+                .into_response(),
+            Self::NotQuantitative => (
+                ErrorRes::DAILY_NOT_QUANTITATIVE.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_NOT_QUANTITATIVE.1,
+                    ErrorRes::DAILY_NOT_QUANTITATIVE.2,
+                )),
+            )
+                .into_response(),
+            Self::ProgressRequired => (
+                ErrorRes::DAILY_PROGRESS_REQUIRED.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_PROGRESS_REQUIRED.1,
+                    ErrorRes::DAILY_PROGRESS_REQUIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::AlreadyCompleted => (
+                ErrorRes::DAILY_ALREADY_COMPLETED.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_ALREADY_COMPLETED.1,
+                    ErrorRes::DAILY_ALREADY_COMPLETED.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+            Self::InvalidQuery(e) => (
+                ErrorRes::INVALID_QUERY.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_QUERY.1,
+                    &format!("{}:{}", ErrorRes::INVALID_QUERY.2, e),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum StreakError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    Validate(String),
+    InvalidVacation(String),
+}
+
+impl From<StreakServiceError> for StreakError {
+    fn from(value: StreakServiceError) -> Self {
+        match value {
+            StreakServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => StreakError::InvalidToken,
+                TokenServiceError::TokenExpired => StreakError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => StreakError::DataMismatch,
+                _ => StreakError::Server,
+            },
+            StreakServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => StreakError::NotFound,
+                RepositoryError::InvalidData(_) => StreakError::InvalidData,
+                RepositoryError::DatabaseError(_) => StreakError::Server,
+            },
+            StreakServiceError::Validation(e) => StreakError::Validate(e.to_string()),
+            StreakServiceError::InvalidVacation(e) => StreakError::InvalidVacation(e),
+        }
+    }
+}
+
+impl IntoResponse for StreakError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+            Self::InvalidVacation(e) => (
+                ErrorRes::INVALID_VACATION.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_VACATION.1,
+                    &format!("{}:{}", ErrorRes::INVALID_VACATION.2, e),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum UserError {
+    DataMismatch,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    UserNotFound,
+    UserAlreadyExists,
+    Validate(String),
+}
+
+impl From<UserServiceError> for UserError {
+    fn from(value: UserServiceError) -> Self {
+        match value {
+            UserServiceError::UserAlreadyExists => Self::UserAlreadyExists,
+            UserServiceError::UserNotFound => Self::UserNotFound,
+            UserServiceError::Validation(e) => Self::Validate(e.to_string()),
+            UserServiceError::TokenError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => Self::InvalidToken,
+                TokenServiceError::TokenExpired => Self::TokenExpired,
+                TokenServiceError::DataMismatch(_) => Self::DataMismatch,
+                _ => Self::Server,
+            },
+            _ => Self::Server,
+        }
+    }
+}
+
+impl IntoResponse for UserError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::UserNotFound => (
+                ErrorRes::USER_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::USER_NOT_FOUND.1,
+                    ErrorRes::USER_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+            Self::UserAlreadyExists => (
+                ErrorRes::USER_ALREADY_EXISTS.0,
+                Json(Error::new(
+                    ErrorRes::USER_ALREADY_EXISTS.1,
+                    ErrorRes::USER_ALREADY_EXISTS.2,
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum QuestError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    QuestAlreadyCompleted,
+    QuestSubtasksIncomplete,
+    Server,
+    TokenExpired,
+    EntityNotFound,
+    Validate(String),
+}
+
+impl From<QuestServiceError> for QuestError {
+    fn from(value: QuestServiceError) -> Self {
+        match value {
+            QuestServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => QuestError::InvalidToken,
+                TokenServiceError::TokenExpired => QuestError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => QuestError::DataMismatch,
+                _ => QuestError::Server,
+            },
+            QuestServiceError::RepositoryError(v) => match v {
+                RepositoryError::NotFound => QuestError::EntityNotFound,
+                RepositoryError::InvalidData(_) => QuestError::InvalidData,
+                RepositoryError::DatabaseError(_) => QuestError::Server,
+            },
+            QuestServiceError::AlreadyCompleted => QuestError::QuestAlreadyCompleted,
+            QuestServiceError::SubtasksIncomplete => QuestError::QuestSubtasksIncomplete,
+            QuestServiceError::Validate(e) => QuestError::Validate(e.to_string()),
+        }
+    }
+}
+
+impl IntoResponse for QuestError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::QuestAlreadyCompleted => (
+                ErrorRes::QUEST_ALREADY_COMPLETED.0,
+                Json(Error::new(
+                    ErrorRes::QUEST_ALREADY_COMPLETED.1,
+                    ErrorRes::QUEST_ALREADY_COMPLETED.2,
+                )),
+            )
+                .into_response(),
+            Self::QuestSubtasksIncomplete => (
+                ErrorRes::QUEST_SUBTASKS_INCOMPLETE.0,
+                Json(Error::new(
+                    ErrorRes::QUEST_SUBTASKS_INCOMPLETE.1,
+                    ErrorRes::QUEST_SUBTASKS_INCOMPLETE.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::EntityNotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum AdminError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Forbidden,
+    Server,
+    TokenExpired,
+    EntityNotFound,
+    Validate(String),
+    InvalidLevelCurve(String),
+}
+
+impl From<AdminServiceError> for AdminError {
+    fn from(value: AdminServiceError) -> Self {
+        match value {
+            AdminServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => AdminError::InvalidToken,
+                TokenServiceError::TokenExpired => AdminError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => AdminError::DataMismatch,
+                _ => AdminError::Server,
+            },
+            AdminServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => AdminError::EntityNotFound,
+                RepositoryError::InvalidData(_) => AdminError::InvalidData,
+                RepositoryError::DatabaseError(_) => AdminError::Server,
+            },
+            AdminServiceError::Forbidden => AdminError::Forbidden,
+            AdminServiceError::Validation(e) => AdminError::Validate(e.to_string()),
+            AdminServiceError::InvalidLevelCurve(e) => AdminError::InvalidLevelCurve(e.to_string()),
+        }
+    }
+}
+
+impl IntoResponse for AdminError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Forbidden => (
+                ErrorRes::FORBIDDEN.0,
+                Json(Error::new(ErrorRes::FORBIDDEN.1, ErrorRes::FORBIDDEN.2)),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::EntityNotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+            Self::InvalidLevelCurve(e) => (
+                ErrorRes::INVALID_LEVEL_CURVE.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_LEVEL_CURVE.1,
+                    &format!("{}:{}", ErrorRes::INVALID_LEVEL_CURVE.2, e),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum AchievementError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+}
+
+impl From<AchievementServiceError> for AchievementError {
+    fn from(value: AchievementServiceError) -> Self {
+        match value {
+            AchievementServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => AchievementError::InvalidToken,
+                TokenServiceError::TokenExpired => AchievementError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => AchievementError::DataMismatch,
+                _ => AchievementError::Server,
+            },
+            AchievementServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => AchievementError::NotFound,
+                RepositoryError::InvalidData(_) => AchievementError::InvalidData,
+                RepositoryError::DatabaseError(_) => AchievementError::Server,
+            },
+        }
+    }
+}
+
+impl IntoResponse for AchievementError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum FriendError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    SelfRequest,
+    AlreadyExists,
+    RequestRejected,
+}
+
+impl From<FriendServiceError> for FriendError {
+    fn from(value: FriendServiceError) -> Self {
+        match value {
+            FriendServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => FriendError::InvalidToken,
+                TokenServiceError::TokenExpired => FriendError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => FriendError::DataMismatch,
+                _ => FriendError::Server,
+            },
+            FriendServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => FriendError::NotFound,
+                RepositoryError::InvalidData(_) => FriendError::InvalidData,
+                RepositoryError::DatabaseError(_) => FriendError::Server,
+            },
+            FriendServiceError::SelfRequest => FriendError::SelfRequest,
+            FriendServiceError::AlreadyFriends | FriendServiceError::AlreadyRequested => {
+                FriendError::AlreadyExists
+            }
+            FriendServiceError::RequestRejected => FriendError::RequestRejected,
+        }
+    }
+}
+
+impl IntoResponse for FriendError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::SelfRequest => (
+                ErrorRes::FRIEND_SELF_REQUEST.0,
+                Json(Error::new(
+                    ErrorRes::FRIEND_SELF_REQUEST.1,
+                    ErrorRes::FRIEND_SELF_REQUEST.2,
+                )),
+            )
+                .into_response(),
+            Self::AlreadyExists => (
+                ErrorRes::FRIEND_ALREADY_EXISTS.0,
+                Json(Error::new(
+                    ErrorRes::FRIEND_ALREADY_EXISTS.1,
+                    ErrorRes::FRIEND_ALREADY_EXISTS.2,
+                )),
+            )
+                .into_response(),
+            Self::RequestRejected => (
+                ErrorRes::FRIEND_REQUEST_REJECTED.0,
+                Json(Error::new(
+                    ErrorRes::FRIEND_REQUEST_REJECTED.1,
+                    ErrorRes::FRIEND_REQUEST_REJECTED.2,
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum LeaderboardError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    InvalidQuery(String),
+}
+
+impl From<LeaderboardServiceError> for LeaderboardError {
+    fn from(value: LeaderboardServiceError) -> Self {
+        match value {
+            LeaderboardServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => LeaderboardError::InvalidToken,
+                TokenServiceError::TokenExpired => LeaderboardError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => LeaderboardError::DataMismatch,
+                _ => LeaderboardError::Server,
+            },
+            LeaderboardServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => LeaderboardError::NotFound,
+                RepositoryError::InvalidData(_) => LeaderboardError::InvalidData,
+                RepositoryError::DatabaseError(_) => LeaderboardError::Server,
+            },
+            LeaderboardServiceError::InvalidQuery(e) => LeaderboardError::InvalidQuery(e),
+            LeaderboardServiceError::LevelCurve(_) => LeaderboardError::Server,
+        }
+    }
+}
+
+impl IntoResponse for LeaderboardError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidQuery(e) => (
+                ErrorRes::INVALID_QUERY.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_QUERY.1,
+                    &format!("{}:{}", ErrorRes::INVALID_QUERY.2, e),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum GroupError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    Validate(String),
+    Forbidden,
+    AlreadyMember,
+    GroupFull(u32),
+    MissionOverCap(u32),
+    AlreadyCompleted,
+    OwnerCannotLeave,
+    InvalidRole,
+}
+
+impl From<GroupServiceError> for GroupError {
+    fn from(value: GroupServiceError) -> Self {
+        match value {
+            GroupServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => GroupError::InvalidToken,
+                TokenServiceError::TokenExpired => GroupError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => GroupError::DataMismatch,
+                _ => GroupError::Server,
+            },
+            GroupServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => GroupError::NotFound,
+                RepositoryError::InvalidData(_) => GroupError::InvalidData,
+                RepositoryError::DatabaseError(_) => GroupError::Server,
+            },
+            GroupServiceError::Validate(e) => GroupError::Validate(e.to_string()),
+            GroupServiceError::Forbidden => GroupError::Forbidden,
+            GroupServiceError::AlreadyMember => GroupError::AlreadyMember,
+            GroupServiceError::GroupFull(limit) => GroupError::GroupFull(limit),
+            GroupServiceError::MissionOverCapacity(limit) => GroupError::MissionOverCap(limit),
+            GroupServiceError::AlreadyCompleted => GroupError::AlreadyCompleted,
+            GroupServiceError::OwnerCannotLeave => GroupError::OwnerCannotLeave,
+            GroupServiceError::InvalidRole => GroupError::InvalidRole,
+        }
+    }
+}
+
+impl IntoResponse for GroupError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+            Self::Forbidden => (
+                ErrorRes::FORBIDDEN.0,
+                Json(Error::new(ErrorRes::FORBIDDEN.1, ErrorRes::FORBIDDEN.2)),
+            )
+                .into_response(),
+            Self::AlreadyMember => (
+                ErrorRes::GROUP_ALREADY_MEMBER.0,
+                Json(Error::new(
+                    ErrorRes::GROUP_ALREADY_MEMBER.1,
+                    ErrorRes::GROUP_ALREADY_MEMBER.2,
+                )),
+            )
+                .into_response(),
+            Self::GroupFull(limit) => (
+                ErrorRes::GROUP_FULL.0,
+                Json(Error::new(
+                    ErrorRes::GROUP_FULL.1,
+                    &format!("{} (limit: {})", ErrorRes::GROUP_FULL.2, limit),
+                )),
+            )
+                .into_response(),
+            Self::MissionOverCap(limit) => (
+                ErrorRes::GROUP_MISSION_OVER_CAP.0,
+                Json(Error::new(
+                    ErrorRes::GROUP_MISSION_OVER_CAP.1,
+                    &format!("{} (limit: {})", ErrorRes::GROUP_MISSION_OVER_CAP.2, limit),
+                )),
+            )
+                .into_response(),
+            Self::AlreadyCompleted => (
+                ErrorRes::GROUP_MISSION_ALREADY_COMPLETED.0,
+                Json(Error::new(
+                    ErrorRes::GROUP_MISSION_ALREADY_COMPLETED.1,
+                    ErrorRes::GROUP_MISSION_ALREADY_COMPLETED.2,
+                )),
+            )
+                .into_response(),
+            Self::OwnerCannotLeave => (
+                ErrorRes::GROUP_OWNER_CANNOT_LEAVE.0,
+                Json(Error::new(
+                    ErrorRes::GROUP_OWNER_CANNOT_LEAVE.1,
+                    ErrorRes::GROUP_OWNER_CANNOT_LEAVE.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidRole => (
+                ErrorRes::GROUP_INVALID_ROLE.0,
+                Json(Error::new(
+                    ErrorRes::GROUP_INVALID_ROLE.1,
+                    ErrorRes::GROUP_INVALID_ROLE.2,
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum TemplateError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    Validate(String),
+    Forbidden,
+}
+
+impl From<TemplateServiceError> for TemplateError {
+    fn from(value: TemplateServiceError) -> Self {
+        match value {
+            TemplateServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => TemplateError::InvalidToken,
+                TokenServiceError::TokenExpired => TemplateError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => TemplateError::DataMismatch,
+                _ => TemplateError::Server,
+            },
+            TemplateServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => TemplateError::NotFound,
+                RepositoryError::InvalidData(_) => TemplateError::InvalidData,
+                RepositoryError::DatabaseError(_) => TemplateError::Server,
+            },
+            TemplateServiceError::Validate(e) => TemplateError::Validate(e.to_string()),
+            TemplateServiceError::Forbidden => TemplateError::Forbidden,
+        }
+    }
+}
+
+impl IntoResponse for TemplateError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+            Self::Forbidden => (
+                ErrorRes::FORBIDDEN.0,
+                Json(Error::new(ErrorRes::FORBIDDEN.1, ErrorRes::FORBIDDEN.2)),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum TransferError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    Malformed(String),
+    OverCap(u32),
+    TooManyRows(usize),
+}
+
+impl From<MissionTransferServiceError> for TransferError {
+    fn from(value: MissionTransferServiceError) -> Self {
+        match value {
+            MissionTransferServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => TransferError::InvalidToken,
+                TokenServiceError::TokenExpired => TransferError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => TransferError::DataMismatch,
+                _ => TransferError::Server,
+            },
+            MissionTransferServiceError::RepositoryError(e) => match e {
+                RepositoryError::InvalidData(_) => TransferError::InvalidData,
+                _ => TransferError::Server,
+            },
+            MissionTransferServiceError::CodecError(e) => match e {
+                MissionCodecError::Malformed(e) => TransferError::Malformed(e),
+                MissionCodecError::Encode(_) => TransferError::Server,
+            },
+            MissionTransferServiceError::OverCapacity(limit) => TransferError::OverCap(limit),
+            MissionTransferServiceError::TooManyRows(limit) => TransferError::TooManyRows(limit),
+        }
+    }
+}
+
+impl IntoResponse for TransferError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::Malformed(e) => (
+                ErrorRes::DAILY_IMPORT_MALFORMED.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_IMPORT_MALFORMED.1,
+                    &format!("{}:{}", ErrorRes::DAILY_IMPORT_MALFORMED.2, e),
+                )),
+            )
+                .into_response(),
+            Self::OverCap(limit) => (
+                ErrorRes::DAILY_OVER_CAP.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_OVER_CAP.1,
+                    &format!("{} (limit: {})", ErrorRes::DAILY_OVER_CAP.2, limit),
+                )),
+            )
+                .into_response(),
+            Self::TooManyRows(limit) => (
+                ErrorRes::DAILY_IMPORT_TOO_MANY_ROWS.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_IMPORT_TOO_MANY_ROWS.1,
+                    &format!(
+                        "{} (limit: {})",
+                        ErrorRes::DAILY_IMPORT_TOO_MANY_ROWS.2,
+                        limit
+                    ),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum CalendarError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+}
+
+impl From<CalendarServiceError> for CalendarError {
+    fn from(value: CalendarServiceError) -> Self {
+        match value {
+            CalendarServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => CalendarError::InvalidToken,
+                TokenServiceError::TokenExpired => CalendarError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => CalendarError::DataMismatch,
+                _ => CalendarError::Server,
+            },
+            CalendarServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => CalendarError::NotFound,
+                RepositoryError::InvalidData(_) => CalendarError::InvalidData,
+                RepositoryError::DatabaseError(_) => CalendarError::Server,
+            },
+        }
+    }
+}
+
+impl IntoResponse for CalendarError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum ReminderError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+}
+
+impl From<ReminderServiceError> for ReminderError {
+    fn from(value: ReminderServiceError) -> Self {
+        match value {
+            ReminderServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => ReminderError::InvalidToken,
+                TokenServiceError::TokenExpired => ReminderError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => ReminderError::DataMismatch,
+                _ => ReminderError::Server,
+            },
+            ReminderServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => ReminderError::NotFound,
+                RepositoryError::InvalidData(_) => ReminderError::InvalidData,
+                RepositoryError::DatabaseError(_) => ReminderError::Server,
+            },
+        }
+    }
+}
+
+impl IntoResponse for ReminderError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum PushError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    Validate(String),
+}
+
+impl From<PushServiceError> for PushError {
+    fn from(value: PushServiceError) -> Self {
+        match value {
+            PushServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => PushError::InvalidToken,
+                TokenServiceError::TokenExpired => PushError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => PushError::DataMismatch,
+                _ => PushError::Server,
+            },
+            PushServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => PushError::NotFound,
+                RepositoryError::InvalidData(_) => PushError::InvalidData,
+                RepositoryError::DatabaseError(_) => PushError::Server,
+            },
+            PushServiceError::Validate(e) => PushError::Validate(e.to_string()),
+            PushServiceError::PushError(_) => PushError::Server,
+        }
+    }
+}
+
+impl IntoResponse for PushError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
+                Json(Error::new(
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum WebhookError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    OverCap(u32),
+    Validate(String),
+}
+
+impl From<WebhookServiceError> for WebhookError {
+    fn from(value: WebhookServiceError) -> Self {
+        match value {
+            WebhookServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => WebhookError::InvalidToken,
+                TokenServiceError::TokenExpired => WebhookError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => WebhookError::DataMismatch,
+                _ => WebhookError::Server,
+            },
+            WebhookServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => WebhookError::NotFound,
+                RepositoryError::InvalidData(_) => WebhookError::InvalidData,
+                RepositoryError::DatabaseError(_) => WebhookError::Server,
+            },
+            WebhookServiceError::OverCapacity(limit) => WebhookError::OverCap(limit),
+            WebhookServiceError::Validate(e) => WebhookError::Validate(e.to_string()),
+        }
+    }
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::OverCap(limit) => (
+                ErrorRes::WEBHOOK_OVER_CAP.0,
+                Json(Error::new(
+                    ErrorRes::WEBHOOK_OVER_CAP.1,
+                    &format!("{} (limit: {})", ErrorRes::WEBHOOK_OVER_CAP.2, limit),
                 )),
             )
                 .into_response(),
             Self::Validate(e) => (
                 ErrorRes::VALIDATION.0,
                 Json(Error::new(
-                    ErrorRes::VALIDATION.1,
-                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                )),
+            )
+                .into_response(),
+        }
+    }
+}
+
+pub(crate) enum StatsError {
+    DataMismatch,
+    InvalidData,
+    InvalidToken,
+    Server,
+    TokenExpired,
+    NotFound,
+    InvalidQuery(String),
+}
+
+impl From<StatsServiceError> for StatsError {
+    fn from(value: StatsServiceError) -> Self {
+        match value {
+            StatsServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => StatsError::InvalidToken,
+                TokenServiceError::TokenExpired => StatsError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => StatsError::DataMismatch,
+                _ => StatsError::Server,
+            },
+            StatsServiceError::RepositoryError(e) => match e {
+                RepositoryError::NotFound => StatsError::NotFound,
+                RepositoryError::InvalidData(_) => StatsError::InvalidData,
+                RepositoryError::DatabaseError(_) => StatsError::Server,
+            },
+            StatsServiceError::InvalidQuery(e) => StatsError::InvalidQuery(e),
+        }
+    }
+}
+
+impl IntoResponse for StatsError {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::DataMismatch => (
+                ErrorRes::DATA_MISMATCH.0,
+                Json(Error::new(
+                    ErrorRes::DATA_MISMATCH.1,
+                    ErrorRes::DATA_MISMATCH.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidData => (
+                ErrorRes::INVALID_DATA.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_DATA.1,
+                    ErrorRes::INVALID_DATA.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidToken => (
+                ErrorRes::INVALID_TOKEN.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_TOKEN.1,
+                    ErrorRes::INVALID_TOKEN.2,
+                )),
+            )
+                .into_response(),
+            Self::Server => (
+                ErrorRes::SERVER.0,
+                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
+            )
+                .into_response(),
+            Self::TokenExpired => (
+                ErrorRes::TOKEN_EXPIRED.0,
+                Json(Error::new(
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::NotFound => (
+                ErrorRes::ENTITY_NOT_FOUND.0,
+                Json(Error::new(
+                    ErrorRes::ENTITY_NOT_FOUND.1,
+                    ErrorRes::ENTITY_NOT_FOUND.2,
+                )),
+            )
+                .into_response(),
+            Self::InvalidQuery(e) => (
+                ErrorRes::INVALID_QUERY.0,
+                Json(Error::new(
+                    ErrorRes::INVALID_QUERY.1,
+                    &format!("{}:{}", ErrorRes::INVALID_QUERY.2, e),
                 )),
             )
                 .into_response(),
@@ -295,34 +1824,27 @@ impl IntoResponse for DailyError {
     }
 }
 
-pub(crate) enum UserError {
+pub(crate) enum LiveEventError {
     DataMismatch,
     InvalidToken,
     Server,
     TokenExpired,
-    UserNotFound,
-    UserAlreadyExists,
-    Validate(String),
 }
 
-impl From<UserServiceError> for UserError {
-    fn from(value: UserServiceError) -> Self {
+impl From<LiveEventServiceError> for LiveEventError {
+    fn from(value: LiveEventServiceError) -> Self {
         match value {
-            UserServiceError::UserAlreadyExists => Self::UserAlreadyExists,
-            UserServiceError::UserNotFound => Self::UserNotFound,
-            UserServiceError::Validation(e) => Self::Validate(e.to_string()),
-            UserServiceError::TokenError(e) => match e {
-                TokenServiceError::TokenInvalid(_) => Self::InvalidToken,
-                TokenServiceError::TokenExpired => Self::TokenExpired,
-                TokenServiceError::DataMismatch(_) => Self::DataMismatch,
-                _ => Self::Server,
+            LiveEventServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => LiveEventError::InvalidToken,
+                TokenServiceError::TokenExpired => LiveEventError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => LiveEventError::DataMismatch,
+                _ => LiveEventError::Server,
             },
-            _ => Self::Server,
         }
     }
 }
 
-impl IntoResponse for UserError {
+impl IntoResponse for LiveEventError {
     fn into_response(self) -> axum::response::Response {
         match self {
             Self::DataMismatch => (
@@ -348,30 +1870,9 @@ impl IntoResponse for UserError {
                 .into_response(),
             Self::TokenExpired => (
                 ErrorRes::TOKEN_EXPIRED.0,
-                Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
-            )
-                .into_response(),
-            Self::UserNotFound => (
-                ErrorRes::USER_NOT_FOUND.0,
-                Json(Error::new(
-                    ErrorRes::USER_NOT_FOUND.1,
-                    ErrorRes::USER_NOT_FOUND.2,
-                )),
-            )
-                .into_response(),
-            Self::Validate(e) => (
-                ErrorRes::VALIDATION.0,
-                Json(Error::new(
-                    ErrorRes::VALIDATION.1,
-                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
-                )),
-            )
-                .into_response(),
-            Self::UserAlreadyExists => (
-                ErrorRes::USER_ALREADY_EXISTS.0,
                 Json(Error::new(
-                    ErrorRes::USER_ALREADY_EXISTS.1,
-                    ErrorRes::USER_ALREADY_EXISTS.2,
+                    ErrorRes::TOKEN_EXPIRED.1,
+                    ErrorRes::TOKEN_EXPIRED.2,
                 )),
             )
                 .into_response(),
@@ -387,10 +1888,17 @@ pub(crate) enum CombineError {
     DataMismatch,
     UserNotFound,
     InvalidData,
-    ExpOverflow,
-    OverCap,
+    OverCap(u32),
+    NotDue,
+    NotQuantitative,
+    ProgressRequired,
+    AlreadyCompleted,
+    QuestAlreadyCompleted,
+    QuestSubtasksIncomplete,
     EntityNotFound,
     Validate(String),
+    Forbidden,
+    GroupMissionAlreadyCompleted,
 }
 
 impl From<ExpServiceError> for CombineError {
@@ -407,7 +1915,8 @@ impl From<ExpServiceError> for CombineError {
                 RepositoryError::InvalidData(_) => CombineError::InvalidData,
                 RepositoryError::DatabaseError(_) => CombineError::Server,
             },
-            ExpServiceError::DetectedExpOverflow(_) => CombineError::ExpOverflow,
+            ExpServiceError::InvalidQuery(e) => CombineError::Validate(e),
+            ExpServiceError::LevelCurve(_) => CombineError::Server,
         }
     }
 }
@@ -426,13 +1935,123 @@ impl From<DailyMissionServiceError> for CombineError {
                 RepositoryError::InvalidData(_) => CombineError::InvalidData,
                 RepositoryError::DatabaseError(_) => CombineError::Server,
             },
-            DailyMissionServiceError::OverCapacity => CombineError::OverCap,
+            DailyMissionServiceError::OverCapacity(limit) => CombineError::OverCap(limit),
+            DailyMissionServiceError::NotDue => CombineError::NotDue,
+            DailyMissionServiceError::NotQuantitative => CombineError::NotQuantitative,
+            DailyMissionServiceError::ProgressRequired => CombineError::ProgressRequired,
+            DailyMissionServiceError::AlreadyCompleted => CombineError::AlreadyCompleted,
             DailyMissionServiceError::Validate(e) => CombineError::Validate(e.to_string()),
+            DailyMissionServiceError::InvalidQuery(e) => CombineError::Validate(e),
             DailyMissionServiceError::UnknownError(_) => CombineError::Server,
         }
     }
 }
 
+impl From<QuestServiceError> for CombineError {
+    fn from(value: QuestServiceError) -> Self {
+        match value {
+            QuestServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => CombineError::InvalidToken,
+                TokenServiceError::TokenExpired => CombineError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => CombineError::DataMismatch,
+                _ => CombineError::Server,
+            },
+            QuestServiceError::RepositoryError(v) => match v {
+                RepositoryError::NotFound => CombineError::EntityNotFound,
+                RepositoryError::InvalidData(_) => CombineError::InvalidData,
+                RepositoryError::DatabaseError(_) => CombineError::Server,
+            },
+            QuestServiceError::AlreadyCompleted => CombineError::QuestAlreadyCompleted,
+            QuestServiceError::SubtasksIncomplete => CombineError::QuestSubtasksIncomplete,
+            QuestServiceError::Validate(e) => CombineError::Validate(e.to_string()),
+        }
+    }
+}
+
+impl From<AchievementServiceError> for CombineError {
+    fn from(value: AchievementServiceError) -> Self {
+        match value {
+            AchievementServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => CombineError::InvalidToken,
+                TokenServiceError::TokenExpired => CombineError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => CombineError::DataMismatch,
+                _ => CombineError::Server,
+            },
+            AchievementServiceError::RepositoryError(v) => match v {
+                RepositoryError::NotFound => CombineError::EntityNotFound,
+                RepositoryError::InvalidData(_) => CombineError::InvalidData,
+                RepositoryError::DatabaseError(_) => CombineError::Server,
+            },
+        }
+    }
+}
+
+impl From<StreakServiceError> for CombineError {
+    fn from(value: StreakServiceError) -> Self {
+        match value {
+            StreakServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => CombineError::InvalidToken,
+                TokenServiceError::TokenExpired => CombineError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => CombineError::DataMismatch,
+                _ => CombineError::Server,
+            },
+            StreakServiceError::RepositoryError(v) => match v {
+                RepositoryError::NotFound => CombineError::EntityNotFound,
+                RepositoryError::InvalidData(_) => CombineError::InvalidData,
+                RepositoryError::DatabaseError(_) => CombineError::Server,
+            },
+            StreakServiceError::Validation(e) => CombineError::Validate(e.to_string()),
+            StreakServiceError::InvalidVacation(e) => CombineError::Validate(e),
+        }
+    }
+}
+
+impl From<GroupServiceError> for CombineError {
+    fn from(value: GroupServiceError) -> Self {
+        match value {
+            GroupServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => CombineError::InvalidToken,
+                TokenServiceError::TokenExpired => CombineError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => CombineError::DataMismatch,
+                _ => CombineError::Server,
+            },
+            GroupServiceError::RepositoryError(v) => match v {
+                RepositoryError::NotFound => CombineError::EntityNotFound,
+                RepositoryError::InvalidData(_) => CombineError::InvalidData,
+                RepositoryError::DatabaseError(_) => CombineError::Server,
+            },
+            GroupServiceError::Forbidden => CombineError::Forbidden,
+            GroupServiceError::AlreadyCompleted => CombineError::GroupMissionAlreadyCompleted,
+            GroupServiceError::Validate(e) => CombineError::Validate(e.to_string()),
+            e @ (GroupServiceError::AlreadyMember
+            | GroupServiceError::GroupFull(_)
+            | GroupServiceError::MissionOverCapacity(_)
+            | GroupServiceError::OwnerCannotLeave
+            | GroupServiceError::InvalidRole) => CombineError::Validate(e.to_string()),
+        }
+    }
+}
+
+impl From<TemplateServiceError> for CombineError {
+    fn from(value: TemplateServiceError) -> Self {
+        match value {
+            TemplateServiceError::AuthError(e) => match e {
+                TokenServiceError::TokenInvalid(_) => CombineError::InvalidToken,
+                TokenServiceError::TokenExpired => CombineError::TokenExpired,
+                TokenServiceError::DataMismatch(_) => CombineError::DataMismatch,
+                _ => CombineError::Server,
+            },
+            TemplateServiceError::RepositoryError(v) => match v {
+                RepositoryError::NotFound => CombineError::EntityNotFound,
+                RepositoryError::InvalidData(_) => CombineError::InvalidData,
+                RepositoryError::DatabaseError(_) => CombineError::Server,
+            },
+            TemplateServiceError::Validate(e) => CombineError::Validate(e.to_string()),
+            TemplateServiceError::Forbidden => CombineError::Forbidden,
+        }
+    }
+}
+
 impl IntoResponse for CombineError {
     fn into_response(self) -> axum::response::Response {
         match self {
@@ -478,27 +2097,67 @@ impl IntoResponse for CombineError {
                 )),
             )
                 .into_response(),
-            Self::OverCap => (
+            Self::OverCap(limit) => (
                 ErrorRes::DAILY_OVER_CAP.0,
                 Json(Error::new(
                     ErrorRes::DAILY_OVER_CAP.1,
-                    ErrorRes::DAILY_OVER_CAP.2,
+                    &format!("{} (limit: {})", ErrorRes::DAILY_OVER_CAP.2, limit),
                 )),
             )
                 .into_response(),
-            Self::Validate(e) => (
-                ErrorRes::VALIDATION.0,
+            Self::NotDue => (
+                ErrorRes::DAILY_NOT_DUE.0,
                 Json(Error::new(
-                    ErrorRes::VALIDATION.1,
-                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
+                    ErrorRes::DAILY_NOT_DUE.1,
+                    ErrorRes::DAILY_NOT_DUE.2,
+                )),
+            )
+                .into_response(),
+            Self::NotQuantitative => (
+                ErrorRes::DAILY_NOT_QUANTITATIVE.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_NOT_QUANTITATIVE.1,
+                    ErrorRes::DAILY_NOT_QUANTITATIVE.2,
+                )),
+            )
+                .into_response(),
+            Self::ProgressRequired => (
+                ErrorRes::DAILY_PROGRESS_REQUIRED.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_PROGRESS_REQUIRED.1,
+                    ErrorRes::DAILY_PROGRESS_REQUIRED.2,
+                )),
+            )
+                .into_response(),
+            Self::AlreadyCompleted => (
+                ErrorRes::DAILY_ALREADY_COMPLETED.0,
+                Json(Error::new(
+                    ErrorRes::DAILY_ALREADY_COMPLETED.1,
+                    ErrorRes::DAILY_ALREADY_COMPLETED.2,
+                )),
+            )
+                .into_response(),
+            Self::QuestAlreadyCompleted => (
+                ErrorRes::QUEST_ALREADY_COMPLETED.0,
+                Json(Error::new(
+                    ErrorRes::QUEST_ALREADY_COMPLETED.1,
+                    ErrorRes::QUEST_ALREADY_COMPLETED.2,
+                )),
+            )
+                .into_response(),
+            Self::QuestSubtasksIncomplete => (
+                ErrorRes::QUEST_SUBTASKS_INCOMPLETE.0,
+                Json(Error::new(
+                    ErrorRes::QUEST_SUBTASKS_INCOMPLETE.1,
+                    ErrorRes::QUEST_SUBTASKS_INCOMPLETE.2,
                 )),
             )
                 .into_response(),
-            Self::ExpOverflow => (
-                ErrorRes::EXP_OVERFLOW.0,
+            Self::Validate(e) => (
+                ErrorRes::VALIDATION.0,
                 Json(Error::new(
-                    ErrorRes::EXP_OVERFLOW.1,
-                    ErrorRes::EXP_OVERFLOW.2,
+                    ErrorRes::VALIDATION.1,
+                    &format!("{}:{}", ErrorRes::VALIDATION.2, e),
                 )),
             )
                 .into_response(),
@@ -515,6 +2174,19 @@ impl IntoResponse for CombineError {
                 Json(Error::new(ErrorRes::SERVER.1, ErrorRes::SERVER.2)),
             )
                 .into_response(),
+            Self::Forbidden => (
+                ErrorRes::FORBIDDEN.0,
+                Json(Error::new(ErrorRes::FORBIDDEN.1, ErrorRes::FORBIDDEN.2)),
+            )
+                .into_response(),
+            Self::GroupMissionAlreadyCompleted => (
+                ErrorRes::GROUP_MISSION_ALREADY_COMPLETED.0,
+                Json(Error::new(
+                    ErrorRes::GROUP_MISSION_ALREADY_COMPLETED.1,
+                    ErrorRes::GROUP_MISSION_ALREADY_COMPLETED.2,
+                )),
+            )
+                .into_response(),
         }
     }
 }
@@ -548,8 +2220,13 @@ impl ErrorRes {
     const ENTITY_NOT_FOUND: (StatusCode, u32, &str) =
         { (StatusCode::NOT_FOUND, 108, "Entity not found") };
 
-    const EXP_OVERFLOW: (StatusCode, u32, &str) =
-        { (StatusCode::BAD_REQUEST, 200, "Exp is fulled") };
+    const INVALID_QUERY: (StatusCode, u32, &str) =
+        { (StatusCode::BAD_REQUEST, 109, "Invalid query") };
+
+    const FORBIDDEN: (StatusCode, u32, &str) = { (StatusCode::FORBIDDEN, 110, "Forbidden") };
+
+    const INVALID_LEVEL_CURVE: (StatusCode, u32, &str) =
+        { (StatusCode::BAD_REQUEST, 201, "Invalid level curve") };
 
     const DAILY_OVER_CAP: (StatusCode, u32, &str) = {
         (
@@ -559,6 +2236,138 @@ impl ErrorRes {
         )
     };
 
+    const DAILY_NOT_DUE: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            301,
+            "The DailyMission is not due today",
+        )
+    };
+
+    const DAILY_NOT_QUANTITATIVE: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            302,
+            "The DailyMission has no target quantity",
+        )
+    };
+
+    const DAILY_PROGRESS_REQUIRED: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            303,
+            "The DailyMission is completed by recording progress",
+        )
+    };
+
+    const DAILY_IMPORT_MALFORMED: (StatusCode, u32, &str) =
+        { (StatusCode::BAD_REQUEST, 304, "The import file is malformed") };
+
+    const DAILY_IMPORT_TOO_MANY_ROWS: (StatusCode, u32, &str) =
+        { (StatusCode::BAD_REQUEST, 305, "Too many rows to import") };
+
+    const DAILY_ALREADY_COMPLETED: (StatusCode, u32, &str) = {
+        (
+            StatusCode::CONFLICT,
+            306,
+            "The DailyMission is already completed today",
+        )
+    };
+
     const USER_ALREADY_EXISTS: (StatusCode, u32, &str) =
         { (StatusCode::BAD_REQUEST, 400, "User already exists") };
+
+    const INVALID_VACATION: (StatusCode, u32, &str) =
+        { (StatusCode::BAD_REQUEST, 500, "Invalid vacation") };
+
+    const QUEST_ALREADY_COMPLETED: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            600,
+            "The Quest is already completed",
+        )
+    };
+
+    const QUEST_SUBTASKS_INCOMPLETE: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            601,
+            "The Quest has incomplete subtasks",
+        )
+    };
+
+    const FRIEND_SELF_REQUEST: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            700,
+            "Cannot send a friend request to yourself",
+        )
+    };
+
+    const FRIEND_ALREADY_EXISTS: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            701,
+            "Already friends or the friend request is already sent",
+        )
+    };
+
+    const FRIEND_REQUEST_REJECTED: (StatusCode, u32, &str) = {
+        (
+            StatusCode::FORBIDDEN,
+            702,
+            "The user does not accept friend requests",
+        )
+    };
+
+    const GROUP_ALREADY_MEMBER: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            800,
+            "The user is already a member of the group",
+        )
+    };
+
+    const GROUP_FULL: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            801,
+            "The number of group members is fulled",
+        )
+    };
+
+    const GROUP_MISSION_OVER_CAP: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            802,
+            "The number of GroupMissions is fulled",
+        )
+    };
+
+    const GROUP_MISSION_ALREADY_COMPLETED: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            803,
+            "The GroupMission is already completed today",
+        )
+    };
+
+    const GROUP_OWNER_CANNOT_LEAVE: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            804,
+            "The owner cannot leave the group",
+        )
+    };
+
+    const GROUP_INVALID_ROLE: (StatusCode, u32, &str) =
+        { (StatusCode::BAD_REQUEST, 805, "The role cannot be changed") };
+
+    const WEBHOOK_OVER_CAP: (StatusCode, u32, &str) = {
+        (
+            StatusCode::BAD_REQUEST,
+            900,
+            "The number of webhooks is fulled",
+        )
+    };
 }
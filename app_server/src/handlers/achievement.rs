@@ -0,0 +1,24 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use domain::service::achievement_service::AchievementService;
+use infrastructure::{
+    repository::achievement_repository_impl::AchievementRepositoryImpl,
+    service::token_service_impl::TokenServiceImpl,
+};
+use sqlx::MySqlPool;
+
+use crate::{error::AchievementError, types::token_warper::TokenWrap};
+
+pub async fn find_all(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, AchievementError> {
+    let service = achievement_service(pool);
+    let achievements = service.find_all(token).await?;
+    Ok((StatusCode::OK, Json(achievements)))
+}
+
+pub(super) fn achievement_service(
+    pool: MySqlPool,
+) -> AchievementService<TokenServiceImpl, AchievementRepositoryImpl> {
+    AchievementService::new(TokenServiceImpl, AchievementRepositoryImpl::new(pool))
+}
@@ -0,0 +1,76 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::{
+        mission_capacity::{CapacityInput, PlanInput, UserPlanInput},
+        user_id::UserId,
+    },
+    service::admin_service::AdminService,
+};
+use infrastructure::{
+    repository::admin_repository_impl::AdminRepositoryImpl,
+    service::{level_convert_impl::LevelConvertImpl, token_service_impl::TokenServiceImpl},
+};
+use sqlx::MySqlPool;
+
+use crate::{error::AdminError, types::token_warper::TokenWrap};
+
+pub async fn save_plan(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(plan_id): Path<String>,
+    Json(payload): Json<PlanInput>,
+) -> Result<impl IntoResponse, AdminError> {
+    let service = admin_service(pool);
+    service.save_plan(token, plan_id, payload).await?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn update_user_plan(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(user_id): Path<String>,
+    Json(payload): Json<UserPlanInput>,
+) -> Result<impl IntoResponse, AdminError> {
+    let service = admin_service(pool);
+    service
+        .update_user_plan(token, UserId(user_id), payload)
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn update_user_capacity(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(user_id): Path<String>,
+    Json(payload): Json<CapacityInput>,
+) -> Result<impl IntoResponse, AdminError> {
+    let service = admin_service(pool);
+    service
+        .update_user_capacity(token, UserId(user_id), payload)
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn reload_level_curve(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, AdminError> {
+    let service = admin_service(pool);
+    let curve = service.reload_level_curve(token).await?;
+    Ok((StatusCode::OK, Json(curve.as_ref().clone())))
+}
+
+fn admin_service(
+    pool: MySqlPool,
+) -> AdminService<TokenServiceImpl, AdminRepositoryImpl, LevelConvertImpl> {
+    AdminService::new(
+        TokenServiceImpl,
+        AdminRepositoryImpl::new(pool),
+        LevelConvertImpl,
+    )
+}
@@ -0,0 +1,70 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use domain::service::calendar_service::CalendarService;
+use infrastructure::{
+    repository::calendar_repository_impl::CalendarRepositoryImpl,
+    service::{token_service_impl::TokenServiceImpl, uuid_service_impl::UUIDServiceImpl},
+};
+use sqlx::MySqlPool;
+
+use crate::{error::CalendarError, types::token_warper::TokenWrap};
+
+pub async fn find_feed(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, CalendarError> {
+    let service = calendar_service(pool);
+    let feed = service.find_feed(token).await?;
+    Ok((StatusCode::OK, Json(feed)))
+}
+
+pub async fn regenerate_feed(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, CalendarError> {
+    let service = calendar_service(pool);
+    let feed = service.regenerate(token).await?;
+    Ok((StatusCode::CREATED, Json(feed)))
+}
+
+pub async fn revoke_feed(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, CalendarError> {
+    let service = calendar_service(pool);
+    service.revoke(token).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// カレンダーアプリから購読されるため、認証Cookieの代わりにURLのfeed_tokenで認可する
+pub async fn render_feed(
+    State(pool): State<MySqlPool>,
+    Path(feed_token): Path<String>,
+) -> Result<impl IntoResponse, CalendarError> {
+    let service = calendar_service(pool);
+    // カレンダーアプリによっては拡張子が必要なため、末尾の.icsを許容する
+    let feed_token = feed_token
+        .strip_suffix(".ics")
+        .unwrap_or(&feed_token)
+        .to_string();
+    let ics = service.render(feed_token).await?;
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        ics,
+    ))
+}
+
+fn calendar_service(
+    pool: MySqlPool,
+) -> CalendarService<TokenServiceImpl, UUIDServiceImpl, CalendarRepositoryImpl> {
+    CalendarService::new(
+        TokenServiceImpl,
+        UUIDServiceImpl,
+        CalendarRepositoryImpl::new(pool),
+    )
+}
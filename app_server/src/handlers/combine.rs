@@ -1,16 +1,50 @@
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::{
+        daily_mission_id::DailyMissionId,
+        exp_reward::{ExpReward, RewardedCompletion, RewardedProgress},
+        exp_transaction::NewExpTransaction,
+        group_id::GroupId,
+        group_mission::RewardedGroupCompletion,
+        group_mission_id::GroupMissionId,
+        mission_completion::MissionCompletion,
+        mission_progress::ProgressInput,
+        mission_template_id::MissionTemplateId,
+        quest_id::QuestId,
+        token::Token,
+    },
+    service::{
+        achievement_service::AchievementService, streak_service::StreakService,
+        user_exp_service::UserExpService,
+    },
 };
-use domain::entity::daily_mission_id::DailyMissionId;
 use http::StatusCode;
-use sqlx::MySqlPool;
+use infrastructure::{
+    repository::{
+        achievement_repository_impl::AchievementRepositoryImpl,
+        streak_repository_impl::StreakRepositoryImpl,
+        user_exp_repository_impl::UserExpRepositoryImpl,
+    },
+    service::{level_convert_impl::LevelConvertImpl, token_service_impl::TokenServiceImpl},
+};
+use sqlx::{MySql, MySqlPool, Transaction};
 
 use crate::{error::CombineError, types::token_warper::TokenWrap};
 
-use super::{daily_mission::daily_mission_service, exp::user_exp_service};
+use super::{
+    achievement::achievement_service, daily_mission::daily_mission_service, exp::user_exp_service,
+    group::group_service, quest::quest_service, streak::streak_service, template::template_service,
+    webhook::event_outbox_service,
+};
 
-static ADDITIONAL_POINT: i64 = 2;
+// クエストは一度しか達成できないため、デイリーミッションより多くの経験値を付与する
+static QUEST_POINT: i64 = 10;
+// グループミッションの完了で付与する経験値(全員が完了した時のボーナスは別に配布する)
+static GROUP_MISSION_POINT: i64 = 2;
 
 pub(crate) async fn set_complete_with_add_exp(
     TokenWrap(token): TokenWrap,
@@ -19,21 +53,556 @@ pub(crate) async fn set_complete_with_add_exp(
 ) -> Result<impl IntoResponse, CombineError> {
     let daily_service = daily_mission_service(pool.clone());
     let exp_service = user_exp_service(pool.clone());
+    let streak_service = streak_service(pool.clone());
+    let achievement_service = achievement_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
 
     // トランザクション開始
     let mut transaction = pool.begin().await.map_err(|_| CombineError::Server)?;
+    // 同じユーザーの完了処理を直列化する
+    streak_service
+        .lock_user(&mut transaction, token.clone())
+        .await?;
     // 1.デイリーミッションのis_completeをTRUEに変更
-    daily_service
+    let mut completion = daily_service
         .set_complete_true(&mut transaction, token.clone(), DailyMissionId(mission_id))
         .await?;
-    // ユーザーの経験値を上昇させる
-    exp_service
-        .add_experience(&mut transaction, token, ADDITIONAL_POINT)
+    // 2.連続達成記録を更新
+    let streak = streak_service
+        .record_completion(&mut transaction, token.clone(), &completion)
+        .await?;
+    // 3.難易度と連続達成日数、完了した時刻から経験値を計算する
+    daily_service
+        .award_exp(token.clone(), &mut completion, &streak)
+        .await?;
+    // 4.経験値の台帳に追記し、ユーザーの経験値を上昇させる
+    let mut reward = exp_service
+        .add_experience(
+            &mut transaction,
+            token.clone(),
+            NewExpTransaction::mission_complete(&completion),
+        )
+        .await?;
+    // 実際に反映された経験値を完了記録に保存する(取り消しで戻す量と一致させる)
+    daily_service
+        .save_exp_awarded(
+            &mut transaction,
+            token.clone(),
+            &mut completion,
+            reward.exp_gained,
+        )
+        .await?;
+    // 5.その日のミッションをすべて完了した場合は全完了ボーナスを付与する
+    award_all_clear_bonus(
+        &mut transaction,
+        &token,
+        &exp_service,
+        &streak_service,
+        &completion,
+        &mut reward,
+    )
+    .await?;
+    // 6.条件を満たした実績を解除し、ボーナス経験値を付与する
+    unlock_achievements(
+        &mut transaction,
+        &token,
+        &exp_service,
+        &achievement_service,
+        &mut reward,
+    )
+    .await?;
+    // 7.完了と経験値の増減、実績の解除のイベントの配信を同じトランザクションで記録する
+    let mut events = vec![
+        daily_service
+            .completed_event(token.clone(), &completion)
+            .await?,
+    ];
+    events.extend(exp_service.reward_events(token.clone(), &reward)?);
+    events.extend(achievement_service.unlocked_events(token, &reward.unlocked_achievements)?);
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| CombineError::Server)?;
+    // コミット
+    transaction
+        .commit()
+        .await
+        .map_err(|_| CombineError::Transaction)?;
+    // 8.接続しているクライアントにイベントを発行する
+    outbox.publish(events);
+    Ok((
+        StatusCode::OK,
+        Json(RewardedCompletion { completion, reward }),
+    ))
+}
+
+pub(crate) async fn undo_complete_with_sub_exp(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(mission_id): Path<String>,
+) -> Result<impl IntoResponse, CombineError> {
+    let daily_service = daily_mission_service(pool.clone());
+    let exp_service = user_exp_service(pool.clone());
+    let streak_service = streak_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+
+    // トランザクション開始
+    let mut transaction = pool.begin().await.map_err(|_| CombineError::Server)?;
+    // 同じユーザーの完了処理を直列化する
+    streak_service
+        .lock_user(&mut transaction, token.clone())
+        .await?;
+    // 1.今日の完了記録を削除
+    let completion = daily_service
+        .undo_complete(&mut transaction, token.clone(), DailyMissionId(mission_id))
+        .await?;
+    // 2.連続達成記録を再計算
+    streak_service
+        .revert_completion(&mut transaction, token.clone(), &completion)
+        .await?;
+    // 3.完了時に付与した経験値を戻す(台帳には打ち消しの記録を追記する)
+    let mut reward = exp_service
+        .add_experience(
+            &mut transaction,
+            token.clone(),
+            NewExpTransaction::mission_undo(&completion),
+        )
+        .await?;
+    // 4.全完了でなくなった場合は、その日の全完了ボーナスも戻す
+    if let Some(bonus) = streak_service
+        .revert_all_clear_bonus(&mut transaction, token.clone(), completion.date)
+        .await?
+    {
+        let bonus_reward = exp_service
+            .add_experience(
+                &mut transaction,
+                token.clone(),
+                NewExpTransaction::all_clear_bonus_undo(&bonus),
+            )
+            .await?;
+        reward.merge(bonus_reward);
+    }
+    // 5.完了の取り消しと経験値の増減のイベントの配信を同じトランザクションで記録する
+    let mut events = vec![daily_service.uncompleted_event(token.clone(), &completion)?];
+    events.extend(exp_service.reward_events(token, &reward)?);
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| CombineError::Server)?;
+    // コミット
+    transaction
+        .commit()
+        .await
+        .map_err(|_| CombineError::Transaction)?;
+    // 6.接続しているクライアントにイベントを発行する
+    outbox.publish(events);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) async fn add_progress_with_add_exp(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(mission_id): Path<String>,
+    Json(payload): Json<ProgressInput>,
+) -> Result<impl IntoResponse, CombineError> {
+    let daily_service = daily_mission_service(pool.clone());
+    let exp_service = user_exp_service(pool.clone());
+    let streak_service = streak_service(pool.clone());
+    let achievement_service = achievement_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+
+    // トランザクション開始
+    let mut transaction = pool.begin().await.map_err(|_| CombineError::Server)?;
+    // 同じユーザーの完了処理を直列化する
+    streak_service
+        .lock_user(&mut transaction, token.clone())
+        .await?;
+    // 1.今日の進捗を加算し、目標に達した場合はミッションを完了にする
+    let (progress, completion) = daily_service
+        .add_progress(
+            &mut transaction,
+            token.clone(),
+            DailyMissionId(mission_id),
+            payload,
+        )
+        .await?;
+    // 目標に達した時のみ連続達成記録の更新と経験値の上昇を行う
+    let mut reward = None;
+    let mut completed = None;
+    if let Some(mut completion) = completion {
+        // 2.連続達成記録を更新
+        let streak = streak_service
+            .record_completion(&mut transaction, token.clone(), &completion)
+            .await?;
+        // 3.難易度と連続達成日数、完了した時刻から経験値を計算する
+        daily_service
+            .award_exp(token.clone(), &mut completion, &streak)
+            .await?;
+        // 4.経験値の台帳に追記し、ユーザーの経験値を上昇させる
+        let mut completed_reward = exp_service
+            .add_experience(
+                &mut transaction,
+                token.clone(),
+                NewExpTransaction::mission_complete(&completion),
+            )
+            .await?;
+        // 実際に反映された経験値を完了記録に保存する(取り消しで戻す量と一致させる)
+        daily_service
+            .save_exp_awarded(
+                &mut transaction,
+                token.clone(),
+                &mut completion,
+                completed_reward.exp_gained,
+            )
+            .await?;
+        // 5.その日のミッションをすべて完了した場合は全完了ボーナスを付与する
+        award_all_clear_bonus(
+            &mut transaction,
+            &token,
+            &exp_service,
+            &streak_service,
+            &completion,
+            &mut completed_reward,
+        )
+        .await?;
+        // 6.条件を満たした実績を解除し、ボーナス経験値を付与する
+        unlock_achievements(
+            &mut transaction,
+            &token,
+            &exp_service,
+            &achievement_service,
+            &mut completed_reward,
+        )
+        .await?;
+        reward = Some(completed_reward);
+        completed = Some(completion);
+    }
+    // 7.完了と経験値の増減、実績の解除のイベントの配信を同じトランザクションで記録する
+    let mut events = Vec::new();
+    if let Some(completion) = &completed {
+        events.push(
+            daily_service
+                .completed_event(token.clone(), completion)
+                .await?,
+        );
+    }
+    if let Some(reward) = &reward {
+        events.extend(exp_service.reward_events(token.clone(), reward)?);
+        events.extend(achievement_service.unlocked_events(token, &reward.unlocked_achievements)?);
+    }
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| CombineError::Server)?;
+    // コミット
+    transaction
+        .commit()
+        .await
+        .map_err(|_| CombineError::Transaction)?;
+    // 8.接続しているクライアントにイベントを発行する
+    outbox.publish(events);
+    Ok((StatusCode::OK, Json(RewardedProgress { progress, reward })))
+}
+
+pub(crate) async fn complete_quest_with_add_exp(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(quest_id): Path<String>,
+) -> Result<impl IntoResponse, CombineError> {
+    let quest_service = quest_service(pool.clone());
+    let exp_service = user_exp_service(pool.clone());
+    let achievement_service = achievement_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+
+    // トランザクション開始
+    let mut transaction = pool.begin().await.map_err(|_| CombineError::Server)?;
+    // 1.クエストを達成にする(達成済みの場合はエラーになるため、経験値は一度だけ付与される)
+    let quest_id = QuestId(quest_id);
+    quest_service
+        .set_complete_true(&mut transaction, token.clone(), quest_id.clone())
+        .await?;
+    // 2.経験値の台帳に追記し、ユーザーの経験値を上昇させる
+    let mut reward = exp_service
+        .add_experience(
+            &mut transaction,
+            token.clone(),
+            NewExpTransaction::quest_complete(&quest_id, QUEST_POINT),
+        )
+        .await?;
+    // 3.レベルが上がって条件を満たした実績を解除し、ボーナス経験値を付与する
+    unlock_achievements(
+        &mut transaction,
+        &token,
+        &exp_service,
+        &achievement_service,
+        &mut reward,
+    )
+    .await?;
+    // 4.経験値の増減と実績の解除のイベントの配信を同じトランザクションで記録する
+    let mut events = exp_service.reward_events(token.clone(), &reward)?;
+    events.extend(achievement_service.unlocked_events(token, &reward.unlocked_achievements)?);
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| CombineError::Server)?;
+    // コミット
+    transaction
+        .commit()
+        .await
+        .map_err(|_| CombineError::Transaction)?;
+    // 5.接続しているクライアントにイベントを発行する
+    outbox.publish(events);
+    Ok((StatusCode::OK, Json(reward)))
+}
+
+pub(crate) async fn complete_group_mission_with_add_exp(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path((group_id, mission_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, CombineError> {
+    let group_service = group_service(pool.clone());
+    let exp_service = user_exp_service(pool.clone());
+    let streak_service = streak_service(pool.clone());
+    let achievement_service = achievement_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+
+    // トランザクション開始
+    let mut transaction = pool.begin().await.map_err(|_| CombineError::Server)?;
+    // 同じユーザーの完了処理を直列化する
+    streak_service
+        .lock_user(&mut transaction, token.clone())
+        .await?;
+    // 1.グループミッションを完了にし、全員が完了した場合はその日のボーナスを記録する
+    let (completion, progress, bonus) = group_service
+        .complete_mission(
+            &mut transaction,
+            token.clone(),
+            GroupId(group_id),
+            GroupMissionId(mission_id),
+            GROUP_MISSION_POINT,
+        )
+        .await?;
+    // 2.経験値の台帳に追記し、ユーザーの経験値を上昇させる
+    let mut reward = exp_service
+        .add_experience(
+            &mut transaction,
+            token.clone(),
+            NewExpTransaction::group_mission_complete(&completion),
+        )
         .await?;
+    // 3.全員が完了した場合はメンバー全員にボーナス経験値を配布する
+    // 経験値が上限に達しているメンバーには付与しない
+    let mut group_bonus = None;
+    let mut member_rewards = Vec::new();
+    if let Some(bonus) = &bonus {
+        for member_id in &bonus.members {
+            let new_transaction =
+                NewExpTransaction::group_bonus(&bonus.mission_id, bonus.exp_awarded);
+            if *member_id == completion.user_id {
+                if add_bonus_exp(
+                    &mut transaction,
+                    &token,
+                    &exp_service,
+                    new_transaction,
+                    &mut reward,
+                )
+                .await?
+                {
+                    group_bonus = Some(bonus.exp_awarded);
+                }
+                continue;
+            }
+            let member_reward = exp_service
+                .add_experience_to(&mut transaction, member_id, new_transaction)
+                .await?;
+            if member_reward.exp_gained > 0 {
+                member_rewards.push((member_id.clone(), member_reward));
+            }
+        }
+    }
+    // 4.条件を満たした実績を解除し、ボーナス経験値を付与する
+    // 他のメンバーの実績はそれぞれの次の完了時に判定される
+    unlock_achievements(
+        &mut transaction,
+        &token,
+        &exp_service,
+        &achievement_service,
+        &mut reward,
+    )
+    .await?;
+    // 5.メンバー全員の経験値の増減と実績の解除のイベントの配信を同じトランザクションで記録する
+    let mut events = exp_service.reward_events(token.clone(), &reward)?;
+    for (member_id, member_reward) in member_rewards {
+        events.extend(exp_service.reward_events_of(member_id, &member_reward));
+    }
+    events.extend(achievement_service.unlocked_events(token, &reward.unlocked_achievements)?);
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| CombineError::Server)?;
     // コミット
     transaction
         .commit()
         .await
         .map_err(|_| CombineError::Transaction)?;
-    Ok(StatusCode::OK)
+    // 6.接続しているクライアントにイベントを発行する
+    outbox.publish(events);
+    Ok((
+        StatusCode::OK,
+        Json(RewardedGroupCompletion {
+            completion,
+            progress,
+            reward,
+            group_bonus,
+        }),
+    ))
+}
+
+pub(crate) async fn clone_template_with_create(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(template_id): Path<String>,
+) -> Result<impl IntoResponse, CombineError> {
+    let template_service = template_service(pool.clone());
+    let daily_service = daily_mission_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+    // 1.カタログのテンプレートからミッションの内容を作る
+    let input = template_service
+        .instantiate_curated(token.clone(), MissionTemplateId(template_id))
+        .await?;
+    // 2.通常のミッションの作成と同じく、上限の確認と保存を同じトランザクションで行う
+    let mut transaction = pool.begin().await.map_err(|_| CombineError::Server)?;
+    let mission_id = daily_service
+        .create(&mut transaction, token.clone(), input)
+        .await?;
+    let events = daily_service
+        .created_events(&mut transaction, token, std::slice::from_ref(&mission_id))
+        .await?;
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| CombineError::Server)?;
+    transaction
+        .commit()
+        .await
+        .map_err(|_| CombineError::Transaction)?;
+    outbox.publish(events);
+    Ok((StatusCode::CREATED, Json(mission_id)))
+}
+
+pub(crate) async fn clone_shared_template_with_create(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(share_code): Path<String>,
+) -> Result<impl IntoResponse, CombineError> {
+    let template_service = template_service(pool.clone());
+    let daily_service = daily_mission_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+    // 1.共有コードのテンプレートからミッションの内容を作る
+    let input = template_service
+        .instantiate_shared(token.clone(), share_code)
+        .await?;
+    // 2.通常のミッションの作成と同じく、上限の確認と保存を同じトランザクションで行う
+    let mut transaction = pool.begin().await.map_err(|_| CombineError::Server)?;
+    let mission_id = daily_service
+        .create(&mut transaction, token.clone(), input)
+        .await?;
+    let events = daily_service
+        .created_events(&mut transaction, token, std::slice::from_ref(&mission_id))
+        .await?;
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| CombineError::Server)?;
+    transaction
+        .commit()
+        .await
+        .map_err(|_| CombineError::Transaction)?;
+    outbox.publish(events);
+    Ok((StatusCode::CREATED, Json(mission_id)))
+}
+
+// 条件を満たした実績を解除し、ボーナス経験値をrewardにまとめる
+// ボーナス経験値でレベルが上がると新たに条件を満たす実績があるため、解除されなくなるまで繰り返す
+async fn unlock_achievements(
+    transaction: &mut Transaction<'_, MySql>,
+    token: &Token,
+    exp_service: &UserExpService<UserExpRepositoryImpl, LevelConvertImpl, TokenServiceImpl>,
+    achievement_service: &AchievementService<TokenServiceImpl, AchievementRepositoryImpl>,
+    reward: &mut ExpReward,
+) -> Result<(), CombineError> {
+    loop {
+        let unlocked = achievement_service
+            .evaluate(transaction, token.clone(), reward.new_level)
+            .await?;
+        if unlocked.is_empty() {
+            return Ok(());
+        }
+        for achievement in unlocked {
+            // 経験値が上限に達している場合はボーナスを付与せずに実績の解除だけ行う
+            if achievement.bonus_exp > 0 {
+                add_bonus_exp(
+                    transaction,
+                    token,
+                    exp_service,
+                    NewExpTransaction::achievement_bonus(&achievement),
+                    reward,
+                )
+                .await?;
+            }
+            reward.unlocked_achievements.push(achievement);
+        }
+    }
+}
+
+// 完了したミッションがその日の最後のミッションだった場合に全完了ボーナスを付与し、rewardにまとめる
+// 付与の記録は1日1行のため、取り消して再び完了しても二重には付与されない
+async fn award_all_clear_bonus(
+    transaction: &mut Transaction<'_, MySql>,
+    token: &Token,
+    exp_service: &UserExpService<UserExpRepositoryImpl, LevelConvertImpl, TokenServiceImpl>,
+    streak_service: &StreakService<TokenServiceImpl, StreakRepositoryImpl>,
+    completion: &MissionCompletion,
+    reward: &mut ExpReward,
+) -> Result<(), CombineError> {
+    let Some(mut bonus) = streak_service
+        .find_all_clear_bonus(transaction, token.clone(), completion.date)
+        .await?
+    else {
+        return Ok(());
+    };
+    // 経験値を先に加算し、実際に加算した量だけを記録する(上限に達していた場合は記録しない)
+    let bonus_reward = exp_service
+        .add_experience(
+            transaction,
+            token.clone(),
+            NewExpTransaction::all_clear_bonus(&bonus),
+        )
+        .await?;
+    bonus.exp_awarded = bonus_reward.exp_gained;
+    reward.merge(bonus_reward);
+    if bonus.exp_awarded > 0 {
+        streak_service
+            .save_all_clear_bonus(transaction, token.clone(), &bonus)
+            .await?;
+        reward.all_clear_bonus = Some(bonus.exp_awarded);
+    }
+    Ok(())
+}
+
+// ボーナス経験値を加算してrewardにまとめる
+// 経験値が上限に達している場合は付与されない(付与した場合はtrueを返す)
+async fn add_bonus_exp(
+    transaction: &mut Transaction<'_, MySql>,
+    token: &Token,
+    exp_service: &UserExpService<UserExpRepositoryImpl, LevelConvertImpl, TokenServiceImpl>,
+    new_transaction: NewExpTransaction,
+    reward: &mut ExpReward,
+) -> Result<bool, CombineError> {
+    let bonus = exp_service
+        .add_experience(transaction, token.clone(), new_transaction)
+        .await?;
+    let applied = bonus.exp_gained > 0;
+    reward.merge(bonus);
+    Ok(applied)
 }
@@ -1,28 +1,50 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use domain::{
-    entity::{daily_mission_id::DailyMissionId, daily_mission_input::DailyMissionInput},
+    entity::{
+        daily_mission_id::DailyMissionId, daily_mission_input::DailyMissionInput,
+        history_query::HistoryQuery,
+    },
     service::daily_mission_service::DailyMissionService,
 };
 use infrastructure::{
     repository::daily_mission_repository_impl::DailyMissionRepositoryImpl,
-    service::{token_service_impl::TokenServiceImpl, uuid_service_impl::UUIDServiceImpl},
+    service::{
+        event_publisher_impl::EventPublisherImpl, token_service_impl::TokenServiceImpl,
+        uuid_service_impl::UUIDServiceImpl,
+    },
 };
 use sqlx::MySqlPool;
 
 use crate::{error::DailyError, types::token_warper::TokenWrap};
 
+use super::webhook::event_outbox_service;
+
 pub async fn create(
     TokenWrap(token): TokenWrap,
     State(pool): State<MySqlPool>,
     Json(mission_payload): Json<DailyMissionInput>,
 ) -> Result<impl IntoResponse, DailyError> {
-    let service = daily_mission_service(pool);
-    service.create(token, mission_payload).await?;
+    let service = daily_mission_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+    // 上限の確認と保存、イベントの配信の記録を同じトランザクションで行う
+    let mut transaction = pool.begin().await.map_err(|_| DailyError::Server)?;
+    let mission_id = service
+        .create(&mut transaction, token.clone(), mission_payload)
+        .await?;
+    let events = service
+        .created_events(&mut transaction, token, &[mission_id])
+        .await?;
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| DailyError::Server)?;
+    transaction.commit().await.map_err(|_| DailyError::Server)?;
+    outbox.publish(events);
     Ok(StatusCode::CREATED)
 }
 
@@ -70,12 +92,41 @@ pub async fn delete(
     Ok(StatusCode::NO_CONTENT)
 }
 
+pub async fn history_one(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(mission_id): Path<String>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<impl IntoResponse, DailyError> {
+    let service = daily_mission_service(pool);
+    let history = service
+        .history(token, Some(DailyMissionId(mission_id)), query)
+        .await?;
+    Ok((StatusCode::OK, Json(history)))
+}
+
+pub async fn history_all(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<impl IntoResponse, DailyError> {
+    let service = daily_mission_service(pool);
+    let history = service.history(token, None, query).await?;
+    Ok((StatusCode::OK, Json(history)))
+}
+
 pub(super) fn daily_mission_service(
     pool: MySqlPool,
-) -> DailyMissionService<TokenServiceImpl, UUIDServiceImpl, DailyMissionRepositoryImpl> {
+) -> DailyMissionService<
+    TokenServiceImpl,
+    UUIDServiceImpl,
+    DailyMissionRepositoryImpl,
+    EventPublisherImpl,
+> {
     DailyMissionService::new(
         TokenServiceImpl,
         UUIDServiceImpl,
         DailyMissionRepositoryImpl::new(pool),
+        EventPublisherImpl,
     )
 }
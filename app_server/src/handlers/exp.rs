@@ -1,5 +1,10 @@
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
-use domain::service::user_exp_service::UserExpService;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{entity::exp_transaction::ExpHistoryQuery, service::user_exp_service::UserExpService};
 use infrastructure::{
     repository::user_exp_repository_impl::UserExpRepositoryImpl,
     service::{level_convert_impl::LevelConvertImpl, token_service_impl::TokenServiceImpl},
@@ -17,7 +22,23 @@ pub async fn find(
     Ok((StatusCode::OK, Json(exp_with_level)))
 }
 
-pub(super) fn user_exp_service(
+pub async fn levels(State(pool): State<MySqlPool>) -> Result<impl IntoResponse, ExpError> {
+    let service = user_exp_service(pool);
+    let curve = service.level_curve()?;
+    Ok((StatusCode::OK, Json(curve.as_ref().clone())))
+}
+
+pub async fn history(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<ExpHistoryQuery>,
+) -> Result<impl IntoResponse, ExpError> {
+    let service = user_exp_service(pool);
+    let history = service.history(token, query).await?;
+    Ok((StatusCode::OK, Json(history)))
+}
+
+pub(crate) fn user_exp_service(
     pool: MySqlPool,
 ) -> UserExpService<UserExpRepositoryImpl, LevelConvertImpl, TokenServiceImpl> {
     UserExpService::new(
@@ -0,0 +1,126 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::{friend::FriendRequestInput, user_id::UserId},
+    service::friend_service::FriendService,
+};
+use infrastructure::{
+    repository::friend_repository_impl::FriendRepositoryImpl,
+    service::token_service_impl::TokenServiceImpl,
+};
+use sqlx::MySqlPool;
+
+use crate::{error::FriendError, types::token_warper::TokenWrap};
+
+pub async fn find_friends(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool);
+    let friends = service.find_friends(token).await?;
+    Ok((StatusCode::OK, Json(friends)))
+}
+
+pub async fn remove_friend(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(friend_id): Path<String>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| FriendError::Server)?;
+    service
+        .remove_friend(&mut tx, token, UserId(friend_id))
+        .await?;
+    tx.commit().await.map_err(|_| FriendError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn find_requests(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool);
+    let requests = service.find_requests(token).await?;
+    Ok((StatusCode::OK, Json(requests)))
+}
+
+pub async fn send_request(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(payload): Json<FriendRequestInput>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| FriendError::Server)?;
+    let result = service.send_request(&mut tx, token, payload).await?;
+    tx.commit().await.map_err(|_| FriendError::Server)?;
+    Ok((StatusCode::CREATED, Json(result)))
+}
+
+pub async fn accept_request(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| FriendError::Server)?;
+    service
+        .accept_request(&mut tx, token, UserId(user_id))
+        .await?;
+    tx.commit().await.map_err(|_| FriendError::Server)?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn decline_request(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| FriendError::Server)?;
+    service
+        .decline_request(&mut tx, token, UserId(user_id))
+        .await?;
+    tx.commit().await.map_err(|_| FriendError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn find_blocked(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool);
+    let blocked = service.find_blocked(token).await?;
+    Ok((StatusCode::OK, Json(blocked)))
+}
+
+pub async fn block(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| FriendError::Server)?;
+    service.block(&mut tx, token, UserId(user_id)).await?;
+    tx.commit().await.map_err(|_| FriendError::Server)?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn unblock(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, FriendError> {
+    let service = friend_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| FriendError::Server)?;
+    service.unblock(&mut tx, token, UserId(user_id)).await?;
+    tx.commit().await.map_err(|_| FriendError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn friend_service(pool: MySqlPool) -> FriendService<TokenServiceImpl, FriendRepositoryImpl> {
+    FriendService::new(TokenServiceImpl, FriendRepositoryImpl::new(pool))
+}
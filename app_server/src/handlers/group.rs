@@ -0,0 +1,211 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::{
+        group::{GroupInput, GroupInviteInput, GroupRoleInput},
+        group_id::GroupId,
+        group_mission::GroupMissionInput,
+        group_mission_id::GroupMissionId,
+        user_id::UserId,
+    },
+    service::group_service::GroupService,
+};
+use infrastructure::{
+    repository::group_repository_impl::GroupRepositoryImpl,
+    service::{token_service_impl::TokenServiceImpl, uuid_service_impl::UUIDServiceImpl},
+};
+use sqlx::MySqlPool;
+
+use crate::{error::GroupError, types::token_warper::TokenWrap};
+
+pub async fn create(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(payload): Json<GroupInput>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service.create(&mut tx, token, payload).await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::CREATED)
+}
+
+pub async fn get_all(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool);
+    let groups = service.find_all(token).await?;
+    Ok((StatusCode::OK, Json(groups)))
+}
+
+pub async fn get_one(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool);
+    let group = service.find_by_id(token, GroupId(group_id)).await?;
+    Ok((StatusCode::OK, Json(group)))
+}
+
+pub async fn delete(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service.delete(&mut tx, token, GroupId(group_id)).await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn find_members(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool);
+    let members = service.find_members(token, GroupId(group_id)).await?;
+    Ok((StatusCode::OK, Json(members)))
+}
+
+pub async fn update_role(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path((group_id, user_id)): Path<(String, String)>,
+    Json(payload): Json<GroupRoleInput>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service
+        .update_role(&mut tx, token, GroupId(group_id), UserId(user_id), payload)
+        .await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn remove_member(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path((group_id, user_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service
+        .remove_member(&mut tx, token, GroupId(group_id), UserId(user_id))
+        .await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn invite(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+    Json(payload): Json<GroupInviteInput>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service
+        .invite(&mut tx, token, GroupId(group_id), payload)
+        .await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::CREATED)
+}
+
+pub async fn find_invites(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool);
+    let invites = service.find_invites(token).await?;
+    Ok((StatusCode::OK, Json(invites)))
+}
+
+pub async fn accept_invite(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service
+        .accept_invite(&mut tx, token, GroupId(group_id))
+        .await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn decline_invite(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service
+        .decline_invite(&mut tx, token, GroupId(group_id))
+        .await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn create_mission(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+    Json(payload): Json<GroupMissionInput>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service
+        .create_mission(&mut tx, token, GroupId(group_id), payload)
+        .await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::CREATED)
+}
+
+pub async fn find_missions(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(group_id): Path<String>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool);
+    let missions = service.find_missions(token, GroupId(group_id)).await?;
+    Ok((StatusCode::OK, Json(missions)))
+}
+
+pub async fn delete_mission(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path((group_id, mission_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, GroupError> {
+    let service = group_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| GroupError::Server)?;
+    service
+        .delete_mission(
+            &mut tx,
+            token,
+            GroupId(group_id),
+            GroupMissionId(mission_id),
+        )
+        .await?;
+    tx.commit().await.map_err(|_| GroupError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(super) fn group_service(
+    pool: MySqlPool,
+) -> GroupService<TokenServiceImpl, UUIDServiceImpl, GroupRepositoryImpl> {
+    GroupService::new(
+        TokenServiceImpl,
+        UUIDServiceImpl,
+        GroupRepositoryImpl::new(pool),
+    )
+}
@@ -0,0 +1,36 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::leaderboard::LeaderboardQuery, service::leaderboard_service::LeaderboardService,
+};
+use infrastructure::{
+    repository::leaderboard_repository_impl::LeaderboardRepositoryImpl,
+    service::{level_convert_impl::LevelConvertImpl, token_service_impl::TokenServiceImpl},
+};
+use sqlx::MySqlPool;
+
+use crate::{error::LeaderboardError, types::token_warper::TokenWrap};
+
+pub async fn find(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<LeaderboardQuery>,
+) -> Result<impl IntoResponse, LeaderboardError> {
+    let service = leaderboard_service(pool);
+    let leaderboard = service.find(token, query).await?;
+    Ok((StatusCode::OK, Json(leaderboard)))
+}
+
+fn leaderboard_service(
+    pool: MySqlPool,
+) -> LeaderboardService<TokenServiceImpl, LeaderboardRepositoryImpl, LevelConvertImpl> {
+    LeaderboardService::new(
+        TokenServiceImpl,
+        LeaderboardRepositoryImpl::new(pool),
+        LevelConvertImpl,
+    )
+}
@@ -0,0 +1,39 @@
+use axum::response::{
+    sse::{Event, KeepAlive, Sse},
+    IntoResponse,
+};
+use domain::service::{
+    event_subscriber::EventSubscription, live_event_service::LiveEventService,
+    service_error::subscription_error::SubscriptionError,
+};
+use futures_util::stream;
+use infrastructure::service::{
+    event_publisher_impl::EventPublisherImpl, token_service_impl::TokenServiceImpl,
+};
+
+use crate::{error::LiveEventError, types::token_warper::TokenWrap};
+
+/// ログインしているユーザーのドメインイベントをServer-Sent Eventsで送り続ける
+/// イベントはmessageとして`{"type": ..., "data": ...}`の形で送る
+/// 処理が遅れてイベントを取りこぼした場合はresyncを送るため、クライアントは最新の状態を取得し直す
+pub async fn stream(TokenWrap(token): TokenWrap) -> Result<impl IntoResponse, LiveEventError> {
+    let service = live_event_service();
+    let subscription = service.subscribe(token)?;
+    let events = stream::unfold(subscription, |mut subscription| async move {
+        let event = match subscription.recv().await {
+            Ok(event) => Event::default().json_data(&event),
+            Err(SubscriptionError::Lagged(skipped)) => {
+                Ok(Event::default().event("resync").data(skipped.to_string()))
+            }
+            // 配信元が終了した場合は接続を閉じる(クライアントは自動で再接続する)
+            Err(SubscriptionError::Closed) => return None,
+        };
+        Some((event, subscription))
+    });
+    // プロキシに接続を切られないよう、イベントがない間も定期的にコメントを送る
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+fn live_event_service() -> LiveEventService<TokenServiceImpl, EventPublisherImpl> {
+    LiveEventService::new(TokenServiceImpl, EventPublisherImpl)
+}
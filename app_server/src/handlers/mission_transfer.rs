@@ -0,0 +1,113 @@
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use bytes::Bytes;
+use domain::{
+    entity::mission_transfer::TransferQuery,
+    service::mission_transfer_service::MissionTransferService,
+};
+use infrastructure::{
+    repository::daily_mission_repository_impl::DailyMissionRepositoryImpl,
+    service::{
+        mission_codec_impl::MissionCodecImpl, token_service_impl::TokenServiceImpl,
+        uuid_service_impl::UUIDServiceImpl,
+    },
+};
+use sqlx::MySqlPool;
+
+use crate::{error::TransferError, types::token_warper::TokenWrap};
+
+use super::{daily_mission::daily_mission_service, webhook::event_outbox_service};
+
+pub async fn import(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<TransferQuery>,
+    body: Bytes,
+) -> Result<impl IntoResponse, TransferError> {
+    let service = mission_transfer_service(pool.clone());
+    let outbox = event_outbox_service(pool.clone());
+    // 上限の確認とすべての行の保存、イベントの配信の記録を同じトランザクションで行う
+    let mut transaction = pool.begin().await.map_err(|_| TransferError::Server)?;
+    let report = service
+        .import(
+            &mut transaction,
+            token.clone(),
+            query.format,
+            &body,
+            query.dry_run,
+        )
+        .await?;
+    if !report.applied {
+        // dry-runまたはエラーのある行がある場合は何も登録していない
+        transaction
+            .rollback()
+            .await
+            .map_err(|_| TransferError::Server)?;
+        let status = if report.errors.is_empty() {
+            StatusCode::OK
+        } else {
+            StatusCode::UNPROCESSABLE_ENTITY
+        };
+        return Ok((status, Json(report)));
+    }
+    let events = daily_mission_service(pool)
+        .created_events(&mut transaction, token, &report.mission_ids)
+        .await
+        .map_err(|_| TransferError::Server)?;
+    outbox
+        .record(&mut transaction, &events)
+        .await
+        .map_err(|_| TransferError::Server)?;
+    transaction
+        .commit()
+        .await
+        .map_err(|_| TransferError::Server)?;
+    outbox.publish(events);
+    Ok((StatusCode::CREATED, Json(report)))
+}
+
+pub async fn export(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<TransferQuery>,
+) -> Result<impl IntoResponse, TransferError> {
+    let service = mission_transfer_service(pool);
+    let body = service.export(token, query.format).await?;
+    Ok((
+        StatusCode::OK,
+        [
+            (
+                header::CONTENT_TYPE,
+                query.format.content_type().to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"missions.{}\"",
+                    query.format.extension()
+                ),
+            ),
+        ],
+        body,
+    ))
+}
+
+fn mission_transfer_service(
+    pool: MySqlPool,
+) -> MissionTransferService<
+    TokenServiceImpl,
+    UUIDServiceImpl,
+    DailyMissionRepositoryImpl,
+    MissionCodecImpl,
+> {
+    MissionTransferService::new(
+        TokenServiceImpl,
+        UUIDServiceImpl,
+        DailyMissionRepositoryImpl::new(pool),
+        MissionCodecImpl,
+    )
+}
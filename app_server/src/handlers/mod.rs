@@ -1,5 +1,20 @@
+pub mod achievement;
+pub mod admin;
 pub mod auth;
+pub mod calendar;
 pub mod combine;
 pub mod daily_mission;
 pub mod exp;
+pub mod friend;
+pub mod group;
+pub mod leaderboard;
+pub mod live_event;
+pub mod mission_transfer;
+pub mod push;
+pub mod quest;
+pub mod reminder;
+pub mod stats;
+pub mod streak;
+pub mod template;
 pub mod user;
+pub mod webhook;
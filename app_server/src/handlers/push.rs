@@ -0,0 +1,67 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::push_subscription::PushSubscriptionInput, service::push_service::PushService,
+};
+use infrastructure::{
+    repository::push_repository_impl::PushRepositoryImpl,
+    service::{
+        push_sender_impl::PushSenderImpl, token_service_impl::TokenServiceImpl,
+        uuid_service_impl::UUIDServiceImpl,
+    },
+};
+use sqlx::MySqlPool;
+
+use crate::{error::PushError, types::token_warper::TokenWrap};
+
+pub async fn vapid_public_key(
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, PushError> {
+    let service = push_service(pool);
+    let public_key = service.vapid_public_key()?;
+    Ok((StatusCode::OK, Json(public_key)))
+}
+
+pub async fn find_subscriptions(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, PushError> {
+    let service = push_service(pool);
+    let subscriptions = service.find_subscriptions(token).await?;
+    Ok((StatusCode::OK, Json(subscriptions)))
+}
+
+pub async fn subscribe(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(payload): Json<PushSubscriptionInput>,
+) -> Result<impl IntoResponse, PushError> {
+    let service = push_service(pool);
+    let subscription = service.subscribe(token, payload).await?;
+    Ok((StatusCode::CREATED, Json(subscription)))
+}
+
+pub async fn unsubscribe(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(subscription_id): Path<String>,
+) -> Result<impl IntoResponse, PushError> {
+    let service = push_service(pool);
+    service.unsubscribe(token, subscription_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn push_service(
+    pool: MySqlPool,
+) -> PushService<TokenServiceImpl, UUIDServiceImpl, PushRepositoryImpl, PushSenderImpl> {
+    PushService::new(
+        TokenServiceImpl,
+        UUIDServiceImpl,
+        PushRepositoryImpl::new(pool),
+        PushSenderImpl::new(),
+    )
+}
@@ -0,0 +1,95 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::{
+        quest_id::QuestId,
+        quest_input::{QuestInput, SubtaskCheckInput},
+    },
+    service::quest_service::QuestService,
+};
+use infrastructure::{
+    repository::quest_repository_impl::QuestRepositoryImpl,
+    service::{token_service_impl::TokenServiceImpl, uuid_service_impl::UUIDServiceImpl},
+};
+use sqlx::MySqlPool;
+
+use crate::{error::QuestError, types::token_warper::TokenWrap};
+
+pub async fn create(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(quest_payload): Json<QuestInput>,
+) -> Result<impl IntoResponse, QuestError> {
+    let service = quest_service(pool);
+    service.create(token, quest_payload).await?;
+    Ok(StatusCode::CREATED)
+}
+
+pub async fn get_one(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(quest_id): Path<String>,
+) -> Result<impl IntoResponse, QuestError> {
+    let service = quest_service(pool);
+    let quest = service.find_by_id(token, QuestId(quest_id)).await?;
+    Ok((StatusCode::OK, Json(quest)))
+}
+
+pub async fn get_all(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, QuestError> {
+    let service = quest_service(pool);
+    let quests = service.find_all(token).await?;
+    Ok((StatusCode::OK, Json(quests)))
+}
+
+pub async fn update(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(quest_id): Path<String>,
+    Json(quest_payload): Json<QuestInput>,
+) -> Result<impl IntoResponse, QuestError> {
+    let service = quest_service(pool);
+    service
+        .update(token, QuestId(quest_id), quest_payload)
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn update_subtask(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path((quest_id, subtask_id)): Path<(String, i64)>,
+    Json(check_payload): Json<SubtaskCheckInput>,
+) -> Result<impl IntoResponse, QuestError> {
+    let service = quest_service(pool);
+    service
+        .update_subtask(token, QuestId(quest_id), subtask_id, check_payload)
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn delete(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(quest_id): Path<String>,
+) -> Result<impl IntoResponse, QuestError> {
+    let service = quest_service(pool);
+    service.delete(token, QuestId(quest_id)).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(super) fn quest_service(
+    pool: MySqlPool,
+) -> QuestService<TokenServiceImpl, UUIDServiceImpl, QuestRepositoryImpl> {
+    QuestService::new(
+        TokenServiceImpl,
+        UUIDServiceImpl,
+        QuestRepositoryImpl::new(pool),
+    )
+}
@@ -0,0 +1,57 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::{daily_mission_id::DailyMissionId, reminder::ReminderInput},
+    service::reminder_service::ReminderService,
+};
+use infrastructure::{
+    repository::reminder_repository_impl::ReminderRepositoryImpl,
+    service::token_service_impl::TokenServiceImpl,
+};
+use sqlx::MySqlPool;
+
+use crate::{error::ReminderError, types::token_warper::TokenWrap};
+
+pub async fn find_settings(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, ReminderError> {
+    let service = reminder_service(pool);
+    let settings = service.find_settings(token).await?;
+    Ok((StatusCode::OK, Json(settings)))
+}
+
+pub async fn set_mission_reminder(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(mission_id): Path<String>,
+    Json(payload): Json<ReminderInput>,
+) -> Result<impl IntoResponse, ReminderError> {
+    let service = reminder_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| ReminderError::Server)?;
+    service
+        .set_mission_reminder(&mut tx, token, DailyMissionId(mission_id), payload)
+        .await?;
+    tx.commit().await.map_err(|_| ReminderError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn set_summary_time(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(payload): Json<ReminderInput>,
+) -> Result<impl IntoResponse, ReminderError> {
+    let service = reminder_service(pool.clone());
+    let mut tx = pool.begin().await.map_err(|_| ReminderError::Server)?;
+    service.set_summary_time(&mut tx, token, payload).await?;
+    tx.commit().await.map_err(|_| ReminderError::Server)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn reminder_service(pool: MySqlPool) -> ReminderService<TokenServiceImpl, ReminderRepositoryImpl> {
+    ReminderService::new(TokenServiceImpl, ReminderRepositoryImpl::new(pool))
+}
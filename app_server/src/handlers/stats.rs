@@ -0,0 +1,71 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::stats::{HeatmapQuery, StatsQuery, WeeklyExpQuery},
+    service::stats_service::StatsService,
+};
+use infrastructure::{
+    repository::stats_repository_impl::StatsRepositoryImpl,
+    service::token_service_impl::TokenServiceImpl,
+};
+use sqlx::MySqlPool;
+
+use crate::{error::StatsError, types::token_warper::TokenWrap};
+
+pub async fn heatmap(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<HeatmapQuery>,
+) -> Result<impl IntoResponse, StatsError> {
+    let service = stats_service(pool);
+    let heatmap = service.heatmap(token, query).await?;
+    Ok((StatusCode::OK, Json(heatmap)))
+}
+
+pub async fn mission_rates(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<StatsQuery>,
+) -> Result<impl IntoResponse, StatsError> {
+    let service = stats_service(pool);
+    let rates = service.mission_rates(token, query).await?;
+    Ok((StatusCode::OK, Json(rates)))
+}
+
+pub async fn weekdays(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<StatsQuery>,
+) -> Result<impl IntoResponse, StatsError> {
+    let service = stats_service(pool);
+    let weekdays = service.weekdays(token, query).await?;
+    Ok((StatusCode::OK, Json(weekdays)))
+}
+
+pub async fn weekly_exp(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<WeeklyExpQuery>,
+) -> Result<impl IntoResponse, StatsError> {
+    let service = stats_service(pool);
+    let weeks = service.weekly_exp(token, query).await?;
+    Ok((StatusCode::OK, Json(weeks)))
+}
+
+pub async fn summary(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Query(query): Query<StatsQuery>,
+) -> Result<impl IntoResponse, StatsError> {
+    let service = stats_service(pool);
+    let summary = service.summary(token, query).await?;
+    Ok((StatusCode::OK, Json(summary)))
+}
+
+fn stats_service(pool: MySqlPool) -> StatsService<TokenServiceImpl, StatsRepositoryImpl> {
+    StatsService::new(TokenServiceImpl, StatsRepositoryImpl::new(pool))
+}
@@ -0,0 +1,81 @@
+use std::sync::LazyLock;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{entity::vacation::VacationInput, service::streak_service::StreakService};
+use infrastructure::{
+    repository::streak_repository_impl::StreakRepositoryImpl,
+    service::token_service_impl::TokenServiceImpl,
+};
+use sqlx::MySqlPool;
+
+use crate::{error::StreakError, types::token_warper::TokenWrap};
+
+// その日に実施するミッションをすべて完了した時のボーナス経験値
+static ALL_CLEAR_BONUS: LazyLock<i64> = LazyLock::new(|| {
+    dotenvy::var("ALL_CLEAR_BONUS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(5)
+});
+
+pub async fn find_stats(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, StreakError> {
+    let service = streak_service(pool);
+    let stats = service.find_stats(token).await?;
+    Ok((StatusCode::OK, Json(stats)))
+}
+
+pub async fn create_vacation(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(payload): Json<VacationInput>,
+) -> Result<impl IntoResponse, StreakError> {
+    let service = streak_service(pool);
+    let vacation = service.create_vacation(token, payload).await?;
+    Ok((StatusCode::CREATED, Json(vacation)))
+}
+
+pub async fn find_vacations(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, StreakError> {
+    let service = streak_service(pool);
+    let vacations = service.find_vacations(token).await?;
+    Ok((StatusCode::OK, Json(vacations)))
+}
+
+pub async fn cancel_vacation(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(vacation_id): Path<i64>,
+) -> Result<impl IntoResponse, StreakError> {
+    let service = streak_service(pool);
+    service.cancel_vacation(token, vacation_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn find_freezes(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, StreakError> {
+    let service = streak_service(pool);
+    let freezes = service.find_freezes(token).await?;
+    Ok((StatusCode::OK, Json(freezes)))
+}
+
+pub(super) fn streak_service(
+    pool: MySqlPool,
+) -> StreakService<TokenServiceImpl, StreakRepositoryImpl> {
+    StreakService::new(
+        TokenServiceImpl,
+        StreakRepositoryImpl::new(pool),
+        *ALL_CLEAR_BONUS,
+    )
+}
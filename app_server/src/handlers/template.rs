@@ -0,0 +1,118 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::{
+        daily_mission_id::DailyMissionId, daily_mission_input::DailyMissionInput,
+        mission_template_id::MissionTemplateId,
+    },
+    service::template_service::TemplateService,
+};
+use infrastructure::{
+    repository::{
+        admin_repository_impl::AdminRepositoryImpl,
+        template_repository_impl::TemplateRepositoryImpl,
+    },
+    service::{token_service_impl::TokenServiceImpl, uuid_service_impl::UUIDServiceImpl},
+};
+use sqlx::MySqlPool;
+
+use crate::{error::TemplateError, types::token_warper::TokenWrap};
+
+pub async fn find_catalog(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    let templates = service.find_catalog(token).await?;
+    Ok((StatusCode::OK, Json(templates)))
+}
+
+pub async fn find_mine(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    let templates = service.find_mine(token).await?;
+    Ok((StatusCode::OK, Json(templates)))
+}
+
+pub async fn find_shared(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(share_code): Path<String>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    let template = service.find_shared(token, share_code).await?;
+    Ok((StatusCode::OK, Json(template)))
+}
+
+pub async fn share(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(mission_id): Path<String>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    let shared = service.share(token, DailyMissionId(mission_id)).await?;
+    Ok((StatusCode::CREATED, Json(shared)))
+}
+
+pub async fn unshare(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(share_code): Path<String>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    service.unshare(token, share_code).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn create_curated(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(payload): Json<DailyMissionInput>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    let template_id = service.create_curated(token, payload).await?;
+    Ok((StatusCode::CREATED, Json(template_id)))
+}
+
+pub async fn update_curated(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(template_id): Path<String>,
+    Json(payload): Json<DailyMissionInput>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    service
+        .update_curated(token, MissionTemplateId(template_id), payload)
+        .await?;
+    Ok(StatusCode::OK)
+}
+
+pub async fn delete_curated(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(template_id): Path<String>,
+) -> Result<impl IntoResponse, TemplateError> {
+    let service = template_service(pool);
+    service
+        .delete_curated(token, MissionTemplateId(template_id))
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(super) fn template_service(
+    pool: MySqlPool,
+) -> TemplateService<TokenServiceImpl, UUIDServiceImpl, TemplateRepositoryImpl, AdminRepositoryImpl>
+{
+    TemplateService::new(
+        TokenServiceImpl,
+        UUIDServiceImpl,
+        TemplateRepositoryImpl::new(pool.clone()),
+        AdminRepositoryImpl::new(pool),
+    )
+}
@@ -4,7 +4,12 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use domain::{entity::user_input::UserInput, service::user_service::UserService};
+use domain::{
+    entity::{
+        privacy_settings::PrivacySettings, time_zone_input::TimeZoneInput, user_input::UserInput,
+    },
+    service::user_service::UserService,
+};
 use infrastructure::{
     repository::user_repository_impl::UserRepositoryImpl,
     service::{
@@ -60,6 +65,26 @@ pub async fn update_name(
     Ok(())
 }
 
+pub async fn update_time_zone(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(time_zone): Json<TimeZoneInput>,
+) -> Result<impl IntoResponse, UserError> {
+    let service = user_service(pool);
+    service.update_time_zone(token, time_zone).await?;
+    Ok(())
+}
+
+pub async fn update_privacy(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(settings): Json<PrivacySettings>,
+) -> Result<impl IntoResponse, UserError> {
+    let service = user_service(pool);
+    service.update_privacy_settings(token, settings).await?;
+    Ok(())
+}
+
 pub async fn delete(
     TokenWrap(token): TokenWrap,
     State(pool): State<MySqlPool>,
@@ -0,0 +1,119 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use domain::{
+    entity::webhook::WebhookInput,
+    service::{event_outbox_service::EventOutboxService, webhook_service::WebhookService},
+};
+use infrastructure::{
+    repository::{
+        job_repository_impl::JobRepositoryImpl, webhook_repository_impl::WebhookRepositoryImpl,
+    },
+    service::{
+        event_publisher_impl::EventPublisherImpl, token_service_impl::TokenServiceImpl,
+        uuid_service_impl::UUIDServiceImpl,
+    },
+};
+use sqlx::MySqlPool;
+
+use crate::{error::WebhookError, types::token_warper::TokenWrap};
+
+pub async fn find_all(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+) -> Result<impl IntoResponse, WebhookError> {
+    let service = webhook_service(pool);
+    let webhooks = service.find_all(token).await?;
+    Ok((StatusCode::OK, Json(webhooks)))
+}
+
+pub async fn create(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Json(payload): Json<WebhookInput>,
+) -> Result<impl IntoResponse, WebhookError> {
+    let service = webhook_service(pool.clone());
+    // 上限の確認と保存を同じトランザクションで行う
+    let mut transaction = pool.begin().await.map_err(|_| WebhookError::Server)?;
+    let webhook = service.create(&mut transaction, token, payload).await?;
+    transaction
+        .commit()
+        .await
+        .map_err(|_| WebhookError::Server)?;
+    Ok((StatusCode::CREATED, Json(webhook)))
+}
+
+pub async fn update(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(webhook_id): Path<String>,
+    Json(payload): Json<WebhookInput>,
+) -> Result<impl IntoResponse, WebhookError> {
+    let service = webhook_service(pool);
+    let webhook = service.update(token, webhook_id, payload).await?;
+    Ok((StatusCode::OK, Json(webhook)))
+}
+
+pub async fn delete(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(webhook_id): Path<String>,
+) -> Result<impl IntoResponse, WebhookError> {
+    let service = webhook_service(pool);
+    service.delete(token, webhook_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn rotate_secret(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(webhook_id): Path<String>,
+) -> Result<impl IntoResponse, WebhookError> {
+    let service = webhook_service(pool);
+    let webhook = service.rotate_secret(token, webhook_id).await?;
+    Ok((StatusCode::OK, Json(webhook)))
+}
+
+pub async fn find_deliveries(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path(webhook_id): Path<String>,
+) -> Result<impl IntoResponse, WebhookError> {
+    let service = webhook_service(pool);
+    let deliveries = service.find_deliveries(token, webhook_id).await?;
+    Ok((StatusCode::OK, Json(deliveries)))
+}
+
+pub async fn redeliver(
+    TokenWrap(token): TokenWrap,
+    State(pool): State<MySqlPool>,
+    Path((webhook_id, delivery_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, WebhookError> {
+    let service = webhook_service(pool);
+    let delivery = service.redeliver(token, webhook_id, delivery_id).await?;
+    Ok((StatusCode::CREATED, Json(delivery)))
+}
+
+fn webhook_service(
+    pool: MySqlPool,
+) -> WebhookService<TokenServiceImpl, UUIDServiceImpl, WebhookRepositoryImpl> {
+    WebhookService::new(
+        TokenServiceImpl,
+        UUIDServiceImpl,
+        WebhookRepositoryImpl::new(pool),
+    )
+}
+
+// ドメインイベントの配信と通知を記録し、コミット後に発行する
+pub(super) fn event_outbox_service(
+    pool: MySqlPool,
+) -> EventOutboxService<JobRepositoryImpl, WebhookRepositoryImpl, EventPublisherImpl> {
+    EventOutboxService::new(
+        JobRepositoryImpl::new(pool.clone()),
+        WebhookRepositoryImpl::new(pool),
+        EventPublisherImpl,
+    )
+}
@@ -0,0 +1,119 @@
+use std::{sync::LazyLock, time::Duration};
+
+use domain::service::{
+    job_service::JobService, notification_channel::NotificationChannel, uuid_service::UUIDService,
+    webhook_delivery_service::WebhookDeliveryService,
+};
+use infrastructure::{
+    repository::{
+        daily_mission_repository_impl::DailyMissionRepositoryImpl,
+        job_repository_impl::JobRepositoryImpl, webhook_repository_impl::WebhookRepositoryImpl,
+    },
+    service::{
+        log_notification_channel_impl::LogNotificationChannelImpl,
+        uuid_service_impl::UUIDServiceImpl, webhook_sender_impl::WebhookSenderImpl,
+    },
+};
+use sqlx::MySqlPool;
+use tokio::time::MissedTickBehavior;
+
+use crate::handlers::push::push_service;
+
+// falseの場合はこのインスタンスでジョブを実行しない(APIだけを提供するインスタンスなど)
+static JOB_RUNNER_ENABLED: LazyLock<bool> = LazyLock::new(|| {
+    dotenvy::var("JOB_RUNNER_ENABLED")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(true)
+});
+
+// ジョブの登録と実行を行う間隔(秒)
+static JOB_TICK_SECONDS: LazyLock<u32> = LazyLock::new(|| {
+    dotenvy::var("JOB_TICK_SECONDS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|&v| v > 0)
+        .unwrap_or(30)
+});
+
+// Webhookを送信するインスタンスのリースの最短の期間(秒)
+// 同時に送信する配信はそれぞれ最大DELIVERY_TIMEOUT_SECONDS(15秒)で打ち切る
+static WEBHOOK_LEASE_MIN_SECONDS: u32 = 60;
+
+// 通知の送信方法
+// webpush: 購読している端末にWeb Pushで送る
+// log: 標準出力に書き出す(開発環境での確認用)
+static NOTIFICATION_CHANNEL: LazyLock<String> =
+    LazyLock::new(|| dotenvy::var("NOTIFICATION_CHANNEL").unwrap_or("webpush".to_string()));
+
+/// リマインダーとまとめ、レベルアップの通知と、Webhookの配信を実行するバックグラウンドのタスクを起動する
+pub fn spawn(pool: MySqlPool) {
+    match NOTIFICATION_CHANNEL.as_str() {
+        "log" => spawn_with(pool, LogNotificationChannelImpl),
+        _ => spawn_with(pool.clone(), push_service(pool)),
+    }
+}
+
+fn spawn_with<N>(pool: MySqlPool, channel: N)
+where
+    N: NotificationChannel + Send + Sync + 'static,
+{
+    let webhooks = WebhookDeliveryService::new(
+        JobRepositoryImpl::new(pool.clone()),
+        WebhookRepositoryImpl::new(pool.clone()),
+        WebhookSenderImpl::default(),
+    );
+    // レベルアップの通知とWebhookの配信はイベントと同じトランザクションでDBに登録されるため、
+    // ドメインイベントを購読せずにDBのキューから送信する
+    if *JOB_RUNNER_ENABLED {
+        spawn_job_runner(pool, channel);
+        spawn_webhook_runner(webhooks);
+    }
+}
+
+// ジョブはDBに保存されるため、再起動しても失われず、複数のインスタンスではリースを持つ1つだけが実行する
+fn spawn_job_runner<N>(pool: MySqlPool, channel: N)
+where
+    N: NotificationChannel + Send + Sync + 'static,
+{
+    let holder = UUIDServiceImpl.generate();
+    // 実行が多少遅れてもリースが切れないよう、間隔の3倍の期間を確保する
+    let lease_seconds = *JOB_TICK_SECONDS * 3;
+    let service = JobService::new(
+        JobRepositoryImpl::new(pool.clone()),
+        DailyMissionRepositoryImpl::new(pool),
+        channel,
+    );
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(*JOB_TICK_SECONDS as u64));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            // 失敗しても次の実行で再び試みる
+            if let Err(e) = service.run_once(&holder, lease_seconds).await {
+                eprintln!("Failed to run jobs: {}", e);
+            }
+        }
+    });
+}
+
+// 配信はDBに保存されるため、再起動しても失われず、複数のインスタンスではリースを持つ1つだけが送信する
+fn spawn_webhook_runner(
+    service: WebhookDeliveryService<JobRepositoryImpl, WebhookRepositoryImpl, WebhookSenderImpl>,
+) {
+    let holder = UUIDServiceImpl.generate();
+    // 実行が多少遅れてもリースが切れないよう、間隔の3倍の期間を確保する
+    // 同時に送信する配信の送信にかかる時間より短いと送信中に切れるため、最低でもWEBHOOK_LEASE_MIN_SECONDSにする
+    let lease_seconds = (*JOB_TICK_SECONDS * 3).max(WEBHOOK_LEASE_MIN_SECONDS);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(*JOB_TICK_SECONDS as u64));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            // 失敗しても次の実行で再び試みる
+            if let Err(e) = service.run_once(&holder, lease_seconds).await {
+                eprintln!("Failed to deliver webhooks: {}", e);
+            }
+        }
+    });
+}
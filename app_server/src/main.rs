@@ -1,8 +1,14 @@
+use domain::{entity::user_id::UserId, service::level_convert::LevelConvert};
+use handlers::exp::user_exp_service;
+use infrastructure::service::{
+    level_convert_impl::LevelConvertImpl, push_sender_impl::PushSenderImpl,
+};
 use router::app;
 use sqlx::MySqlPool;
 
 mod error;
 mod handlers;
+mod jobs;
 mod router;
 mod types;
 
@@ -10,16 +16,44 @@ static COOKIE_KEY: &str = "token";
 
 #[tokio::main]
 async fn main() {
-    let allow_origin = dotenvy::var("ALLOW_ORIGIN").expect("Failed to get cors data");
+    // レベルカーブの設定を起動時に検証する
+    // 不正な場合はリクエストを受け付ける前に終了する
+    if let Err(e) = LevelConvertImpl.reload() {
+        eprintln!("Invalid level curve: {}", e);
+        std::process::exit(1);
+    }
+
     let database_url = dotenvy::var("DATABASE_URL").expect("Failed to get database url");
     let pool = MySqlPool::connect(&database_url)
         .await
         .expect("Failed to get mysql connection");
 
+    // `app_server rebuild-exp [user_id]`
+    // 経験値の台帳からuser_expを再計算して終了する
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("rebuild-exp") {
+        let user_id = args.get(2).map(|id| UserId(id.to_string()));
+        let rebuilt = user_exp_service(pool)
+            .rebuild_projection(user_id)
+            .await
+            .expect("Failed to rebuild user exp");
+        println!("Rebuilt experience points of {} user(s)", rebuilt);
+        return;
+    }
+
+    // Web PushのVAPIDの鍵を読み込む(設定もファイルもない場合は生成して保存する)
+    if let Err(e) = PushSenderImpl::load_key() {
+        eprintln!("Failed to load VAPID key: {}", e);
+        std::process::exit(1);
+    }
+
+    let allow_origin = dotenvy::var("ALLOW_ORIGIN").expect("Failed to get cors data");
     let listener = tokio::net::TcpListener::bind("0.0.0.0:8080")
         .await
         .expect("Failed to bind listener");
 
+    jobs::spawn(pool.clone());
+
     let app = app(pool, &allow_origin);
     axum::serve(listener, app).await.unwrap()
 }
@@ -1,5 +1,5 @@
 use axum::{
-    routing::{get, post, put},
+    routing::{delete, get, post, put},
     Router,
 };
 use http::{
@@ -12,7 +12,10 @@ use http::{
 use sqlx::MySqlPool;
 use tower_http::cors::CorsLayer;
 
-use crate::handlers::{auth, combine, daily_mission, exp, user};
+use crate::handlers::{
+    achievement, admin, auth, calendar, combine, daily_mission, exp, friend, group, leaderboard,
+    live_event, mission_transfer, push, quest, reminder, stats, streak, template, user, webhook,
+};
 
 pub fn app(pool: MySqlPool, allow_origin: &str) -> Router {
     Router::new()
@@ -23,6 +26,8 @@ pub fn app(pool: MySqlPool, allow_origin: &str) -> Router {
                 .put(user::update_name)
                 .delete(user::delete),
         )
+        .route("/api/user/timezone", put(user::update_time_zone))
+        .route("/api/user/privacy", put(user::update_privacy))
         .route("/api/login", post(auth::login))
         .route(
             "/api/daily",
@@ -34,10 +39,151 @@ pub fn app(pool: MySqlPool, allow_origin: &str) -> Router {
                 .put(daily_mission::update)
                 .delete(daily_mission::delete),
         )
+        .route("/api/daily/import", post(mission_transfer::import))
+        .route("/api/daily/export", get(mission_transfer::export))
+        .route("/api/daily/:id/history", get(daily_mission::history_one))
+        .route("/api/daily/:id/share", post(template::share))
+        .route(
+            "/api/daily/:id/reminder",
+            put(reminder::set_mission_reminder),
+        )
+        .route("/api/history", get(daily_mission::history_all))
         .route("/api/exp", get(exp::find))
+        .route("/api/exp/history", get(exp::history))
+        .route("/api/levels", get(exp::levels))
+        .route("/api/achievements", get(achievement::find_all))
+        .route("/api/stats/streaks", get(streak::find_stats))
+        .route(
+            "/api/vacation",
+            post(streak::create_vacation).get(streak::find_vacations),
+        )
+        .route("/api/vacation/:id", delete(streak::cancel_vacation))
+        .route("/api/streak-freezes", get(streak::find_freezes))
         .route(
             "/api/daily/complete/:id",
-            put(combine::set_complete_with_add_exp),
+            put(combine::set_complete_with_add_exp).delete(combine::undo_complete_with_sub_exp),
+        )
+        .route(
+            "/api/daily/progress/:id",
+            post(combine::add_progress_with_add_exp),
+        )
+        .route("/api/quests", post(quest::create).get(quest::get_all))
+        .route(
+            "/api/quests/:id",
+            get(quest::get_one).put(quest::update).delete(quest::delete),
+        )
+        .route(
+            "/api/quests/:id/subtasks/:subtask_id",
+            put(quest::update_subtask),
+        )
+        .route(
+            "/api/quests/complete/:id",
+            put(combine::complete_quest_with_add_exp),
+        )
+        .route("/api/friends", get(friend::find_friends))
+        .route("/api/friends/:id", delete(friend::remove_friend))
+        .route(
+            "/api/friends/requests",
+            post(friend::send_request).get(friend::find_requests),
+        )
+        .route(
+            "/api/friends/requests/:id",
+            put(friend::accept_request).delete(friend::decline_request),
+        )
+        .route("/api/friends/blocks", get(friend::find_blocked))
+        .route(
+            "/api/friends/blocks/:id",
+            put(friend::block).delete(friend::unblock),
+        )
+        .route("/api/leaderboard", get(leaderboard::find))
+        .route("/api/groups", post(group::create).get(group::get_all))
+        .route("/api/groups/:id", get(group::get_one).delete(group::delete))
+        .route("/api/groups/invites", get(group::find_invites))
+        .route(
+            "/api/groups/invites/:id",
+            put(group::accept_invite).delete(group::decline_invite),
+        )
+        .route("/api/groups/:id/invites", post(group::invite))
+        .route("/api/groups/:id/members", get(group::find_members))
+        .route(
+            "/api/groups/:id/members/:user_id",
+            put(group::update_role).delete(group::remove_member),
+        )
+        .route(
+            "/api/groups/:id/missions",
+            post(group::create_mission).get(group::find_missions),
+        )
+        .route(
+            "/api/groups/:id/missions/:mission_id",
+            delete(group::delete_mission),
+        )
+        .route(
+            "/api/groups/:id/missions/:mission_id/complete",
+            put(combine::complete_group_mission_with_add_exp),
+        )
+        .route(
+            "/api/calendar/feed",
+            get(calendar::find_feed)
+                .post(calendar::regenerate_feed)
+                .delete(calendar::revoke_feed),
+        )
+        .route("/api/calendar/feeds/:token", get(calendar::render_feed))
+        .route("/api/reminders", get(reminder::find_settings))
+        .route("/api/reminders/summary", put(reminder::set_summary_time))
+        .route("/api/push/vapid-public-key", get(push::vapid_public_key))
+        .route(
+            "/api/push/subscriptions",
+            get(push::find_subscriptions).post(push::subscribe),
+        )
+        .route("/api/push/subscriptions/:id", delete(push::unsubscribe))
+        .route(
+            "/api/webhooks",
+            get(webhook::find_all).post(webhook::create),
+        )
+        .route(
+            "/api/webhooks/:id",
+            put(webhook::update).delete(webhook::delete),
+        )
+        .route("/api/webhooks/:id/secret", post(webhook::rotate_secret))
+        .route(
+            "/api/webhooks/:id/deliveries",
+            get(webhook::find_deliveries),
+        )
+        .route(
+            "/api/webhooks/:id/deliveries/:delivery_id/redeliver",
+            post(webhook::redeliver),
+        )
+        .route("/api/events", get(live_event::stream))
+        .route("/api/stats/summary", get(stats::summary))
+        .route("/api/stats/heatmap", get(stats::heatmap))
+        .route("/api/stats/missions", get(stats::mission_rates))
+        .route("/api/stats/weekdays", get(stats::weekdays))
+        .route("/api/stats/exp", get(stats::weekly_exp))
+        .route("/api/templates", get(template::find_catalog))
+        .route("/api/templates/mine", get(template::find_mine))
+        .route(
+            "/api/templates/shared/:code",
+            get(template::find_shared).delete(template::unshare),
+        )
+        .route(
+            "/api/templates/:id/clone",
+            post(combine::clone_template_with_create),
+        )
+        .route(
+            "/api/templates/shared/:code/clone",
+            post(combine::clone_shared_template_with_create),
+        )
+        .route("/api/admin/plans/:id", put(admin::save_plan))
+        .route("/api/admin/users/:id/plan", put(admin::update_user_plan))
+        .route(
+            "/api/admin/users/:id/capacity",
+            put(admin::update_user_capacity),
+        )
+        .route("/api/admin/levels/reload", post(admin::reload_level_curve))
+        .route("/api/admin/templates", post(template::create_curated))
+        .route(
+            "/api/admin/templates/:id",
+            put(template::update_curated).delete(template::delete_curated),
         )
         .with_state(pool)
         .layer(
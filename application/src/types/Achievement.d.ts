@@ -0,0 +1,15 @@
+export type AchievementMetric = "totalCompletions" | "missionStreak" | "allClearStreak" | "level";
+
+export type Achievement = {
+  achievementId: string;
+  title: string;
+  description: string;
+  metric: AchievementMetric;
+  threshold: number;
+  bonusExp: number;
+}
+
+// GET /api/achievements のレスポンスの要素(未解除の場合はunlockedAtがnull)
+export type UserAchievement = Achievement & {
+  unlockedAt: string | null;
+}
@@ -0,0 +1,6 @@
+// GET /api/calendar/feed, POST /api/calendar/feed のレスポンス
+// 購読するURLは /api/calendar/feeds/{feedToken}.ics
+export type CalendarFeed = {
+  feedToken: string;
+  createdAt: string;
+}
@@ -1,7 +1,32 @@
+export type MissionSchedule =
+  | { type: "daily" }
+  | { type: "weekdays"; weekdays: ("Mon" | "Tue" | "Wed" | "Thu" | "Fri" | "Sat" | "Sun")[] }
+  | { type: "everyNDays"; interval: number; startDate: string }
+  | { type: "timesPerWeek"; times: number };
+
+export type MissionDifficulty = "easy" | "normal" | "hard" | "epic";
+
 export type DailyMission = {
   userId: string;
   missionId: string;
   title: string;
   description: string | null;
   isComplete: boolean;
+  schedule: MissionSchedule;
+  isDue: boolean;
+  targetQuantity: number | null;
+  unit: string | null;
+  progress: number;
+  difficulty: MissionDifficulty;
+  expWeight: number | null;
+  currentStreak: number;
+  longestStreak: number;
+}
+
+export type MissionProgress = {
+  missionId: string;
+  date: string;
+  quantity: number;
+  targetQuantity: number;
+  isComplete: boolean;
 }
@@ -1,4 +1,9 @@
 type DailyMissionInput = {
     title: string;
     description: string | null;
+    schedule?: import("./DailyMission").MissionSchedule;
+    targetQuantity?: number | null;
+    unit?: string | null;
+    difficulty?: import("./DailyMission").MissionDifficulty;
+    expWeight?: number | null;
 }
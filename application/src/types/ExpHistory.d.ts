@@ -0,0 +1,18 @@
+export type ExpReason = "missionComplete" | "missionUndo" | "questComplete" | "achievementBonus" | "allClearBonus" | "allClearBonusUndo" | "groupMissionComplete" | "groupBonus" | "openingBalance";
+
+export type ExpTransaction = {
+    transactionId: number;
+    amount: number;
+    reason: ExpReason;
+    missionId: string | null;
+    completionId: number | null;
+    questId: string | null;
+    achievementId: string | null;
+    groupMissionId: string | null;
+    createdAt: string;
+}
+
+export type ExpHistory = {
+    transactions: ExpTransaction[];
+    nextCursor: string | null;
+}
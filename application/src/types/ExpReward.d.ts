@@ -0,0 +1,26 @@
+import { Achievement } from "./Achievement";
+import { MissionProgress } from "./DailyMission";
+
+// PUT /api/quests/complete/:id のレスポンス
+export type ExpReward = {
+  expGained: number;
+  totalExp: number;
+  previousLevel: number;
+  newLevel: number;
+  leveledUp: boolean;
+  unlockedAchievements: Achievement[];
+  // その日のミッションをすべて完了した場合のボーナス(expGainedに含まれる)
+  allClearBonus: number | null;
+}
+
+// PUT /api/daily/complete/:id のレスポンス
+export type RewardedCompletion = ExpReward & {
+  completionId: number;
+  missionId: string;
+  date: string;
+  completedAt: string | null;
+  expAwarded: number;
+}
+
+// POST /api/daily/progress/:id のレスポンス(目標に達して完了した場合のみExpRewardを含む)
+export type RewardedProgress = MissionProgress & Partial<ExpReward>;
@@ -0,0 +1,20 @@
+export type FriendStatus = "pending" | "accepted" | "blocked";
+
+// GET /api/friends, GET /api/friends/blocks のレスポンスの要素
+export type Friend = {
+  userId: string;
+  userName: string;
+  since: string;
+}
+
+// GET /api/friends/requests のレスポンス
+export type FriendRequests = {
+  incoming: Friend[];
+  outgoing: Friend[];
+}
+
+// POST /api/friends/requests のレスポンス(相手からの申請があった場合はaccepted)
+export type FriendRequestResult = {
+  userId: string;
+  status: FriendStatus;
+}
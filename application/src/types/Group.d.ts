@@ -0,0 +1,62 @@
+import { ExpReward } from "./ExpReward";
+
+export type GroupRole = "owner" | "admin" | "member";
+
+// GET /api/groups のレスポンスの要素、GET /api/groups/:id のレスポンス(roleは自分の役割)
+export type Group = {
+  groupId: string;
+  name: string;
+  role: GroupRole;
+  memberCount: number;
+  createdAt: string;
+}
+
+// GET /api/groups/:id/members のレスポンスの要素
+export type GroupMember = {
+  userId: string;
+  userName: string;
+  role: GroupRole;
+  joinedAt: string;
+}
+
+// GET /api/groups/invites のレスポンスの要素
+export type GroupInvite = {
+  groupId: string;
+  groupName: string;
+  invitedBy: string;
+  invitedByName: string;
+  createdAt: string;
+}
+
+// POST /api/groups/:id/missions のリクエスト(bonusExpを省略した場合は10)
+export type GroupMissionInput = {
+  title: string;
+  description?: string;
+  bonusExp?: number;
+}
+
+// GET /api/groups/:id/missions のレスポンスの要素(dateは自分のタイムゾーンの今日)
+export type GroupMissionProgress = {
+  missionId: string;
+  groupId: string;
+  title: string;
+  descriptions: string | null;
+  bonusExp: number;
+  createdAt: string;
+  date: string;
+  completedBy: string[];
+  memberCount: number;
+  allCompleted: boolean;
+  bonusAwarded: boolean;
+}
+
+// PUT /api/groups/:id/missions/:missionId/complete のレスポンス
+export type RewardedGroupCompletion = ExpReward & {
+  missionId: string;
+  userId: string;
+  date: string;
+  expAwarded: number;
+  progress: GroupMissionProgress;
+  // 全員が完了して自分に配布されたボーナス(expGainedに含まれる)
+  groupBonus: number | null;
+}
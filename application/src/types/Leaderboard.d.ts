@@ -0,0 +1,18 @@
+export type LeaderboardMetric = "totalExp" | "level" | "weeklyExp" | "streak";
+
+export type LeaderboardScope = "global" | "friends";
+
+export type LeaderboardEntry = {
+  rank: number;
+  userId: string;
+  userName: string;
+  score: number;
+}
+
+// GET /api/leaderboard のレスポンス
+export type Leaderboard = {
+  metric: LeaderboardMetric;
+  scope: LeaderboardScope;
+  entries: LeaderboardEntry[];
+  nextCursor: string | null;
+}
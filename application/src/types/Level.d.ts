@@ -4,3 +4,10 @@ type Level = {
     level: number;
     remain: number | null;
 }
+
+// GET /api/levels のレスポンス
+type LevelCurve = {
+    formula: { base: number; exponent: number; maxLevel: number } | null;
+    maxLevel: number;
+    levels: { level: number; exp: number }[];
+}
@@ -0,0 +1,56 @@
+// GET /api/events (Server-Sent Events) で送られるイベント
+// messageイベントのdataをJSONとして読むとこの形になる
+// occurredAtはUTC、dateはユーザーのタイムゾーンにおける日付
+export type LiveEvent =
+  | {
+      type: "missionCreated";
+      data: { userId: string; missionId: string; title: string; occurredAt: string };
+    }
+  | {
+      type: "missionUpdated";
+      data: { userId: string; missionId: string; title: string; occurredAt: string };
+    }
+  | {
+      type: "missionDeleted";
+      data: { userId: string; missionId: string; occurredAt: string };
+    }
+  | {
+      type: "missionCompleted";
+      data: {
+        userId: string;
+        missionId: string;
+        title: string;
+        date: string;
+        expAwarded: number;
+        occurredAt: string;
+      };
+    }
+  | {
+      type: "missionUncompleted";
+      data: { userId: string; missionId: string; date: string; occurredAt: string };
+    }
+  | {
+      type: "expChanged";
+      // amountは取り消しの場合は負の値
+      data: { userId: string; amount: number; totalExp: number; level: number; occurredAt: string };
+    }
+  | {
+      type: "levelUp";
+      data: {
+        userId: string;
+        previousLevel: number;
+        newLevel: number;
+        totalExp: number;
+        occurredAt: string;
+      };
+    }
+  | {
+      type: "achievementUnlocked";
+      data: {
+        userId: string;
+        achievementId: string;
+        title: string;
+        bonusExp: number;
+        occurredAt: string;
+      };
+    };
@@ -0,0 +1,23 @@
+// POST /api/daily/import, GET /api/daily/export のクエリ(formatを省略した場合はjson)
+export type MissionFormat = "json" | "csv" | "yaml";
+
+// 取り込めなかった行(rowは1始まりのデータの行番号)
+export type ImportRowError = {
+  row: number;
+  message: string;
+}
+
+// POST /api/daily/import?format=...&dryRun=... のレスポンス
+// 登録した場合は201、dry-runの場合は200、エラーのある行がある場合は422(1件も登録しない)
+export type ImportReport = {
+  dryRun: boolean;
+  applied: boolean;
+  total: number;
+  valid: number;
+  capacity: number;
+  existing: number;
+  exceedsCapacity: boolean;
+  errors: ImportRowError[];
+  // 登録したミッションのID(登録しなかった場合は空)
+  missionIds: string[];
+}
@@ -0,0 +1,26 @@
+// GET /api/push/vapid-public-key のレスポンス
+// ブラウザで購読する際のapplicationServerKeyに使う
+export type VapidPublicKey = {
+  publicKey: string;
+}
+
+// POST /api/push/subscriptions のリクエスト
+// PushSubscription.toJSON()の結果にdeviceNameを加えたもの
+export type PushSubscriptionInput = {
+  endpoint: string;
+  expirationTime?: number | null;
+  keys: {
+    p256dh: string;
+    auth: string;
+  };
+  deviceName?: string;
+}
+
+// GET /api/push/subscriptions, POST /api/push/subscriptions のレスポンス
+export type PushSubscription = {
+  subscriptionId: string;
+  endpoint: string;
+  deviceName: string | null;
+  expiresAt: string | null;
+  createdAt: string;
+}
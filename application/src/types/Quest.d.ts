@@ -0,0 +1,22 @@
+export type Subtask = {
+  subtaskId: number;
+  title: string;
+  isComplete: boolean;
+}
+
+export type Quest = {
+  userId: string;
+  questId: string;
+  title: string;
+  description: string | null;
+  dueDate: string | null;
+  completedDate: string | null;
+  subtasks: Subtask[];
+}
+
+export type QuestInput = {
+  title: string;
+  description: string | null;
+  dueDate?: string | null;
+  subtasks?: { title: string; isComplete?: boolean }[];
+}
@@ -0,0 +1,17 @@
+// PUT /api/daily/:id/reminder, PUT /api/reminders/summary のリクエスト
+// timeは自分のタイムゾーンの時刻("08:30")で、nullの場合は通知しない
+export type ReminderInput = {
+  time: string | null;
+}
+
+export type MissionReminder = {
+  missionId: string;
+  title: string;
+  reminderTime: string | null;
+}
+
+// GET /api/reminders のレスポンス
+export type ReminderSettings = {
+  summaryTime: string | null;
+  missions: MissionReminder[];
+}
@@ -0,0 +1,79 @@
+// 日付はすべてユーザーのタイムゾーンにおける日付(YYYY-MM-DD)
+// 完了率は0から1の値で、期間に実施日がない場合はnull
+
+export type Weekday = "Mon" | "Tue" | "Wed" | "Thu" | "Fri" | "Sat" | "Sun";
+
+export type DateRange = {
+  from: string;
+  to: string;
+}
+
+export type DailyStat = {
+  date: string;
+  completions: number;
+  expGained: number;
+}
+
+// GET /api/stats/heatmap?days=365 のレスポンス
+// 完了も経験値の増減もない日はdaysに含まれない
+export type Heatmap = DateRange & {
+  maxCompletions: number;
+  days: DailyStat[];
+}
+
+export type MissionRate = {
+  missionId: string;
+  title: string;
+  dueDays: number;
+  completedDays: number;
+  rate: number | null;
+  previousRate: number | null;
+  change: number | null;
+}
+
+// GET /api/stats/missions?days=30 のレスポンス
+export type MissionRates = {
+  current: DateRange;
+  previous: DateRange;
+  missions: MissionRate[];
+}
+
+export type WeekdayStat = {
+  weekday: Weekday;
+  completions: number;
+  average: number;
+}
+
+// GET /api/stats/weekdays?days=30 のレスポンス
+export type WeekdayStats = DateRange & {
+  weekdays: WeekdayStat[];
+  bestWeekday: Weekday | null;
+}
+
+// GET /api/stats/exp?weeks=12 のレスポンスの要素(古い順)
+// weekStartはその週の月曜日
+export type WeeklyExp = {
+  weekStart: string;
+  expGained: number;
+  completions: number;
+}
+
+export type PeriodTotals = DateRange & {
+  completions: number;
+  activeDays: number;
+  expGained: number;
+  completionRate: number | null;
+}
+
+// GET /api/stats/summary?days=30 のレスポンス
+// changeは直前の同じ長さの期間からの増減
+export type StatsSummary = {
+  current: PeriodTotals;
+  previous: PeriodTotals;
+  change: {
+    completions: number;
+    activeDays: number;
+    expGained: number;
+    completionRate: number | null;
+  };
+}
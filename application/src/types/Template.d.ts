@@ -0,0 +1,22 @@
+import { MissionDifficulty, MissionSchedule } from "./DailyMission";
+
+// GET /api/templates, GET /api/templates/mine のレスポンスの要素、GET /api/templates/shared/:code のレスポンス
+// shareCodeはユーザーが公開したテンプレートのみ持つ
+export type MissionTemplate = {
+  templateId: string;
+  title: string;
+  description: string | null;
+  schedule: MissionSchedule;
+  targetQuantity: number | null;
+  unit: string | null;
+  difficulty: MissionDifficulty;
+  expWeight: number | null;
+  shareCode: string | null;
+  createdAt: string;
+}
+
+// POST /api/daily/:id/share のレスポンス
+export type SharedTemplate = {
+  templateId: string;
+  shareCode: string;
+}
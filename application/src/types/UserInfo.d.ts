@@ -1,4 +1,11 @@
+export type PrivacySettings = {
+    showOnGlobalLeaderboard: boolean;
+    showOnFriendLeaderboard: boolean;
+    allowFriendRequests: boolean;
+}
+
 export type UserInfo = {
     userId: string;
     userName: string;
+    privacy: PrivacySettings;
 }
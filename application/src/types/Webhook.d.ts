@@ -0,0 +1,46 @@
+// Webhookで購読できるイベント
+export type WebhookEvent =
+  | "mission.created"
+  | "mission.completed"
+  | "level.up"
+  | "achievement.unlocked";
+
+// POST /api/webhooks, PUT /api/webhooks/:id のリクエスト
+export type WebhookInput = {
+  url: string;
+  events: WebhookEvent[];
+  // falseの場合は配信を止める(省略時はtrue)
+  active?: boolean;
+}
+
+// GET /api/webhooks, PUT /api/webhooks/:id のレスポンス
+export type Webhook = {
+  webhookId: string;
+  url: string;
+  events: WebhookEvent[];
+  active: boolean;
+  createdAt: string;
+}
+
+// POST /api/webhooks, POST /api/webhooks/:id/secret のレスポンス
+// secretはこの時だけ返される
+export type WebhookSecret = Webhook & {
+  secret: string;
+}
+
+export type DeliveryStatus = "pending" | "running" | "succeeded" | "failed";
+
+// GET /api/webhooks/:id/deliveries, POST /api/webhooks/:id/deliveries/:deliveryId/redeliver のレスポンス
+// payloadは送信したJSONの文字列
+export type WebhookDelivery = {
+  deliveryId: string;
+  event: WebhookEvent;
+  status: DeliveryStatus;
+  attempts: number;
+  payload: string;
+  lastStatusCode: number | null;
+  lastError: string | null;
+  nextAttemptAt: string;
+  createdAt: string;
+  deliveredAt: string | null;
+}
@@ -0,0 +1,27 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use domain::entity::level_curve::{LevelCurve, LevelFormula};
+
+// GET /api/expで毎回行われるレベルへの変換の計測
+// デフォルトの100レベルと、シーズンごとのトラックを想定した数千レベルのカーブで比較する
+fn bench_to_level_with_remain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("to_level_with_remain");
+    for max_level in [100, 1_000, 10_000] {
+        let curve = LevelCurve::from_formula(LevelFormula {
+            base: 10.0,
+            exponent: 1.5,
+            max_level,
+        })
+        .expect("invalid level curve");
+        // 最大レベル付近(線形探索では最も遅いケース)
+        let experience_point = i64::from(curve.levels()[max_level as usize - 2].exp);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(max_level),
+            &experience_point,
+            |b, exp| b.iter(|| curve.to_level_with_remain(black_box(*exp))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_to_level_with_remain);
+criterion_main!(benches);
@@ -0,0 +1,139 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+/// 実績の解除条件に使う指標
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AchievementMetric {
+    /// ミッションの累計完了数
+    TotalCompletions,
+    /// いずれかのミッションの最長連続達成日数
+    MissionStreak,
+    /// すべてのミッションを完了した日の最長連続日数
+    AllClearStreak,
+    /// レベル
+    Level,
+}
+
+impl AchievementMetric {
+    /// DBのmetricカラムの値から復元する
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "totalCompletions" => Ok(Self::TotalCompletions),
+            "missionStreak" => Ok(Self::MissionStreak),
+            "allClearStreak" => Ok(Self::AllClearStreak),
+            "level" => Ok(Self::Level),
+            v => Err(format!("unknown achievement metric: {}", v)),
+        }
+    }
+}
+
+/// 実績の定義(achievementsテーブルの1行)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Achievement {
+    pub achievement_id: String,
+    pub title: String,
+    pub description: String,
+    pub metric: AchievementMetric,
+    pub threshold: u32,
+    /// 解除した時に付与する経験値(0の場合は付与しない)
+    pub bonus_exp: i64,
+}
+
+impl Achievement {
+    /// 現在の記録とレベルで解除条件を満たしているか
+    pub fn is_satisfied(&self, stats: &AchievementStats, level: u32) -> bool {
+        let value = match self.metric {
+            AchievementMetric::TotalCompletions => stats.total_completions,
+            AchievementMetric::MissionStreak => stats.longest_mission_streak,
+            AchievementMetric::AllClearStreak => stats.longest_all_clear_streak,
+            AchievementMetric::Level => level,
+        };
+        value >= self.threshold
+    }
+}
+
+impl FromRow<'_, MySqlRow> for Achievement {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            achievement_id: row.try_get("achievement_id")?,
+            title: row.try_get("title")?,
+            description: row.try_get("description")?,
+            metric: AchievementMetric::from_column(row.try_get("metric")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            threshold: row.try_get::<i32, _>("threshold")?.max(0) as u32,
+            bonus_exp: row.try_get("bonus_exp")?,
+        })
+    }
+}
+
+/// ユーザーごとの実績の状態
+/// unlocked_atは解除した日時(UTC)で、未解除の場合はNone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAchievement {
+    #[serde(flatten)]
+    pub achievement: Achievement,
+    pub unlocked_at: Option<NaiveDateTime>,
+}
+
+impl FromRow<'_, MySqlRow> for UserAchievement {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            achievement: Achievement::from_row(row)?,
+            unlocked_at: row.try_get("unlocked_at")?,
+        })
+    }
+}
+
+/// 実績の解除条件の判定に使うユーザーの記録
+/// 連続日数は途切れても減らない最長記録を使う
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AchievementStats {
+    pub total_completions: u32,
+    pub longest_mission_streak: u32,
+    pub longest_all_clear_streak: u32,
+}
+
+impl FromRow<'_, MySqlRow> for AchievementStats {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            total_completions: row.try_get::<i64, _>("total_completions")?.max(0) as u32,
+            longest_mission_streak: row.try_get::<i64, _>("longest_mission_streak")?.max(0) as u32,
+            longest_all_clear_streak: row.try_get::<i64, _>("longest_all_clear_streak")?.max(0)
+                as u32,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Achievement, AchievementMetric, AchievementStats};
+
+    fn achievement(metric: AchievementMetric, threshold: u32) -> Achievement {
+        Achievement {
+            achievement_id: "test".to_string(),
+            title: "test".to_string(),
+            description: "test".to_string(),
+            metric,
+            threshold,
+            bonus_exp: 0,
+        }
+    }
+
+    #[test]
+    fn test_is_satisfied() {
+        let stats = AchievementStats {
+            total_completions: 100,
+            longest_mission_streak: 6,
+            longest_all_clear_streak: 30,
+        };
+        assert!(achievement(AchievementMetric::TotalCompletions, 100).is_satisfied(&stats, 1));
+        assert!(!achievement(AchievementMetric::MissionStreak, 7).is_satisfied(&stats, 1));
+        assert!(achievement(AchievementMetric::AllClearStreak, 30).is_satisfied(&stats, 1));
+        assert!(!achievement(AchievementMetric::Level, 50).is_satisfied(&stats, 49));
+        assert!(achievement(AchievementMetric::Level, 50).is_satisfied(&stats, 50));
+    }
+}
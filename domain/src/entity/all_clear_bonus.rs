@@ -0,0 +1,11 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// すべてのミッションを完了した日に付与したボーナス経験値
+/// dateはユーザーのタイムゾーンの日付
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllClearBonus {
+    pub date: NaiveDate,
+    pub exp_awarded: i64,
+}
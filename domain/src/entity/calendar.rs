@@ -0,0 +1,322 @@
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, Weekday};
+use serde::Serialize;
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{daily_mission_id::DailyMissionId, mission_schedule::MissionSchedule};
+
+/// フィードに含める過去の完了の日数
+pub const FEED_COMPLETION_DAYS: u32 = 90;
+
+static PRODID: &str = "-//missions-systems//Calendar Feed//JA";
+// UIDのドメイン部分
+static UID_DOMAIN: &str = "missions-systems";
+// RFC 5545の1行の最大オクテット数(改行を除く)
+static MAX_LINE_OCTETS: usize = 75;
+
+/// 発行済みのフィードのURL
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarFeed {
+    pub feed_token: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for CalendarFeed {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            feed_token: row.try_get("feed_token")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// フィードに繰り返しの予定として含めるミッション
+/// start_dateはEveryNDaysの場合はスケジュールの開始日、それ以外はミッションを登録した日
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarMission {
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    pub description: Option<String>,
+    pub schedule: MissionSchedule,
+    pub start_date: NaiveDate,
+}
+
+/// フィードに完了済みのToDoとして含める完了記録
+/// completed_atはUTCの日時(機能追加前の完了記録はNone)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarCompletion {
+    pub completion_id: i64,
+    pub title: String,
+    pub date: NaiveDate,
+    pub completed_at: Option<NaiveDateTime>,
+}
+
+/// ミッションと完了記録をiCalendar(RFC 5545)の形式に変換する
+/// 実施日が決まっているミッションは終日のVEVENT、週の回数だけが決まっているミッションは週ごとのVTODOになる
+/// dtstampはフィードを生成した日時(UTC)
+pub fn render_ics(
+    missions: &[CalendarMission],
+    completions: &[CalendarCompletion],
+    dtstamp: NaiveDateTime,
+) -> String {
+    let stamp = format_utc(dtstamp);
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "X-WR-CALNAME:Missions".to_string(),
+    ];
+
+    for mission in missions {
+        let uid = format!("mission-{}@{}", mission.mission_id.0, UID_DOMAIN);
+        let start = first_occurrence(&mission.schedule, mission.start_date);
+        let component = match mission.schedule {
+            MissionSchedule::TimesPerWeek { .. } => "VTODO",
+            _ => "VEVENT",
+        };
+        lines.push(format!("BEGIN:{}", component));
+        lines.push(format!("UID:{}", uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!("DTSTART;VALUE=DATE:{}", format_date(start)));
+        match mission.schedule {
+            MissionSchedule::TimesPerWeek { times } => {
+                // 週の終わりまでに行えばよいため、期限を翌週の月曜日にする
+                lines.push(format!(
+                    "DUE;VALUE=DATE:{}",
+                    format_date(start + Days::new(7))
+                ));
+                lines.push(format!(
+                    "SUMMARY:{}",
+                    escape_text(&format!("{} ({}x/week)", mission.title, times))
+                ));
+            }
+            _ => {
+                lines.push(format!(
+                    "DTEND;VALUE=DATE:{}",
+                    format_date(start + Days::new(1))
+                ));
+                lines.push(format!("SUMMARY:{}", escape_text(&mission.title)));
+                lines.push("TRANSP:TRANSPARENT".to_string());
+            }
+        }
+        lines.push(format!("RRULE:{}", rrule(&mission.schedule)));
+        if let Some(description) = &mission.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        lines.push(format!("END:{}", component));
+    }
+
+    for completion in completions {
+        lines.push("BEGIN:VTODO".to_string());
+        lines.push(format!(
+            "UID:completion-{}@{}",
+            completion.completion_id, UID_DOMAIN
+        ));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(format!(
+            "DTSTART;VALUE=DATE:{}",
+            format_date(completion.date)
+        ));
+        lines.push(format!("SUMMARY:{}", escape_text(&completion.title)));
+        lines.push("STATUS:COMPLETED".to_string());
+        lines.push("PERCENT-COMPLETE:100".to_string());
+        if let Some(completed_at) = completion.completed_at {
+            lines.push(format!("COMPLETED:{}", format_utc(completed_at)));
+        }
+        lines.push("END:VTODO".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+/// スケジュールをRRULEの値に変換する
+pub fn rrule(schedule: &MissionSchedule) -> String {
+    match schedule {
+        MissionSchedule::Daily => "FREQ=DAILY".to_string(),
+        MissionSchedule::Weekdays { weekdays } => {
+            let mut days = weekdays.clone();
+            days.sort_by_key(|w| w.num_days_from_monday());
+            days.dedup();
+            format!(
+                "FREQ=WEEKLY;BYDAY={}",
+                days.iter()
+                    .map(|w| by_day(*w))
+                    .collect::<Vec<_>>()
+                    .join(",")
+            )
+        }
+        MissionSchedule::EveryNDays { interval, .. } => {
+            format!("FREQ=DAILY;INTERVAL={}", interval)
+        }
+        MissionSchedule::TimesPerWeek { .. } => "FREQ=WEEKLY".to_string(),
+    }
+}
+
+// DTSTARTは繰り返しの最初の日と一致させる必要があるため、start以降で最初の実施日を求める
+// TimesPerWeekはstartを含む週の月曜日にする
+fn first_occurrence(schedule: &MissionSchedule, start: NaiveDate) -> NaiveDate {
+    match schedule {
+        MissionSchedule::Weekdays { weekdays } if !weekdays.is_empty() => start
+            .iter_days()
+            .take(7)
+            .find(|d| weekdays.contains(&d.weekday()))
+            .unwrap_or(start),
+        MissionSchedule::TimesPerWeek { .. } => {
+            start - Days::new(u64::from(start.weekday().num_days_from_monday()))
+        }
+        _ => start,
+    }
+}
+
+fn by_day(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn format_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+fn format_utc(datetime: NaiveDateTime) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+// TEXT型の値のエスケープ(RFC 5545 3.3.11)
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace(['\n', '\r'], "\\n")
+}
+
+// 75オクテットを超える行を折り返し、CRLFで終える(RFC 5545 3.1)
+// 継続行は先頭の空白を含めて75オクテット以内にする
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, Weekday};
+
+    use crate::entity::{daily_mission_id::DailyMissionId, mission_schedule::MissionSchedule};
+
+    use super::{escape_text, fold_line, render_ics, rrule, CalendarCompletion, CalendarMission};
+
+    #[test]
+    fn test_rrule() {
+        assert_eq!(rrule(&MissionSchedule::Daily), "FREQ=DAILY");
+        assert_eq!(
+            rrule(&MissionSchedule::Weekdays {
+                weekdays: vec![Weekday::Fri, Weekday::Mon, Weekday::Wed]
+            }),
+            "FREQ=WEEKLY;BYDAY=MO,WE,FR"
+        );
+        assert_eq!(
+            rrule(&MissionSchedule::EveryNDays {
+                interval: 3,
+                start_date: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            }),
+            "FREQ=DAILY;INTERVAL=3"
+        );
+        assert_eq!(
+            rrule(&MissionSchedule::TimesPerWeek { times: 3 }),
+            "FREQ=WEEKLY"
+        );
+    }
+
+    #[test]
+    fn test_escape_and_fold() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+
+        let line = format!("SUMMARY:{}", "あ".repeat(40));
+        let folded = fold_line(&line);
+        assert!(folded.ends_with("\r\n"));
+        for part in folded.trim_end_matches("\r\n").split("\r\n") {
+            assert!(part.len() <= 75);
+        }
+        // 折り返しを戻すと元の行になる
+        assert_eq!(folded.trim_end_matches("\r\n").replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn test_render_ics() {
+        // 2026-10-20は火曜日
+        let start_date = NaiveDate::from_ymd_opt(2026, 10, 20).unwrap();
+        let missions = vec![
+            CalendarMission {
+                mission_id: DailyMissionId("weekly".to_string()),
+                title: "walk".to_string(),
+                description: Some("park, river".to_string()),
+                schedule: MissionSchedule::Weekdays {
+                    weekdays: vec![Weekday::Thu],
+                },
+                start_date,
+            },
+            CalendarMission {
+                mission_id: DailyMissionId("times".to_string()),
+                title: "gym".to_string(),
+                description: None,
+                schedule: MissionSchedule::TimesPerWeek { times: 2 },
+                start_date,
+            },
+        ];
+        let completions = vec![CalendarCompletion {
+            completion_id: 7,
+            title: "walk".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 10, 22).unwrap(),
+            completed_at: NaiveDate::from_ymd_opt(2026, 10, 22)
+                .unwrap()
+                .and_hms_opt(9, 30, 0),
+        }];
+        let dtstamp = NaiveDate::from_ymd_opt(2026, 10, 23)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let ics = render_ics(&missions, &completions, dtstamp);
+        let lines: Vec<&str> = ics.split("\r\n").collect();
+
+        assert_eq!(lines[0], "BEGIN:VCALENDAR");
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        // 曜日指定は最初の実施日(木曜日)から始まる
+        assert!(lines.contains(&"DTSTART;VALUE=DATE:20261022"));
+        assert!(lines.contains(&"RRULE:FREQ=WEEKLY;BYDAY=TH"));
+        assert!(lines.contains(&"DESCRIPTION:park\\, river"));
+        // 週の回数のミッションはその週の月曜日から始まるToDoになる
+        assert!(lines.contains(&"DTSTART;VALUE=DATE:20261019"));
+        assert!(lines.contains(&"DUE;VALUE=DATE:20261026"));
+        assert!(lines.contains(&"SUMMARY:gym (2x/week)"));
+        // 完了記録は完了済みのToDoになる
+        assert!(lines.contains(&"UID:completion-7@missions-systems"));
+        assert!(lines.contains(&"STATUS:COMPLETED"));
+        assert!(lines.contains(&"COMPLETED:20261022T093000Z"));
+        assert!(lines.contains(&"DTSTAMP:20261023T000000Z"));
+    }
+}
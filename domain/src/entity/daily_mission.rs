@@ -1,7 +1,10 @@
 use serde::Serialize;
 use sqlx::{mysql::MySqlRow, FromRow, Row};
 
-use super::{daily_mission_id::DailyMissionId, user_id::UserId};
+use super::{
+    daily_mission_id::DailyMissionId, mission_difficulty::MissionDifficulty,
+    mission_schedule::MissionSchedule, user_id::UserId,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -11,6 +14,21 @@ pub struct DailyMission {
     pub title: String,
     pub description: Option<String>,
     pub is_complete: bool,
+    pub schedule: MissionSchedule,
+    /// 今日がスケジュール上の実施日かどうか
+    pub is_due: bool,
+    /// 数量目標(Noneの場合は完了/未完了のミッション)
+    pub target_quantity: Option<u32>,
+    pub unit: Option<String>,
+    /// 今日の進捗の累計
+    pub progress: u32,
+    pub difficulty: MissionDifficulty,
+    /// 難易度の代わりに使用する経験値の重み
+    pub exp_weight: Option<u32>,
+    /// 今日の時点での連続達成日数
+    pub current_streak: u32,
+    /// 最長の連続達成日数
+    pub longest_streak: u32,
 }
 
 impl FromRow<'_, MySqlRow> for DailyMission {
@@ -21,6 +39,44 @@ impl FromRow<'_, MySqlRow> for DailyMission {
             title: row.try_get("title")?,
             description: row.try_get("descriptions")?,
             is_complete: row.try_get("is_complete")?,
+            schedule: MissionSchedule::from_columns(
+                row.try_get("schedule_type")?,
+                row.try_get("schedule_value")?,
+                row.try_get("schedule_start")?,
+            )
+            .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            is_due: row.try_get("is_due")?,
+            target_quantity: row
+                .try_get::<Option<i32>, _>("target_quantity")?
+                .map(|q| q.max(0) as u32),
+            unit: row.try_get("unit")?,
+            progress: row
+                .try_get::<Option<i32>, _>("progress")?
+                .unwrap_or_default()
+                .max(0) as u32,
+            difficulty: MissionDifficulty::from_column(row.try_get("difficulty")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            exp_weight: row
+                .try_get::<Option<i32>, _>("exp_weight")?
+                .map(|w| w.max(0) as u32),
+            current_streak: row.try_get::<i32, _>("current_streak")?.max(0) as u32,
+            longest_streak: row.try_get::<i32, _>("longest_streak")?.max(0) as u32,
+        })
+    }
+}
+
+/// ミッションのIDとタイトル(イベントの作成に使う)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissionTitle {
+    pub mission_id: DailyMissionId,
+    pub title: String,
+}
+
+impl FromRow<'_, MySqlRow> for MissionTitle {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            mission_id: DailyMissionId(row.try_get("mission_id")?),
+            title: row.try_get("title")?,
         })
     }
 }
@@ -1,4 +1,7 @@
-use super::{daily_mission::DailyMission, daily_mission_id::DailyMissionId, user_id::UserId};
+use super::{
+    daily_mission::DailyMission, daily_mission_id::DailyMissionId,
+    mission_difficulty::MissionDifficulty, mission_schedule::MissionSchedule, user_id::UserId,
+};
 
 #[derive(Debug, Clone)]
 pub struct DailyMissionBuilder {
@@ -6,6 +9,11 @@ pub struct DailyMissionBuilder {
     mission_id: DailyMissionId,
     title: String,
     description: Option<String>,
+    schedule: MissionSchedule,
+    target_quantity: Option<u32>,
+    unit: Option<String>,
+    difficulty: MissionDifficulty,
+    exp_weight: Option<u32>,
 }
 
 impl DailyMissionBuilder {
@@ -33,6 +41,31 @@ impl DailyMissionBuilder {
         self
     }
 
+    pub fn schedule(mut self, schedule: &MissionSchedule) -> DailyMissionBuilder {
+        self.schedule = schedule.to_owned();
+        self
+    }
+
+    pub fn target_quantity(mut self, target_quantity: &Option<u32>) -> DailyMissionBuilder {
+        self.target_quantity = target_quantity.to_owned();
+        self
+    }
+
+    pub fn unit(mut self, unit: &Option<String>) -> DailyMissionBuilder {
+        self.unit = unit.to_owned();
+        self
+    }
+
+    pub fn difficulty(mut self, difficulty: &MissionDifficulty) -> DailyMissionBuilder {
+        self.difficulty = difficulty.to_owned();
+        self
+    }
+
+    pub fn exp_weight(mut self, exp_weight: &Option<u32>) -> DailyMissionBuilder {
+        self.exp_weight = exp_weight.to_owned();
+        self
+    }
+
     pub fn build(self) -> DailyMission {
         DailyMission {
             user_id: self.user_id,
@@ -40,6 +73,15 @@ impl DailyMissionBuilder {
             title: self.title,
             description: self.description,
             is_complete: false,
+            schedule: self.schedule,
+            is_due: true,
+            target_quantity: self.target_quantity,
+            unit: self.unit,
+            progress: 0,
+            difficulty: self.difficulty,
+            exp_weight: self.exp_weight,
+            current_streak: 0,
+            longest_streak: 0,
         }
     }
 }
@@ -51,6 +93,11 @@ impl Default for DailyMissionBuilder {
             mission_id: DailyMissionId(String::default()),
             title: String::default(),
             description: Option::default(),
+            schedule: MissionSchedule::default(),
+            target_quantity: Option::default(),
+            unit: Option::default(),
+            difficulty: MissionDifficulty::default(),
+            exp_weight: Option::default(),
         }
     }
 }
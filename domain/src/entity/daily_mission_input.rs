@@ -1,10 +1,44 @@
 use serde::Deserialize;
-use validator::Validate;
+use validator::{Validate, ValidationError};
+
+use super::{
+    mission_difficulty::{MissionDifficulty, MAX_EXP_WEIGHT},
+    mission_progress::MAX_TARGET_QUANTITY,
+    mission_schedule::{validate_schedule, MissionSchedule},
+};
 
 #[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_target"))]
 pub struct DailyMissionInput {
     #[validate(length(min = 1, max = 20))]
     pub title: String,
     #[validate(length(max = 100))]
     pub description: Option<String>,
+    /// 省略した場合は毎日
+    #[serde(default)]
+    #[validate(custom(function = "validate_schedule"))]
+    pub schedule: MissionSchedule,
+    /// 省略した場合は完了/未完了のミッション
+    #[serde(default)]
+    #[validate(range(min = 1, max = MAX_TARGET_QUANTITY))]
+    pub target_quantity: Option<u32>,
+    #[serde(default)]
+    #[validate(length(min = 1, max = 16))]
+    pub unit: Option<String>,
+    /// 省略した場合はnormal
+    #[serde(default)]
+    pub difficulty: MissionDifficulty,
+    /// 設定した場合は難易度の代わりに経験値の基本値として使用する
+    #[serde(default)]
+    #[validate(range(min = 1, max = MAX_EXP_WEIGHT))]
+    pub exp_weight: Option<u32>,
+}
+
+// 単位は数量目標があるミッションのみ設定できる
+fn validate_target(input: &DailyMissionInput) -> Result<(), ValidationError> {
+    if input.target_quantity.is_none() && input.unit.is_some() {
+        return Err(ValidationError::new("unit_without_target"));
+    }
+    Ok(())
 }
@@ -0,0 +1,142 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Serialize;
+
+use super::{daily_mission_id::DailyMissionId, user_id::UserId};
+
+/// ドメインイベント
+/// 実績や通知、Webhookなど他の機能はEventPublisherを通じてこれらを購読する
+/// トランザクションがロールバックされた場合に通知しないよう、コミット後に発行する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum DomainEvent {
+    MissionCreated(MissionCreated),
+    MissionUpdated(MissionUpdated),
+    MissionDeleted(MissionDeleted),
+    MissionCompleted(MissionCompleted),
+    MissionUncompleted(MissionUncompleted),
+    ExpChanged(ExpChanged),
+    LevelUp(LevelUp),
+    AchievementUnlocked(AchievementUnlocked),
+}
+
+impl DomainEvent {
+    /// イベントが発生したユーザー
+    pub fn user_id(&self) -> &UserId {
+        match self {
+            Self::MissionCreated(event) => &event.user_id,
+            Self::MissionUpdated(event) => &event.user_id,
+            Self::MissionDeleted(event) => &event.user_id,
+            Self::MissionCompleted(event) => &event.user_id,
+            Self::MissionUncompleted(event) => &event.user_id,
+            Self::ExpChanged(event) => &event.user_id,
+            Self::LevelUp(event) => &event.user_id,
+            Self::AchievementUnlocked(event) => &event.user_id,
+        }
+    }
+
+    /// イベントが発生した時刻(UTC)
+    pub fn occurred_at(&self) -> NaiveDateTime {
+        match self {
+            Self::MissionCreated(event) => event.occurred_at,
+            Self::MissionUpdated(event) => event.occurred_at,
+            Self::MissionDeleted(event) => event.occurred_at,
+            Self::MissionCompleted(event) => event.occurred_at,
+            Self::MissionUncompleted(event) => event.occurred_at,
+            Self::ExpChanged(event) => event.occurred_at,
+            Self::LevelUp(event) => event.occurred_at,
+            Self::AchievementUnlocked(event) => event.occurred_at,
+        }
+    }
+}
+
+/// デイリーミッションを登録した
+/// occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionCreated {
+    pub user_id: UserId,
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// デイリーミッションの内容を更新した
+/// occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionUpdated {
+    pub user_id: UserId,
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// デイリーミッションを削除した
+/// occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionDeleted {
+    pub user_id: UserId,
+    pub mission_id: DailyMissionId,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// デイリーミッションを完了した
+/// dateはユーザーのタイムゾーンにおける完了した日、occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionCompleted {
+    pub user_id: UserId,
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    pub date: NaiveDate,
+    pub exp_awarded: i64,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// デイリーミッションの完了を取り消した
+/// dateはユーザーのタイムゾーンにおける取り消した完了の日、occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionUncompleted {
+    pub user_id: UserId,
+    pub mission_id: DailyMissionId,
+    pub date: NaiveDate,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// 経験値が増減した
+/// amountは実際に増減した量(取り消しの場合は負の値)、occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpChanged {
+    pub user_id: UserId,
+    pub amount: i64,
+    pub total_exp: i64,
+    pub level: u32,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// 経験値の獲得によってレベルが上がった
+/// occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelUp {
+    pub user_id: UserId,
+    pub previous_level: u32,
+    pub new_level: u32,
+    pub total_exp: i64,
+    pub occurred_at: NaiveDateTime,
+}
+
+/// 実績を解除した
+/// occurred_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AchievementUnlocked {
+    pub user_id: UserId,
+    pub achievement_id: String,
+    pub title: String,
+    pub bonus_exp: i64,
+    pub occurred_at: NaiveDateTime,
+}
@@ -0,0 +1,185 @@
+use serde::Serialize;
+
+use crate::service::{
+    level_convert::LevelConvert, service_error::level_curve_error::LevelCurveError,
+};
+
+use super::{
+    achievement::Achievement, mission_completion::MissionCompletion,
+    mission_progress::MissionProgress, user_exp::ExpChange,
+};
+
+/// 経験値を獲得した結果
+/// クライアントがGET /api/expをポーリングせずにレベルアップを検知できるようにレスポンスに含める
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpReward {
+    /// 実際に加算された経験値(上限で飽和した場合は要求した量より少ない)
+    pub exp_gained: i64,
+    pub total_exp: i64,
+    pub previous_level: u32,
+    pub new_level: u32,
+    pub leveled_up: bool,
+    /// 経験値の上限に達したため、獲得するはずだった経験値の一部または全部が加算されなかった
+    pub capped: bool,
+    /// この完了によって解除した実績(ボーナス経験値はexp_gainedに含まれる)
+    pub unlocked_achievements: Vec<Achievement>,
+    /// この完了でその日のミッションをすべて完了した場合の全完了ボーナス(exp_gainedに含まれる)
+    pub all_clear_bonus: Option<i64>,
+}
+
+impl ExpReward {
+    pub fn new<T>(change: &ExpChange, converter: &T) -> Result<Self, LevelCurveError>
+    where
+        T: LevelConvert,
+    {
+        let curve = converter.curve()?;
+        let (previous_level, _) = curve.to_level_with_remain(change.before);
+        let (new_level, _) = curve.to_level_with_remain(change.after);
+        Ok(Self {
+            exp_gained: change.applied(),
+            total_exp: change.after,
+            previous_level,
+            new_level,
+            leveled_up: new_level > previous_level,
+            capped: change.is_capped(),
+            unlocked_achievements: Vec::new(),
+            all_clear_bonus: None,
+        })
+    }
+
+    /// 続けて獲得した経験値(実績のボーナスなど)の結果をまとめる
+    pub fn merge(&mut self, next: ExpReward) {
+        self.exp_gained += next.exp_gained;
+        self.total_exp = next.total_exp;
+        self.new_level = next.new_level;
+        self.leveled_up = self.new_level > self.previous_level;
+        self.capped |= next.capped;
+        self.unlocked_achievements
+            .extend(next.unlocked_achievements);
+        self.all_clear_bonus = self.all_clear_bonus.or(next.all_clear_bonus);
+    }
+}
+
+/// ミッションを完了した時のレスポンス
+/// 完了記録に獲得した経験値とレベルの変化を加えたもの
+#[derive(Debug, Clone, Serialize)]
+pub struct RewardedCompletion {
+    #[serde(flatten)]
+    pub completion: MissionCompletion,
+    #[serde(flatten)]
+    pub reward: ExpReward,
+}
+
+/// 進捗を記録した時のレスポンス
+/// 目標に達して完了した場合のみ、獲得した経験値とレベルの変化を含む
+#[derive(Debug, Clone, Serialize)]
+pub struct RewardedProgress {
+    #[serde(flatten)]
+    pub progress: MissionProgress,
+    #[serde(flatten)]
+    pub reward: Option<ExpReward>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        entity::{level_curve::LevelCurve, user_exp::ExpChange},
+        service::{level_convert::LevelConvert, service_error::level_curve_error::LevelCurveError},
+    };
+
+    use super::ExpReward;
+
+    // 10expごとに1レベル上がる
+    struct TenPerLevel;
+
+    impl LevelConvert for TenPerLevel {
+        fn curve(&self) -> Result<Arc<LevelCurve>, LevelCurveError> {
+            Ok(Arc::new(LevelCurve::from_table(vec![
+                (1, 10),
+                (2, 20),
+                (3, 30),
+            ])?))
+        }
+
+        fn reload(&self) -> Result<Arc<LevelCurve>, LevelCurveError> {
+            self.curve()
+        }
+    }
+
+    #[test]
+    fn test_exp_reward() {
+        let reward = ExpReward::new(
+            &ExpChange {
+                before: 8,
+                after: 12,
+                requested: 4,
+            },
+            &TenPerLevel,
+        )
+        .unwrap();
+        assert_eq!(reward.exp_gained, 4);
+        assert_eq!(reward.total_exp, 12);
+        assert_eq!((reward.previous_level, reward.new_level), (1, 2));
+        assert!(reward.leveled_up);
+
+        let reward = ExpReward::new(
+            &ExpChange {
+                before: 12,
+                after: 14,
+                requested: 2,
+            },
+            &TenPerLevel,
+        )
+        .unwrap();
+        assert!(!reward.leveled_up);
+
+        // 2回目の獲得でレベルが上がった場合
+        let mut merged = reward.clone();
+        merged.merge(
+            ExpReward::new(
+                &ExpChange {
+                    before: 14,
+                    after: 25,
+                    requested: 11,
+                },
+                &TenPerLevel,
+            )
+            .unwrap(),
+        );
+        assert_eq!(merged.exp_gained, 13);
+        assert_eq!(merged.total_exp, 25);
+        assert_eq!((merged.previous_level, merged.new_level), (2, 3));
+        assert!(merged.leveled_up);
+        assert!(!merged.capped);
+
+        // 上限で飽和した場合
+        let capped = ExpReward::new(
+            &ExpChange {
+                before: 25,
+                after: 30,
+                requested: 8,
+            },
+            &TenPerLevel,
+        )
+        .unwrap();
+        assert_eq!(capped.exp_gained, 5);
+        assert!(capped.capped);
+        merged.merge(capped);
+        assert!(merged.capped);
+
+        // 減算は上限による切り捨てではない
+        let reward = ExpReward::new(
+            &ExpChange {
+                before: 3,
+                after: 0,
+                requested: -5,
+            },
+            &TenPerLevel,
+        )
+        .unwrap();
+        assert!(!reward.capped);
+    }
+}
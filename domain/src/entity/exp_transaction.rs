@@ -0,0 +1,265 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{
+    achievement::Achievement,
+    all_clear_bonus::AllClearBonus,
+    daily_mission_id::DailyMissionId,
+    group_mission::GroupMissionCompletion,
+    group_mission_id::GroupMissionId,
+    history_query::{DEFAULT_HISTORY_LIMIT, MAX_HISTORY_LIMIT},
+    mission_completion::MissionCompletion,
+    quest_id::QuestId,
+};
+
+/// 経験値が増減した理由
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExpReason {
+    MissionComplete,
+    MissionUndo,
+    QuestComplete,
+    AchievementBonus,
+    AllClearBonus,
+    AllClearBonusUndo,
+    GroupMissionComplete,
+    /// グループのメンバー全員がグループミッションを完了したボーナス
+    GroupBonus,
+    /// 台帳の導入前に獲得していた経験値
+    OpeningBalance,
+}
+
+impl ExpReason {
+    /// DBのreasonカラムの値に変換する
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MissionComplete => "missionComplete",
+            Self::MissionUndo => "missionUndo",
+            Self::QuestComplete => "questComplete",
+            Self::AchievementBonus => "achievementBonus",
+            Self::AllClearBonus => "allClearBonus",
+            Self::AllClearBonusUndo => "allClearBonusUndo",
+            Self::GroupMissionComplete => "groupMissionComplete",
+            Self::GroupBonus => "groupBonus",
+            Self::OpeningBalance => "openingBalance",
+        }
+    }
+
+    /// DBのreasonカラムの値から復元する
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "missionComplete" => Ok(Self::MissionComplete),
+            "missionUndo" => Ok(Self::MissionUndo),
+            "questComplete" => Ok(Self::QuestComplete),
+            "achievementBonus" => Ok(Self::AchievementBonus),
+            "allClearBonus" => Ok(Self::AllClearBonus),
+            "allClearBonusUndo" => Ok(Self::AllClearBonusUndo),
+            "groupMissionComplete" => Ok(Self::GroupMissionComplete),
+            "groupBonus" => Ok(Self::GroupBonus),
+            "openingBalance" => Ok(Self::OpeningBalance),
+            v => Err(format!("unknown exp reason: {}", v)),
+        }
+    }
+}
+
+/// 台帳に追記する経験値の増減
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewExpTransaction {
+    pub amount: i64,
+    pub reason: ExpReason,
+    pub mission_id: Option<DailyMissionId>,
+    pub completion_id: Option<i64>,
+    pub quest_id: Option<QuestId>,
+    pub achievement_id: Option<String>,
+    pub group_mission_id: Option<GroupMissionId>,
+}
+
+impl NewExpTransaction {
+    /// ミッションの完了で付与した経験値
+    pub fn mission_complete(completion: &MissionCompletion) -> Self {
+        Self {
+            amount: completion.exp_awarded,
+            reason: ExpReason::MissionComplete,
+            mission_id: Some(completion.mission_id.to_owned()),
+            completion_id: Some(completion.completion_id),
+            quest_id: None,
+            achievement_id: None,
+            group_mission_id: None,
+        }
+    }
+
+    /// ミッションの完了の取り消しで戻す経験値
+    pub fn mission_undo(completion: &MissionCompletion) -> Self {
+        Self {
+            amount: -completion.exp_awarded,
+            reason: ExpReason::MissionUndo,
+            mission_id: Some(completion.mission_id.to_owned()),
+            completion_id: Some(completion.completion_id),
+            quest_id: None,
+            achievement_id: None,
+            group_mission_id: None,
+        }
+    }
+
+    /// クエストの達成で付与した経験値
+    pub fn quest_complete(quest_id: &QuestId, amount: i64) -> Self {
+        Self {
+            amount,
+            reason: ExpReason::QuestComplete,
+            mission_id: None,
+            completion_id: None,
+            quest_id: Some(quest_id.to_owned()),
+            achievement_id: None,
+            group_mission_id: None,
+        }
+    }
+
+    /// 実績の解除で付与したボーナス経験値
+    pub fn achievement_bonus(achievement: &Achievement) -> Self {
+        Self {
+            amount: achievement.bonus_exp,
+            reason: ExpReason::AchievementBonus,
+            mission_id: None,
+            completion_id: None,
+            quest_id: None,
+            achievement_id: Some(achievement.achievement_id.to_owned()),
+            group_mission_id: None,
+        }
+    }
+
+    /// その日のミッションをすべて完了したことで付与したボーナス経験値
+    pub fn all_clear_bonus(bonus: &AllClearBonus) -> Self {
+        Self {
+            amount: bonus.exp_awarded,
+            reason: ExpReason::AllClearBonus,
+            mission_id: None,
+            completion_id: None,
+            quest_id: None,
+            achievement_id: None,
+            group_mission_id: None,
+        }
+    }
+
+    /// 完了の取り消しで全完了でなくなったことで戻すボーナス経験値
+    pub fn all_clear_bonus_undo(bonus: &AllClearBonus) -> Self {
+        Self {
+            amount: -bonus.exp_awarded,
+            reason: ExpReason::AllClearBonusUndo,
+            mission_id: None,
+            completion_id: None,
+            quest_id: None,
+            achievement_id: None,
+            group_mission_id: None,
+        }
+    }
+
+    /// グループミッションの完了で付与した経験値
+    pub fn group_mission_complete(completion: &GroupMissionCompletion) -> Self {
+        Self {
+            amount: completion.exp_awarded,
+            reason: ExpReason::GroupMissionComplete,
+            mission_id: None,
+            completion_id: None,
+            quest_id: None,
+            achievement_id: None,
+            group_mission_id: Some(completion.mission_id.to_owned()),
+        }
+    }
+
+    /// グループのメンバー全員がグループミッションを完了したことで付与したボーナス経験値
+    pub fn group_bonus(mission_id: &GroupMissionId, amount: i64) -> Self {
+        Self {
+            amount,
+            reason: ExpReason::GroupBonus,
+            mission_id: None,
+            completion_id: None,
+            quest_id: None,
+            achievement_id: None,
+            group_mission_id: Some(mission_id.to_owned()),
+        }
+    }
+}
+
+/// 経験値の台帳の1行(exp_transactionsテーブルの1行)
+/// created_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpTransaction {
+    pub transaction_id: i64,
+    pub amount: i64,
+    pub reason: ExpReason,
+    pub mission_id: Option<DailyMissionId>,
+    pub completion_id: Option<i64>,
+    pub quest_id: Option<QuestId>,
+    pub achievement_id: Option<String>,
+    pub group_mission_id: Option<GroupMissionId>,
+    pub created_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for ExpTransaction {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            transaction_id: row.try_get("id")?,
+            amount: row.try_get("amount")?,
+            reason: ExpReason::from_column(row.try_get("reason")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            mission_id: row
+                .try_get::<Option<String>, _>("mission_id")?
+                .map(DailyMissionId),
+            completion_id: row
+                .try_get::<Option<i32>, _>("completion_id")?
+                .map(i64::from),
+            quest_id: row.try_get::<Option<String>, _>("quest_id")?.map(QuestId),
+            achievement_id: row.try_get("achievement_id")?,
+            group_mission_id: row
+                .try_get::<Option<String>, _>("group_mission_id")?
+                .map(GroupMissionId),
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// 経験値の履歴APIのクエリパラメータ
+/// 例: `?limit=30&cursor=...`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ExpHistoryQuery {
+    pub cursor: Option<String>,
+    pub limit: Option<u32>,
+}
+
+/// バリデーション済みの経験値の履歴の取得条件
+/// 履歴は新しい順(id DESC)で返すため、cursorは最後に返した行のid
+#[derive(Debug, Clone)]
+pub struct ExpHistoryPage {
+    pub cursor: Option<i64>,
+    pub limit: u32,
+}
+
+impl TryFrom<ExpHistoryQuery> for ExpHistoryPage {
+    type Error = String;
+
+    fn try_from(value: ExpHistoryQuery) -> Result<Self, Self::Error> {
+        let limit = value.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+        if limit == 0 || limit > MAX_HISTORY_LIMIT {
+            return Err(format!(
+                "`limit` must be between 1 and {}",
+                MAX_HISTORY_LIMIT
+            ));
+        }
+        let cursor = match value.cursor {
+            Some(c) => Some(c.parse().map_err(|_| "Invalid cursor".to_string())?),
+            None => None,
+        };
+        Ok(Self { cursor, limit })
+    }
+}
+
+/// 経験値の履歴のレスポンス
+/// next_cursorがSomeの場合は続きのページが存在する
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpHistory {
+    pub transactions: Vec<ExpTransaction>,
+    pub next_cursor: Option<String>,
+}
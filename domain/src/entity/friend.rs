@@ -0,0 +1,81 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::user_id::UserId;
+
+/// フレンド関係の状態(friendships.status)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FriendStatus {
+    /// user_idからfriend_idへ申請中
+    Pending,
+    Accepted,
+    /// user_idがfriend_idをブロックしている
+    Blocked,
+}
+
+impl FriendStatus {
+    /// DBのstatusカラムの値に変換する
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Accepted => "accepted",
+            Self::Blocked => "blocked",
+        }
+    }
+
+    /// DBのstatusカラムの値から復元する
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "accepted" => Ok(Self::Accepted),
+            "blocked" => Ok(Self::Blocked),
+            v => Err(format!("unknown friend status: {}", v)),
+        }
+    }
+}
+
+/// フレンド・申請・ブロックの一覧の1件
+/// sinceは関係が作られた日時(UTC)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Friend {
+    pub user_id: UserId,
+    pub user_name: String,
+    pub since: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for Friend {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            user_id: UserId(row.try_get("user_id")?),
+            user_name: row.try_get("user_name")?,
+            since: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// 受け取った申請と送った申請の一覧
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendRequests {
+    pub incoming: Vec<Friend>,
+    pub outgoing: Vec<Friend>,
+}
+
+/// フレンド申請でクライアントから送られるPayload
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendRequestInput {
+    pub user_id: String,
+}
+
+/// フレンド申請の結果
+/// 相手からの申請がすでにあった場合はstatusがacceptedになる
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FriendRequestResult {
+    pub user_id: UserId,
+    pub status: FriendStatus,
+}
@@ -0,0 +1,19 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// ストリークが凍結された(途切れない)日
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrozenDay {
+    pub date: NaiveDate,
+    pub reason: FrozenReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FrozenReason {
+    /// 休暇モードの期間
+    Vacation,
+    /// ストリークフリーズを消費した
+    Freeze,
+}
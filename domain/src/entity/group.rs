@@ -0,0 +1,139 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+use validator::Validate;
+
+use super::{group_id::GroupId, user_id::UserId};
+
+/// 1グループに所属できるメンバーの最大数
+pub const MAX_GROUP_MEMBERS: u32 = 50;
+
+/// グループでの役割
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GroupRole {
+    /// グループの作成者(1グループに1人)
+    Owner,
+    /// メンバーの招待とグループミッションの管理ができる
+    Admin,
+    Member,
+}
+
+impl GroupRole {
+    /// DBのroleカラムの値に変換する
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Owner => "owner",
+            Self::Admin => "admin",
+            Self::Member => "member",
+        }
+    }
+
+    /// DBのroleカラムの値から復元する
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "owner" => Ok(Self::Owner),
+            "admin" => Ok(Self::Admin),
+            "member" => Ok(Self::Member),
+            v => Err(format!("unknown group role: {}", v)),
+        }
+    }
+
+    /// メンバーの招待とグループミッションの管理ができるか
+    pub fn can_manage(&self) -> bool {
+        matches!(self, Self::Owner | Self::Admin)
+    }
+}
+
+/// 所属しているグループ
+/// roleは自分の役割
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Group {
+    pub group_id: GroupId,
+    pub name: String,
+    pub role: GroupRole,
+    pub member_count: u32,
+    pub created_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for Group {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            group_id: GroupId(row.try_get("group_id")?),
+            name: row.try_get("name")?,
+            role: GroupRole::from_column(row.try_get("role")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            member_count: row.try_get::<i64, _>("member_count")? as u32,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// グループのメンバー
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMember {
+    pub user_id: UserId,
+    pub user_name: String,
+    pub role: GroupRole,
+    pub joined_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for GroupMember {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            user_id: UserId(row.try_get("user_id")?),
+            user_name: row.try_get("user_name")?,
+            role: GroupRole::from_column(row.try_get("role")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            joined_at: row.try_get("joined_at")?,
+        })
+    }
+}
+
+/// 受け取ったグループへの招待
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupInvite {
+    pub group_id: GroupId,
+    pub group_name: String,
+    pub invited_by: UserId,
+    pub invited_by_name: String,
+    pub created_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for GroupInvite {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            group_id: GroupId(row.try_get("group_id")?),
+            group_name: row.try_get("group_name")?,
+            invited_by: UserId(row.try_get("invited_by")?),
+            invited_by_name: row.try_get("invited_by_name")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// グループの作成でクライアントから送られるPayload
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupInput {
+    #[validate(length(min = 1, max = 50))]
+    pub name: String,
+}
+
+/// グループへの招待でクライアントから送られるPayload
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupInviteInput {
+    pub user_id: String,
+}
+
+/// メンバーの役割の変更でクライアントから送られるPayload
+/// ownerには変更できない
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupRoleInput {
+    pub role: GroupRole,
+}
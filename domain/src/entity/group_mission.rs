@@ -0,0 +1,152 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+use validator::Validate;
+
+use super::{
+    exp_reward::ExpReward, group_id::GroupId, group_mission_id::GroupMissionId, user_id::UserId,
+};
+
+/// 1グループに登録できるグループミッションの最大数
+/// 個人のミッションの登録上限(MISSION_CAPACITY)とは別に数える
+pub const MAX_GROUP_MISSIONS: u32 = 10;
+/// メンバー全員が完了した時にそれぞれに配布するボーナス経験値のデフォルト値
+pub const DEFAULT_GROUP_BONUS: i64 = 10;
+/// 設定できるボーナス経験値の上限
+pub const MAX_GROUP_BONUS: i64 = 100;
+
+/// グループで共有するミッション
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMission {
+    pub mission_id: GroupMissionId,
+    pub group_id: GroupId,
+    pub title: String,
+    pub descriptions: Option<String>,
+    pub bonus_exp: i64,
+    pub created_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for GroupMission {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            mission_id: GroupMissionId(row.try_get("mission_id")?),
+            group_id: GroupId(row.try_get("group_id")?),
+            title: row.try_get("title")?,
+            descriptions: row.try_get("descriptions")?,
+            bonus_exp: row.try_get("bonus_exp")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// グループミッションの作成でクライアントから送られるPayload
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMissionInput {
+    #[validate(length(min = 1, max = 20))]
+    pub title: String,
+    #[validate(length(max = 100))]
+    pub description: Option<String>,
+    /// 省略した場合はDEFAULT_GROUP_BONUS
+    #[serde(default)]
+    #[validate(range(min = 0, max = MAX_GROUP_BONUS))]
+    pub bonus_exp: Option<i64>,
+}
+
+/// メンバーのグループミッションの完了記録
+/// dateは完了したメンバーのタイムゾーンの日付
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMissionCompletion {
+    pub mission_id: GroupMissionId,
+    pub user_id: UserId,
+    pub date: NaiveDate,
+    pub exp_awarded: i64,
+}
+
+/// グループミッションのある日の進捗(メンバー全体の集計)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMissionProgress {
+    #[serde(flatten)]
+    pub mission: GroupMission,
+    pub date: NaiveDate,
+    pub completed_by: Vec<UserId>,
+    pub member_count: u32,
+    pub all_completed: bool,
+    /// この日のボーナス経験値を配布済みか
+    pub bonus_awarded: bool,
+}
+
+impl GroupMissionProgress {
+    pub fn new(
+        mission: GroupMission,
+        date: NaiveDate,
+        completed_by: Vec<UserId>,
+        member_count: u32,
+        bonus_awarded: bool,
+    ) -> Self {
+        Self {
+            all_completed: completed_by.len() as u32 >= member_count,
+            mission,
+            date,
+            completed_by,
+            member_count,
+            bonus_awarded,
+        }
+    }
+}
+
+/// メンバー全員がグループミッションを完了した日のボーナス
+/// membersはボーナスを配布するメンバー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupBonus {
+    pub mission_id: GroupMissionId,
+    pub date: NaiveDate,
+    pub exp_awarded: i64,
+    pub members: Vec<UserId>,
+}
+
+/// グループミッションを完了した時のレスポンス
+/// 全員が完了してボーナスを配布した場合は、自分の分のボーナスもrewardに含まれる
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardedGroupCompletion {
+    #[serde(flatten)]
+    pub completion: GroupMissionCompletion,
+    pub progress: GroupMissionProgress,
+    #[serde(flatten)]
+    pub reward: ExpReward,
+    /// 自分に配布されたボーナス経験値
+    pub group_bonus: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+
+    use super::{GroupMission, GroupMissionProgress};
+    use crate::entity::{group_id::GroupId, group_mission_id::GroupMissionId, user_id::UserId};
+
+    fn mission() -> GroupMission {
+        GroupMission {
+            mission_id: GroupMissionId("mission".to_string()),
+            group_id: GroupId("group".to_string()),
+            title: "test".to_string(),
+            descriptions: None,
+            bonus_exp: 10,
+            created_at: Utc::now().naive_utc(),
+        }
+    }
+
+    #[test]
+    fn test_all_completed() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let completed_by = vec![UserId("a".to_string()), UserId("b".to_string())];
+        let progress = GroupMissionProgress::new(mission(), date, completed_by.clone(), 3, false);
+        assert!(!progress.all_completed);
+        let progress = GroupMissionProgress::new(mission(), date, completed_by, 2, true);
+        assert!(progress.all_completed);
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+/// 1ページあたりの件数のデフォルト値
+pub const DEFAULT_HISTORY_LIMIT: u32 = 30;
+/// 1ページあたりの件数の上限
+pub const MAX_HISTORY_LIMIT: u32 = 100;
+
+/// 完了履歴APIのクエリパラメータ
+/// 例: `?from=2024-12-01&to=2024-12-31&limit=30&cursor=...`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HistoryQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub cursor: Option<String>,
+    pub limit: Option<u32>,
+}
+
+/// 完了履歴のページングに使用するカーソル
+/// 履歴は(date DESC, id DESC)の順で返すため、最後に返した行の(date, id)を保持する
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HistoryCursor {
+    pub date: NaiveDate,
+    pub completion_id: i64,
+}
+
+impl HistoryCursor {
+    /// `"{date}_{id}"`の形式の文字列に変換する
+    pub fn encode(&self) -> String {
+        format!("{}_{}", self.date, self.completion_id)
+    }
+
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let (date, id) = cursor.split_once('_')?;
+        Some(Self {
+            date: date.parse().ok()?,
+            completion_id: id.parse().ok()?,
+        })
+    }
+}
+
+/// バリデーション済みの履歴の取得条件
+/// リポジトリにはこの型を渡す
+#[derive(Debug, Clone)]
+pub struct HistoryPage {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub cursor: Option<HistoryCursor>,
+    pub limit: u32,
+}
+
+impl TryFrom<HistoryQuery> for HistoryPage {
+    type Error = String;
+
+    fn try_from(value: HistoryQuery) -> Result<Self, Self::Error> {
+        if let (Some(from), Some(to)) = (value.from, value.to) {
+            if from > to {
+                return Err("`from` must be before or equal to `to`".to_string());
+            }
+        }
+        let limit = value.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+        if limit == 0 || limit > MAX_HISTORY_LIMIT {
+            return Err(format!("`limit` must be between 1 and {}", MAX_HISTORY_LIMIT));
+        }
+        let cursor = match value.cursor {
+            Some(c) => Some(HistoryCursor::decode(&c).ok_or("Invalid cursor".to_string())?),
+            None => None,
+        };
+        Ok(Self {
+            from: value.from,
+            to: value.to,
+            cursor,
+            limit,
+        })
+    }
+}
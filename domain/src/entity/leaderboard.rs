@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{
+    history_query::{DEFAULT_HISTORY_LIMIT, MAX_HISTORY_LIMIT},
+    user_id::UserId,
+};
+
+/// ランキングの指標
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LeaderboardMetric {
+    /// 累計の経験値
+    #[default]
+    TotalExp,
+    /// レベル(累計の経験値の順と同じになる)
+    Level,
+    /// 今週(自分のタイムゾーンの月曜日から)獲得した経験値
+    /// 今週経験値を獲得していないユーザーは含まない
+    WeeklyExp,
+    /// すべてのミッションを完了した日の現在の連続日数
+    /// 連続日数が途切れているユーザーは含まない
+    Streak,
+}
+
+/// ランキングの対象
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LeaderboardScope {
+    /// 全体のランキングに表示することを許可しているユーザー
+    #[default]
+    Global,
+    /// 自分とフレンド
+    Friends,
+}
+
+impl LeaderboardScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Global => "global",
+            Self::Friends => "friends",
+        }
+    }
+}
+
+/// ランキングAPIのクエリパラメータ
+/// 例: `?metric=weeklyExp&scope=friends&limit=30&cursor=...`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct LeaderboardQuery {
+    pub metric: Option<LeaderboardMetric>,
+    pub scope: Option<LeaderboardScope>,
+    pub cursor: Option<String>,
+    pub limit: Option<u32>,
+}
+
+/// ランキングのページングに使用するカーソル
+/// ランキングは(score DESC, user_id ASC)の順で返すため、最後に返した行の(score, user_id)を保持する
+/// レベルのランキングでは経験値をscoreとして保持する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardCursor {
+    pub score: i64,
+    pub user_id: UserId,
+}
+
+impl LeaderboardCursor {
+    /// `"{score}_{user_id}"`の形式の文字列に変換する
+    pub fn encode(&self) -> String {
+        format!("{}_{}", self.score, self.user_id.0)
+    }
+
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let (score, user_id) = cursor.split_once('_')?;
+        if user_id.is_empty() {
+            return None;
+        }
+        Some(Self {
+            score: score.parse().ok()?,
+            user_id: UserId(user_id.to_string()),
+        })
+    }
+}
+
+/// バリデーション済みのランキングの取得条件
+#[derive(Debug, Clone)]
+pub struct LeaderboardPage {
+    pub metric: LeaderboardMetric,
+    pub scope: LeaderboardScope,
+    pub cursor: Option<LeaderboardCursor>,
+    pub limit: u32,
+}
+
+impl TryFrom<LeaderboardQuery> for LeaderboardPage {
+    type Error = String;
+
+    fn try_from(value: LeaderboardQuery) -> Result<Self, Self::Error> {
+        let limit = value.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+        if limit == 0 || limit > MAX_HISTORY_LIMIT {
+            return Err(format!(
+                "`limit` must be between 1 and {}",
+                MAX_HISTORY_LIMIT
+            ));
+        }
+        let cursor = match value.cursor {
+            Some(c) => Some(LeaderboardCursor::decode(&c).ok_or("Invalid cursor".to_string())?),
+            None => None,
+        };
+        Ok(Self {
+            metric: value.metric.unwrap_or_default(),
+            scope: value.scope.unwrap_or_default(),
+            cursor,
+            limit,
+        })
+    }
+}
+
+/// ランキングの1行
+/// 同じscoreのユーザーは同じ順位になる(1, 2, 2, 4, ...)
+/// 順位はページを取得してからLeaderboardServiceで計算する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
+    pub rank: u64,
+    pub user_id: UserId,
+    pub user_name: String,
+    pub score: i64,
+}
+
+impl FromRow<'_, MySqlRow> for LeaderboardEntry {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            rank: 0,
+            user_id: UserId(row.try_get("user_id")?),
+            user_name: row.try_get("user_name")?,
+            score: row.try_get("score")?,
+        })
+    }
+}
+
+/// ランキングのレスポンス
+/// next_cursorがSomeの場合は続きのページが存在する
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leaderboard {
+    pub metric: LeaderboardMetric,
+    pub scope: LeaderboardScope,
+    pub entries: Vec<LeaderboardEntry>,
+    pub next_cursor: Option<String>,
+}
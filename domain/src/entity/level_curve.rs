@@ -0,0 +1,333 @@
+use serde::Serialize;
+
+use crate::service::service_error::level_curve_error::LevelCurveError;
+
+/// 設定できる最大レベルの上限
+/// シーズンごとのトラックなど数千レベルのカーブも扱えるようにしている
+pub const MAX_LEVEL_LIMIT: u32 = 10_000;
+
+/// レベルごとの必要経験値
+/// expはそのレベルを終えるのに必要な累計の経験値
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelThreshold {
+    pub level: u32,
+    pub exp: u32,
+}
+
+/// 計算式でレベルカーブを定義する場合のパラメータ
+/// Required_exp = base * Level^exponent (整数値に丸める)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelFormula {
+    pub base: f64,
+    pub exponent: f64,
+    pub max_level: u32,
+}
+
+/// 経験値とレベルの対応表
+/// 計算式(LevelFormula)またはテーブル(level, exp)から作成し、作成時に検証する
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelCurve {
+    /// 計算式から作成した場合はそのパラメータ、テーブルから作成した場合はNone
+    formula: Option<LevelFormula>,
+    max_level: u32,
+    levels: Vec<LevelThreshold>,
+}
+
+impl LevelCurve {
+    /// (level, exp)のテーブルから作成する
+    /// levelは1から連番で、expは正の値かつ狭義単調増加でなければならない
+    pub fn from_table(rows: Vec<(u32, u32)>) -> Result<Self, LevelCurveError> {
+        if rows.is_empty() {
+            return Err(LevelCurveError::Empty);
+        }
+        if rows.len() > MAX_LEVEL_LIMIT as usize {
+            return Err(LevelCurveError::TooManyLevels(rows.len(), MAX_LEVEL_LIMIT));
+        }
+        let mut prev_exp = 0;
+        for (i, (level, exp)) in rows.iter().enumerate() {
+            let expected = i as u32 + 1;
+            if *level != expected {
+                return Err(LevelCurveError::NonConsecutiveLevel {
+                    expected,
+                    found: *level,
+                });
+            }
+            if *exp <= prev_exp {
+                return Err(LevelCurveError::NotIncreasing(*level));
+            }
+            prev_exp = *exp;
+        }
+        Ok(Self {
+            formula: None,
+            max_level: rows.len() as u32,
+            levels: rows
+                .into_iter()
+                .map(|(level, exp)| LevelThreshold { level, exp })
+                .collect(),
+        })
+    }
+
+    /// 計算式から作成する
+    pub fn from_formula(formula: LevelFormula) -> Result<Self, LevelCurveError> {
+        if !formula.base.is_finite() || formula.base <= 0.0 {
+            return Err(LevelCurveError::InvalidParameter(format!(
+                "base must be a positive number, got {}",
+                formula.base
+            )));
+        }
+        if !formula.exponent.is_finite() || formula.exponent <= 0.0 {
+            return Err(LevelCurveError::InvalidParameter(format!(
+                "exponent must be a positive number, got {}",
+                formula.exponent
+            )));
+        }
+        if formula.max_level == 0 || formula.max_level > MAX_LEVEL_LIMIT {
+            return Err(LevelCurveError::InvalidParameter(format!(
+                "max level must be between 1 and {}, got {}",
+                MAX_LEVEL_LIMIT, formula.max_level
+            )));
+        }
+        let rows = (1..=formula.max_level)
+            .map(|level| {
+                let exp = (formula.base * f64::from(level).powf(formula.exponent)).round();
+                if exp > f64::from(u32::MAX) {
+                    return Err(LevelCurveError::InvalidParameter(format!(
+                        "required exp at level {} exceeds {}",
+                        level,
+                        u32::MAX
+                    )));
+                }
+                Ok((level, exp as u32))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // 丸めによって必要経験値が増えないレベルがある場合もエラーになる
+        let mut curve = Self::from_table(rows)?;
+        curve.formula = Some(formula);
+        Ok(curve)
+    }
+
+    pub fn max_level(&self) -> u32 {
+        self.max_level
+    }
+
+    pub fn levels(&self) -> &[LevelThreshold] {
+        &self.levels
+    }
+
+    /// 所持できる経験値の上限(最大レベルの必要経験値をすべて満たす経験値)
+    /// これ以上の経験値はレベルにもレベルアップに必要な経験値にも影響しない
+    pub fn max_exp(&self) -> i64 {
+        self.levels
+            .last()
+            .map_or(0, |threshold| i64::from(threshold.exp))
+    }
+
+    /// levelに到達するのに必要な累計の経験値
+    /// レベル1は0で、存在しないレベルの場合はNoneを返す
+    pub fn exp_to_reach(&self, level: u32) -> Option<i64> {
+        match level {
+            0 => None,
+            1 => Some(0),
+            _ if level > self.max_level => None,
+            _ => Some(i64::from(self.levels[level as usize - 2].exp)),
+        }
+    }
+
+    /// 所持経験値を(現在のレベル, レベルアップに必要な経験値量)に変換する
+    /// 最大レベルを超える場合は最大レベルにし、すべての必要経験値を超えた場合はNoneを返す
+    pub fn to_level_with_remain(&self, experience_point: i64) -> (u32, Option<u32>) {
+        // 負の経験値は0として扱う
+        // u32に変換せずi64のまま比較するため、大きな値でも切り捨てられない
+        let experience_point = experience_point.max(0);
+        // expは狭義単調増加のため、二分探索で到達済みのレベルの数を求める[O(log n)]
+        let reached = self
+            .levels
+            .partition_point(|threshold| i64::from(threshold.exp) <= experience_point);
+        let current_level = (reached as u32 + 1).min(self.max_level);
+        // レベルアップに必要な経験値量を計算
+        // 次の必要経験値より小さい値のため、差は必ずu32に収まる
+        let remain = self
+            .levels
+            .get(reached)
+            .and_then(|threshold| u32::try_from(i64::from(threshold.exp) - experience_point).ok());
+        (current_level, remain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::service::service_error::level_curve_error::LevelCurveError;
+
+    use super::{LevelCurve, LevelFormula};
+
+    #[test]
+    fn test_from_table() {
+        let curve = LevelCurve::from_table(vec![(1, 10), (2, 30), (3, 60)]).unwrap();
+        assert_eq!(curve.max_level(), 3);
+        assert_eq!(curve.to_level_with_remain(0), (1, Some(10)));
+        assert_eq!(curve.to_level_with_remain(10), (2, Some(20)));
+        assert_eq!(curve.to_level_with_remain(59), (3, Some(1)));
+        assert_eq!(curve.to_level_with_remain(60), (3, None));
+        assert_eq!(curve.to_level_with_remain(-5), (1, Some(10)));
+        assert_eq!(curve.to_level_with_remain(i64::MAX), (3, None));
+        // 上限は最大レベルの必要経験値をすべて満たす経験値
+        assert_eq!(curve.max_exp(), 60);
+        assert_eq!(curve.to_level_with_remain(curve.max_exp()), (3, None));
+    }
+
+    #[test]
+    fn test_invalid_table() {
+        assert_eq!(LevelCurve::from_table(vec![]), Err(LevelCurveError::Empty));
+        assert_eq!(
+            LevelCurve::from_table(vec![(1, 10), (3, 30)]),
+            Err(LevelCurveError::NonConsecutiveLevel {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            LevelCurve::from_table(vec![(1, 10), (2, 10)]),
+            Err(LevelCurveError::NotIncreasing(2))
+        );
+        assert_eq!(
+            LevelCurve::from_table(vec![(1, 0)]),
+            Err(LevelCurveError::NotIncreasing(1))
+        );
+    }
+
+    #[test]
+    fn test_from_formula() {
+        // exp_table.csvと同じ計算式
+        let curve = LevelCurve::from_formula(LevelFormula {
+            base: 10.0,
+            exponent: 1.5,
+            max_level: 100,
+        })
+        .unwrap();
+        assert_eq!(curve.max_level(), 100);
+        assert_eq!(curve.to_level_with_remain(2), (1, Some(8)));
+        assert_eq!(curve.to_level_with_remain(7623), (84, Some(76)));
+        assert_eq!(curve.to_level_with_remain(10000), (100, None));
+
+        for formula in [
+            LevelFormula {
+                base: 0.0,
+                exponent: 1.5,
+                max_level: 100,
+            },
+            LevelFormula {
+                base: 10.0,
+                exponent: f64::NAN,
+                max_level: 100,
+            },
+            LevelFormula {
+                base: 10.0,
+                exponent: 1.5,
+                max_level: 0,
+            },
+            LevelFormula {
+                base: 1e9,
+                exponent: 3.0,
+                max_level: 100,
+            },
+        ] {
+            assert!(matches!(
+                LevelCurve::from_formula(formula),
+                Err(LevelCurveError::InvalidParameter(_))
+            ));
+        }
+        // 丸めによって必要経験値が増えないレベルがある
+        assert_eq!(
+            LevelCurve::from_formula(LevelFormula {
+                base: 1.0,
+                exponent: 0.1,
+                max_level: 10,
+            }),
+            Err(LevelCurveError::NotIncreasing(2))
+        );
+    }
+
+    // 二分探索に置き換える前の線形探索の実装
+    fn linear_to_level_with_remain(
+        curve: &LevelCurve,
+        experience_point: i64,
+    ) -> (u32, Option<u32>) {
+        let experience_point = experience_point.max(0);
+        let mut current_level = 1;
+        let mut required_exp = None;
+        for threshold in curve.levels().iter() {
+            if experience_point < i64::from(threshold.exp) {
+                required_exp = Some(threshold.exp);
+                break;
+            }
+            current_level = threshold.level + 1;
+        }
+        let current_level = current_level.min(curve.max_level());
+        let remain =
+            required_exp.and_then(|exp| u32::try_from(i64::from(exp) - experience_point).ok());
+        (current_level, remain)
+    }
+
+    // 正の増分の累積和から狭義単調増加のテーブルを作る
+    fn arb_curve() -> impl Strategy<Value = LevelCurve> {
+        prop::collection::vec(1u32..1000, 1..3000).prop_map(|increments| {
+            let rows = increments
+                .iter()
+                .scan(0u32, |exp, inc| {
+                    *exp += inc;
+                    Some(*exp)
+                })
+                .enumerate()
+                .map(|(i, exp)| (i as u32 + 1, exp))
+                .collect();
+            LevelCurve::from_table(rows).unwrap()
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_linear_search(
+            curve in arb_curve(),
+            experience_point in prop_oneof![any::<i64>(), -10i64..3_000_000],
+        ) {
+            prop_assert_eq!(
+                curve.to_level_with_remain(experience_point),
+                linear_to_level_with_remain(&curve, experience_point)
+            );
+        }
+
+        // 境界(必要経験値ちょうどとその1つ前)でも一致する
+        #[test]
+        fn prop_matches_linear_search_at_thresholds(curve in arb_curve(), index in any::<prop::sample::Index>()) {
+            let threshold = curve.levels()[index.index(curve.levels().len())];
+            for experience_point in [i64::from(threshold.exp) - 1, i64::from(threshold.exp)] {
+                prop_assert_eq!(
+                    curve.to_level_with_remain(experience_point),
+                    linear_to_level_with_remain(&curve, experience_point)
+                );
+            }
+        }
+
+        #[test]
+        fn prop_exp_to_reach_is_the_first_exp_of_the_level(curve in arb_curve(), index in any::<prop::sample::Index>()) {
+            let level = index.index(curve.max_level() as usize) as u32 + 1;
+            let exp = curve.exp_to_reach(level).unwrap();
+            prop_assert_eq!(curve.to_level_with_remain(exp).0, level);
+            if level > 1 {
+                prop_assert_eq!(curve.to_level_with_remain(exp - 1).0, level - 1);
+            }
+            prop_assert_eq!(curve.exp_to_reach(curve.max_level() + 1), None);
+        }
+
+        #[test]
+        fn prop_level_is_monotonic(curve in arb_curve(), a in 0i64..3_000_000, b in 0i64..3_000_000) {
+            let (low, high) = if a <= b { (a, b) } else { (b, a) };
+            prop_assert!(curve.to_level_with_remain(low).0 <= curve.to_level_with_remain(high).0);
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// 管理者が設定できるミッションの登録上限の最大値
+pub const MAX_MISSION_CAPACITY: u32 = 100;
+
+/// プランの設定で管理者から送られるPayload
+/// mission_capacityがNoneの場合は設定(MISSION_CAPACITY)の値を使用する
+#[derive(Debug, Clone, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanInput {
+    #[validate(range(max = MAX_MISSION_CAPACITY))]
+    pub mission_capacity: Option<u32>,
+}
+
+/// ユーザーのプラン変更で管理者から送られるPayload
+/// plan_idがNoneの場合はプランを解除する
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPlanInput {
+    #[validate(length(min = 1, max = 64))]
+    pub plan_id: Option<String>,
+}
+
+/// ユーザーごとの上限の変更で管理者から送られるPayload
+/// mission_capacityがNoneの場合はプランまたは設定の値に戻す
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacityInput {
+    #[validate(range(max = MAX_MISSION_CAPACITY))]
+    pub mission_capacity: Option<u32>,
+}
@@ -0,0 +1,46 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Serialize;
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{
+    daily_mission_id::DailyMissionId, frozen_day::FrozenDay, mission_progress::MissionProgress,
+};
+
+/// ミッションの完了記録(mission_completedテーブルの1行)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionCompletion {
+    pub completion_id: i64,
+    pub mission_id: DailyMissionId,
+    pub date: NaiveDate,
+    /// 完了した時刻(ユーザーのタイムゾーン)
+    /// 機能追加前の完了記録はNone
+    pub completed_at: Option<NaiveDateTime>,
+    /// この完了で付与した経験値
+    pub exp_awarded: i64,
+}
+
+impl FromRow<'_, MySqlRow> for MissionCompletion {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            completion_id: row.try_get::<i32, _>("id")?.into(),
+            mission_id: DailyMissionId(row.try_get("mission_id")?),
+            date: row.try_get("date")?,
+            completed_at: row.try_get("completed_at")?,
+            exp_awarded: row.try_get("exp_awarded")?,
+        })
+    }
+}
+
+/// 完了履歴のレスポンス
+/// frozen_daysはcompletionsと同じ期間のストリークが凍結された日
+/// progressはcompletionsと同じ期間の数量目標のあるミッションの日ごとの進捗
+/// next_cursorがSomeの場合は続きのページが存在する
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionHistory {
+    pub completions: Vec<MissionCompletion>,
+    pub frozen_days: Vec<FrozenDay>,
+    pub progress: Vec<MissionProgress>,
+    pub next_cursor: Option<String>,
+}
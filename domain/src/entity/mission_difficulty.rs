@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+/// 難易度の代わりに設定できる経験値の重みの最大値
+pub const MAX_EXP_WEIGHT: u32 = 20;
+
+/// ミッションの難易度
+/// 完了時に付与する経験値の基本値になる
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MissionDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Epic,
+}
+
+impl MissionDifficulty {
+    /// 難易度ごとの経験値の基本値
+    pub fn base_exp(&self) -> i64 {
+        match self {
+            Self::Easy => 1,
+            Self::Normal => 2,
+            Self::Hard => 4,
+            Self::Epic => 8,
+        }
+    }
+
+    /// DBのdifficultyカラムの値に変換する
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Normal => "normal",
+            Self::Hard => "hard",
+            Self::Epic => "epic",
+        }
+    }
+
+    /// DBのdifficultyカラムの値から復元する
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "easy" => Ok(Self::Easy),
+            "normal" => Ok(Self::Normal),
+            "hard" => Ok(Self::Hard),
+            "epic" => Ok(Self::Epic),
+            v => Err(format!("unknown difficulty: {}", v)),
+        }
+    }
+}
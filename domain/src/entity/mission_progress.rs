@@ -0,0 +1,44 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+use validator::Validate;
+
+use super::daily_mission_id::DailyMissionId;
+
+/// 数量目標と一度に記録できる進捗の最大値
+pub const MAX_TARGET_QUANTITY: u32 = 1_000_000;
+
+/// 進捗の記録でユーザーから送られるPayload
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ProgressInput {
+    #[validate(range(min = 1, max = MAX_TARGET_QUANTITY))]
+    pub amount: u32,
+}
+
+/// ミッションの1日の進捗の累計(mission_progressテーブルの1行)
+/// target_quantityは記録時点の目標
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionProgress {
+    pub mission_id: DailyMissionId,
+    pub date: NaiveDate,
+    pub quantity: u32,
+    pub target_quantity: u32,
+    pub is_complete: bool,
+    /// その日の完了を取り消した場合はtrue
+    /// 取り消した日は目標に達していても再び完了にならない
+    pub completion_undone: bool,
+}
+
+impl FromRow<'_, MySqlRow> for MissionProgress {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            mission_id: DailyMissionId(row.try_get("mission_id")?),
+            date: row.try_get("date")?,
+            quantity: row.try_get::<i32, _>("quantity")?.max(0) as u32,
+            target_quantity: row.try_get::<i32, _>("target_quantity")?.max(0) as u32,
+            is_complete: row.try_get("is_complete")?,
+            completion_undone: row.try_get("completion_undone")?,
+        })
+    }
+}
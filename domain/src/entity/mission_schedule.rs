@@ -0,0 +1,267 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use validator::ValidationError;
+
+/// EveryNDaysで設定できる最大の間隔
+pub const MAX_INTERVAL_DAYS: u32 = 365;
+
+/// ミッションを実施する日
+/// JSONでは`{"type": "weekdays", "weekdays": ["Mon", "Wed"]}`のように表す
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum MissionSchedule {
+    /// 毎日
+    #[default]
+    Daily,
+    /// 指定した曜日
+    Weekdays { weekdays: Vec<Weekday> },
+    /// start_dateからinterval日ごと
+    EveryNDays {
+        interval: u32,
+        start_date: NaiveDate,
+    },
+    /// ISO週(月曜日始まり)ごとにtimes回
+    TimesPerWeek { times: u32 },
+}
+
+impl MissionSchedule {
+    /// dateが実施日として決まっているか
+    /// TimesPerWeekは特定の日に縛られないため常にfalseになる
+    pub fn is_scheduled(&self, date: NaiveDate) -> bool {
+        match self {
+            Self::Daily => true,
+            Self::Weekdays { weekdays } => weekdays.contains(&date.weekday()),
+            Self::EveryNDays {
+                interval,
+                start_date,
+            } => {
+                date >= *start_date
+                    && (date - *start_date).num_days() % i64::from((*interval).max(1)) == 0
+            }
+            Self::TimesPerWeek { .. } => false,
+        }
+    }
+
+    /// dateに実施するミッションかどうか
+    /// week_countはdateを含むISO週における、dateより前に達成した日数
+    pub fn is_due(&self, date: NaiveDate, week_count: u32) -> bool {
+        match self {
+            Self::TimesPerWeek { times } => week_count < *times,
+            schedule => schedule.is_scheduled(date),
+        }
+    }
+
+    /// 前回の達成日lastからdateまでの連続記録の評価で、dayに達成しなくても途切れないかどうか
+    /// last_week_countはlastを含むISO週の達成日数
+    pub fn is_exempt(
+        &self,
+        day: NaiveDate,
+        last: NaiveDate,
+        date: NaiveDate,
+        last_week_count: u32,
+    ) -> bool {
+        match self {
+            // 評価する週はまだ終わっていない
+            // 前回の達成日の週は回数を満たしていれば残りの日は達成しなくてよい
+            Self::TimesPerWeek { times } => {
+                is_same_week(day, date) || (is_same_week(day, last) && last_week_count >= *times)
+            }
+            schedule => !schedule.is_scheduled(day),
+        }
+    }
+
+    /// lastを含むISO週が回数を満たさずに終わったかどうか(TimesPerWeekのみ)
+    /// 週の残りの日のうち凍結された日は達成した日として数える
+    pub fn is_quota_missed(
+        &self,
+        last: NaiveDate,
+        date: NaiveDate,
+        last_week_count: u32,
+        is_frozen: impl Fn(NaiveDate) -> bool,
+    ) -> bool {
+        match self {
+            Self::TimesPerWeek { times } if !is_same_week(last, date) => {
+                let frozen = last
+                    .iter_days()
+                    .skip(1)
+                    .take_while(|d| is_same_week(*d, last))
+                    .filter(|d| is_frozen(*d))
+                    .count() as u32;
+                last_week_count + frozen < *times
+            }
+            _ => false,
+        }
+    }
+
+    /// 連続記録の評価でdayを飛ばして良いか
+    /// (凍結された日、またはスケジュール上達成しなくてよい日)
+    pub fn skips<'a>(
+        &'a self,
+        last: Option<NaiveDate>,
+        date: NaiveDate,
+        last_week_count: u32,
+        is_frozen: &'a impl Fn(NaiveDate) -> bool,
+    ) -> impl Fn(NaiveDate) -> bool + 'a {
+        move |day| {
+            is_frozen(day)
+                || last.is_some_and(|last| self.is_exempt(day, last, date, last_week_count))
+        }
+    }
+
+    /// どのミッションも実施日として決まっていない日かどうか
+    /// すべてのミッションを完了した日の連続記録では、このような日を飛ばして評価する
+    pub fn is_day_off(schedules: &[Self], day: NaiveDate) -> bool {
+        !schedules.iter().any(|s| s.is_scheduled(day))
+    }
+
+    /// DBに保存する値(schedule_type, schedule_value, schedule_start)
+    pub fn to_columns(&self) -> (&'static str, i32, Option<NaiveDate>) {
+        match self {
+            Self::Daily => ("daily", 0, None),
+            Self::Weekdays { weekdays } => (
+                "weekdays",
+                weekdays
+                    .iter()
+                    .fold(0, |mask, w| mask | 1 << w.num_days_from_monday()),
+                None,
+            ),
+            Self::EveryNDays {
+                interval,
+                start_date,
+            } => ("every_n_days", *interval as i32, Some(*start_date)),
+            Self::TimesPerWeek { times } => ("times_per_week", *times as i32, None),
+        }
+    }
+
+    /// DBの値から復元する
+    pub fn from_columns(
+        schedule_type: &str,
+        value: i32,
+        start: Option<NaiveDate>,
+    ) -> Result<Self, String> {
+        let schedule = match (schedule_type, start) {
+            ("daily", _) => Self::Daily,
+            ("weekdays", _) => Self::Weekdays {
+                weekdays: (0..7)
+                    .filter(|i| value & (1 << i) != 0)
+                    .filter_map(|i| Weekday::try_from(i as u8).ok())
+                    .collect(),
+            },
+            ("every_n_days", Some(start_date)) => Self::EveryNDays {
+                interval: value.max(1) as u32,
+                start_date,
+            },
+            ("times_per_week", _) => Self::TimesPerWeek {
+                times: value.max(0) as u32,
+            },
+            _ => return Err(format!("invalid schedule: {}", schedule_type)),
+        };
+        Ok(schedule)
+    }
+}
+
+pub(crate) fn validate_schedule(schedule: &MissionSchedule) -> Result<(), ValidationError> {
+    match schedule {
+        MissionSchedule::Weekdays { weekdays } if weekdays.is_empty() => {
+            Err(ValidationError::new("empty_weekdays"))
+        }
+        MissionSchedule::EveryNDays { interval, .. }
+            if !(1..=MAX_INTERVAL_DAYS).contains(interval) =>
+        {
+            Err(ValidationError::new("invalid_interval"))
+        }
+        MissionSchedule::TimesPerWeek { times } if !(1..=7).contains(times) => {
+            Err(ValidationError::new("invalid_times_per_week"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// aとbが同じISO週の日付かどうか
+pub fn is_same_week(a: NaiveDate, b: NaiveDate) -> bool {
+    a.iso_week() == b.iso_week()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday};
+
+    use super::MissionSchedule;
+
+    // 2024-12-02は月曜日
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 12, d).unwrap()
+    }
+
+    #[test]
+    fn test_is_scheduled() {
+        let weekdays = MissionSchedule::Weekdays {
+            weekdays: vec![Weekday::Mon, Weekday::Wed, Weekday::Fri],
+        };
+        let scheduled: Vec<_> = (2..=8)
+            .filter(|d| weekdays.is_scheduled(date(*d)))
+            .collect();
+        assert_eq!(scheduled, vec![2, 4, 6]);
+
+        let every_3_days = MissionSchedule::EveryNDays {
+            interval: 3,
+            start_date: date(3),
+        };
+        let scheduled: Vec<_> = (1..=10)
+            .filter(|d| every_3_days.is_scheduled(date(*d)))
+            .collect();
+        assert_eq!(scheduled, vec![3, 6, 9]);
+
+        assert!(MissionSchedule::Daily.is_scheduled(date(1)));
+        assert!(!MissionSchedule::TimesPerWeek { times: 3 }.is_scheduled(date(1)));
+    }
+
+    #[test]
+    fn test_is_due_times_per_week() {
+        let schedule = MissionSchedule::TimesPerWeek { times: 3 };
+        assert!(schedule.is_due(date(4), 2));
+        assert!(!schedule.is_due(date(4), 3));
+    }
+
+    #[test]
+    fn test_weekly_exemption() {
+        let schedule = MissionSchedule::TimesPerWeek { times: 2 };
+        // 前の週(12/2 ~ 12/8)に2回達成していれば12/9の週まで途切れない
+        assert!(schedule.is_exempt(date(7), date(6), date(10), 2));
+        assert!(schedule.is_exempt(date(9), date(6), date(10), 2));
+        assert!(!schedule.is_quota_missed(date(6), date(10), 2, |_| false));
+        // 回数を満たしていない場合は途切れる
+        assert!(!schedule.is_exempt(date(7), date(6), date(10), 1));
+        assert!(schedule.is_quota_missed(date(8), date(10), 1, |_| false));
+        // 週の残りが凍結されていれば達成した日として数える
+        assert!(!schedule.is_quota_missed(date(6), date(10), 1, |d| d == date(7)));
+        // 間の週がまるごと空いている場合は途切れる
+        assert!(!schedule.is_exempt(date(11), date(6), date(17), 2));
+    }
+
+    #[test]
+    fn test_columns() {
+        let schedules = [
+            MissionSchedule::Daily,
+            MissionSchedule::Weekdays {
+                weekdays: vec![Weekday::Tue, Weekday::Sun],
+            },
+            MissionSchedule::EveryNDays {
+                interval: 2,
+                start_date: date(1),
+            },
+            MissionSchedule::TimesPerWeek { times: 3 },
+        ];
+        for schedule in schedules {
+            let (schedule_type, value, start) = schedule.to_columns();
+            assert_eq!(
+                MissionSchedule::from_columns(schedule_type, value, start),
+                Ok(schedule)
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{
+    daily_mission_input::DailyMissionInput, mission_difficulty::MissionDifficulty,
+    mission_schedule::MissionSchedule, mission_template_id::MissionTemplateId,
+};
+
+/// 共有コードの文字数
+pub const SHARE_CODE_LENGTH: usize = 12;
+
+/// ミッションのテンプレート
+/// share_codeはユーザーが公開したテンプレートのみ持つ(カタログのテンプレートはNone)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionTemplate {
+    pub template_id: MissionTemplateId,
+    pub title: String,
+    pub description: Option<String>,
+    pub schedule: MissionSchedule,
+    pub target_quantity: Option<u32>,
+    pub unit: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub exp_weight: Option<u32>,
+    pub share_code: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionTemplate {
+    /// テンプレートからデイリーミッションの作成に使うPayloadを作る
+    pub fn to_input(&self) -> DailyMissionInput {
+        DailyMissionInput {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            schedule: self.schedule.clone(),
+            target_quantity: self.target_quantity,
+            unit: self.unit.clone(),
+            difficulty: self.difficulty,
+            exp_weight: self.exp_weight,
+        }
+    }
+}
+
+impl FromRow<'_, MySqlRow> for MissionTemplate {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            template_id: MissionTemplateId(row.try_get("template_id")?),
+            title: row.try_get("title")?,
+            description: row.try_get("descriptions")?,
+            schedule: MissionSchedule::from_columns(
+                row.try_get("schedule_type")?,
+                row.try_get("schedule_value")?,
+                row.try_get("schedule_start")?,
+            )
+            .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            target_quantity: row
+                .try_get::<Option<i32>, _>("target_quantity")?
+                .map(|q| q.max(0) as u32),
+            unit: row.try_get("unit")?,
+            difficulty: MissionDifficulty::from_column(row.try_get("difficulty")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            exp_weight: row
+                .try_get::<Option<i32>, _>("exp_weight")?
+                .map(|w| w.max(0) as u32),
+            share_code: row.try_get("share_code")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+/// ミッションを共有した時のレスポンス
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedTemplate {
+    pub template_id: MissionTemplateId,
+    pub share_code: String,
+}
+
+/// 入力された共有コードを保存している形式(英数字のみの大文字)に揃える
+pub fn normalize_share_code(share_code: &str) -> String {
+    share_code
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_ascii_uppercase()
+}
+
+/// UUIDから共有コードを作る
+/// 入力しやすいよう、ハイフンを除いた先頭SHARE_CODE_LENGTH文字にする
+pub fn share_code_from_uuid(uuid: &str) -> String {
+    normalize_share_code(uuid)
+        .chars()
+        .take(SHARE_CODE_LENGTH)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_share_code, share_code_from_uuid, SHARE_CODE_LENGTH};
+
+    #[test]
+    fn test_share_code_from_uuid() {
+        let code = share_code_from_uuid("3f9a1c0b-7d2e-4a6b-9c1d-2e3f4a5b6c7d");
+        assert_eq!(code, "3F9A1C0B7D2E");
+        assert_eq!(code.len(), SHARE_CODE_LENGTH);
+        assert_eq!(normalize_share_code("3f9a-1c0b-7d2e"), code);
+    }
+}
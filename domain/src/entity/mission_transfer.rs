@@ -0,0 +1,300 @@
+use chrono::{NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{
+    daily_mission_id::DailyMissionId, daily_mission_input::DailyMissionInput,
+    mission_difficulty::MissionDifficulty, mission_schedule::MissionSchedule,
+};
+
+/// 一度に取り込める最大の行数
+pub const MAX_IMPORT_ROWS: usize = 500;
+
+/// 取り込み/書き出しのファイル形式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MissionFormat {
+    #[default]
+    Json,
+    Csv,
+    Yaml,
+}
+
+impl MissionFormat {
+    /// レスポンスのContent-Type
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Yaml => "application/yaml",
+        }
+    }
+
+    /// 書き出すファイルの拡張子
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Yaml => "yaml",
+        }
+    }
+}
+
+/// 取り込み/書き出しのクエリパラメータ
+/// 例: `?format=csv&dryRun=true`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferQuery {
+    #[serde(default)]
+    pub format: MissionFormat,
+    /// trueの場合は登録せずに取り込みの結果だけを返す
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 書き出すミッションの定義
+/// JSON/YAMLではDailyMissionInputと同じ形になるため、そのまま取り込み直すことができる
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionRecord {
+    pub title: String,
+    pub description: Option<String>,
+    pub schedule: MissionSchedule,
+    pub target_quantity: Option<u32>,
+    pub unit: Option<String>,
+    pub difficulty: MissionDifficulty,
+    pub exp_weight: Option<u32>,
+}
+
+impl FromRow<'_, MySqlRow> for MissionRecord {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            title: row.try_get("title")?,
+            description: row.try_get("descriptions")?,
+            schedule: MissionSchedule::from_columns(
+                row.try_get("schedule_type")?,
+                row.try_get("schedule_value")?,
+                row.try_get("schedule_start")?,
+            )
+            .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            target_quantity: row
+                .try_get::<Option<i32>, _>("target_quantity")?
+                .map(|q| q.max(0) as u32),
+            unit: row.try_get("unit")?,
+            difficulty: MissionDifficulty::from_column(row.try_get("difficulty")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            exp_weight: row
+                .try_get::<Option<i32>, _>("exp_weight")?
+                .map(|w| w.max(0) as u32),
+        })
+    }
+}
+
+/// CSVの1行
+/// スケジュールは種類ごとの列に分けて表し、曜日は`Mon;Wed;Fri`のように区切って並べる
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionCsvRow {
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// daily, weekdays, everyNDays, timesPerWeekのいずれか(空の場合は毎日)
+    #[serde(default)]
+    pub schedule: Option<String>,
+    #[serde(default)]
+    pub weekdays: Option<String>,
+    #[serde(default)]
+    pub interval: Option<u32>,
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub times: Option<u32>,
+    #[serde(default)]
+    pub target_quantity: Option<u32>,
+    #[serde(default)]
+    pub unit: Option<String>,
+    #[serde(default)]
+    pub difficulty: Option<MissionDifficulty>,
+    #[serde(default)]
+    pub exp_weight: Option<u32>,
+}
+
+impl From<&MissionRecord> for MissionCsvRow {
+    fn from(record: &MissionRecord) -> Self {
+        let mut row = Self {
+            title: record.title.clone(),
+            description: record.description.clone(),
+            target_quantity: record.target_quantity,
+            unit: record.unit.clone(),
+            difficulty: Some(record.difficulty),
+            exp_weight: record.exp_weight,
+            ..Default::default()
+        };
+        match &record.schedule {
+            MissionSchedule::Daily => row.schedule = Some("daily".to_string()),
+            MissionSchedule::Weekdays { weekdays } => {
+                row.schedule = Some("weekdays".to_string());
+                row.weekdays = Some(
+                    weekdays
+                        .iter()
+                        .map(|w| w.to_string())
+                        .collect::<Vec<_>>()
+                        .join(";"),
+                );
+            }
+            MissionSchedule::EveryNDays {
+                interval,
+                start_date,
+            } => {
+                row.schedule = Some("everyNDays".to_string());
+                row.interval = Some(*interval);
+                row.start_date = Some(*start_date);
+            }
+            MissionSchedule::TimesPerWeek { times } => {
+                row.schedule = Some("timesPerWeek".to_string());
+                row.times = Some(*times);
+            }
+        }
+        row
+    }
+}
+
+impl TryFrom<MissionCsvRow> for DailyMissionInput {
+    type Error = String;
+
+    fn try_from(row: MissionCsvRow) -> Result<Self, Self::Error> {
+        let schedule = match row.schedule.as_deref().map(str::trim) {
+            None | Some("") | Some("daily") => MissionSchedule::Daily,
+            Some("weekdays") => MissionSchedule::Weekdays {
+                weekdays: row
+                    .weekdays
+                    .as_deref()
+                    .unwrap_or_default()
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|w| !w.is_empty())
+                    .map(|w| {
+                        w.parse::<Weekday>()
+                            .map_err(|_| format!("invalid weekday: {}", w))
+                    })
+                    .collect::<Result<_, _>>()?,
+            },
+            Some("everyNDays") => MissionSchedule::EveryNDays {
+                interval: row.interval.ok_or("interval is required")?,
+                start_date: row.start_date.ok_or("startDate is required")?,
+            },
+            Some("timesPerWeek") => MissionSchedule::TimesPerWeek {
+                times: row.times.ok_or("times is required")?,
+            },
+            Some(v) => return Err(format!("invalid schedule: {}", v)),
+        };
+        Ok(Self {
+            title: row.title,
+            description: row.description.filter(|d| !d.is_empty()),
+            schedule,
+            target_quantity: row.target_quantity,
+            unit: row.unit.filter(|u| !u.is_empty()),
+            difficulty: row.difficulty.unwrap_or_default(),
+            exp_weight: row.exp_weight,
+        })
+    }
+}
+
+/// 取り込めなかった行(rowは1始まりのデータの行番号)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRowError {
+    pub row: usize,
+    pub message: String,
+}
+
+/// 取り込みの結果
+/// 1行でもエラーがある場合や上限を超える場合は1件も登録しない
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub dry_run: bool,
+    /// 登録したかどうか(dry-runの場合は常にfalse)
+    pub applied: bool,
+    pub total: usize,
+    pub valid: usize,
+    pub capacity: u32,
+    /// 取り込む前に登録されていたミッションの数
+    pub existing: u32,
+    pub exceeds_capacity: bool,
+    pub errors: Vec<ImportRowError>,
+    /// 登録したミッションのID(登録しなかった場合は空)
+    pub mission_ids: Vec<DailyMissionId>,
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{NaiveDate, Weekday};
+
+    use crate::entity::{
+        daily_mission_input::DailyMissionInput, mission_difficulty::MissionDifficulty,
+        mission_schedule::MissionSchedule,
+    };
+
+    use super::{MissionCsvRow, MissionRecord};
+
+    #[test]
+    fn test_csv_row_round_trip() {
+        let schedules = [
+            MissionSchedule::Daily,
+            MissionSchedule::Weekdays {
+                weekdays: vec![Weekday::Mon, Weekday::Fri],
+            },
+            MissionSchedule::EveryNDays {
+                interval: 3,
+                start_date: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            },
+            MissionSchedule::TimesPerWeek { times: 2 },
+        ];
+        for schedule in schedules {
+            let record = MissionRecord {
+                title: "read".to_string(),
+                description: None,
+                schedule: schedule.clone(),
+                target_quantity: Some(30),
+                unit: Some("pages".to_string()),
+                difficulty: MissionDifficulty::Hard,
+                exp_weight: None,
+            };
+            let input = DailyMissionInput::try_from(MissionCsvRow::from(&record)).unwrap();
+            assert_eq!(input.schedule, schedule);
+            assert_eq!(input.target_quantity, Some(30));
+            assert_eq!(input.difficulty, MissionDifficulty::Hard);
+        }
+    }
+
+    #[test]
+    fn test_csv_row_errors() {
+        let row = MissionCsvRow {
+            title: "walk".to_string(),
+            schedule: Some("weekdays".to_string()),
+            weekdays: Some("Mon;Funday".to_string()),
+            ..Default::default()
+        };
+        assert!(DailyMissionInput::try_from(row).is_err());
+
+        let row = MissionCsvRow {
+            title: "walk".to_string(),
+            schedule: Some("everyNDays".to_string()),
+            interval: Some(2),
+            ..Default::default()
+        };
+        assert!(DailyMissionInput::try_from(row).is_err());
+
+        // 空欄の場合は毎日、難易度はnormalになる
+        let row = MissionCsvRow {
+            title: "walk".to_string(),
+            description: Some(String::new()),
+            ..Default::default()
+        };
+        let input = DailyMissionInput::try_from(row).unwrap();
+        assert_eq!(input.schedule, MissionSchedule::Daily);
+        assert_eq!(input.description, None);
+        assert_eq!(input.difficulty, MissionDifficulty::Normal);
+    }
+}
@@ -1,9 +1,44 @@
+pub mod achievement;
+pub mod all_clear_bonus;
 pub mod auth_request;
+pub mod calendar;
 pub mod claims;
 pub mod daily_mission;
 pub mod daily_mission_builder;
 pub mod daily_mission_id;
 pub mod daily_mission_input;
+pub mod domain_event;
+pub mod exp_reward;
+pub mod exp_transaction;
+pub mod friend;
+pub mod frozen_day;
+pub mod group;
+pub mod group_id;
+pub mod group_mission;
+pub mod group_mission_id;
+pub mod history_query;
+pub mod leaderboard;
+pub mod level_curve;
+pub mod mission_capacity;
+pub mod mission_completion;
+pub mod mission_difficulty;
+pub mod mission_progress;
+pub mod mission_schedule;
+pub mod mission_template;
+pub mod mission_template_id;
+pub mod mission_transfer;
+pub mod notification;
+pub mod privacy_settings;
+pub mod push_subscription;
+pub mod quest;
+pub mod quest_builder;
+pub mod quest_id;
+pub mod quest_input;
+pub mod reminder;
+pub mod scheduled_job;
+pub mod stats;
+pub mod streak;
+pub mod time_zone_input;
 pub mod token;
 pub mod user;
 pub mod user_builder;
@@ -12,3 +47,5 @@ pub mod user_id;
 pub mod user_info;
 pub mod user_input;
 pub mod user_level;
+pub mod vacation;
+pub mod webhook;
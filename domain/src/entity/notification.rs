@@ -0,0 +1,128 @@
+use serde::Serialize;
+
+use super::{daily_mission::DailyMission, user_id::UserId};
+
+/// ユーザーに送る通知
+/// 送信の方法はNotificationChannelの実装が決める
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    pub user_id: UserId,
+    pub title: String,
+    pub body: String,
+}
+
+impl Notification {
+    /// ミッションのリマインダー
+    /// 今日の実施日でない場合や完了済みの場合は通知しない
+    pub fn reminder(mission: &DailyMission) -> Option<Self> {
+        if !mission.is_due || mission.is_complete {
+            return None;
+        }
+        let body = match mission.target_quantity {
+            Some(target) => format!(
+                "{} ({}/{}{})",
+                mission.title,
+                mission.progress,
+                target,
+                mission
+                    .unit
+                    .as_ref()
+                    .map(|u| format!(" {}", u))
+                    .unwrap_or_default()
+            ),
+            None => mission.title.clone(),
+        };
+        Some(Self {
+            user_id: mission.user_id.clone(),
+            title: "Mission reminder".to_string(),
+            body,
+        })
+    }
+
+    /// その日のミッションのまとめ
+    /// 今日のミッションがすべて完了している場合は通知しない
+    pub fn summary(missions: &[DailyMission]) -> Option<Self> {
+        let due: Vec<_> = missions.iter().filter(|m| m.is_due).collect();
+        let remaining: Vec<_> = due.iter().filter(|m| !m.is_complete).collect();
+        let first = remaining.first()?;
+        let titles = remaining
+            .iter()
+            .map(|m| m.title.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        Some(Self {
+            user_id: first.user_id.clone(),
+            title: format!("{} of {} missions left today", remaining.len(), due.len()),
+            body: titles,
+        })
+    }
+
+    /// レベルアップ
+    pub fn level_up(user_id: &UserId, new_level: u32, total_exp: i64) -> Self {
+        Self {
+            user_id: user_id.clone(),
+            title: "Level up!".to_string(),
+            body: format!("You reached level {} (total {} exp)", new_level, total_exp),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::entity::{
+        daily_mission::DailyMission, daily_mission_id::DailyMissionId,
+        mission_difficulty::MissionDifficulty, mission_schedule::MissionSchedule, user_id::UserId,
+    };
+
+    use super::Notification;
+
+    fn gen_mission(title: &str, is_complete: bool, is_due: bool) -> DailyMission {
+        DailyMission {
+            user_id: UserId("test_user".to_string()),
+            mission_id: DailyMissionId(title.to_string()),
+            title: title.to_string(),
+            description: None,
+            is_complete,
+            schedule: MissionSchedule::Daily,
+            is_due,
+            target_quantity: None,
+            unit: None,
+            progress: 0,
+            difficulty: MissionDifficulty::Normal,
+            exp_weight: None,
+            current_streak: 0,
+            longest_streak: 0,
+        }
+    }
+
+    #[test]
+    fn test_reminder() {
+        assert!(Notification::reminder(&gen_mission("read", true, true)).is_none());
+        assert!(Notification::reminder(&gen_mission("read", false, false)).is_none());
+
+        let mut mission = gen_mission("read", false, true);
+        mission.target_quantity = Some(30);
+        mission.progress = 12;
+        mission.unit = Some("pages".to_string());
+        let notification = Notification::reminder(&mission).unwrap();
+        assert_eq!(notification.body, "read (12/30 pages)");
+    }
+
+    #[test]
+    fn test_summary() {
+        let missions = vec![
+            gen_mission("read", true, true),
+            gen_mission("walk", false, true),
+            gen_mission("clean", false, true),
+            gen_mission("swim", false, false),
+        ];
+        let notification = Notification::summary(&missions).unwrap();
+        assert_eq!(notification.title, "2 of 3 missions left today");
+        assert_eq!(notification.body, "walk, clean");
+
+        let missions = vec![gen_mission("read", true, true)];
+        assert!(Notification::summary(&missions).is_none());
+        assert!(Notification::summary(&[]).is_none());
+    }
+}
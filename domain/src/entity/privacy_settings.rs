@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// ランキングとフレンド申請の公開設定
+/// すべてデフォルトで公開され、ユーザーが個別にオプトアウトできる
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivacySettings {
+    /// 全体のランキングに表示する
+    pub show_on_global_leaderboard: bool,
+    /// フレンドのランキングに表示する
+    pub show_on_friend_leaderboard: bool,
+    /// フレンド申請を受け付ける
+    pub allow_friend_requests: bool,
+}
+
+impl Default for PrivacySettings {
+    fn default() -> Self {
+        Self {
+            show_on_global_leaderboard: true,
+            show_on_friend_leaderboard: true,
+            allow_friend_requests: true,
+        }
+    }
+}
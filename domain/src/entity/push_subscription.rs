@@ -0,0 +1,201 @@
+use chrono::{DateTime, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+use validator::{Validate, ValidationError};
+
+use super::{user_id::UserId, webhook::validate_url};
+
+/// 購読の登録でクライアントから送られるPayload
+/// ブラウザの`PushSubscription.toJSON()`の結果に端末名を加えたもの
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscriptionInput {
+    #[validate(length(max = 512), custom(function = "validate_endpoint"))]
+    pub endpoint: String,
+    /// 購読の有効期限(UNIX時間のミリ秒、期限がない場合はnull)
+    #[serde(default)]
+    pub expiration_time: Option<i64>,
+    #[validate(nested)]
+    pub keys: PushKeys,
+    #[serde(default)]
+    #[validate(length(min = 1, max = 50))]
+    pub device_name: Option<String>,
+}
+
+/// 端末の公開鍵(P-256の非圧縮形式、65バイト)と認証用の秘密(16バイト)をbase64urlで表したもの
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PushKeys {
+    #[validate(custom(function = "validate_p256dh"))]
+    pub p256dh: String,
+    #[validate(custom(function = "validate_auth"))]
+    pub auth: String,
+}
+
+impl PushSubscriptionInput {
+    /// expiration_timeをUTCの日時にする
+    pub fn expires_at(&self) -> Option<NaiveDateTime> {
+        self.expiration_time
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|t| t.naive_utc())
+    }
+}
+
+// プッシュサービスはHTTPSで提供される
+// 利用者が指定したURLにサーバーから送信するため、内部のサービスを指すURLは受け付けない
+fn validate_endpoint(endpoint: &str) -> Result<(), ValidationError> {
+    if !endpoint.starts_with("https://") {
+        return Err(ValidationError::new("endpoint_not_https"));
+    }
+    validate_url(endpoint).map_err(|_| ValidationError::new("endpoint_not_public"))
+}
+
+fn validate_p256dh(value: &str) -> Result<(), ValidationError> {
+    validate_base64url(value, 65).map_err(|_| ValidationError::new("invalid_p256dh"))
+}
+
+fn validate_auth(value: &str) -> Result<(), ValidationError> {
+    validate_base64url(value, 16).map_err(|_| ValidationError::new("invalid_auth"))
+}
+
+// base64url(パディングは任意)でbytesバイトを表しているかどうか
+fn validate_base64url(value: &str, bytes: usize) -> Result<(), ()> {
+    let value = value.trim_end_matches('=');
+    let is_base64url = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !is_base64url || value.len() != (bytes * 4).div_ceil(3) {
+        return Err(());
+    }
+    Ok(())
+}
+
+/// 登録済みの購読
+/// created_at, expires_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushSubscription {
+    pub subscription_id: String,
+    #[serde(skip)]
+    pub user_id: UserId,
+    pub endpoint: String,
+    #[serde(skip)]
+    pub p256dh: String,
+    #[serde(skip)]
+    pub auth: String,
+    pub device_name: Option<String>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for PushSubscription {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            subscription_id: row.try_get("subscription_id")?,
+            user_id: UserId(row.try_get("user_id")?),
+            endpoint: row.try_get("endpoint")?,
+            p256dh: row.try_get("p256dh")?,
+            auth: row.try_get("auth")?,
+            device_name: row.try_get("device_name")?,
+            expires_at: row.try_get("expires_at")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+impl PushSubscription {
+    /// nowの時点で購読の有効期限が切れているかどうか
+    pub fn is_expired(&self, now: NaiveDateTime) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// ブラウザで購読する際に`applicationServerKey`として渡すVAPIDの公開鍵
+/// P-256の非圧縮形式の公開鍵をbase64url(パディングなし)で表したもの
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VapidPublicKey {
+    pub public_key: String,
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{DateTime, NaiveDate};
+    use validator::Validate;
+
+    use crate::entity::user_id::UserId;
+
+    use super::{PushKeys, PushSubscription, PushSubscriptionInput};
+
+    fn gen_input() -> PushSubscriptionInput {
+        PushSubscriptionInput {
+            endpoint: "https://push.example.com/send/abc".to_string(),
+            expiration_time: None,
+            keys: PushKeys {
+                p256dh: "BNcRdreALRFXTkOOUHK1EtK2wtaz5Ry4YfYCA_0QTpQtUbVlUls0VJXg7A8u-Ts1XbjhazAkj7I99e8QcYP7DkM".to_string(),
+                auth: "tBHItJI5svbpez7KI4CCXg".to_string(),
+            },
+            device_name: Some("laptop".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_validate_input() {
+        assert!(gen_input().validate().is_ok());
+
+        let mut input = gen_input();
+        input.endpoint = "http://push.example.com/send/abc".to_string();
+        assert!(input.validate().is_err());
+
+        // ループバック、プライベート、リンクローカルのホストは受け付けない
+        for endpoint in [
+            "https://localhost/send/abc",
+            "https://127.0.0.1/send/abc",
+            "https://10.0.0.1/send/abc",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/send/abc",
+        ] {
+            let mut input = gen_input();
+            input.endpoint = endpoint.to_string();
+            assert!(input.validate().is_err(), "{}", endpoint);
+        }
+
+        let mut input = gen_input();
+        input.keys.auth = "tBHItJI5svbpez7KI4CC".to_string();
+        assert!(input.validate().is_err());
+
+        // パディング付きも受け付けるが、base64urlでない文字は受け付けない
+        let mut input = gen_input();
+        input.keys.auth = "tBHItJI5svbpez7KI4CCXg==".to_string();
+        assert!(input.validate().is_ok());
+        input.keys.auth = "tBHItJI5svbpez7KI4CC+g".to_string();
+        assert!(input.validate().is_err());
+    }
+
+    #[test]
+    fn test_expiration() {
+        let mut input = gen_input();
+        assert_eq!(input.expires_at(), None);
+        input.expiration_time = Some(1_792_454_400_000);
+        let expires_at = DateTime::from_timestamp(1_792_454_400, 0)
+            .unwrap()
+            .naive_utc();
+        assert_eq!(input.expires_at(), Some(expires_at));
+
+        let subscription = PushSubscription {
+            subscription_id: "id".to_string(),
+            user_id: UserId("test_user".to_string()),
+            endpoint: input.endpoint.clone(),
+            p256dh: input.keys.p256dh.clone(),
+            auth: input.keys.auth.clone(),
+            device_name: None,
+            expires_at: input.expires_at(),
+            created_at: NaiveDate::from_ymd_opt(2026, 10, 20)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        };
+        assert!(!subscription.is_expired(expires_at - chrono::Duration::seconds(1)));
+        assert!(subscription.is_expired(expires_at));
+    }
+}
@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{quest_id::QuestId, user_id::UserId};
+
+/// 一度だけ達成するクエスト
+/// すべてのサブタスクを完了すると、クエストを達成できる
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quest {
+    pub user_id: UserId,
+    pub quest_id: QuestId,
+    pub title: String,
+    pub description: Option<String>,
+    /// 期限(Noneの場合は期限なし)
+    pub due_date: Option<NaiveDate>,
+    /// 達成した日(ユーザーのタイムゾーン)
+    pub completed_date: Option<NaiveDate>,
+    pub subtasks: Vec<Subtask>,
+}
+
+impl Quest {
+    pub fn is_complete(&self) -> bool {
+        self.completed_date.is_some()
+    }
+
+    pub fn is_subtasks_complete(&self) -> bool {
+        self.subtasks.iter().all(|s| s.is_complete)
+    }
+}
+
+/// クエストのサブタスク(チェックリストの1項目)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Subtask {
+    pub subtask_id: i64,
+    pub title: String,
+    pub is_complete: bool,
+}
+
+impl FromRow<'_, MySqlRow> for Subtask {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            subtask_id: row.try_get::<i32, _>("id")?.into(),
+            title: row.try_get("title")?,
+            is_complete: row.try_get("is_complete")?,
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::NaiveDate;
+
+use super::{
+    quest::{Quest, Subtask},
+    quest_id::QuestId,
+    user_id::UserId,
+};
+
+#[derive(Debug, Clone)]
+pub struct QuestBuilder {
+    user_id: UserId,
+    quest_id: QuestId,
+    title: String,
+    description: Option<String>,
+    due_date: Option<NaiveDate>,
+    subtasks: Vec<Subtask>,
+}
+
+impl QuestBuilder {
+    pub fn new() -> Self {
+        QuestBuilder::default()
+    }
+
+    pub fn user_id(mut self, user_id: &UserId) -> QuestBuilder {
+        self.user_id = user_id.to_owned();
+        self
+    }
+
+    pub fn quest_id(mut self, quest_id: &QuestId) -> QuestBuilder {
+        self.quest_id = quest_id.to_owned();
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> QuestBuilder {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn description(mut self, description: &Option<String>) -> QuestBuilder {
+        self.description = description.to_owned();
+        self
+    }
+
+    pub fn due_date(mut self, due_date: &Option<NaiveDate>) -> QuestBuilder {
+        self.due_date = due_date.to_owned();
+        self
+    }
+
+    /// サブタスクのidは保存時に採番されるため、0とする
+    pub fn subtasks(mut self, subtasks: &[(String, bool)]) -> QuestBuilder {
+        self.subtasks = subtasks
+            .iter()
+            .map(|(title, is_complete)| Subtask {
+                subtask_id: 0,
+                title: title.to_owned(),
+                is_complete: *is_complete,
+            })
+            .collect();
+        self
+    }
+
+    pub fn build(self) -> Quest {
+        Quest {
+            user_id: self.user_id,
+            quest_id: self.quest_id,
+            title: self.title,
+            description: self.description,
+            due_date: self.due_date,
+            completed_date: None,
+            subtasks: self.subtasks,
+        }
+    }
+}
+
+impl Default for QuestBuilder {
+    fn default() -> Self {
+        Self {
+            user_id: UserId(String::default()),
+            quest_id: QuestId(String::default()),
+            title: String::default(),
+            description: Option::default(),
+            due_date: Option::default(),
+            subtasks: Vec::default(),
+        }
+    }
+}
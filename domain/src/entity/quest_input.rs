@@ -0,0 +1,37 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// クエストに登録できるサブタスクの最大数
+pub const MAX_SUBTASKS: u64 = 20;
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestInput {
+    #[validate(length(min = 1, max = 20))]
+    pub title: String,
+    #[validate(length(max = 100))]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+    /// 更新時はサブタスクをこの内容で置き換える
+    #[serde(default)]
+    #[validate(length(max = MAX_SUBTASKS), nested)]
+    pub subtasks: Vec<SubtaskInput>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtaskInput {
+    #[validate(length(min = 1, max = 50))]
+    pub title: String,
+    #[serde(default)]
+    pub is_complete: bool,
+}
+
+/// サブタスクのチェックの変更でユーザーから送られるPayload
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtaskCheckInput {
+    pub is_complete: bool,
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::daily_mission_id::DailyMissionId;
+
+/// リマインダーやまとめの時刻の変更でクライアントから送られるPayload
+/// timeはユーザーのタイムゾーンの時刻で、`"08:30"`または`"08:30:00"`の形式(nullの場合は通知しない)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderInput {
+    pub time: Option<NaiveTime>,
+}
+
+/// ミッションごとのリマインダーの設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionReminder {
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    pub reminder_time: Option<NaiveTime>,
+}
+
+impl FromRow<'_, MySqlRow> for MissionReminder {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            mission_id: DailyMissionId(row.try_get("mission_id")?),
+            title: row.try_get("title")?,
+            reminder_time: row.try_get("reminder_time")?,
+        })
+    }
+}
+
+/// ユーザーの通知の設定
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderSettings {
+    /// その日のミッションのまとめを通知する時刻
+    pub summary_time: Option<NaiveTime>,
+    pub missions: Vec<MissionReminder>,
+}
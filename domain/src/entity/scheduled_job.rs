@@ -0,0 +1,168 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{daily_mission_id::DailyMissionId, user_id::UserId};
+
+/// 同じジョブを再試行する最大の回数(最初の実行を含む)
+pub const MAX_JOB_ATTEMPTS: u32 = 3;
+
+/// 実行予定の時刻からこの秒数を過ぎても実行されなかったジョブは実行しない
+/// サーバーが長時間停止していた場合に、古いリマインダーをまとめて送らないようにする
+pub const JOB_GRACE_SECONDS: u32 = 3600;
+
+/// 一度に取得して実行するジョブの最大数
+pub const JOB_BATCH_SIZE: u32 = 100;
+
+/// 完了したジョブを削除するまでの日数
+pub const JOB_RETENTION_DAYS: u32 = 30;
+
+/// ジョブの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobKind {
+    /// ミッションごとのリマインダー
+    Reminder,
+    /// その日のミッションのまとめ
+    Summary,
+    /// レベルアップの通知(レベルが上がったトランザクションで登録する)
+    LevelUp,
+}
+
+impl JobKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Reminder => "reminder",
+            Self::Summary => "summary",
+            Self::LevelUp => "level_up",
+        }
+    }
+
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "reminder" => Ok(Self::Reminder),
+            "summary" => Ok(Self::Summary),
+            "level_up" => Ok(Self::LevelUp),
+            v => Err(format!("invalid job kind: {}", v)),
+        }
+    }
+}
+
+/// ジョブの状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    /// 実行待ち
+    Pending,
+    /// いずれかのインスタンスが実行中
+    Running,
+    /// 通知を送った
+    Done,
+    /// 通知する必要がなかった(ミッションが完了済み、期限切れなど)
+    Skipped,
+    /// 再試行の上限に達した
+    Failed,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Done => "done",
+            Self::Skipped => "skipped",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// 実行するジョブ
+/// local_dateはユーザーのタイムゾーンにおける対象の日付、run_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledJob {
+    pub id: i64,
+    pub kind: JobKind,
+    pub user_id: UserId,
+    /// リマインダーの対象のミッション(まとめの場合はNone)
+    pub mission_id: Option<DailyMissionId>,
+    pub local_date: NaiveDate,
+    pub run_at: NaiveDateTime,
+    /// レベルアップの通知の内容(それ以外のジョブの場合はNone)
+    pub new_level: Option<u32>,
+    pub total_exp: Option<i64>,
+    /// 今回の実行を含めた実行回数
+    pub attempts: u32,
+}
+
+impl FromRow<'_, MySqlRow> for ScheduledJob {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            kind: JobKind::from_column(row.try_get("kind")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            user_id: UserId(row.try_get("user_id")?),
+            mission_id: row
+                .try_get::<Option<String>, _>("mission_id")?
+                .map(DailyMissionId),
+            local_date: row.try_get("local_date")?,
+            run_at: row.try_get("run_at")?,
+            new_level: row
+                .try_get::<Option<i32>, _>("new_level")?
+                .map(|level| level.max(0) as u32),
+            total_exp: row.try_get("total_exp")?,
+            attempts: row.try_get::<i32, _>("attempts")?.max(0) as u32,
+        })
+    }
+}
+
+impl ScheduledJob {
+    /// 失敗したジョブを再試行するかどうか
+    pub fn can_retry(&self) -> bool {
+        self.attempts < MAX_JOB_ATTEMPTS
+    }
+}
+
+/// attempts回目の実行に失敗したジョブを再試行するまでの秒数
+/// 1分、4分、16分...と間隔を広げる
+pub fn retry_delay_seconds(attempts: u32) -> u32 {
+    60 * 4u32.pow(attempts.clamp(1, 5) - 1)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::entity::user_id::UserId;
+
+    use super::{retry_delay_seconds, JobKind, ScheduledJob, MAX_JOB_ATTEMPTS};
+
+    #[test]
+    fn test_retry() {
+        assert_eq!(retry_delay_seconds(1), 60);
+        assert_eq!(retry_delay_seconds(2), 240);
+        assert_eq!(retry_delay_seconds(3), 960);
+        // 上限を設けて桁あふれしないようにする
+        assert_eq!(retry_delay_seconds(100), retry_delay_seconds(5));
+
+        let date = NaiveDate::from_ymd_opt(2026, 10, 20).unwrap();
+        let mut job = ScheduledJob {
+            id: 1,
+            kind: JobKind::Summary,
+            user_id: UserId("test_user".to_string()),
+            mission_id: None,
+            local_date: date,
+            run_at: date.and_hms_opt(0, 0, 0).unwrap(),
+            new_level: None,
+            total_exp: None,
+            attempts: 1,
+        };
+        assert!(job.can_retry());
+        job.attempts = MAX_JOB_ATTEMPTS;
+        assert!(!job.can_retry());
+    }
+
+    #[test]
+    fn test_job_kind_columns() {
+        for kind in [JobKind::Reminder, JobKind::Summary, JobKind::LevelUp] {
+            assert_eq!(JobKind::from_column(kind.as_str()), Ok(kind));
+        }
+        assert!(JobKind::from_column("weekly").is_err());
+    }
+}
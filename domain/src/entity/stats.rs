@@ -0,0 +1,613 @@
+use std::collections::{BTreeMap, HashSet};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{daily_mission_id::DailyMissionId, mission_schedule::MissionSchedule};
+
+/// 完了率などを集計できる期間(日数)
+pub const STATS_PERIODS: [u32; 3] = [7, 30, 90];
+pub const DEFAULT_STATS_PERIOD: u32 = 30;
+/// ヒートマップで取得できる日数
+pub const DEFAULT_HEATMAP_DAYS: u32 = 365;
+pub const MAX_HEATMAP_DAYS: u32 = 366;
+/// 週ごとの経験値で取得できる週の数
+pub const DEFAULT_EXP_WEEKS: u32 = 12;
+pub const MAX_EXP_WEEKS: u32 = 53;
+
+/// 集計する期間(fromとtoを含む)
+/// 日付はすべてユーザーのタイムゾーンにおける日付
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DateRange {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+impl DateRange {
+    /// todayで終わるdays日間
+    pub fn ending(today: NaiveDate, days: u32) -> Self {
+        Self {
+            from: today - Days::new(u64::from(days.max(1) - 1)),
+            to: today,
+        }
+    }
+
+    /// 直前の同じ長さの期間
+    pub fn previous(&self) -> Self {
+        let len = self.len();
+        Self {
+            from: self.from - Days::new(u64::from(len)),
+            to: self.from - Days::new(1),
+        }
+    }
+
+    /// 期間の日数
+    pub fn len(&self) -> u32 {
+        ((self.to - self.from).num_days() + 1).max(0) as u32
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 期間に含まれる日付
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.from.iter_days().take_while(|d| *d <= self.to)
+    }
+}
+
+/// 完了率などを集計する期間の指定(7、30、90日)
+#[derive(Debug, Clone, Deserialize)]
+pub struct StatsQuery {
+    pub days: Option<u32>,
+}
+
+impl StatsQuery {
+    /// バリデーション済みの日数
+    pub fn period(&self) -> Result<u32, String> {
+        let days = self.days.unwrap_or(DEFAULT_STATS_PERIOD);
+        if !STATS_PERIODS.contains(&days) {
+            return Err("`days` must be one of 7, 30 or 90".to_string());
+        }
+        Ok(days)
+    }
+}
+
+/// ヒートマップの日数の指定
+#[derive(Debug, Clone, Deserialize)]
+pub struct HeatmapQuery {
+    pub days: Option<u32>,
+}
+
+impl HeatmapQuery {
+    /// バリデーション済みの日数
+    pub fn period(&self) -> Result<u32, String> {
+        let days = self.days.unwrap_or(DEFAULT_HEATMAP_DAYS);
+        if days == 0 || days > MAX_HEATMAP_DAYS {
+            return Err(format!("`days` must be between 1 and {}", MAX_HEATMAP_DAYS));
+        }
+        Ok(days)
+    }
+}
+
+/// 週ごとの経験値の週の数の指定
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeeklyExpQuery {
+    pub weeks: Option<u32>,
+}
+
+impl WeeklyExpQuery {
+    /// バリデーション済みの週の数
+    pub fn period(&self) -> Result<u32, String> {
+        let weeks = self.weeks.unwrap_or(DEFAULT_EXP_WEEKS);
+        if weeks == 0 || weeks > MAX_EXP_WEEKS {
+            return Err(format!("`weeks` must be between 1 and {}", MAX_EXP_WEEKS));
+        }
+        Ok(weeks)
+    }
+}
+
+/// 1日の集計(user_daily_stats)
+/// ミッションを削除しても集計は残る
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyStat {
+    pub date: NaiveDate,
+    pub completions: u32,
+    /// 完了やボーナスで獲得した経験値(取り消した分を差し引く)
+    pub exp_gained: i64,
+}
+
+impl FromRow<'_, MySqlRow> for DailyStat {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            date: row.try_get("date")?,
+            completions: row.try_get::<i32, _>("completions")?.max(0) as u32,
+            exp_gained: row.try_get("exp_gained")?,
+        })
+    }
+}
+
+/// 完了率を集計するミッション
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatsMission {
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    pub schedule: MissionSchedule,
+    /// 集計を始める日(登録した日と最初に完了した日のうち早い方)
+    pub started_on: NaiveDate,
+}
+
+/// ミッションを完了した日
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionDate {
+    pub mission_id: DailyMissionId,
+    pub date: NaiveDate,
+}
+
+impl FromRow<'_, MySqlRow> for CompletionDate {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            mission_id: DailyMissionId(row.try_get("mission_id")?),
+            date: row.try_get("date")?,
+        })
+    }
+}
+
+/// 期間に実施するはずだった日数と、そのうち完了した日数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionCount {
+    pub due_days: u32,
+    pub completed_days: u32,
+}
+
+impl CompletionCount {
+    /// ミッションの期間における完了の数を数える
+    /// 凍結された日(休暇モードとストリークフリーズ)と、集計を始める前の日は数えない
+    /// TimesPerWeekは週ごとに回数(週の残りの日数が少ない場合はその日数)を上限として数える
+    pub fn count(
+        mission: &StatsMission,
+        range: DateRange,
+        completed: &HashSet<NaiveDate>,
+        frozen: &HashSet<NaiveDate>,
+    ) -> Self {
+        let range = DateRange {
+            from: range.from.max(mission.started_on),
+            to: range.to,
+        };
+        let days = range.days().filter(|d| !frozen.contains(d));
+        match &mission.schedule {
+            MissionSchedule::TimesPerWeek { times } => {
+                let mut weeks: BTreeMap<_, Self> = BTreeMap::new();
+                for day in days {
+                    let week = weeks.entry(day.iso_week()).or_default();
+                    week.due_days += 1;
+                    week.completed_days += u32::from(completed.contains(&day));
+                }
+                weeks.into_values().fold(Self::default(), |total, week| {
+                    let due_days = week.due_days.min(*times);
+                    Self {
+                        due_days: total.due_days + due_days,
+                        completed_days: total.completed_days + week.completed_days.min(due_days),
+                    }
+                })
+            }
+            schedule => {
+                days.filter(|d| schedule.is_scheduled(*d))
+                    .fold(Self::default(), |total, day| Self {
+                        due_days: total.due_days + 1,
+                        completed_days: total.completed_days + u32::from(completed.contains(&day)),
+                    })
+            }
+        }
+    }
+
+    /// 完了率(0.0〜1.0)
+    /// 実施する日がなかった場合はNone
+    pub fn rate(&self) -> Option<f64> {
+        (self.due_days > 0).then(|| f64::from(self.completed_days) / f64::from(self.due_days))
+    }
+
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            due_days: self.due_days + other.due_days,
+            completed_days: self.completed_days + other.completed_days,
+        }
+    }
+}
+
+/// ミッションごとの完了率と、直前の同じ長さの期間との比較
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionRate {
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    #[serde(flatten)]
+    pub count: CompletionCount,
+    pub rate: Option<f64>,
+    pub previous_rate: Option<f64>,
+    /// rateとprevious_rateの差(どちらかがNoneの場合はNone)
+    pub change: Option<f64>,
+}
+
+impl MissionRate {
+    pub fn new(
+        mission: &StatsMission,
+        current: CompletionCount,
+        previous: CompletionCount,
+    ) -> Self {
+        Self {
+            mission_id: mission.mission_id.clone(),
+            title: mission.title.clone(),
+            count: current,
+            rate: current.rate(),
+            previous_rate: previous.rate(),
+            change: rate_change(current, previous),
+        }
+    }
+}
+
+/// GET /api/stats/missions のレスポンス
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionRates {
+    pub current: DateRange,
+    pub previous: DateRange,
+    pub missions: Vec<MissionRate>,
+}
+
+/// カレンダーのヒートマップ
+/// daysには完了または経験値の増減があった日だけを日付順に含める
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Heatmap {
+    #[serde(flatten)]
+    pub range: DateRange,
+    /// 期間で最も多く完了した日の完了の数(色の濃さの基準)
+    pub max_completions: u32,
+    pub days: Vec<DailyStat>,
+}
+
+impl Heatmap {
+    pub fn new(range: DateRange, mut days: Vec<DailyStat>) -> Self {
+        days.retain(|d| d.completions > 0 || d.exp_gained != 0);
+        days.sort_by_key(|d| d.date);
+        Self {
+            range,
+            max_completions: days.iter().map(|d| d.completions).max().unwrap_or(0),
+            days,
+        }
+    }
+}
+
+/// 曜日ごとの完了の数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekdayStat {
+    pub weekday: Weekday,
+    pub completions: u32,
+    /// 期間に含まれるその曜日1日あたりの完了の数
+    pub average: f64,
+}
+
+/// 曜日ごとの完了の数と、1日あたりの完了が最も多い曜日
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekdayStats {
+    #[serde(flatten)]
+    pub range: DateRange,
+    /// 月曜日から日曜日の順
+    pub weekdays: Vec<WeekdayStat>,
+    /// 完了がない場合はNone(同じ場合は週の早い曜日)
+    pub best_weekday: Option<Weekday>,
+}
+
+impl WeekdayStats {
+    pub fn new(range: DateRange, days: &[DailyStat]) -> Self {
+        let weekdays: Vec<WeekdayStat> = (0..7)
+            .filter_map(|i| Weekday::try_from(i as u8).ok())
+            .map(|weekday| {
+                let occurrences = range.days().filter(|d| d.weekday() == weekday).count();
+                let completions = days
+                    .iter()
+                    .filter(|d| d.date.weekday() == weekday)
+                    .map(|d| d.completions)
+                    .sum();
+                WeekdayStat {
+                    weekday,
+                    completions,
+                    average: if occurrences > 0 {
+                        f64::from(completions) / occurrences as f64
+                    } else {
+                        0.0
+                    },
+                }
+            })
+            .collect();
+        let best_weekday = weekdays
+            .iter()
+            .filter(|w| w.completions > 0)
+            .fold(None::<&WeekdayStat>, |best, w| match best {
+                Some(b) if b.average >= w.average => Some(b),
+                _ => Some(w),
+            })
+            .map(|w| w.weekday);
+        Self {
+            range,
+            weekdays,
+            best_weekday,
+        }
+    }
+}
+
+/// 1週間(ISO週)に獲得した経験値
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeeklyExp {
+    /// 週の初め(月曜日)
+    pub week_start: NaiveDate,
+    pub exp_gained: i64,
+    pub completions: u32,
+}
+
+impl WeeklyExp {
+    /// todayを含む週まで、weeks週の範囲
+    pub fn range(today: NaiveDate, weeks: u32) -> DateRange {
+        let this_week = week_start(today);
+        DateRange {
+            from: this_week - Days::new(7 * u64::from(weeks.max(1) - 1)),
+            to: today,
+        }
+    }
+
+    /// 週ごとにまとめる(経験値の増減がなかった週も含めて古い順)
+    pub fn group(range: DateRange, days: &[DailyStat]) -> Vec<Self> {
+        let mut weeks: BTreeMap<NaiveDate, Self> = range
+            .days()
+            .map(week_start)
+            .map(|week_start| {
+                (
+                    week_start,
+                    Self {
+                        week_start,
+                        exp_gained: 0,
+                        completions: 0,
+                    },
+                )
+            })
+            .collect();
+        for day in days {
+            if let Some(week) = weeks.get_mut(&week_start(day.date)) {
+                week.exp_gained += day.exp_gained;
+                week.completions += day.completions;
+            }
+        }
+        weeks.into_values().collect()
+    }
+}
+
+/// 期間の合計
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodTotals {
+    #[serde(flatten)]
+    pub range: DateRange,
+    pub completions: u32,
+    /// 1つ以上完了した日数
+    pub active_days: u32,
+    pub exp_gained: i64,
+    /// 現在のミッション全体の完了率
+    pub completion_rate: Option<f64>,
+}
+
+impl PeriodTotals {
+    pub fn new(range: DateRange, days: &[DailyStat], count: CompletionCount) -> Self {
+        let days = days
+            .iter()
+            .filter(|d| range.from <= d.date && d.date <= range.to);
+        let (completions, active_days, exp_gained) =
+            days.fold((0, 0, 0), |(completions, active_days, exp_gained), d| {
+                (
+                    completions + d.completions,
+                    active_days + u32::from(d.completions > 0),
+                    exp_gained + d.exp_gained,
+                )
+            });
+        Self {
+            range,
+            completions,
+            active_days,
+            exp_gained,
+            completion_rate: count.rate(),
+        }
+    }
+}
+
+/// 直前の期間との差
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodChange {
+    pub completions: i64,
+    pub active_days: i64,
+    pub exp_gained: i64,
+    pub completion_rate: Option<f64>,
+}
+
+/// GET /api/stats/summary のレスポンス
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSummary {
+    pub current: PeriodTotals,
+    pub previous: PeriodTotals,
+    pub change: PeriodChange,
+}
+
+impl StatsSummary {
+    pub fn new(current: PeriodTotals, previous: PeriodTotals) -> Self {
+        let change = PeriodChange {
+            completions: i64::from(current.completions) - i64::from(previous.completions),
+            active_days: i64::from(current.active_days) - i64::from(previous.active_days),
+            exp_gained: current.exp_gained - previous.exp_gained,
+            completion_rate: current
+                .completion_rate
+                .zip(previous.completion_rate)
+                .map(|(c, p)| c - p),
+        };
+        Self {
+            current,
+            previous,
+            change,
+        }
+    }
+}
+
+/// dateを含むISO週の月曜日
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+}
+
+fn rate_change(current: CompletionCount, previous: CompletionCount) -> Option<f64> {
+    current.rate().zip(previous.rate()).map(|(c, p)| c - p)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::{NaiveDate, Weekday};
+
+    use crate::entity::{daily_mission_id::DailyMissionId, mission_schedule::MissionSchedule};
+
+    use super::{
+        CompletionCount, DailyStat, DateRange, StatsMission, StatsQuery, WeekdayStats, WeeklyExp,
+    };
+
+    fn date(day: u32) -> NaiveDate {
+        // 2026-10-05は月曜日
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    fn gen_mission(schedule: MissionSchedule, started_on: NaiveDate) -> StatsMission {
+        StatsMission {
+            mission_id: DailyMissionId("test_mission".to_string()),
+            title: "test".to_string(),
+            schedule,
+            started_on,
+        }
+    }
+
+    fn gen_stat(day: u32, completions: u32, exp_gained: i64) -> DailyStat {
+        DailyStat {
+            date: date(day),
+            completions,
+            exp_gained,
+        }
+    }
+
+    #[test]
+    fn test_date_range() {
+        let range = DateRange::ending(date(18), 7);
+        assert_eq!(range.from, date(12));
+        assert_eq!(range.len(), 7);
+        let previous = range.previous();
+        assert_eq!((previous.from, previous.to), (date(5), date(11)));
+
+        assert!(StatsQuery { days: None }.period().is_ok());
+        assert!(StatsQuery { days: Some(90) }.period().is_ok());
+        assert!(StatsQuery { days: Some(14) }.period().is_err());
+    }
+
+    #[test]
+    fn test_completion_count() {
+        let range = DateRange::ending(date(18), 14);
+        let completed: HashSet<_> = [date(5), date(6), date(8), date(12)].into();
+        let no_frozen = HashSet::new();
+
+        // 毎日のミッションは集計を始めた日から数える
+        let daily = gen_mission(MissionSchedule::Daily, date(9));
+        let count = CompletionCount::count(&daily, range, &completed, &no_frozen);
+        assert_eq!(
+            count,
+            CompletionCount {
+                due_days: 10,
+                completed_days: 1
+            }
+        );
+        assert_eq!(count.rate(), Some(0.1));
+
+        // 凍結された日は実施日に含めない
+        let frozen: HashSet<_> = [date(10), date(11)].into();
+        let count = CompletionCount::count(&daily, range, &completed, &frozen);
+        assert_eq!(count.due_days, 8);
+
+        // 曜日指定は実施日だけを数える
+        let weekdays = gen_mission(
+            MissionSchedule::Weekdays {
+                weekdays: vec![Weekday::Mon, Weekday::Wed],
+            },
+            date(1),
+        );
+        let count = CompletionCount::count(&weekdays, range, &completed, &no_frozen);
+        assert_eq!(
+            count,
+            CompletionCount {
+                due_days: 4,
+                completed_days: 2
+            }
+        );
+
+        // 週に2回のミッションは週ごとに2回を上限とする
+        let times = gen_mission(MissionSchedule::TimesPerWeek { times: 2 }, date(1));
+        let count = CompletionCount::count(&times, range, &completed, &no_frozen);
+        assert_eq!(
+            count,
+            CompletionCount {
+                due_days: 4,
+                completed_days: 3
+            }
+        );
+
+        // 期間の後に始めたミッションは実施日がない
+        let future = gen_mission(MissionSchedule::Daily, date(19));
+        let count = CompletionCount::count(&future, range, &completed, &no_frozen);
+        assert_eq!(count.rate(), None);
+    }
+
+    #[test]
+    fn test_weekday_stats() {
+        let range = DateRange::ending(date(18), 14);
+        let stats = WeekdayStats::new(
+            range,
+            &[gen_stat(5, 1, 2), gen_stat(12, 3, 6), gen_stat(7, 3, 6)],
+        );
+        assert_eq!(stats.weekdays.len(), 7);
+        assert_eq!(stats.weekdays[0].completions, 4);
+        assert_eq!(stats.weekdays[0].average, 2.0);
+        assert_eq!(stats.weekdays[2].average, 1.5);
+        assert_eq!(stats.best_weekday, Some(Weekday::Mon));
+
+        let empty = WeekdayStats::new(range, &[]);
+        assert_eq!(empty.best_weekday, None);
+    }
+
+    #[test]
+    fn test_weekly_exp() {
+        // 水曜日を含む3週
+        let range = WeeklyExp::range(date(21), 3);
+        assert_eq!(range.from, date(5));
+        let weeks = WeeklyExp::group(
+            range,
+            &[gen_stat(5, 1, 2), gen_stat(11, 2, 5), gen_stat(21, 1, -2)],
+        );
+        assert_eq!(
+            weeks
+                .iter()
+                .map(|w| (w.week_start, w.exp_gained, w.completions))
+                .collect::<Vec<_>>(),
+            vec![(date(5), 7, 3), (date(12), 0, 0), (date(19), -2, 1)]
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+
+use super::{
+    daily_mission_id::DailyMissionId,
+    mission_schedule::{is_same_week, MissionSchedule},
+};
+
+/// 連続達成日数の記録
+/// 完了のたびにadvance()で更新し、読み込み時はcurrent_at()で現在の値を求める
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Streak {
+    pub current: u32,
+    pub longest: u32,
+    /// 最後に達成した日付(ユーザーのタイムゾーン)
+    pub last_date: Option<NaiveDate>,
+    /// last_dateを含むISO週の達成日数
+    pub week_count: u32,
+}
+
+impl Streak {
+    /// dateに達成したことを記録する
+    /// 前回の達成日からの間に凍結されていない日がある場合は途切れたとみなす
+    /// 同じ日や過去の日付の場合は何もしない
+    pub fn advance(&mut self, date: NaiveDate, is_frozen: impl Fn(NaiveDate) -> bool) {
+        match self.last_date {
+            Some(last) if date <= last => return,
+            Some(_) if self.missed_days(date, &is_frozen).next().is_none() => self.current += 1,
+            _ => self.current = 1,
+        }
+        self.week_count = match self.last_date {
+            Some(last) if is_same_week(last, date) => self.week_count + 1,
+            _ => 1,
+        };
+        self.longest = self.longest.max(self.current);
+        self.last_date = Some(date);
+    }
+
+    /// 達成した日付の一覧から連続記録を再計算する
+    /// 順不同・重複ありでも良い
+    pub fn from_dates(
+        dates: impl IntoIterator<Item = NaiveDate>,
+        is_frozen: impl Fn(NaiveDate) -> bool,
+    ) -> Self {
+        let mut dates: Vec<_> = dates.into_iter().collect();
+        dates.sort_unstable();
+        let mut streak = Self::default();
+        dates
+            .into_iter()
+            .for_each(|d| streak.advance(d, &is_frozen));
+        streak
+    }
+
+    /// スケジュールに従ってdateに達成したことを記録する
+    pub fn advance_on_schedule(
+        &mut self,
+        date: NaiveDate,
+        schedule: &MissionSchedule,
+        is_frozen: impl Fn(NaiveDate) -> bool,
+    ) {
+        if let Some(last) = self.last_date {
+            // 週の回数を満たせなかった場合は1から数え直す
+            if date > last && schedule.is_quota_missed(last, date, self.week_count, &is_frozen) {
+                self.current = 0;
+            }
+        }
+        let skips = schedule.skips(self.last_date, date, self.week_count, &is_frozen);
+        self.advance(date, skips);
+    }
+
+    /// 達成した日付の一覧からスケジュールに従って連続記録を再計算する
+    /// 順不同・重複ありでも良い
+    pub fn from_dates_on_schedule(
+        dates: impl IntoIterator<Item = NaiveDate>,
+        schedule: &MissionSchedule,
+        is_frozen: impl Fn(NaiveDate) -> bool,
+    ) -> Self {
+        let mut dates: Vec<_> = dates.into_iter().collect();
+        dates.sort_unstable();
+        dates.dedup();
+        let mut streak = Self::default();
+        dates
+            .into_iter()
+            .for_each(|d| streak.advance_on_schedule(d, schedule, &is_frozen));
+        streak
+    }
+
+    /// 前回の達成日の翌日からdateの前日までのうち、凍結されていない(=達成しなかった)日
+    pub fn missed_days<'a>(
+        &self,
+        date: NaiveDate,
+        is_frozen: &'a impl Fn(NaiveDate) -> bool,
+    ) -> impl Iterator<Item = NaiveDate> + 'a {
+        self.last_date
+            .into_iter()
+            .flat_map(|last| last.iter_days().skip(1))
+            .take_while(move |d| *d < date)
+            .filter(move |d| !is_frozen(*d))
+    }
+
+    /// todayの時点での連続日数
+    /// 今日または昨日(凍結された日を除く)に達成していない場合は途切れているため0になる
+    /// ただし達成しなかった日を所持しているストリークフリーズ(spare_freezes)で補える場合は途切れていない
+    pub fn current_at(
+        &self,
+        today: NaiveDate,
+        is_frozen: impl Fn(NaiveDate) -> bool,
+        spare_freezes: u32,
+    ) -> u32 {
+        match self.last_date {
+            Some(last) if last >= today => self.current,
+            Some(_) => {
+                let missed = self
+                    .missed_days(today, &is_frozen)
+                    .take(spare_freezes as usize + 1)
+                    .count();
+                if missed <= spare_freezes as usize {
+                    self.current
+                } else {
+                    0
+                }
+            }
+            None => 0,
+        }
+    }
+
+    pub fn summary_at(
+        &self,
+        today: NaiveDate,
+        is_frozen: impl Fn(NaiveDate) -> bool,
+        spare_freezes: u32,
+    ) -> StreakSummary {
+        StreakSummary {
+            current_streak: self.current_at(today, is_frozen, spare_freezes),
+            longest_streak: self.longest,
+        }
+    }
+
+    /// スケジュールに従ってtodayの時点の連続記録を求める
+    pub fn summary_on_schedule(
+        &self,
+        schedule: &MissionSchedule,
+        today: NaiveDate,
+        is_frozen: impl Fn(NaiveDate) -> bool,
+        spare_freezes: u32,
+    ) -> StreakSummary {
+        if let Some(last) = self.last_date {
+            if today > last && schedule.is_quota_missed(last, today, self.week_count, &is_frozen) {
+                return StreakSummary {
+                    current_streak: 0,
+                    longest_streak: self.longest,
+                };
+            }
+        }
+        let skips = schedule.skips(self.last_date, today, self.week_count, &is_frozen);
+        self.summary_at(today, skips, spare_freezes)
+    }
+
+    /// todayを含むISO週の、todayより前に達成した日数
+    /// 前回の達成日が今日の場合は今日の分を除く
+    pub fn week_count_before(&self, today: NaiveDate) -> u32 {
+        match self.last_date {
+            Some(last) if is_same_week(last, today) && last < today => self.week_count,
+            Some(last) if is_same_week(last, today) => self.week_count.saturating_sub(1),
+            _ => 0,
+        }
+    }
+}
+
+impl FromRow<'_, MySqlRow> for Streak {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            current: row.try_get::<i32, _>("current_streak")?.max(0) as u32,
+            longest: row.try_get::<i32, _>("longest_streak")?.max(0) as u32,
+            last_date: row.try_get("last_date")?,
+            // user_streakは週の達成日数を持たない
+            week_count: match row.try_get::<i32, _>("week_count") {
+                Ok(count) => count.max(0) as u32,
+                Err(sqlx::Error::ColumnNotFound(_)) => 0,
+                Err(e) => return Err(e),
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakSummary {
+    pub current_streak: u32,
+    pub longest_streak: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionStreak {
+    pub mission_id: DailyMissionId,
+    pub title: String,
+    #[serde(flatten)]
+    pub streak: StreakSummary,
+}
+
+/// `/api/stats/streaks`のレスポンス
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakStats {
+    /// すべてのミッションを完了した日の連続記録
+    pub all_clear: StreakSummary,
+    pub missions: Vec<MissionStreak>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday};
+
+    use crate::entity::mission_schedule::MissionSchedule;
+
+    use super::Streak;
+
+    fn date(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 12, d).unwrap()
+    }
+
+    fn not_frozen(_: NaiveDate) -> bool {
+        false
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut streak = Streak::default();
+        streak.advance(date(1), not_frozen);
+        streak.advance(date(2), not_frozen);
+        streak.advance(date(2), not_frozen);
+        streak.advance(date(3), not_frozen);
+        assert_eq!((streak.current, streak.longest), (3, 3));
+        // 途切れた場合は1から数え直す
+        streak.advance(date(5), not_frozen);
+        assert_eq!((streak.current, streak.longest), (1, 3));
+        assert_eq!(streak.last_date, Some(date(5)));
+    }
+
+    #[test]
+    fn test_advance_over_frozen_days() {
+        let frozen = |d: NaiveDate| d == date(3) || d == date(4);
+        let mut streak = Streak::from_dates([date(1), date(2)], frozen);
+        // 凍結された日は途切れないが、連続日数にも数えない
+        streak.advance(date(5), frozen);
+        assert_eq!((streak.current, streak.longest), (3, 3));
+        streak.advance(date(7), frozen);
+        assert_eq!((streak.current, streak.longest), (1, 3));
+    }
+
+    #[test]
+    fn test_missed_days() {
+        let frozen = |d: NaiveDate| d == date(3);
+        let streak = Streak::from_dates([date(1)], frozen);
+        let missed: Vec<_> = streak.missed_days(date(5), &frozen).collect();
+        assert_eq!(missed, vec![date(2), date(4)]);
+        assert_eq!(Streak::default().missed_days(date(5), &frozen).count(), 0);
+    }
+
+    #[test]
+    fn test_from_dates() {
+        let streak = Streak::from_dates([date(5), date(1), date(2), date(6), date(5)], not_frozen);
+        assert_eq!((streak.current, streak.longest), (2, 2));
+        assert_eq!(Streak::from_dates([], not_frozen), Streak::default());
+    }
+
+    #[test]
+    fn test_current_at() {
+        let streak = Streak::from_dates([date(1), date(2)], not_frozen);
+        assert_eq!(streak.current_at(date(2), not_frozen, 0), 2);
+        assert_eq!(streak.current_at(date(3), not_frozen, 0), 2);
+        assert_eq!(streak.current_at(date(4), not_frozen, 0), 0);
+        // 凍結された日やストリークフリーズで補える場合は途切れていない
+        assert_eq!(streak.current_at(date(4), |d| d == date(3), 0), 2);
+        assert_eq!(streak.current_at(date(5), not_frozen, 2), 2);
+        assert_eq!(streak.current_at(date(6), not_frozen, 2), 0);
+    }
+
+    #[test]
+    fn test_streak_on_schedule() {
+        // 2024-12-02は月曜日
+        let weekdays = MissionSchedule::Weekdays {
+            weekdays: vec![Weekday::Mon, Weekday::Wed, Weekday::Fri],
+        };
+        let streak =
+            Streak::from_dates_on_schedule([date(2), date(4), date(6)], &weekdays, not_frozen);
+        assert_eq!((streak.current, streak.longest), (3, 3));
+        // 実施日でない週末は途切れない
+        let summary = streak.summary_on_schedule(&weekdays, date(9), not_frozen, 0);
+        assert_eq!(summary.current_streak, 3);
+        let summary = streak.summary_on_schedule(&weekdays, date(10), not_frozen, 0);
+        assert_eq!(summary.current_streak, 0);
+
+        let weekly = MissionSchedule::TimesPerWeek { times: 2 };
+        let streak =
+            Streak::from_dates_on_schedule([date(2), date(5), date(11)], &weekly, not_frozen);
+        assert_eq!((streak.current, streak.longest), (3, 3));
+        assert_eq!(streak.week_count, 1);
+        // 12/9の週は1回しか達成していないため、翌週に途切れる
+        let mut next = streak;
+        next.advance_on_schedule(date(16), &weekly, not_frozen);
+        assert_eq!((next.current, next.longest), (1, 3));
+        let summary = streak.summary_on_schedule(&weekly, date(16), not_frozen, 0);
+        assert_eq!(summary.current_streak, 0);
+        // 週の途中であれば途切れていない
+        let summary = streak.summary_on_schedule(&weekly, date(15), not_frozen, 0);
+        assert_eq!(summary.current_streak, 3);
+    }
+
+    #[test]
+    fn test_week_count_before() {
+        // 2024-12-02は月曜日
+        let mut streak = Streak::from_dates([date(2), date(4)], not_frozen);
+        assert_eq!(streak.week_count, 2);
+        assert_eq!(streak.week_count_before(date(5)), 2);
+        // 前回の達成日が今日の場合は今日の分を含まない
+        assert_eq!(streak.week_count_before(date(4)), 1);
+        // 前回の達成日が先週の場合は0
+        assert_eq!(streak.week_count_before(date(9)), 0);
+        assert_eq!(Streak::default().week_count_before(date(4)), 0);
+        // 週が変わると1から数え直す
+        streak.advance(date(9), |_| true);
+        assert_eq!(streak.week_count, 1);
+    }
+}
@@ -0,0 +1,48 @@
+use chrono_tz::Tz;
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+/// ユーザーのタイムゾーン変更でクライアントから送られるPayload
+/// time_zoneはIANAのタイムゾーン名(Asia/Tokyo, America/New_Yorkなど)
+/// 夏時間のある地域でも今日の日付を正しく判定できるよう、固定のオフセットではなく名前で受け取る
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeZoneInput {
+    #[validate(custom(function = "validate_time_zone"))]
+    pub time_zone: String,
+}
+
+impl TimeZoneInput {
+    /// IANAのタイムゾーン名として解釈できない場合はNone
+    pub fn tz(&self) -> Option<Tz> {
+        self.time_zone.parse().ok()
+    }
+}
+
+fn validate_time_zone(time_zone: &str) -> Result<(), ValidationError> {
+    time_zone
+        .parse::<Tz>()
+        .map(|_| ())
+        .map_err(|_| ValidationError::new("unknown_time_zone"))
+}
+
+#[cfg(test)]
+mod tests {
+    use validator::Validate;
+
+    use super::TimeZoneInput;
+
+    #[test]
+    fn test_validate_time_zone() {
+        let input = |time_zone: &str| TimeZoneInput {
+            time_zone: time_zone.to_string(),
+        };
+        assert!(input("Asia/Tokyo").validate().is_ok());
+        assert!(input("America/New_York").validate().is_ok());
+        assert!(input("UTC").validate().is_ok());
+        // 固定のオフセットやIANAにない名前は受け付けない
+        assert!(input("+09:00").validate().is_err());
+        assert!(input("Asia/Atlantis").validate().is_err());
+        assert!(input("").validate().is_err());
+    }
+}
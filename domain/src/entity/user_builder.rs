@@ -63,3 +63,9 @@ impl UserBuilder {
         }
     }
 }
+
+impl Default for UserBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
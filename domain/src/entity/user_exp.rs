@@ -1,3 +1,4 @@
+use serde::Serialize;
 use sqlx::{mysql::MySqlRow, FromRow, Row};
 
 use super::user_id::UserId;
@@ -8,6 +9,20 @@ pub struct UserExp {
     pub experience_points: i64,
 }
 
+impl UserExp {
+    /// 所持経験値にamountを加算した値を返す
+    /// 結果は0以上に、加算する場合はmax_exp(レベルカーブの上限)以下に飽和させる
+    /// カーブの変更などですでにmax_expを超えている場合は、加算しても減らさずにそのままにする
+    pub fn saturating_apply(experience_points: i64, amount: i64, max_exp: i64) -> i64 {
+        let exp = experience_points.saturating_add(amount).max(0);
+        if amount > 0 {
+            exp.min(max_exp.max(experience_points))
+        } else {
+            exp
+        }
+    }
+}
+
 impl FromRow<'_, MySqlRow> for UserExp {
     fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
         Ok(Self {
@@ -16,3 +31,59 @@ impl FromRow<'_, MySqlRow> for UserExp {
         })
     }
 }
+
+/// 経験値を増減した結果
+/// 上限や0で飽和した場合、after - beforeは要求した増減量と一致しない
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpChange {
+    pub before: i64,
+    pub after: i64,
+    /// 加算を要求した増減量
+    pub requested: i64,
+}
+
+impl ExpChange {
+    /// 実際に反映された増減量
+    pub fn applied(&self) -> i64 {
+        self.after - self.before
+    }
+
+    /// 上限に達したため、要求した経験値の一部または全部が加算されなかった
+    pub fn is_capped(&self) -> bool {
+        self.requested > 0 && self.applied() < self.requested
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserExp;
+
+    const MAX_EXP: i64 = 10_000;
+
+    #[test]
+    fn test_saturating_apply() {
+        assert_eq!(UserExp::saturating_apply(10, 5, MAX_EXP), 15);
+        assert_eq!(UserExp::saturating_apply(10, -5, MAX_EXP), 5);
+        // 0より小さくならない
+        assert_eq!(UserExp::saturating_apply(3, -5, MAX_EXP), 0);
+        // 上限で飽和する
+        assert_eq!(UserExp::saturating_apply(MAX_EXP - 1, 5, MAX_EXP), MAX_EXP);
+        assert_eq!(UserExp::saturating_apply(MAX_EXP, 1, MAX_EXP), MAX_EXP);
+        // 上限を超えている場合は加算しても減らない
+        assert_eq!(
+            UserExp::saturating_apply(MAX_EXP + 5, 1, MAX_EXP),
+            MAX_EXP + 5
+        );
+        assert_eq!(
+            UserExp::saturating_apply(MAX_EXP + 5, -1, MAX_EXP),
+            MAX_EXP + 4
+        );
+        // i64の範囲を超える場合
+        assert_eq!(
+            UserExp::saturating_apply(MAX_EXP, i64::MAX, MAX_EXP),
+            MAX_EXP
+        );
+        assert_eq!(UserExp::saturating_apply(-1, i64::MIN, MAX_EXP), 0);
+    }
+}
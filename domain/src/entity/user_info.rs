@@ -1,19 +1,21 @@
 use serde::Serialize;
 
-use super::{user::User, user_id::UserId};
+use super::{privacy_settings::PrivacySettings, user::User, user_id::UserId};
 
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserInfo {
     pub user_id: UserId,
     pub user_name: String,
+    pub privacy: PrivacySettings,
 }
 
-impl From<User> for UserInfo {
-    fn from(value: User) -> Self {
+impl UserInfo {
+    pub fn new(user: User, privacy: PrivacySettings) -> Self {
         Self {
-            user_id: value.user_id,
-            user_name: value.user_name,
+            user_id: user.user_id,
+            user_name: user.user_name,
+            privacy,
         }
     }
 }
@@ -1,6 +1,8 @@
 use serde::Serialize;
 
-use crate::service::level_convert::LevelConvert;
+use crate::service::{
+    level_convert::LevelConvert, service_error::level_curve_error::LevelCurveError,
+};
 
 use super::{user_exp::UserExp, user_id::UserId};
 
@@ -14,17 +16,17 @@ pub struct UserLevel {
 }
 
 impl UserLevel {
-    pub fn new<T>(user_exp: UserExp, converter: &T) -> Self
+    pub fn new<T>(user_exp: UserExp, converter: &T) -> Result<Self, LevelCurveError>
     where
         T: LevelConvert,
     {
         // 現在のレベルとレベルアップに必要な経験値量を計算
-        let (level, remain) = converter.to_level_with_remain(user_exp.experience_points);
-        Self {
+        let (level, remain) = converter.to_level_with_remain(user_exp.experience_points)?;
+        Ok(Self {
             user_id: user_exp.user_id,
             experience_points: user_exp.experience_points,
             level,
             remain,
-        }
+        })
     }
 }
@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+use validator::{Validate, ValidationError};
+
+/// 一度に設定できる休暇の最大日数
+pub const MAX_VACATION_DAYS: i64 = 90;
+
+/// 休暇モードの期間(start_dateとend_dateを含む)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vacation {
+    pub vacation_id: i64,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl FromRow<'_, MySqlRow> for Vacation {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            vacation_id: row.try_get::<i32, _>("id")?.into(),
+            start_date: row.try_get("start_date")?,
+            end_date: row.try_get("end_date")?,
+        })
+    }
+}
+
+impl Vacation {
+    /// 期間に含まれる日付
+    pub fn days(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.start_date
+            .iter_days()
+            .take_while(|d| *d <= self.end_date)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_period"))]
+pub struct VacationInput {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+fn validate_period(input: &VacationInput) -> Result<(), ValidationError> {
+    let days = (input.end_date - input.start_date).num_days() + 1;
+    if !(1..=MAX_VACATION_DAYS).contains(&days) {
+        return Err(ValidationError::new("invalid_vacation_period"));
+    }
+    Ok(())
+}
+
+/// 所持しているストリークフリーズ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreakFreezes {
+    pub available: u32,
+    pub max: u32,
+}
@@ -0,0 +1,517 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize, Serializer};
+use sqlx::{mysql::MySqlRow, FromRow, Row};
+use validator::{Validate, ValidationError};
+
+use super::{domain_event::DomainEvent, user_id::UserId};
+
+/// ユーザーが登録できるWebhookの最大数
+pub const MAX_WEBHOOKS: u32 = 5;
+
+/// 同じ配信を試みる最大の回数(最初の送信を含む)
+/// 再試行の間隔はジョブと同じく1分、4分、16分...と広げるため、最後の送信は最初の約5時間後になる
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 6;
+
+/// 一度に取得して送信する配信の最大数
+pub const DELIVERY_BATCH_SIZE: u32 = 50;
+
+/// 同時に送信する配信の最大数
+pub const DELIVERY_CONCURRENCY: usize = 10;
+
+/// 1件の配信の接続からレスポンスの受信までにかける最大の秒数
+/// 同時に送信する配信の送信中に送信するインスタンスのリースが切れないよう、リースより十分に短くする
+pub const DELIVERY_TIMEOUT_SECONDS: u64 = 15;
+
+/// 配信の記録として返す最大の件数
+pub const DELIVERY_LOG_LIMIT: u32 = 50;
+
+/// 終了した配信の記録を削除するまでの日数
+pub const DELIVERY_RETENTION_DAYS: u32 = 30;
+
+/// Webhookで購読できるイベント
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookEvent {
+    #[serde(rename = "mission.created")]
+    MissionCreated,
+    #[serde(rename = "mission.completed")]
+    MissionCompleted,
+    #[serde(rename = "level.up")]
+    LevelUp,
+    #[serde(rename = "achievement.unlocked")]
+    AchievementUnlocked,
+}
+
+impl WebhookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MissionCreated => "mission.created",
+            Self::MissionCompleted => "mission.completed",
+            Self::LevelUp => "level.up",
+            Self::AchievementUnlocked => "achievement.unlocked",
+        }
+    }
+
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "mission.created" => Ok(Self::MissionCreated),
+            "mission.completed" => Ok(Self::MissionCompleted),
+            "level.up" => Ok(Self::LevelUp),
+            "achievement.unlocked" => Ok(Self::AchievementUnlocked),
+            v => Err(format!("invalid webhook event: {}", v)),
+        }
+    }
+
+    /// ドメインイベントに対応するWebhookのイベント
+    /// Webhookで配信しないイベントはNone
+    pub fn of(event: &DomainEvent) -> Option<Self> {
+        match event {
+            DomainEvent::MissionCreated(_) => Some(Self::MissionCreated),
+            DomainEvent::MissionCompleted(_) => Some(Self::MissionCompleted),
+            DomainEvent::LevelUp(_) => Some(Self::LevelUp),
+            DomainEvent::AchievementUnlocked(_) => Some(Self::AchievementUnlocked),
+            _ => None,
+        }
+    }
+}
+
+/// Webhookの登録と更新でクライアントから送られるPayload
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookInput {
+    #[validate(length(max = 512), custom(function = "validate_url"))]
+    pub url: String,
+    #[validate(length(min = 1), custom(function = "validate_events"))]
+    pub events: Vec<WebhookEvent>,
+    /// falseの場合は配信を止める(省略時はtrue)
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+/// httpsのみを許可し、ホストがIPアドレスの場合はグローバルに到達できるアドレスに限る
+/// ホスト名の場合は送信のたびに名前解決したアドレスを確認する
+/// Webhookの送信先やプッシュサービスのエンドポイントなど、利用者が指定するURLの検証に使う
+pub(crate) fn validate_url(url: &str) -> Result<(), ValidationError> {
+    let authority = url
+        .strip_prefix("https://")
+        .ok_or(ValidationError::new("url_not_https"))?
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default();
+    // ユーザー情報を含むURLは送信先を誤認させるため受け付けない
+    if authority.is_empty() || authority.contains('@') {
+        return Err(ValidationError::new("url_invalid_host"));
+    }
+    let host = match authority.strip_prefix('[') {
+        Some(v6) => v6.split(']').next().unwrap_or_default(),
+        None => authority.split(':').next().unwrap_or_default(),
+    };
+    let is_local_name =
+        host.eq_ignore_ascii_case("localhost") || host.to_ascii_lowercase().ends_with(".localhost");
+    let is_private_ip = host
+        .parse::<IpAddr>()
+        .is_ok_and(|ip| !is_public_address(&ip));
+    if host.is_empty() || is_local_name || is_private_ip {
+        return Err(ValidationError::new("url_not_public"));
+    }
+    Ok(())
+}
+
+/// グローバルに到達できるユニキャストのアドレスかどうか
+/// ループバック、プライベート、リンクローカル、共有、ドキュメント用などの予約されたアドレスはfalse
+pub fn is_public_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => is_public_v4(&v4),
+            None => is_public_v6(v6),
+        },
+    }
+}
+
+fn is_public_v4(ip: &Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(a == 0
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        // 共有アドレス(100.64.0.0/10)
+        || (a == 100 && (b & 0xc0) == 64)
+        // IETFプロトコル割り当て(192.0.0.0/24)
+        || (a == 192 && b == 0 && c == 0)
+        || ip.is_documentation()
+        // ベンチマーク用(198.18.0.0/15)
+        || (a == 198 && (b & 0xfe) == 18)
+        // マルチキャスト、予約済み、ブロードキャスト(224.0.0.0/3)
+        || a >= 224)
+}
+
+fn is_public_v6(ip: &Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    // グローバルユニキャスト(2000::/3)のうち、ドキュメント用(2001:db8::/32)、
+    // IETFプロトコル割り当て(2001::/23)、6to4(2002::/16)を除く
+    (segments[0] & 0xe000) == 0x2000
+        && !(segments[0] == 0x2001 && segments[1] == 0x0db8)
+        && !(segments[0] == 0x2001 && segments[1] < 0x0200)
+        && segments[0] != 0x2002
+}
+
+fn validate_events(events: &[WebhookEvent]) -> Result<(), ValidationError> {
+    let duplicated = events
+        .iter()
+        .enumerate()
+        .any(|(i, event)| events[..i].contains(event));
+    if duplicated {
+        return Err(ValidationError::new("duplicated_event"));
+    }
+    Ok(())
+}
+
+/// 登録済みのWebhook
+/// secretは署名に使う値で、登録と再発行のレスポンス(WebhookSecret)でのみ返す
+/// created_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    pub webhook_id: String,
+    #[serde(skip)]
+    pub user_id: UserId,
+    pub url: String,
+    #[serde(skip)]
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+}
+
+impl FromRow<'_, MySqlRow> for Webhook {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            webhook_id: row.try_get("webhook_id")?,
+            user_id: UserId(row.try_get("user_id")?),
+            url: row.try_get("url")?,
+            secret: row.try_get("secret")?,
+            events: decode_events(row.try_get("events")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            active: row.try_get("is_active")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+impl Webhook {
+    /// eventsをカンマ区切りにしてテーブルに保存する
+    pub fn events_column(&self) -> String {
+        self.events
+            .iter()
+            .map(|event| event.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+fn decode_events(value: &str) -> Result<Vec<WebhookEvent>, String> {
+    value
+        .split(',')
+        .filter(|v| !v.is_empty())
+        .map(WebhookEvent::from_column)
+        .collect()
+}
+
+/// 登録と署名の秘密の再発行のレスポンス
+/// secretはこの時だけ返すため、受信する側で保存する
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSecret {
+    #[serde(flatten)]
+    pub webhook: Webhook,
+    pub secret: String,
+}
+
+/// 配信の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DeliveryStatus {
+    /// 送信待ち(再試行を待っている場合を含む)
+    Pending,
+    /// いずれかのインスタンスが送信中
+    Running,
+    /// 受信した側が2xxを返した
+    Succeeded,
+    /// 再試行の上限に達した
+    Failed,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Succeeded => "succeeded",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn from_column(value: &str) -> Result<Self, String> {
+        match value {
+            "pending" => Ok(Self::Pending),
+            "running" => Ok(Self::Running),
+            "succeeded" => Ok(Self::Succeeded),
+            "failed" => Ok(Self::Failed),
+            v => Err(format!("invalid delivery status: {}", v)),
+        }
+    }
+}
+
+/// 配信の記録
+/// payloadは送信したリクエストのボディ(JSON)
+/// next_attempt_at, created_at, delivered_atはUTC
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDelivery {
+    pub delivery_id: String,
+    pub event: WebhookEvent,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub payload: String,
+    /// 最後の送信で受信した側が返したHTTPのステータスコード(接続できなかった場合はNone)
+    pub last_status_code: Option<u16>,
+    pub last_error: Option<String>,
+    pub next_attempt_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub delivered_at: Option<NaiveDateTime>,
+}
+
+impl FromRow<'_, MySqlRow> for WebhookDelivery {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            delivery_id: row.try_get("delivery_id")?,
+            event: WebhookEvent::from_column(row.try_get("event")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            status: DeliveryStatus::from_column(row.try_get("status")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            attempts: row.try_get::<i32, _>("attempts")?.max(0) as u32,
+            payload: row.try_get("payload")?,
+            last_status_code: row
+                .try_get::<Option<i32>, _>("last_status_code")?
+                .map(|code| code as u16),
+            last_error: row.try_get("last_error")?,
+            next_attempt_at: row.try_get("next_attempt_at")?,
+            created_at: row.try_get("created_at")?,
+            delivered_at: row.try_get("delivered_at")?,
+        })
+    }
+}
+
+/// 送信する配信
+/// 送信先と署名の秘密はWebhookの現在の値を使う
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingDelivery {
+    pub id: i64,
+    pub delivery_id: String,
+    pub url: String,
+    pub secret: String,
+    pub event: WebhookEvent,
+    pub payload: String,
+    /// 今回の送信を含めた送信回数
+    pub attempts: u32,
+}
+
+impl FromRow<'_, MySqlRow> for PendingDelivery {
+    fn from_row(row: &'_ MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            delivery_id: row.try_get("delivery_id")?,
+            url: row.try_get("url")?,
+            secret: row.try_get("secret")?,
+            event: WebhookEvent::from_column(row.try_get("event")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            payload: row.try_get("payload")?,
+            attempts: row.try_get::<i32, _>("attempts")?.max(0) as u32,
+        })
+    }
+}
+
+impl PendingDelivery {
+    /// 失敗した配信を再試行するかどうか
+    pub fn can_retry(&self) -> bool {
+        self.attempts < MAX_DELIVERY_ATTEMPTS
+    }
+}
+
+/// 配信するリクエストのボディ
+/// dataはイベントの内容(DomainEventの各イベントと同じ形)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload<'a> {
+    pub event: WebhookEvent,
+    pub occurred_at: NaiveDateTime,
+    #[serde(serialize_with = "serialize_event_data")]
+    pub data: &'a DomainEvent,
+}
+
+impl<'a> WebhookPayload<'a> {
+    /// Webhookで配信しないイベントの場合はNoneを返す
+    pub fn new(event: &'a DomainEvent) -> Option<Self> {
+        Some(Self {
+            event: WebhookEvent::of(event)?,
+            occurred_at: event.occurred_at(),
+            data: event,
+        })
+    }
+}
+
+// typeとdataで囲まず、イベントの内容だけを書き出す
+fn serialize_event_data<S>(event: &&DomainEvent, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match event {
+        DomainEvent::MissionCreated(e) => e.serialize(serializer),
+        DomainEvent::MissionUpdated(e) => e.serialize(serializer),
+        DomainEvent::MissionDeleted(e) => e.serialize(serializer),
+        DomainEvent::MissionCompleted(e) => e.serialize(serializer),
+        DomainEvent::MissionUncompleted(e) => e.serialize(serializer),
+        DomainEvent::ExpChanged(e) => e.serialize(serializer),
+        DomainEvent::LevelUp(e) => e.serialize(serializer),
+        DomainEvent::AchievementUnlocked(e) => e.serialize(serializer),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+    use validator::Validate;
+
+    use crate::entity::{
+        domain_event::{DomainEvent, ExpChanged, LevelUp},
+        user_id::UserId,
+    };
+
+    use super::{decode_events, is_public_address, WebhookEvent, WebhookInput, WebhookPayload};
+
+    fn gen_input() -> WebhookInput {
+        WebhookInput {
+            url: "https://example.com/hooks/missions".to_string(),
+            events: vec![WebhookEvent::MissionCompleted, WebhookEvent::LevelUp],
+            active: true,
+        }
+    }
+
+    #[test]
+    fn test_validate_input() {
+        assert!(gen_input().validate().is_ok());
+
+        let mut input = gen_input();
+        input.url = "https://example.com:8443/hook?a=1".to_string();
+        assert!(input.validate().is_ok());
+        input.url = "https://93.184.215.14/hook".to_string();
+        assert!(input.validate().is_ok());
+        // httpsのみ
+        input.url = "http://example.com/hook".to_string();
+        assert!(input.validate().is_err());
+        input.url = "ftp://example.com/hook".to_string();
+        assert!(input.validate().is_err());
+        input.url = "https:///hook".to_string();
+        assert!(input.validate().is_err());
+        // 内部のアドレスは送信先にできない
+        for url in [
+            "https://localhost:8080/hook",
+            "https://127.0.0.1:3306/",
+            "https://169.254.169.254/latest/meta-data",
+            "https://10.0.0.1/hook",
+            "https://[::1]/hook",
+            "https://[::ffff:192.168.0.1]/hook",
+            "https://example.com@127.0.0.1/hook",
+        ] {
+            input.url = url.to_string();
+            assert!(input.validate().is_err(), "{}", url);
+        }
+
+        let mut input = gen_input();
+        input.events = vec![];
+        assert!(input.validate().is_err());
+        input.events = vec![WebhookEvent::LevelUp, WebhookEvent::LevelUp];
+        assert!(input.validate().is_err());
+    }
+
+    #[test]
+    fn test_is_public_address() {
+        for ip in ["93.184.215.14", "8.8.8.8", "2606:4700::1111"] {
+            assert!(is_public_address(&ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in [
+            "0.0.0.0",
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "192.0.2.1",
+            "198.18.0.1",
+            "224.0.0.1",
+            "255.255.255.255",
+            "::",
+            "::1",
+            "fc00::1",
+            "fe80::1",
+            "2001:db8::1",
+            "::ffff:127.0.0.1",
+            "2002:7f00:1::",
+        ] {
+            assert!(!is_public_address(&ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn test_events_column() {
+        for event in [
+            WebhookEvent::MissionCreated,
+            WebhookEvent::MissionCompleted,
+            WebhookEvent::LevelUp,
+            WebhookEvent::AchievementUnlocked,
+        ] {
+            assert_eq!(WebhookEvent::from_column(event.as_str()), Ok(event));
+        }
+        assert_eq!(
+            decode_events("mission.completed,level.up"),
+            Ok(vec![WebhookEvent::MissionCompleted, WebhookEvent::LevelUp])
+        );
+        assert_eq!(decode_events(""), Ok(vec![]));
+        assert!(decode_events("mission.deleted").is_err());
+    }
+
+    #[test]
+    fn test_payload_of_event() {
+        let occurred_at = NaiveDate::from_ymd_opt(2026, 10, 20)
+            .unwrap()
+            .and_hms_opt(1, 2, 3)
+            .unwrap();
+        let level_up = DomainEvent::LevelUp(LevelUp {
+            user_id: UserId("test_user".to_string()),
+            previous_level: 1,
+            new_level: 2,
+            total_exp: 10,
+            occurred_at,
+        });
+        let payload = WebhookPayload::new(&level_up).unwrap();
+        assert_eq!(payload.event, WebhookEvent::LevelUp);
+        assert_eq!(payload.occurred_at, occurred_at);
+
+        // Webhookで配信しないイベントは登録しない
+        let exp_changed = DomainEvent::ExpChanged(ExpChanged {
+            user_id: UserId("test_user".to_string()),
+            amount: -3,
+            total_exp: 7,
+            level: 1,
+            occurred_at,
+        });
+        assert!(WebhookPayload::new(&exp_changed).is_none());
+    }
+}
@@ -0,0 +1,43 @@
+use std::{future::Future, pin::Pin};
+
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{
+    achievement::{Achievement, AchievementStats, UserAchievement},
+    user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層における実績のリポジトリ定義
+/// AchievementRepositoryの実装はinfrastructureで行う
+pub trait AchievementRepository {
+    /// すべての実績をユーザーの解除状況とともに取得する
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<UserAchievement>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーがまだ解除していない実績を取得する
+    fn find_locked<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Achievement>, RepositoryError>> + Send + 'a>>;
+
+    /// 実績の解除条件の判定に使う記録を取得する
+    fn find_stats<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<AchievementStats, RepositoryError>> + Send + 'a>>;
+
+    /// 実績を解除する
+    /// すでに解除済みの場合はfalseを返す
+    fn unlock<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        achievement_id: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+}
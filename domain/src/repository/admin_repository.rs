@@ -0,0 +1,37 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::user_id::UserId;
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層における管理者向け設定のリポジトリ定義
+/// AdminRepositoryの実装はinfrastructureで行う
+pub trait AdminRepository {
+    /// ユーザーが管理者かどうか
+    fn is_admin<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// プランを保存(UPSERT)する
+    fn save_plan<'a>(
+        &'a self,
+        plan_id: &'a str,
+        mission_capacity: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのプランを変更する
+    /// 存在しないプランの場合はNotFoundを返す
+    fn update_user_plan<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        plan_id: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーごとのミッションの登録上限を変更する
+    fn update_user_capacity<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_capacity: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+}
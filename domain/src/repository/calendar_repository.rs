@@ -0,0 +1,52 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::{
+    calendar::{CalendarCompletion, CalendarFeed, CalendarMission},
+    user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるカレンダーのフィードのリポジトリ定義
+/// CalendarRepositoryの実装はinfrastructureで行う
+pub trait CalendarRepository {
+    /// 発行済みのフィードを取得する(未発行の場合はNotFound)
+    fn find_feed<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<CalendarFeed, RepositoryError>> + Send + 'a>>;
+
+    /// フィードを発行する
+    /// 発行済みの場合はfeed_tokenを置き換えるため、古いURLは使えなくなる
+    fn save_feed<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        feed_token: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<CalendarFeed, RepositoryError>> + Send + 'a>>;
+
+    /// フィードを無効にする
+    /// 発行済みのフィードがあった場合はtrueを返す
+    fn delete_feed<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// feed_tokenからフィードの持ち主を取得する(無効なfeed_tokenの場合はNotFound)
+    fn find_user_by_token<'a>(
+        &'a self,
+        feed_token: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<UserId, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのすべてのミッションを取得する
+    fn find_missions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<CalendarMission>, RepositoryError>> + Send + 'a>>;
+
+    /// 今日(ユーザーのタイムゾーン)からdays日前までの完了記録を日付順に取得する
+    fn find_completions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        days: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<CalendarCompletion>, RepositoryError>> + Send + 'a>>;
+}
@@ -1,9 +1,17 @@
 use std::{future::Future, pin::Pin};
 
+use chrono::NaiveDate;
 use sqlx::{MySql, Transaction};
 
 use crate::entity::{
-    daily_mission::DailyMission, daily_mission_id::DailyMissionId, user_id::UserId,
+    daily_mission::{DailyMission, MissionTitle},
+    daily_mission_id::DailyMissionId,
+    frozen_day::FrozenDay,
+    history_query::HistoryPage,
+    mission_completion::MissionCompletion,
+    mission_progress::MissionProgress,
+    mission_transfer::MissionRecord,
+    user_id::UserId,
 };
 
 use super::repository_error::RepositoryError;
@@ -14,15 +22,26 @@ pub trait DailyMissionRepository {
     /// DailyMissionデータを保存する
     fn create<'a>(
         &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
         builder: &'a DailyMission,
     ) -> Pin<Box<dyn Future<Output = Result<DailyMissionId, RepositoryError>> + Send + 'a>>;
 
     /// ユーザーが登録したミッションがいくつあるのかカウントするメソッド
     fn count<'a>(
         &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<i32, RepositoryError>> + Send + 'a>>;
 
+    /// ユーザーが登録できるミッションの上限を取得する
+    /// ユーザーごとの設定、プランの設定、デフォルトの順に優先する
+    /// 同時に登録しても上限を超えないように、ユーザーの行ロックを取得する(SELECT ... FOR UPDATE)
+    fn find_capacity<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>>;
+
     /// DailyMissionIdを使用して一つのDailyMissionデータを取得する
     fn find_by_id<'a>(
         &'a self,
@@ -30,12 +49,27 @@ pub trait DailyMissionRepository {
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<DailyMission, RepositoryError>> + Send + 'a>>;
 
-    /// ユーザーのDailyMissionデータ**すべて**を取得する
+    /// ユーザーのすべてのミッションのIDとタイトルを取得する
+    /// コミット前に登録したミッションのイベントを作るため、トランザクションの中で読み取る
+    fn find_titles<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionTitle>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのDailyMissionデータのうち、今日(ユーザーのタイムゾーン)実施するものすべてを取得する
     fn find_by_user_id<'a>(
         &'a self,
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<DailyMission>, RepositoryError>> + Send + 'a>>;
 
+    /// ユーザーのすべてのDailyMissionの定義を登録順に取得する(書き出しに使う)
+    /// 実施日に関係なくすべてのミッションが対象になる
+    fn find_records<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionRecord>, RepositoryError>> + Send + 'a>>;
+
     /// DailyMissionデータを変更する
     /// DailyMissionIdは引数のDailyMissionから参照する
     fn update<'a>(
@@ -45,13 +79,73 @@ pub trait DailyMissionRepository {
     ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
 
     /// DailyMissionのis_completeフィールドをfalseからtrueにセットする
+    /// 保存した完了記録(ユーザーのタイムゾーンにおける日付)を返す
+    /// 今日すでに完了している場合はNone、ユーザーが所有するミッションでない場合はNotFoundを返す
     fn set_complete_true<'a>(
         &'a self,
         tx: &'a mut Transaction<'_, MySql>,
         mission_id: &'a DailyMissionId,
         user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<MissionCompletion>, RepositoryError>> + Send + 'a>>;
+
+    /// 今日(ユーザーのタイムゾーン)の進捗にamountを加算し、加算後の累計を返す
+    /// 同じ日の進捗の記録が直列化されるように、進捗の行ロックを取得する
+    /// is_completeはロックの取得後に今日の完了記録が存在するかどうか
+    fn add_progress<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        user_id: &'a UserId,
+        amount: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionProgress, RepositoryError>> + Send + 'a>>;
+
+    /// fromからtoまで(両端を含む)の日ごとの進捗を(date DESC, mission_id)の順で取得する
+    /// mission_idがNoneの場合はユーザーのすべてのミッションの進捗を対象にする
+    fn find_progress<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_id: Option<&'a DailyMissionId>,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionProgress>, RepositoryError>> + Send + 'a>>;
+
+    /// 完了記録に付与した経験値を保存する
+    fn save_exp_awarded<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        completion_id: i64,
+        exp_awarded: i64,
     ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
 
+    /// 今日(ユーザーのタイムゾーン)の完了記録を削除し、削除した完了記録を返す
+    /// 数量目標のあるミッションは今日の進捗を残し、取り消したことを記録する
+    /// 今日完了していない場合はNotFoundを返す
+    fn undo_complete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionCompletion, RepositoryError>> + Send + 'a>>;
+
+    /// ミッションの完了履歴を(date DESC, id DESC)の順で取得する
+    /// mission_idがNoneの場合はユーザーのすべてのミッションの履歴を対象にする
+    /// 次のページの有無を判定するため、最大でpage.limit + 1件を返す
+    fn find_completions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_id: Option<&'a DailyMissionId>,
+        page: &'a HistoryPage,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionCompletion>, RepositoryError>> + Send + 'a>>;
+
+    /// fromからtoまで(両端を含む)のストリークが凍結された日を日付順に取得する
+    /// 休暇モードの期間とストリークフリーズを消費した日が対象
+    fn find_frozen_days<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<FrozenDay>, RepositoryError>> + Send + 'a>>;
+
     /// 指定されたDailyMissionデータ一つを削除する
     fn delete<'a>(
         &'a self,
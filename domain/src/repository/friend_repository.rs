@@ -0,0 +1,72 @@
+use std::{future::Future, pin::Pin};
+
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{
+    friend::{Friend, FriendStatus},
+    user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるフレンド関係のリポジトリ定義
+/// FriendRepositoryの実装はinfrastructureで行う
+pub trait FriendRepository {
+    /// 2人のユーザーの行をロックし、存在するユーザーの数を返す
+    /// 同じ2人の間の申請を直列化するため、変更するトランザクションの最初に呼び出す
+    fn lock_users<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+
+    /// user_idからother_idへの関係を取得する
+    fn find_status<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<FriendStatus>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーがフレンド申請を受け付けているか確認する
+    fn accepts_requests<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// user_idからother_idへの関係を保存する
+    /// すでに関係がある場合は上書きする
+    fn save<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+        status: FriendStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// user_idからother_idへのstatusの関係を削除する
+    /// 削除した場合はtrueを返す
+    fn delete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+        status: FriendStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// user_idからstatusの関係にあるユーザーを取得する
+    /// acceptedはフレンド、pendingは送った申請、blockedはブロックしたユーザー
+    fn find_by_status<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        status: FriendStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Friend>, RepositoryError>> + Send + 'a>>;
+
+    /// user_idが受け取った申請を取得する
+    fn find_incoming_requests<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Friend>, RepositoryError>> + Send + 'a>>;
+}
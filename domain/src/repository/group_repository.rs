@@ -0,0 +1,221 @@
+use std::{future::Future, pin::Pin};
+
+use chrono::NaiveDate;
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{
+    group::{Group, GroupInvite, GroupMember, GroupRole},
+    group_id::GroupId,
+    group_mission::{GroupMission, GroupMissionCompletion},
+    group_mission_id::GroupMissionId,
+    user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるグループとグループミッションのリポジトリ定義
+/// GroupRepositoryの実装はinfrastructureで行う
+pub trait GroupRepository {
+    /// グループとオーナーのメンバーを作成する
+    fn create<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        name: &'a str,
+        owner_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// グループを削除する(メンバー、招待、グループミッションも削除される)
+    fn delete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// グループの行をロックし、メンバーの数を返す
+    /// メンバーの変更を直列化するため、変更するトランザクションの最初に呼び出す
+    fn lock_group<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのグループでの役割を取得する(メンバーでない場合はNone)
+    fn find_role<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<GroupRole>, RepositoryError>> + Send + 'a>>;
+
+    /// トランザクション内でユーザーのグループでの役割を取得する
+    fn find_role_in<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<GroupRole>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーが所属しているグループを取得する
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Group>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーが所属しているグループを1件取得する
+    fn find_by_id<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Group, RepositoryError>> + Send + 'a>>;
+
+    /// グループのメンバーを取得する
+    fn find_members<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupMember>, RepositoryError>> + Send + 'a>>;
+
+    /// トランザクション内でグループのメンバーのIDを取得する
+    fn find_member_ids<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<UserId>, RepositoryError>> + Send + 'a>>;
+
+    /// メンバーを追加する
+    fn add_member<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+        role: GroupRole,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// メンバーの役割を変更する
+    fn update_role<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+        role: GroupRole,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// メンバーを削除する
+    /// 削除した場合はtrueを返す
+    fn remove_member<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// 招待を保存する
+    /// すでに招待している場合は招待した日時を更新し、招待するユーザーが存在しない場合はNotFoundを返す
+    fn save_invite<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+        invited_by: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 招待を削除する
+    /// 削除した場合はtrueを返す
+    fn delete_invite<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーが受け取った招待を取得する
+    fn find_invites<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupInvite>, RepositoryError>> + Send + 'a>>;
+
+    /// グループミッションの数を取得する
+    fn count_missions<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>>;
+
+    /// グループミッションを作成する
+    fn create_mission<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission: &'a GroupMission,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// グループミッションを取得する
+    fn find_missions<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupMission>, RepositoryError>> + Send + 'a>>;
+
+    /// グループミッションを削除する(完了記録も削除される)
+    /// 削除した場合はtrueを返す
+    fn delete_mission<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        mission_id: &'a GroupMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// グループミッションの行をロックして取得する
+    /// 同じグループミッションの完了を直列化し、全員の完了を一度だけ判定するために使う
+    fn lock_mission<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        mission_id: &'a GroupMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<GroupMission, RepositoryError>> + Send + 'a>>;
+
+    /// 完了記録を保存する
+    /// 同じ日にすでに完了している場合はfalseを返す
+    fn save_completion<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        completion: &'a GroupMissionCompletion,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// トランザクション内でグループミッションをdateに完了した現在のメンバーを取得する
+    fn find_completed_members<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a GroupMissionId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<UserId>, RepositoryError>> + Send + 'a>>;
+
+    /// グループのすべてのグループミッションについて、dateに完了した現在のメンバーを取得する
+    fn find_completions<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        date: NaiveDate,
+    ) -> Pin<
+        Box<dyn Future<Output = Result<Vec<GroupMissionCompletion>, RepositoryError>> + Send + 'a>,
+    >;
+
+    /// 全員が完了したボーナスの配布を記録する
+    /// 同じ日のボーナスをすでに記録している場合はfalseを返す
+    fn save_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a GroupMissionId,
+        date: NaiveDate,
+        exp_awarded: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// グループのグループミッションのうち、dateのボーナスを配布済みのものを取得する
+    fn find_bonus_missions<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupMissionId>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのタイムゾーンにおける今日の日付を取得する
+    fn find_current_date<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>>;
+}
@@ -0,0 +1,78 @@
+use std::{future::Future, pin::Pin};
+
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{
+    domain_event::LevelUp,
+    scheduled_job::{JobStatus, ScheduledJob},
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるバックグラウンドジョブのリポジトリ定義
+/// JobRepositoryの実装はinfrastructureで行う
+/// 時刻の比較はインスタンスごとの時計のずれの影響を受けないよう、DBの時刻(UTC)で行う
+pub trait JobRepository {
+    /// nameのリースを取得または延長する
+    /// 他のインスタンスが有効なリースを持っている場合はfalseを返す
+    fn acquire_lease<'a>(
+        &'a self,
+        name: &'a str,
+        holder: &'a str,
+        ttl_seconds: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// users.utc_offsetを各ユーザーのタイムゾーン(time_zone)の現在のオフセットに更新する
+    /// 夏時間の切り替え後も、複数のユーザーをまとめて扱うSQL(ジョブの登録やランキング)が今日の日付を正しく求められるようにする
+    /// 更新したユーザーの数を返す
+    fn refresh_utc_offsets<'a>(
+        &'a self,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+
+    /// 今日(ユーザーのタイムゾーン)のリマインダーとまとめのジョブを登録する
+    /// 登録済みのジョブと、実行予定の時刻からgrace_seconds以上過ぎたジョブは登録しない
+    /// 新たに登録したジョブの数を返す
+    fn plan_jobs<'a>(
+        &'a self,
+        grace_seconds: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+
+    /// レベルアップの通知をすぐに実行するジョブとして登録する
+    /// 通知が失われないよう、レベルが上がったトランザクションの中で呼び出す
+    fn enqueue_level_up<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        event: &'a LevelUp,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 実行予定の時刻を過ぎたジョブを最大limit件取得し、holderが実行中の状態にする
+    /// 期限切れのジョブは実行せずにSkippedにし、実行中のまま停止したジョブは実行待ちに戻す
+    fn claim_due<'a>(
+        &'a self,
+        holder: &'a str,
+        limit: u32,
+        grace_seconds: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<ScheduledJob>, RepositoryError>> + Send + 'a>>;
+
+    /// ジョブを終了した状態(Done, Skipped, Failed)にする
+    fn finish<'a>(
+        &'a self,
+        job_id: i64,
+        status: JobStatus,
+        error: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 失敗したジョブをdelay_seconds後に再試行する
+    fn retry<'a>(
+        &'a self,
+        job_id: i64,
+        delay_seconds: u32,
+        error: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 終了してからdays日以上経ったジョブを削除し、削除した数を返す
+    fn purge<'a>(
+        &'a self,
+        days: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+}
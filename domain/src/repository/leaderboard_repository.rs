@@ -0,0 +1,31 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::{
+    leaderboard::{LeaderboardEntry, LeaderboardMetric, LeaderboardPage, LeaderboardScope},
+    user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるランキングのリポジトリ定義
+/// LeaderboardRepositoryの実装はinfrastructureで行う
+pub trait LeaderboardRepository {
+    /// user_idから見たランキングを(score DESC, user_id)の順で、カーソルより後ろから取得する
+    /// 続きのページがあるか判定するため、最大でpage.limit + 1件を返す
+    /// レベルのランキングでは累計の経験値をscoreとして返し、順位(rank)は0のまま返す
+    fn find_entries<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        page: &'a LeaderboardPage,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<LeaderboardEntry>, RepositoryError>> + Send + 'a>>;
+
+    /// ランキングの対象のうち、scoreがscore以上のユーザーの数を取得する
+    /// レベルのランキングでは累計の経験値で比較する
+    fn count_at_least<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        metric: LeaderboardMetric,
+        scope: LeaderboardScope,
+        score: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+}
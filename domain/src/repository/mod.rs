@@ -1,4 +1,18 @@
+pub mod achievement_repository;
+pub mod admin_repository;
+pub mod calendar_repository;
 pub mod daily_mission_repository;
+pub mod friend_repository;
+pub mod group_repository;
+pub mod job_repository;
+pub mod leaderboard_repository;
+pub mod push_repository;
+pub mod quest_repository;
+pub mod reminder_repository;
 pub mod repository_error;
+pub mod stats_repository;
+pub mod streak_repository;
+pub mod template_repository;
 pub mod user_exp_repository;
 pub mod user_repository;
+pub mod webhook_repository;
@@ -0,0 +1,36 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::{push_subscription::PushSubscription, user_id::UserId};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるWeb Pushの購読のリポジトリ定義
+/// PushRepositoryの実装はinfrastructureで行う
+pub trait PushRepository {
+    /// 購読を登録する
+    /// 同じendpointが登録済みの場合は、鍵と持ち主を置き換えて登録済みのsubscription_idを使う
+    fn save<'a>(
+        &'a self,
+        subscription: &'a PushSubscription,
+    ) -> Pin<Box<dyn Future<Output = Result<PushSubscription, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのすべての購読を登録順に取得する
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PushSubscription>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーの購読を削除する
+    /// 削除した場合はtrueを返す
+    fn delete<'a>(
+        &'a self,
+        subscription_id: &'a str,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// 無効になった購読をendpointで削除する
+    fn delete_by_endpoint<'a>(
+        &'a self,
+        endpoint: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+}
@@ -0,0 +1,65 @@
+use std::{future::Future, pin::Pin};
+
+use chrono::NaiveDate;
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{quest::Quest, quest_id::QuestId, user_id::UserId};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるクエストのリポジトリ定義
+/// QuestRepositoryの実装はinfrastructureで行う
+pub trait QuestRepository {
+    /// クエストとサブタスクを保存する
+    fn create<'a>(
+        &'a self,
+        quest: &'a Quest,
+    ) -> Pin<Box<dyn Future<Output = Result<QuestId, RepositoryError>> + Send + 'a>>;
+
+    /// QuestIdを使用して一つのクエストをサブタスクとともに取得する
+    fn find_by_id<'a>(
+        &'a self,
+        quest_id: &'a QuestId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Quest, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのクエストすべてを取得する
+    /// 未達成のものを先に、期限が近い順(期限なしは最後)に並べる
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Quest>, RepositoryError>> + Send + 'a>>;
+
+    /// クエストを変更し、サブタスクを引数の内容で置き換える
+    /// QuestIdは引数のQuestから参照する
+    fn update<'a>(
+        &'a self,
+        quest: &'a Quest,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// サブタスクのチェックを変更する
+    fn update_subtask<'a>(
+        &'a self,
+        quest_id: &'a QuestId,
+        subtask_id: i64,
+        user_id: &'a UserId,
+        is_complete: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 未達成かつすべてのサブタスクが完了しているクエストを達成にする
+    /// 達成した日(ユーザーのタイムゾーン)を返し、条件を満たさない場合はNotFoundを返す
+    fn set_complete_true<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        quest_id: &'a QuestId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>>;
+
+    /// 指定されたクエスト一つを削除する
+    fn delete<'a>(
+        &'a self,
+        quest_id: &'a QuestId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+}
@@ -0,0 +1,39 @@
+use std::{future::Future, pin::Pin};
+
+use chrono::NaiveTime;
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{
+    daily_mission_id::DailyMissionId, reminder::ReminderSettings, user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるリマインダーの設定のリポジトリ定義
+/// ReminderRepositoryの実装はinfrastructureで行う
+pub trait ReminderRepository {
+    /// ユーザーのまとめの時刻とすべてのミッションのリマインダーの時刻を取得する
+    fn find_settings<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<ReminderSettings, RepositoryError>> + Send + 'a>>;
+
+    /// ミッションのリマインダーの時刻を変更する(ミッションが存在しない場合はNotFound)
+    /// 新しい時刻で登録し直すよう、そのミッションの実行待ちのジョブを削除する
+    fn set_mission_reminder<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        user_id: &'a UserId,
+        time: Option<NaiveTime>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// まとめの時刻を変更する
+    /// 新しい時刻で登録し直すよう、ユーザーの実行待ちのまとめのジョブを削除する
+    fn set_summary_time<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        time: Option<NaiveTime>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+}
@@ -0,0 +1,51 @@
+use std::{future::Future, pin::Pin};
+
+use chrono::NaiveDate;
+
+use crate::entity::{
+    stats::{CompletionDate, DailyStat, StatsMission},
+    user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層における統計のリポジトリ定義
+/// StatsRepositoryの実装はinfrastructureで行う
+/// 日付はすべてユーザーのタイムゾーンにおける日付で、fromとtoは両端を含む
+pub trait StatsRepository {
+    /// ユーザーのタイムゾーンにおける今日の日付
+    fn today<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>>;
+
+    /// 日ごとの集計を日付順に取得する(完了も経験値の増減もない日は含まない)
+    fn find_daily_stats<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<DailyStat>, RepositoryError>> + Send + 'a>>;
+
+    /// 完了率を集計する、ユーザーのすべてのミッションを取得する
+    fn find_missions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<StatsMission>, RepositoryError>> + Send + 'a>>;
+
+    /// ミッションを完了した日を取得する(同じ日の重複は除く)
+    fn find_completion_dates<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<CompletionDate>, RepositoryError>> + Send + 'a>>;
+
+    /// 凍結された日(休暇モードの期間とストリークフリーズを消費した日)を取得する
+    fn find_frozen_dates<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<NaiveDate>, RepositoryError>> + Send + 'a>>;
+}
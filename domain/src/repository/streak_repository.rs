@@ -0,0 +1,210 @@
+use std::{future::Future, pin::Pin};
+
+use chrono::NaiveDate;
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{
+    all_clear_bonus::AllClearBonus,
+    daily_mission_id::DailyMissionId,
+    frozen_day::FrozenDay,
+    mission_schedule::MissionSchedule,
+    streak::{Streak, StreakStats},
+    user_id::UserId,
+    vacation::{Vacation, VacationInput},
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層における連続達成記録のリポジトリ定義
+/// StreakRepositoryの実装はinfrastructureで行う
+pub trait StreakRepository {
+    /// ミッションの連続記録を取得する
+    /// 更新のため行ロックを取得する(SELECT ... FOR UPDATE)
+    fn find_mission_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Streak>, RepositoryError>> + Send + 'a>>;
+
+    /// ミッションを完了した日付すべてを取得する(連続記録の再計算用)
+    fn find_completed_dates<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<NaiveDate>, RepositoryError>> + Send + 'a>>;
+
+    /// ミッションのスケジュールを取得する
+    fn find_schedule<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionSchedule, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのミッションすべてのスケジュールを取得する
+    fn find_schedules<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionSchedule>, RepositoryError>> + Send + 'a>>;
+
+    /// ミッションの連続記録を保存(UPSERT)する
+    /// 読み込み時に再集計しないよう、前回の達成日を含むISO週の達成日数(week_count)も合わせて保存する
+    fn save_mission_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        streak: &'a Streak,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// dateに実施するユーザーのミッションがすべて完了しているか
+    fn is_all_complete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// すべてのミッションを完了した日の連続記録を取得する
+    /// 更新のため行ロックを取得する(SELECT ... FOR UPDATE)
+    fn find_user_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Streak>, RepositoryError>> + Send + 'a>>;
+
+    /// 実施するミッションをすべて完了した日付を取得する(連続記録の再計算用)
+    fn find_all_clear_dates<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<NaiveDate>, RepositoryError>> + Send + 'a>>;
+
+    /// すべてのミッションを完了した日の連続記録を保存(UPSERT)する
+    fn save_user_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        streak: &'a Streak,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーの連続記録をすべて取得する
+    /// 連続日数はユーザーのタイムゾーンにおける今日の時点で評価する
+    fn find_stats<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<StreakStats, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのタイムゾーンにおける今日の日付を取得する
+    fn find_current_date<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>>;
+
+    /// fromからtoまで(両端を含む)のストリークが凍結された日を日付順に取得する
+    /// 休暇モードの期間とストリークフリーズを消費した日が対象
+    fn find_frozen_days<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<FrozenDay>, RepositoryError>> + Send + 'a>>;
+
+    /// 同じユーザーの完了と取り消しを直列化するため、usersの行をロックする
+    /// 他のトランザクションで完了したミッションを見落とさないよう、トランザクションの最初に呼び出す
+    fn lock_user<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// dateのボーナス経験値がすでに記録されているか
+    fn exists_all_clear_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// すべてのミッションを完了した日のボーナス経験値を記録する
+    /// すでに記録されている場合はfalseを返す
+    fn save_all_clear_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        bonus: &'a AllClearBonus,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// dateのボーナス経験値の記録を削除し、削除した記録を返す
+    fn delete_all_clear_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AllClearBonus>, RepositoryError>> + Send + 'a>>;
+
+    /// 所持しているストリークフリーズの数を取得する
+    /// 消費するため行ロックを取得する(SELECT ... FOR UPDATE)
+    fn find_freezes<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>>;
+
+    /// datesの日数分ストリークフリーズを消費し、凍結された日として保存する
+    fn consume_freezes<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        dates: &'a [NaiveDate],
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// dateに達成した連続記録でストリークフリーズを1つ付与し、付与した日を記録する
+    /// maxを超える場合は付与せず、記録もしない
+    fn add_freeze<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+        max: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// dateに付与したストリークフリーズを取り除く
+    /// その日に付与していない場合と、すでに消費していて所持していない場合は何もしない
+    fn remove_freeze<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 所持しているストリークフリーズの数を取得する
+    fn find_available_freezes<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>>;
+
+    /// 休暇モードの期間を保存する
+    fn create_vacation<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        input: &'a VacationInput,
+    ) -> Pin<Box<dyn Future<Output = Result<Vacation, RepositoryError>> + Send + 'a>>;
+
+    /// since以降に終わる休暇モードの期間を取得する
+    fn find_vacations<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        since: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Vacation>, RepositoryError>> + Send + 'a>>;
+
+    /// 休暇モードを取り消す
+    /// todayより後に始まる期間は削除し、期間中の場合は昨日までに短縮する
+    /// 過去の期間はストリークに影響するため取り消すことはできない(NotFound)
+    fn cancel_vacation<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        vacation_id: i64,
+        today: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+}
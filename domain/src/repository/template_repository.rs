@@ -0,0 +1,73 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::{
+    daily_mission_id::DailyMissionId, mission_template::MissionTemplate,
+    mission_template_id::MissionTemplateId, user_id::UserId,
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるミッションのテンプレートのリポジトリ定義
+/// TemplateRepositoryの実装はinfrastructureで行う
+pub trait TemplateRepository {
+    /// カタログのテンプレートを取得する
+    fn find_curated<'a>(
+        &'a self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionTemplate>, RepositoryError>> + Send + 'a>>;
+
+    /// カタログのテンプレートを1件取得する
+    fn find_curated_by_id<'a>(
+        &'a self,
+        template_id: &'a MissionTemplateId,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionTemplate, RepositoryError>> + Send + 'a>>;
+
+    /// 共有コードからユーザーが公開したテンプレートを取得する
+    fn find_by_share_code<'a>(
+        &'a self,
+        share_code: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionTemplate, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーが公開したテンプレートを取得する
+    fn find_by_author<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionTemplate>, RepositoryError>> + Send + 'a>>;
+
+    /// カタログのテンプレートを作成する
+    fn create_curated<'a>(
+        &'a self,
+        template: &'a MissionTemplate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// カタログのテンプレートを更新する
+    /// 存在しない場合はNotFoundを返す
+    fn update_curated<'a>(
+        &'a self,
+        template: &'a MissionTemplate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// カタログのテンプレートを削除する
+    /// 削除した場合はtrueを返す
+    fn delete_curated<'a>(
+        &'a self,
+        template_id: &'a MissionTemplateId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのデイリーミッションの現在の内容をテンプレートとして公開する
+    /// ミッションが存在しない場合はNotFoundを返す
+    fn share_mission<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_id: &'a DailyMissionId,
+        template_id: &'a MissionTemplateId,
+        share_code: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーが公開したテンプレートを削除する
+    /// 削除した場合はtrueを返す
+    fn delete_shared<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        share_code: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>>;
+}
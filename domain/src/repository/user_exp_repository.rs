@@ -2,12 +2,17 @@ use std::{future::Future, pin::Pin};
 
 use sqlx::{MySql, Transaction};
 
-use crate::entity::{user_exp::UserExp, user_id::UserId};
+use crate::entity::{
+    exp_transaction::{ExpHistoryPage, ExpTransaction, NewExpTransaction},
+    user_exp::{ExpChange, UserExp},
+    user_id::UserId,
+};
 
 use super::repository_error::RepositoryError;
 
 /// ドメイン層におけるユーザーの経験値情報のリポジトリ定義
 /// UserExpRepositoryの実装はinfrastructureで行う
+/// 経験値はexp_transactionsの台帳に追記し、UserExpは台帳の合計をキャッシュした値として扱う
 pub trait UserExpRepository {
     /// UserExpを初期化(データベースに登録する)
     /// そのため各ユーザー
@@ -23,11 +28,29 @@ pub trait UserExpRepository {
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<UserExp, RepositoryError>> + Send + 'a>>;
 
-    /// UserExpの経験値を増加(変更)させる
-    fn add_exp<'a>(
+    /// 経験値の増減をUserExpの経験値に反映し、台帳に追記する
+    /// 経験値は0以上max_exp以下に飽和させ(UserExp::saturating_apply)、台帳には実際に反映された増減量を記録する
+    /// 反映される増減量が0の場合は何も書き込まない
+    fn add_transaction<'a>(
         &'a self,
         tx: &'a mut Transaction<'_, MySql>,
         user_id: &'a UserId,
-        additional_exp: i64,
-    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+        transaction: &'a NewExpTransaction,
+        max_exp: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<ExpChange, RepositoryError>> + Send + 'a>>;
+
+    /// 台帳を新しい順(id DESC)で取得する
+    /// 次のページの有無を判定するため、最大でpage.limit + 1件を返す
+    fn find_transactions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        page: &'a ExpHistoryPage,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<ExpTransaction>, RepositoryError>> + Send + 'a>>;
+
+    /// 台帳の合計からUserExpの経験値を再計算する
+    /// user_idがNoneの場合はすべてのユーザーが対象で、再計算したユーザーの数を返す
+    fn rebuild<'a>(
+        &'a self,
+        user_id: Option<&'a UserId>,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
 }
@@ -1,8 +1,11 @@
 use std::{future::Future, pin::Pin};
 
+use chrono_tz::Tz;
 use sqlx::{MySql, Transaction};
 
-use crate::entity::{user::User, user_builder::UserBuilder, user_id::UserId};
+use crate::entity::{
+    privacy_settings::PrivacySettings, user::User, user_builder::UserBuilder, user_id::UserId,
+};
 
 use super::repository_error::RepositoryError;
 
@@ -34,6 +37,26 @@ pub trait UserRepository {
         user: &'a User,
     ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
 
+    /// ユーザーのタイムゾーン(IANAのタイムゾーン名)を変更する
+    fn update_time_zone<'a>(
+        &'a self,
+        id: &'a UserId,
+        time_zone: Tz,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// ランキングとフレンド申請の公開設定を取得する
+    fn find_privacy_settings<'a>(
+        &'a self,
+        id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<PrivacySettings, RepositoryError>> + Send + 'a>>;
+
+    /// ランキングとフレンド申請の公開設定を変更する
+    fn update_privacy_settings<'a>(
+        &'a self,
+        id: &'a UserId,
+        settings: &'a PrivacySettings,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
     /// Userデータを削除する
     fn delete<'a>(
         &'a self,
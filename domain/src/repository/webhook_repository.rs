@@ -0,0 +1,127 @@
+use std::{future::Future, pin::Pin};
+
+use sqlx::{MySql, Transaction};
+
+use crate::entity::{
+    user_id::UserId,
+    webhook::{DeliveryStatus, PendingDelivery, Webhook, WebhookDelivery, WebhookPayload},
+};
+
+use super::repository_error::RepositoryError;
+
+/// ドメイン層におけるWebhookと配信のキューのリポジトリ定義
+/// WebhookRepositoryの実装はinfrastructureで行う
+/// 配信の時刻の比較はインスタンスごとの時計のずれの影響を受けないよう、DBの時刻(UTC)で行う
+pub trait WebhookRepository {
+    /// ユーザーが登録しているWebhookの数を取得する
+    /// 上限の確認と登録を直列化するため、ユーザーの行をロックする
+    fn count<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>>;
+
+    fn create<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        webhook: &'a Webhook,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのすべてのWebhookを登録順に取得する
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Webhook>, RepositoryError>> + Send + 'a>>;
+
+    /// ユーザーのWebhookを取得する(他のユーザーのWebhookの場合はNotFound)
+    fn find_by_id<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Webhook, RepositoryError>> + Send + 'a>>;
+
+    /// 送信先、購読するイベント、有効かどうかを更新する
+    fn update<'a>(
+        &'a self,
+        webhook: &'a Webhook,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 署名の秘密を置き換える
+    fn update_secret<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        user_id: &'a UserId,
+        secret: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// Webhookと配信の記録を削除する
+    fn delete<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// イベントを購読している有効なWebhookごとに配信を登録し、登録した数を返す
+    /// 配信が失われないよう、イベントが発生したトランザクションの中で呼び出す
+    fn enqueue<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        payload: &'a WebhookPayload<'a>,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+
+    /// Webhookの配信の記録を新しい順に最大limit件取得する
+    fn find_deliveries<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        limit: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<WebhookDelivery>, RepositoryError>> + Send + 'a>>;
+
+    /// 配信と同じペイロードを新しい配信(new_delivery_id)として登録する
+    fn redeliver<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        delivery_id: &'a str,
+        new_delivery_id: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<WebhookDelivery, RepositoryError>> + Send + 'a>>;
+
+    /// 送信予定の時刻を過ぎた配信を最大limit件取得し、holderが送信中の状態にする
+    /// 送信中のまま停止した配信は送信待ちに戻す
+    fn claim_due<'a>(
+        &'a self,
+        holder: &'a str,
+        limit: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PendingDelivery>, RepositoryError>> + Send + 'a>>;
+
+    /// holderが取得したまま送信していない配信を送信待ちに戻す
+    /// リースを失ったインスタンスが、取得した配信を他のインスタンスに引き渡すために使う
+    /// 送信していないため、取得した時に数えた実行回数は戻す
+    fn release<'a>(
+        &'a self,
+        holder: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+
+    /// 配信を終了した状態(Succeeded, Failed)にする
+    fn finish<'a>(
+        &'a self,
+        id: i64,
+        status: DeliveryStatus,
+        status_code: Option<u16>,
+        error: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 失敗した配信をdelay_seconds後に再試行する
+    fn retry<'a>(
+        &'a self,
+        id: i64,
+        delay_seconds: u32,
+        status_code: Option<u16>,
+        error: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>>;
+
+    /// 終了してからdays日以上経った配信の記録を削除し、削除した数を返す
+    fn purge<'a>(
+        &'a self,
+        days: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>>;
+}
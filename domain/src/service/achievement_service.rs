@@ -0,0 +1,104 @@
+use chrono::Utc;
+use sqlx::{MySql, Transaction};
+
+use crate::{
+    entity::{
+        achievement::{Achievement, UserAchievement},
+        domain_event::{AchievementUnlocked, DomainEvent},
+        token::Token,
+    },
+    repository::achievement_repository::AchievementRepository,
+};
+
+use super::{
+    service_error::achievement_service_error::AchievementServiceError, token_service::TokenService,
+};
+
+/// 実績関連のサービス実装
+/// 実績の定義はachievementsテーブルで管理し、完了やレベルの変化のたびにevaluate()で判定する
+#[derive(Debug, Clone)]
+pub struct AchievementService<T, A>
+where
+    T: TokenService,
+    A: AchievementRepository,
+{
+    token_service: T,
+    achievement_repo: A,
+}
+
+impl<T, A> AchievementService<T, A>
+where
+    T: TokenService,
+    A: AchievementRepository,
+{
+    pub fn new(token_service: T, achievement_repo: A) -> Self {
+        Self {
+            token_service,
+            achievement_repo,
+        }
+    }
+
+    // すべての実績を解除状況とともに取得する
+    pub async fn find_all(
+        &self,
+        token: Token,
+    ) -> Result<Vec<UserAchievement>, AchievementServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let achievements = self.achievement_repo.find_by_user_id(&user_id).await?;
+        Ok(achievements)
+    }
+
+    // 未解除の実績のうち条件を満たしたものを解除し、解除した実績を返す
+    // 完了や経験値の追加と同じトランザクションで呼び出す
+    // ボーナス経験値の付与は呼び出し元で行う
+    pub async fn evaluate<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        token: Token,
+        level: u32,
+    ) -> Result<Vec<Achievement>, AchievementServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let stats = self.achievement_repo.find_stats(tx, &user_id).await?;
+        let locked = self.achievement_repo.find_locked(tx, &user_id).await?;
+
+        let mut unlocked = Vec::new();
+        for achievement in locked {
+            if !achievement.is_satisfied(&stats, level) {
+                continue;
+            }
+            // 同時に別のリクエストで解除された場合はスキップする
+            if self
+                .achievement_repo
+                .unlock(tx, &user_id, &achievement.achievement_id)
+                .await?
+            {
+                unlocked.push(achievement);
+            }
+        }
+        Ok(unlocked)
+    }
+
+    // 解除した実績のAchievementUnlockedイベントを作る
+    // EventOutboxServiceで配信を記録するため、実績の解除と同じトランザクションでコミットの前に呼び出す
+    pub fn unlocked_events(
+        &self,
+        token: Token,
+        achievements: &[Achievement],
+    ) -> Result<Vec<DomainEvent>, AchievementServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let occurred_at = Utc::now().naive_utc();
+        let events = achievements
+            .iter()
+            .map(|achievement| {
+                DomainEvent::AchievementUnlocked(AchievementUnlocked {
+                    user_id: user_id.clone(),
+                    achievement_id: achievement.achievement_id.clone(),
+                    title: achievement.title.clone(),
+                    bonus_exp: achievement.bonus_exp,
+                    occurred_at,
+                })
+            })
+            .collect();
+        Ok(events)
+    }
+}
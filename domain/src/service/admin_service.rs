@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use validator::Validate;
+
+use crate::{
+    entity::{
+        level_curve::LevelCurve,
+        mission_capacity::{CapacityInput, PlanInput, UserPlanInput},
+        token::Token,
+        user_id::UserId,
+    },
+    repository::admin_repository::AdminRepository,
+};
+
+use super::{
+    level_convert::LevelConvert, service_error::admin_service_error::AdminServiceError,
+    token_service::TokenService,
+};
+
+/// 管理者向けの設定を行うサービス
+/// すべてのメソッドは管理者のみ実行できる
+#[derive(Debug, Clone)]
+pub struct AdminService<T, A, L>
+where
+    T: TokenService,
+    A: AdminRepository,
+    L: LevelConvert,
+{
+    token_service: T,
+    admin_repo: A,
+    level_converter: L,
+}
+
+impl<T, A, L> AdminService<T, A, L>
+where
+    T: TokenService,
+    A: AdminRepository,
+    L: LevelConvert,
+{
+    pub fn new(token_service: T, admin_repo: A, level_converter: L) -> Self {
+        Self {
+            token_service,
+            admin_repo,
+            level_converter,
+        }
+    }
+
+    /// プランのミッションの登録上限を設定する
+    /// プランが存在しない場合は作成する
+    pub async fn save_plan(
+        &self,
+        token: Token,
+        plan_id: String,
+        input: PlanInput,
+    ) -> Result<(), AdminServiceError> {
+        self.verify_admin(token).await?;
+        input.validate().map_err(AdminServiceError::Validation)?;
+        self.admin_repo
+            .save_plan(&plan_id, input.mission_capacity)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn update_user_plan(
+        &self,
+        token: Token,
+        user_id: UserId,
+        input: UserPlanInput,
+    ) -> Result<(), AdminServiceError> {
+        self.verify_admin(token).await?;
+        input.validate().map_err(AdminServiceError::Validation)?;
+        self.admin_repo
+            .update_user_plan(&user_id, input.plan_id.as_deref())
+            .await?;
+        Ok(())
+    }
+
+    /// ユーザーごとのミッションの登録上限を設定する
+    /// プランの上限より優先される
+    pub async fn update_user_capacity(
+        &self,
+        token: Token,
+        user_id: UserId,
+        input: CapacityInput,
+    ) -> Result<(), AdminServiceError> {
+        self.verify_admin(token).await?;
+        input.validate().map_err(AdminServiceError::Validation)?;
+        self.admin_repo
+            .update_user_capacity(&user_id, input.mission_capacity)
+            .await?;
+        Ok(())
+    }
+
+    /// レベルカーブを設定から読み込み直す
+    /// 設定が不正な場合は現在のレベルカーブを使い続ける
+    pub async fn reload_level_curve(
+        &self,
+        token: Token,
+    ) -> Result<Arc<LevelCurve>, AdminServiceError> {
+        self.verify_admin(token).await?;
+        let curve = self
+            .level_converter
+            .reload()
+            .map_err(AdminServiceError::InvalidLevelCurve)?;
+        Ok(curve)
+    }
+
+    async fn verify_admin(&self, token: Token) -> Result<UserId, AdminServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self.admin_repo.is_admin(&user_id).await? {
+            return Err(AdminServiceError::Forbidden);
+        }
+        Ok(user_id)
+    }
+}
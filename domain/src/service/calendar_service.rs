@@ -0,0 +1,84 @@
+use chrono::Utc;
+
+use crate::{
+    entity::{
+        calendar::{render_ics, CalendarFeed, FEED_COMPLETION_DAYS},
+        token::Token,
+    },
+    repository::{calendar_repository::CalendarRepository, repository_error::RepositoryError},
+};
+
+use super::{
+    service_error::calendar_service_error::CalendarServiceError, token_service::TokenService,
+    uuid_service::UUIDService,
+};
+
+/// カレンダーアプリから購読するiCalendarフィードのサービス実装
+/// フィードの取得はカレンダーアプリから行われるため、認証Cookieの代わりにURLに含めたfeed_tokenで持ち主を特定する
+#[derive(Debug, Clone)]
+pub struct CalendarService<T, U, C>
+where
+    T: TokenService,
+    U: UUIDService,
+    C: CalendarRepository,
+{
+    token_service: T,
+    uuid_service: U,
+    calendar_repo: C,
+}
+
+impl<T, U, C> CalendarService<T, U, C>
+where
+    T: TokenService,
+    U: UUIDService,
+    C: CalendarRepository,
+{
+    pub fn new(token_service: T, uuid_service: U, calendar_repo: C) -> Self {
+        Self {
+            token_service,
+            uuid_service,
+            calendar_repo,
+        }
+    }
+
+    /// 発行済みのフィードを取得する
+    pub async fn find_feed(&self, token: Token) -> Result<CalendarFeed, CalendarServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let feed = self.calendar_repo.find_feed(&user_id).await?;
+        Ok(feed)
+    }
+
+    /// フィードを発行する(発行済みの場合は再発行し、古いURLを無効にする)
+    pub async fn regenerate(&self, token: Token) -> Result<CalendarFeed, CalendarServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        // URLから推測されないよう、2つのUUIDを連結して十分な長さの乱数にする
+        let feed_token = format!(
+            "{}{}",
+            self.uuid_service.generate(),
+            self.uuid_service.generate()
+        )
+        .replace('-', "");
+        let feed = self.calendar_repo.save_feed(&user_id, &feed_token).await?;
+        Ok(feed)
+    }
+
+    /// フィードを無効にする
+    pub async fn revoke(&self, token: Token) -> Result<(), CalendarServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self.calendar_repo.delete_feed(&user_id).await? {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    /// feed_tokenの持ち主のミッションと過去の完了記録をiCalendarの形式で返す
+    pub async fn render(&self, feed_token: String) -> Result<String, CalendarServiceError> {
+        let user_id = self.calendar_repo.find_user_by_token(&feed_token).await?;
+        let missions = self.calendar_repo.find_missions(&user_id).await?;
+        let completions = self
+            .calendar_repo
+            .find_completions(&user_id, FEED_COMPLETION_DAYS)
+            .await?;
+        Ok(render_ics(&missions, &completions, Utc::now().naive_utc()))
+    }
+}
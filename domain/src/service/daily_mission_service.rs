@@ -1,47 +1,68 @@
+use chrono::Utc;
 use sqlx::{MySql, Transaction};
 use validator::Validate;
 
 use crate::{
     entity::{
-        daily_mission::DailyMission, daily_mission_builder::DailyMissionBuilder,
-        daily_mission_id::DailyMissionId, daily_mission_input::DailyMissionInput, token::Token,
+        daily_mission::DailyMission,
+        daily_mission_builder::DailyMissionBuilder,
+        daily_mission_id::DailyMissionId,
+        daily_mission_input::DailyMissionInput,
+        domain_event::{
+            DomainEvent, MissionCompleted, MissionCreated, MissionDeleted, MissionUncompleted,
+            MissionUpdated,
+        },
+        history_query::{HistoryCursor, HistoryPage, HistoryQuery},
+        mission_completion::{CompletionHistory, MissionCompletion},
+        mission_progress::{MissionProgress, ProgressInput},
+        streak::Streak,
+        token::Token,
     },
     repository::daily_mission_repository::DailyMissionRepository,
 };
 
 use super::{
+    event_publisher::EventPublisher, exp_reward_policy::ExpRewardPolicy,
     service_error::daily_mission_service_error::DailyMissionServiceError,
     token_service::TokenService, uuid_service::UUIDService,
 };
 
 #[derive(Debug, Clone)]
-pub struct DailyMissionService<T, U, M>
+pub struct DailyMissionService<T, U, M, P>
 where
     T: TokenService,
     U: UUIDService,
     M: DailyMissionRepository,
+    P: EventPublisher,
 {
     token_service: T,
     uuid_service: U,
     mission_repo: M,
+    event_publisher: P,
+    reward_policy: ExpRewardPolicy,
 }
 
-impl<T, U, M> DailyMissionService<T, U, M>
+impl<T, U, M, P> DailyMissionService<T, U, M, P>
 where
     T: TokenService,
     U: UUIDService,
     M: DailyMissionRepository,
+    P: EventPublisher,
 {
-    pub fn new(token_service: T, uuid_service: U, mission_repo: M) -> Self {
+    pub fn new(token_service: T, uuid_service: U, mission_repo: M, event_publisher: P) -> Self {
         Self {
             token_service,
             uuid_service,
             mission_repo,
+            event_publisher,
+            reward_policy: ExpRewardPolicy,
         }
     }
 
+    // 上限の確認と保存を同じトランザクションで処理するため、Transaction型を引数に取っている
     pub async fn create(
         &self,
+        tx: &mut Transaction<'_, MySql>,
         token: Token,
         mission_payload: DailyMissionInput,
     ) -> Result<DailyMissionId, DailyMissionServiceError> {
@@ -50,13 +71,15 @@ where
         mission_payload
             .validate()
             .map_err(DailyMissionServiceError::Validate)?;
+        // ユーザーが登録できる上限を取得する
+        // ユーザーの行ロックを取得するため、コミットまで他の登録は待たされる
+        let capacity = self.mission_repo.find_capacity(tx, &user_id).await?;
         // ユーザーが登録しているデイリーミッションをカウント
-        let length = self.mission_repo.count(&user_id).await?;
+        let length = self.mission_repo.count(tx, &user_id).await?;
 
-        // ユーザーは最大7個まで登録することができる
-        // 7個になったら追加できないようにguardする
-        if length >= 7 {
-            return Err(DailyMissionServiceError::OverCapacity);
+        // 上限に達したら追加できないようにguardする
+        if length.max(0) as u32 >= capacity {
+            return Err(DailyMissionServiceError::OverCapacity(capacity));
         }
 
         let mission_id = DailyMissionId(self.uuid_service.generate());
@@ -65,12 +88,81 @@ where
             .mission_id(&mission_id)
             .title(&mission_payload.title)
             .description(&mission_payload.description)
+            .schedule(&mission_payload.schedule)
+            .target_quantity(&mission_payload.target_quantity)
+            .unit(&mission_payload.unit)
+            .difficulty(&mission_payload.difficulty)
+            .exp_weight(&mission_payload.exp_weight)
             .build();
 
-        let mission_id = self.mission_repo.create(&mission).await?;
+        let mission_id = self.mission_repo.create(tx, &mission).await?;
         Ok(mission_id)
     }
 
+    // 登録したミッションのMissionCreatedイベントを作る
+    // EventOutboxServiceで配信を記録するため、登録と同じトランザクションでコミットの前に呼び出す
+    pub async fn created_events(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        mission_ids: &[DailyMissionId],
+    ) -> Result<Vec<DomainEvent>, DailyMissionServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        // 取り込みでは一度に多くのミッションを登録するため、まとめて取得する
+        let missions = self.mission_repo.find_titles(tx, &user_id).await?;
+        let occurred_at = Utc::now().naive_utc();
+        let events = missions
+            .into_iter()
+            .filter(|mission| mission_ids.contains(&mission.mission_id))
+            .map(|mission| {
+                DomainEvent::MissionCreated(MissionCreated {
+                    user_id: user_id.clone(),
+                    mission_id: mission.mission_id,
+                    title: mission.title,
+                    occurred_at,
+                })
+            })
+            .collect();
+        Ok(events)
+    }
+
+    // 完了したミッションのMissionCompletedイベントを作る
+    // EventOutboxServiceで配信を記録するため、完了と同じトランザクションでコミットの前に呼び出す
+    pub async fn completed_event(
+        &self,
+        token: Token,
+        completion: &MissionCompletion,
+    ) -> Result<DomainEvent, DailyMissionServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let mission = self
+            .mission_repo
+            .find_by_id(&completion.mission_id, &user_id)
+            .await?;
+        Ok(DomainEvent::MissionCompleted(MissionCompleted {
+            user_id,
+            mission_id: mission.mission_id,
+            title: mission.title,
+            date: completion.date,
+            exp_awarded: completion.exp_awarded,
+            occurred_at: Utc::now().naive_utc(),
+        }))
+    }
+
+    // 完了を取り消したミッションのMissionUncompletedイベントを作る
+    pub fn uncompleted_event(
+        &self,
+        token: Token,
+        completion: &MissionCompletion,
+    ) -> Result<DomainEvent, DailyMissionServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        Ok(DomainEvent::MissionUncompleted(MissionUncompleted {
+            user_id,
+            mission_id: completion.mission_id.clone(),
+            date: completion.date,
+            occurred_at: Utc::now().naive_utc(),
+        }))
+    }
+
     pub async fn find_by_id(
         &self,
         token: Token,
@@ -97,30 +189,223 @@ where
         mission_payload: DailyMissionInput,
     ) -> Result<(), DailyMissionServiceError> {
         let user_id = self.token_service.verify(token)?;
+        mission_payload
+            .validate()
+            .map_err(DailyMissionServiceError::Validate)?;
         let mission = DailyMissionBuilder::new()
             .user_id(&user_id)
             .mission_id(&mission_id)
             .title(&mission_payload.title)
             .description(&mission_payload.description)
+            .schedule(&mission_payload.schedule)
+            .target_quantity(&mission_payload.target_quantity)
+            .unit(&mission_payload.unit)
+            .difficulty(&mission_payload.difficulty)
+            .exp_weight(&mission_payload.exp_weight)
             .build();
 
         self.mission_repo.update(&mission, &user_id).await?;
+        // トランザクションを使わず更新は確定しているため、続けてイベントを発行する
+        self.event_publisher
+            .publish(DomainEvent::MissionUpdated(MissionUpdated {
+                user_id,
+                mission_id,
+                title: mission_payload.title,
+                occurred_at: Utc::now().naive_utc(),
+            }));
         Ok(())
     }
 
-    pub async fn set_complete_true<'a>(
+    pub async fn set_complete_true(
         &self,
-        tx: &'a mut Transaction<'_, MySql>,
+        tx: &mut Transaction<'_, MySql>,
         token: Token,
         mission_id: DailyMissionId,
-    ) -> Result<(), DailyMissionServiceError> {
+    ) -> Result<MissionCompletion, DailyMissionServiceError> {
         let user_id = self.token_service.verify(token)?;
+        // スケジュール上、今日実施しないミッションは完了にできない
+        let mission = self.mission_repo.find_by_id(&mission_id, &user_id).await?;
+        if !mission.is_due {
+            return Err(DailyMissionServiceError::NotDue);
+        }
+        // 数量目標のあるミッションは進捗が目標に達した時のみ完了になる
+        if mission.target_quantity.is_some() {
+            return Err(DailyMissionServiceError::ProgressRequired);
+        }
+        if mission.is_complete {
+            return Err(DailyMissionServiceError::AlreadyCompleted);
+        }
+        // 確認後に同時に完了された場合は、一意制約によって挿入されない
         self.mission_repo
             .set_complete_true(tx, &mission_id, &user_id)
+            .await?
+            .ok_or(DailyMissionServiceError::AlreadyCompleted)
+    }
+
+    /// 数量目標のあるミッションの今日の進捗を記録する
+    /// 累計が目標に達した場合はミッションを完了にし、その完了記録を返す
+    /// 既に完了している場合は進捗の加算のみ行う
+    pub async fn add_progress(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        mission_id: DailyMissionId,
+        progress_payload: ProgressInput,
+    ) -> Result<(MissionProgress, Option<MissionCompletion>), DailyMissionServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        progress_payload
+            .validate()
+            .map_err(DailyMissionServiceError::Validate)?;
+        let mission = self.mission_repo.find_by_id(&mission_id, &user_id).await?;
+        if mission.target_quantity.is_none() {
+            return Err(DailyMissionServiceError::NotQuantitative);
+        }
+        if !mission.is_due {
+            return Err(DailyMissionServiceError::NotDue);
+        }
+
+        let mut progress = self
+            .mission_repo
+            .add_progress(tx, &mission_id, &user_id, progress_payload.amount)
+            .await?;
+        // 完了を取り消した日は目標に達していても再び完了にしない
+        if progress.is_complete
+            || progress.completion_undone
+            || progress.quantity < progress.target_quantity
+        {
+            return Ok((progress, None));
+        }
+        let completion = self
+            .mission_repo
+            .set_complete_true(tx, &mission_id, &user_id)
+            .await?
+            .ok_or(DailyMissionServiceError::AlreadyCompleted)?;
+        progress.is_complete = true;
+        Ok((progress, Some(completion)))
+    }
+
+    /// 完了に付与する経験値を計算し、completionに設定する
+    /// streakは連続記録に完了を反映した後の値
+    /// 経験値の上限で切り捨てられる場合があるため、実際に反映された量はsave_exp_awarded()で保存する
+    pub async fn award_exp(
+        &self,
+        token: Token,
+        completion: &mut MissionCompletion,
+        streak: &Streak,
+    ) -> Result<i64, DailyMissionServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let mission = self
+            .mission_repo
+            .find_by_id(&completion.mission_id, &user_id)
             .await?;
+        let completed_at =
+            completion
+                .completed_at
+                .ok_or(DailyMissionServiceError::UnknownError(
+                    "completed_at is missing".to_string(),
+                ))?;
+        let exp = self.reward_policy.award(
+            mission.difficulty,
+            mission.exp_weight,
+            streak.current,
+            completed_at.time(),
+        );
+        completion.exp_awarded = exp;
+        Ok(exp)
+    }
+
+    /// 完了によって実際に反映された経験値を完了記録に保存する
+    /// 取り消した場合はこの量を戻す
+    pub async fn save_exp_awarded(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        completion: &mut MissionCompletion,
+        exp_awarded: i64,
+    ) -> Result<(), DailyMissionServiceError> {
+        self.token_service.verify(token)?;
+        self.mission_repo
+            .save_exp_awarded(tx, completion.completion_id, exp_awarded)
+            .await?;
+        completion.exp_awarded = exp_awarded;
         Ok(())
     }
 
+    /// 今日の完了を取り消す
+    /// 取り消した完了記録(付与した経験値を含む)を返す
+    pub async fn undo_complete(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        mission_id: DailyMissionId,
+    ) -> Result<MissionCompletion, DailyMissionServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let completion = self
+            .mission_repo
+            .undo_complete(tx, &mission_id, &user_id)
+            .await?;
+        Ok(completion)
+    }
+
+    /// ミッションの完了履歴を取得する
+    /// mission_idがNoneの場合はユーザーのすべてのミッションの履歴を返す
+    pub async fn history(
+        &self,
+        token: Token,
+        mission_id: Option<DailyMissionId>,
+        query: HistoryQuery,
+    ) -> Result<CompletionHistory, DailyMissionServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let page = HistoryPage::try_from(query).map_err(DailyMissionServiceError::InvalidQuery)?;
+        // 他のユーザーのミッションや存在しないミッションの場合はNotFoundを返す
+        if let Some(mission_id) = &mission_id {
+            self.mission_repo.find_by_id(mission_id, &user_id).await?;
+        }
+
+        let mut completions = self
+            .mission_repo
+            .find_completions(&user_id, mission_id.as_ref(), &page)
+            .await?;
+        // limitより多く取得できた場合は次のページが存在する
+        let next_cursor = if completions.len() > page.limit as usize {
+            completions.truncate(page.limit as usize);
+            completions.last().map(|c| {
+                HistoryCursor {
+                    date: c.date,
+                    completion_id: c.completion_id,
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
+
+        // このページの期間に含まれる凍結された日と進捗
+        // 前のページと重複しないように、カーソルの日付は前のページに含める
+        let from = match next_cursor {
+            Some(_) => completions.last().map(|c| c.date),
+            None => page.from,
+        };
+        let to = match page.cursor {
+            Some(cursor) => cursor.date.pred_opt(),
+            None => page.to,
+        };
+        let frozen_days = self
+            .mission_repo
+            .find_frozen_days(&user_id, from, to)
+            .await?;
+        let progress = self
+            .mission_repo
+            .find_progress(&user_id, mission_id.as_ref(), from, to)
+            .await?;
+        Ok(CompletionHistory {
+            completions,
+            frozen_days,
+            progress,
+            next_cursor,
+        })
+    }
+
     pub async fn delete(
         &self,
         token: Token,
@@ -128,6 +413,12 @@ where
     ) -> Result<(), DailyMissionServiceError> {
         let user_id = self.token_service.verify(token)?;
         self.mission_repo.delete(&mission_id, &user_id).await?;
+        self.event_publisher
+            .publish(DomainEvent::MissionDeleted(MissionDeleted {
+                user_id,
+                mission_id,
+                occurred_at: Utc::now().naive_utc(),
+            }));
         Ok(())
     }
 }
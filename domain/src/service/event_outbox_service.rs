@@ -0,0 +1,69 @@
+use sqlx::{MySql, Transaction};
+
+use crate::{
+    entity::{domain_event::DomainEvent, webhook::WebhookPayload},
+    repository::{
+        job_repository::JobRepository, repository_error::RepositoryError,
+        webhook_repository::WebhookRepository,
+    },
+};
+
+use super::event_publisher::EventPublisher;
+
+/// ドメインイベントをWebhookの配信と通知のジョブとして記録し、コミット後に購読者へ発行するサービス実装
+/// 配信と通知はイベントが発生したトランザクションの中でDBに登録するため、
+/// 購読者の処理が遅れた場合やコミット直後にインスタンスが停止した場合も失われない
+#[derive(Debug, Clone)]
+pub struct EventOutboxService<J, W, P>
+where
+    J: JobRepository,
+    W: WebhookRepository,
+    P: EventPublisher,
+{
+    job_repo: J,
+    webhook_repo: W,
+    event_publisher: P,
+}
+
+impl<J, W, P> EventOutboxService<J, W, P>
+where
+    J: JobRepository,
+    W: WebhookRepository,
+    P: EventPublisher,
+{
+    pub fn new(job_repo: J, webhook_repo: W, event_publisher: P) -> Self {
+        Self {
+            job_repo,
+            webhook_repo,
+            event_publisher,
+        }
+    }
+
+    /// イベントを購読しているWebhookの配信と、レベルアップの通知のジョブを登録する
+    /// イベントが発生した変更と同じトランザクションで、コミットの前に呼び出す
+    pub async fn record(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        events: &[DomainEvent],
+    ) -> Result<(), RepositoryError> {
+        for event in events {
+            if let DomainEvent::LevelUp(level_up) = event {
+                self.job_repo.enqueue_level_up(tx, level_up).await?;
+            }
+            if let Some(payload) = WebhookPayload::new(event) {
+                self.webhook_repo
+                    .enqueue(tx, event.user_id(), &payload)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 同じインスタンスで接続しているクライアント(SSE)にイベントを発行する
+    /// ロールバックされた変更を通知しないよう、トランザクションのコミット後に呼び出す
+    pub fn publish(&self, events: Vec<DomainEvent>) {
+        for event in events {
+            self.event_publisher.publish(event);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+use crate::entity::domain_event::DomainEvent;
+
+// ドメインイベントを発行するトレイト
+// 購読する側の処理を待たずに返すため、発行の失敗(購読者がいない場合など)は呼び出し元に返さない
+pub trait EventPublisher {
+    fn publish(&self, event: DomainEvent);
+}
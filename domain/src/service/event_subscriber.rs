@@ -0,0 +1,24 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::{domain_event::DomainEvent, user_id::UserId};
+
+use super::service_error::subscription_error::SubscriptionError;
+
+// ドメインイベントをユーザーごとに購読するトレイト
+// プロセス内で配信する実装の他に、複数のインスタンスの間で配信するバックエンド(Redisのpub/subなど)に差し替えられる
+// その場合はEventPublisherの実装も同じバックエンドに発行するものに差し替える
+pub trait EventSubscriber {
+    type Subscription: EventSubscription + Send + 'static;
+
+    /// 以降に発行される、指定したユーザーのドメインイベントを購読する
+    fn subscribe_user(&self, user_id: &UserId) -> Self::Subscription;
+}
+
+// 1つの購読(接続しているクライアントごとに作られる)
+pub trait EventSubscription {
+    /// 次のイベントを待つ
+    /// イベントが破棄された場合はLaggedを返し、購読は続けられる
+    fn recv<'a>(
+        &'a mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<DomainEvent, SubscriptionError>> + Send + 'a>>;
+}
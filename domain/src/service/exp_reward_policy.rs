@@ -0,0 +1,98 @@
+use chrono::{NaiveTime, Timelike};
+
+use crate::entity::mission_difficulty::MissionDifficulty;
+
+/// 連続達成日数がこの日数に達するごとにボーナスを加算する
+pub const STREAK_BONUS_INTERVAL: u32 = 7;
+/// STREAK_BONUS_INTERVALごとに加算するボーナス(%)
+pub const STREAK_BONUS_PERCENT: i64 = 10;
+/// 連続達成によるボーナスの上限(%)
+pub const MAX_STREAK_BONUS_PERCENT: i64 = 50;
+/// 早朝(5:00 - 8:59)に完了した場合のボーナス(%)
+pub const EARLY_BIRD_BONUS_PERCENT: i64 = 20;
+
+/// ミッションの完了時に付与する経験値の計算方針
+/// 難易度(または重み)を基本値とし、連続達成日数と完了した時刻によるボーナスを加算する
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExpRewardPolicy;
+
+impl ExpRewardPolicy {
+    /// 付与する経験値を計算する
+    /// streakは今回の完了を含む連続達成日数、completed_atはユーザーのタイムゾーンにおける時刻
+    /// ボーナスは切り捨てで、最低1expを付与する
+    pub fn award(
+        &self,
+        difficulty: MissionDifficulty,
+        exp_weight: Option<u32>,
+        streak: u32,
+        completed_at: NaiveTime,
+    ) -> i64 {
+        let base = exp_weight
+            .map(i64::from)
+            .unwrap_or_else(|| difficulty.base_exp());
+        let streak_bonus = (i64::from(streak / STREAK_BONUS_INTERVAL) * STREAK_BONUS_PERCENT)
+            .min(MAX_STREAK_BONUS_PERCENT);
+        let time_bonus = if (5..9).contains(&completed_at.hour()) {
+            EARLY_BIRD_BONUS_PERCENT
+        } else {
+            0
+        };
+        (base * (100 + streak_bonus + time_bonus) / 100).max(1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveTime;
+
+    use crate::entity::mission_difficulty::MissionDifficulty;
+
+    use super::ExpRewardPolicy;
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_award_by_difficulty() {
+        let policy = ExpRewardPolicy;
+        // 機能追加前と同じ2expになる
+        assert_eq!(
+            policy.award(MissionDifficulty::Normal, None, 1, time(12)),
+            2
+        );
+        assert_eq!(policy.award(MissionDifficulty::Easy, None, 1, time(12)), 1);
+        assert_eq!(policy.award(MissionDifficulty::Epic, None, 1, time(12)), 8);
+        // 重みは難易度より優先される
+        assert_eq!(
+            policy.award(MissionDifficulty::Easy, Some(10), 1, time(12)),
+            10
+        );
+    }
+
+    #[test]
+    fn test_award_with_bonus() {
+        let policy = ExpRewardPolicy;
+        // 14日連続で+20%
+        assert_eq!(
+            policy.award(MissionDifficulty::Epic, Some(10), 14, time(12)),
+            12
+        );
+        // 連続達成のボーナスは+50%まで
+        assert_eq!(
+            policy.award(MissionDifficulty::Epic, Some(10), 365, time(12)),
+            15
+        );
+        // 早朝は+20%
+        assert_eq!(
+            policy.award(MissionDifficulty::Epic, Some(10), 1, time(5)),
+            12
+        );
+        assert_eq!(
+            policy.award(MissionDifficulty::Epic, Some(10), 1, time(9)),
+            10
+        );
+        // 切り捨てで最低1exp
+        assert_eq!(policy.award(MissionDifficulty::Easy, None, 7, time(6)), 1);
+    }
+}
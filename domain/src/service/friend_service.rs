@@ -0,0 +1,245 @@
+use sqlx::{MySql, Transaction};
+
+use crate::{
+    entity::{
+        friend::{Friend, FriendRequestInput, FriendRequestResult, FriendRequests, FriendStatus},
+        token::Token,
+        user_id::UserId,
+    },
+    repository::{friend_repository::FriendRepository, repository_error::RepositoryError},
+};
+
+use super::{service_error::friend_service_error::FriendServiceError, token_service::TokenService};
+
+/// フレンド関係のサービス実装
+/// フレンドは両方向の2行で保存するため、変更はトランザクションで行う
+#[derive(Debug, Clone)]
+pub struct FriendService<T, F>
+where
+    T: TokenService,
+    F: FriendRepository,
+{
+    token_service: T,
+    friend_repo: F,
+}
+
+impl<T, F> FriendService<T, F>
+where
+    T: TokenService,
+    F: FriendRepository,
+{
+    pub fn new(token_service: T, friend_repo: F) -> Self {
+        Self {
+            token_service,
+            friend_repo,
+        }
+    }
+
+    pub async fn find_friends(&self, token: Token) -> Result<Vec<Friend>, FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let friends = self
+            .friend_repo
+            .find_by_status(&user_id, FriendStatus::Accepted)
+            .await?;
+        Ok(friends)
+    }
+
+    pub async fn find_requests(&self, token: Token) -> Result<FriendRequests, FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let incoming = self.friend_repo.find_incoming_requests(&user_id).await?;
+        let outgoing = self
+            .friend_repo
+            .find_by_status(&user_id, FriendStatus::Pending)
+            .await?;
+        Ok(FriendRequests { incoming, outgoing })
+    }
+
+    pub async fn find_blocked(&self, token: Token) -> Result<Vec<Friend>, FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let blocked = self
+            .friend_repo
+            .find_by_status(&user_id, FriendStatus::Blocked)
+            .await?;
+        Ok(blocked)
+    }
+
+    /// フレンド申請を送る
+    /// 相手からの申請がすでにある場合は、その申請を承認してフレンドになる
+    pub async fn send_request(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        input: FriendRequestInput,
+    ) -> Result<FriendRequestResult, FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let other_id = UserId(input.user_id);
+        self.lock_pair(tx, &user_id, &other_id).await?;
+
+        let sent = self
+            .friend_repo
+            .find_status(tx, &user_id, &other_id)
+            .await?;
+        let received = self
+            .friend_repo
+            .find_status(tx, &other_id, &user_id)
+            .await?;
+        let status = match (sent, received) {
+            (Some(FriendStatus::Blocked), _) | (_, Some(FriendStatus::Blocked)) => {
+                return Err(FriendServiceError::RequestRejected)
+            }
+            (Some(FriendStatus::Accepted), _) => return Err(FriendServiceError::AlreadyFriends),
+            (Some(FriendStatus::Pending), _) => return Err(FriendServiceError::AlreadyRequested),
+            (None, Some(FriendStatus::Pending)) => {
+                self.befriend(tx, &user_id, &other_id).await?;
+                FriendStatus::Accepted
+            }
+            _ => {
+                if !self.friend_repo.accepts_requests(tx, &other_id).await? {
+                    return Err(FriendServiceError::RequestRejected);
+                }
+                self.friend_repo
+                    .save(tx, &user_id, &other_id, FriendStatus::Pending)
+                    .await?;
+                FriendStatus::Pending
+            }
+        };
+        Ok(FriendRequestResult {
+            user_id: other_id,
+            status,
+        })
+    }
+
+    /// requester_idから受け取った申請を承認する
+    pub async fn accept_request(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        requester_id: UserId,
+    ) -> Result<(), FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.lock_pair(tx, &user_id, &requester_id).await?;
+        let received = self
+            .friend_repo
+            .find_status(tx, &requester_id, &user_id)
+            .await?;
+        if received != Some(FriendStatus::Pending) {
+            return Err(RepositoryError::NotFound.into());
+        }
+        self.befriend(tx, &user_id, &requester_id).await?;
+        Ok(())
+    }
+
+    /// 受け取った申請を拒否する、または送った申請を取り消す
+    pub async fn decline_request(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        other_id: UserId,
+    ) -> Result<(), FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let declined = self
+            .friend_repo
+            .delete(tx, &other_id, &user_id, FriendStatus::Pending)
+            .await?;
+        let cancelled = self
+            .friend_repo
+            .delete(tx, &user_id, &other_id, FriendStatus::Pending)
+            .await?;
+        if !declined && !cancelled {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    /// フレンドを解除する
+    pub async fn remove_friend(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        friend_id: UserId,
+    ) -> Result<(), FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self
+            .friend_repo
+            .delete(tx, &user_id, &friend_id, FriendStatus::Accepted)
+            .await?
+        {
+            return Err(RepositoryError::NotFound.into());
+        }
+        self.friend_repo
+            .delete(tx, &friend_id, &user_id, FriendStatus::Accepted)
+            .await?;
+        Ok(())
+    }
+
+    /// ユーザーをブロックする
+    /// フレンドや申請は解除され、ブロックを解除するまでどちらからも申請できなくなる
+    pub async fn block(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        other_id: UserId,
+    ) -> Result<(), FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.lock_pair(tx, &user_id, &other_id).await?;
+        // 相手からのブロックは相手が解除するまで残す
+        for status in [FriendStatus::Pending, FriendStatus::Accepted] {
+            self.friend_repo
+                .delete(tx, &other_id, &user_id, status)
+                .await?;
+        }
+        self.friend_repo
+            .save(tx, &user_id, &other_id, FriendStatus::Blocked)
+            .await?;
+        Ok(())
+    }
+
+    /// ブロックを解除する
+    pub async fn unblock(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        other_id: UserId,
+    ) -> Result<(), FriendServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self
+            .friend_repo
+            .delete(tx, &user_id, &other_id, FriendStatus::Blocked)
+            .await?
+        {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    // 自分自身は対象にできず、相手が存在しない場合はNotFoundを返す
+    async fn lock_pair(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        user_id: &UserId,
+        other_id: &UserId,
+    ) -> Result<(), FriendServiceError> {
+        if user_id == other_id {
+            return Err(FriendServiceError::SelfRequest);
+        }
+        if self.friend_repo.lock_users(tx, user_id, other_id).await? != 2 {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    async fn befriend(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        user_id: &UserId,
+        other_id: &UserId,
+    ) -> Result<(), FriendServiceError> {
+        self.friend_repo
+            .save(tx, user_id, other_id, FriendStatus::Accepted)
+            .await?;
+        self.friend_repo
+            .save(tx, other_id, user_id, FriendStatus::Accepted)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,457 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use sqlx::{MySql, Transaction};
+use validator::Validate;
+
+use crate::{
+    entity::{
+        group::{
+            Group, GroupInput, GroupInvite, GroupInviteInput, GroupMember, GroupRole,
+            GroupRoleInput, MAX_GROUP_MEMBERS,
+        },
+        group_id::GroupId,
+        group_mission::{
+            GroupBonus, GroupMission, GroupMissionCompletion, GroupMissionInput,
+            GroupMissionProgress, DEFAULT_GROUP_BONUS, MAX_GROUP_MISSIONS,
+        },
+        group_mission_id::GroupMissionId,
+        token::Token,
+        user_id::UserId,
+    },
+    repository::{group_repository::GroupRepository, repository_error::RepositoryError},
+};
+
+use super::{
+    service_error::group_service_error::GroupServiceError, token_service::TokenService,
+    uuid_service::UUIDService,
+};
+
+/// グループとグループミッションのサービス実装
+/// グループミッションは個人のデイリーミッションとは別に保存し、個人の登録上限には含めない
+#[derive(Debug, Clone)]
+pub struct GroupService<T, U, G>
+where
+    T: TokenService,
+    U: UUIDService,
+    G: GroupRepository,
+{
+    token_service: T,
+    uuid_service: U,
+    group_repo: G,
+}
+
+impl<T, U, G> GroupService<T, U, G>
+where
+    T: TokenService,
+    U: UUIDService,
+    G: GroupRepository,
+{
+    pub fn new(token_service: T, uuid_service: U, group_repo: G) -> Self {
+        Self {
+            token_service,
+            uuid_service,
+            group_repo,
+        }
+    }
+
+    /// グループを作成し、作成したユーザーをオーナーにする
+    pub async fn create(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        input: GroupInput,
+    ) -> Result<GroupId, GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        input.validate().map_err(GroupServiceError::Validate)?;
+        let group_id = GroupId(self.uuid_service.generate());
+        self.group_repo
+            .create(tx, &group_id, &input.name, &user_id)
+            .await?;
+        Ok(group_id)
+    }
+
+    pub async fn find_all(&self, token: Token) -> Result<Vec<Group>, GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let groups = self.group_repo.find_by_user_id(&user_id).await?;
+        Ok(groups)
+    }
+
+    pub async fn find_by_id(
+        &self,
+        token: Token,
+        group_id: GroupId,
+    ) -> Result<Group, GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let group = self.group_repo.find_by_id(&group_id, &user_id).await?;
+        Ok(group)
+    }
+
+    /// グループを削除する(オーナーのみ)
+    pub async fn delete(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+    ) -> Result<(), GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.group_repo.lock_group(tx, &group_id).await?;
+        let role = self.required_role(tx, &group_id, &user_id).await?;
+        if role != GroupRole::Owner {
+            return Err(GroupServiceError::Forbidden);
+        }
+        self.group_repo.delete(tx, &group_id).await?;
+        Ok(())
+    }
+
+    /// グループのメンバーを取得する(メンバーのみ)
+    pub async fn find_members(
+        &self,
+        token: Token,
+        group_id: GroupId,
+    ) -> Result<Vec<GroupMember>, GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if self
+            .group_repo
+            .find_role(&group_id, &user_id)
+            .await?
+            .is_none()
+        {
+            return Err(GroupServiceError::Forbidden);
+        }
+        let members = self.group_repo.find_members(&group_id).await?;
+        Ok(members)
+    }
+
+    /// ユーザーをグループに招待する(オーナーと管理者のみ)
+    pub async fn invite(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+        input: GroupInviteInput,
+    ) -> Result<(), GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let invitee_id = UserId(input.user_id);
+        let member_count = self.group_repo.lock_group(tx, &group_id).await?;
+        let role = self.required_role(tx, &group_id, &user_id).await?;
+        if !role.can_manage() {
+            return Err(GroupServiceError::Forbidden);
+        }
+        if self
+            .group_repo
+            .find_role_in(tx, &group_id, &invitee_id)
+            .await?
+            .is_some()
+        {
+            return Err(GroupServiceError::AlreadyMember);
+        }
+        if member_count >= MAX_GROUP_MEMBERS {
+            return Err(GroupServiceError::GroupFull(MAX_GROUP_MEMBERS));
+        }
+        self.group_repo
+            .save_invite(tx, &group_id, &invitee_id, &user_id)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn find_invites(&self, token: Token) -> Result<Vec<GroupInvite>, GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let invites = self.group_repo.find_invites(&user_id).await?;
+        Ok(invites)
+    }
+
+    /// 受け取った招待を承認してメンバーになる
+    pub async fn accept_invite(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+    ) -> Result<(), GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let member_count = self.group_repo.lock_group(tx, &group_id).await?;
+        if !self
+            .group_repo
+            .delete_invite(tx, &group_id, &user_id)
+            .await?
+        {
+            return Err(RepositoryError::NotFound.into());
+        }
+        if member_count >= MAX_GROUP_MEMBERS {
+            return Err(GroupServiceError::GroupFull(MAX_GROUP_MEMBERS));
+        }
+        self.group_repo
+            .add_member(tx, &group_id, &user_id, GroupRole::Member)
+            .await?;
+        Ok(())
+    }
+
+    /// 受け取った招待を拒否する
+    pub async fn decline_invite(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+    ) -> Result<(), GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self
+            .group_repo
+            .delete_invite(tx, &group_id, &user_id)
+            .await?
+        {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    /// メンバーの役割を変更する(オーナーのみ)
+    /// オーナーへの変更とオーナー自身の役割の変更はできない
+    pub async fn update_role(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+        member_id: UserId,
+        input: GroupRoleInput,
+    ) -> Result<(), GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if input.role == GroupRole::Owner {
+            return Err(GroupServiceError::InvalidRole);
+        }
+        self.group_repo.lock_group(tx, &group_id).await?;
+        if self.required_role(tx, &group_id, &user_id).await? != GroupRole::Owner {
+            return Err(GroupServiceError::Forbidden);
+        }
+        match self
+            .group_repo
+            .find_role_in(tx, &group_id, &member_id)
+            .await?
+        {
+            None => return Err(RepositoryError::NotFound.into()),
+            Some(GroupRole::Owner) => return Err(GroupServiceError::InvalidRole),
+            Some(_) => {}
+        }
+        self.group_repo
+            .update_role(tx, &group_id, &member_id, input.role)
+            .await?;
+        Ok(())
+    }
+
+    /// メンバーを削除する
+    /// 自分を指定した場合はグループを抜ける(オーナーは抜けられない)
+    /// 他のメンバーはオーナーと管理者が削除でき、管理者を削除できるのはオーナーのみ
+    pub async fn remove_member(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+        member_id: UserId,
+    ) -> Result<(), GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.group_repo.lock_group(tx, &group_id).await?;
+        let role = self.required_role(tx, &group_id, &user_id).await?;
+        if member_id == user_id {
+            if role == GroupRole::Owner {
+                return Err(GroupServiceError::OwnerCannotLeave);
+            }
+        } else {
+            let member_role = self
+                .group_repo
+                .find_role_in(tx, &group_id, &member_id)
+                .await?
+                .ok_or(RepositoryError::NotFound)?;
+            let permitted = match member_role {
+                GroupRole::Owner => false,
+                GroupRole::Admin => role == GroupRole::Owner,
+                GroupRole::Member => role.can_manage(),
+            };
+            if !permitted {
+                return Err(GroupServiceError::Forbidden);
+            }
+        }
+        self.group_repo
+            .remove_member(tx, &group_id, &member_id)
+            .await?;
+        Ok(())
+    }
+
+    /// グループミッションを作成する(オーナーと管理者のみ)
+    pub async fn create_mission(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+        input: GroupMissionInput,
+    ) -> Result<GroupMissionId, GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        input.validate().map_err(GroupServiceError::Validate)?;
+        self.group_repo.lock_group(tx, &group_id).await?;
+        if !self
+            .required_role(tx, &group_id, &user_id)
+            .await?
+            .can_manage()
+        {
+            return Err(GroupServiceError::Forbidden);
+        }
+        if self.group_repo.count_missions(tx, &group_id).await? >= MAX_GROUP_MISSIONS {
+            return Err(GroupServiceError::MissionOverCapacity(MAX_GROUP_MISSIONS));
+        }
+
+        let mission = GroupMission {
+            mission_id: GroupMissionId(self.uuid_service.generate()),
+            group_id,
+            title: input.title,
+            descriptions: input.description,
+            bonus_exp: input.bonus_exp.unwrap_or(DEFAULT_GROUP_BONUS),
+            created_at: Utc::now().naive_utc(),
+        };
+        self.group_repo.create_mission(tx, &mission).await?;
+        Ok(mission.mission_id)
+    }
+
+    /// グループミッションと自分のタイムゾーンにおける今日の進捗を取得する(メンバーのみ)
+    pub async fn find_missions(
+        &self,
+        token: Token,
+        group_id: GroupId,
+    ) -> Result<Vec<GroupMissionProgress>, GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if self
+            .group_repo
+            .find_role(&group_id, &user_id)
+            .await?
+            .is_none()
+        {
+            return Err(GroupServiceError::Forbidden);
+        }
+        let today = self.group_repo.find_current_date(&user_id).await?;
+        let missions = self.group_repo.find_missions(&group_id).await?;
+        let member_count = self.group_repo.find_members(&group_id).await?.len() as u32;
+        let bonus_missions = self
+            .group_repo
+            .find_bonus_missions(&group_id, today)
+            .await?;
+
+        let mut completed_by: HashMap<GroupMissionId, Vec<UserId>> = HashMap::new();
+        for completion in self.group_repo.find_completions(&group_id, today).await? {
+            completed_by
+                .entry(completion.mission_id)
+                .or_default()
+                .push(completion.user_id);
+        }
+        let progress = missions
+            .into_iter()
+            .map(|mission| {
+                let completed = completed_by.remove(&mission.mission_id).unwrap_or_default();
+                let bonus_awarded = bonus_missions.contains(&mission.mission_id);
+                GroupMissionProgress::new(mission, today, completed, member_count, bonus_awarded)
+            })
+            .collect();
+        Ok(progress)
+    }
+
+    /// グループミッションを削除する(オーナーと管理者のみ)
+    pub async fn delete_mission(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+        mission_id: GroupMissionId,
+    ) -> Result<(), GroupServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.group_repo.lock_group(tx, &group_id).await?;
+        if !self
+            .required_role(tx, &group_id, &user_id)
+            .await?
+            .can_manage()
+        {
+            return Err(GroupServiceError::Forbidden);
+        }
+        if !self
+            .group_repo
+            .delete_mission(tx, &group_id, &mission_id)
+            .await?
+        {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    /// グループミッションを自分のタイムゾーンの今日の日付で完了する
+    /// 完了によってメンバー全員が完了した場合は、その日のボーナスを一度だけ記録して返す
+    /// ボーナスの経験値の付与は呼び出し側で行う
+    pub async fn complete_mission(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        group_id: GroupId,
+        mission_id: GroupMissionId,
+        exp_awarded: i64,
+    ) -> Result<
+        (
+            GroupMissionCompletion,
+            GroupMissionProgress,
+            Option<GroupBonus>,
+        ),
+        GroupServiceError,
+    > {
+        let user_id = self.token_service.verify(token)?;
+        // 同じグループミッションの完了を直列化し、全員の完了の判定が重複しないようにする
+        let mission = self
+            .group_repo
+            .lock_mission(tx, &group_id, &mission_id)
+            .await?;
+        self.required_role(tx, &group_id, &user_id).await?;
+
+        let date = self.group_repo.find_current_date(&user_id).await?;
+        let completion = GroupMissionCompletion {
+            mission_id,
+            user_id,
+            date,
+            exp_awarded,
+        };
+        if !self.group_repo.save_completion(tx, &completion).await? {
+            return Err(GroupServiceError::AlreadyCompleted);
+        }
+
+        let members = self.group_repo.find_member_ids(tx, &group_id).await?;
+        let completed_by = self
+            .group_repo
+            .find_completed_members(tx, &completion.mission_id, date)
+            .await?;
+        let all_completed = members.iter().all(|m| completed_by.contains(m));
+        // ボーナスの記録は1日1行のため、メンバーが抜けて全員が完了した状態になっても二重には配布されない
+        let bonus_due = all_completed && mission.bonus_exp > 0;
+        let bonus = if bonus_due
+            && self
+                .group_repo
+                .save_bonus(tx, &completion.mission_id, date, mission.bonus_exp)
+                .await?
+        {
+            Some(GroupBonus {
+                mission_id: completion.mission_id.clone(),
+                date,
+                exp_awarded: mission.bonus_exp,
+                members: members.clone(),
+            })
+        } else {
+            None
+        };
+        let progress =
+            GroupMissionProgress::new(mission, date, completed_by, members.len() as u32, bonus_due);
+        Ok((completion, progress, bonus))
+    }
+
+    // メンバーの役割を取得する(メンバーでない場合はForbidden)
+    async fn required_role(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        group_id: &GroupId,
+        user_id: &UserId,
+    ) -> Result<GroupRole, GroupServiceError> {
+        self.group_repo
+            .find_role_in(tx, group_id, user_id)
+            .await?
+            .ok_or(GroupServiceError::Forbidden)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    entity::{
+        notification::Notification,
+        scheduled_job::{
+            retry_delay_seconds, JobKind, JobStatus, ScheduledJob, JOB_BATCH_SIZE,
+            JOB_GRACE_SECONDS, JOB_RETENTION_DAYS,
+        },
+    },
+    repository::{
+        daily_mission_repository::DailyMissionRepository, job_repository::JobRepository,
+        repository_error::RepositoryError,
+    },
+};
+
+use super::{
+    notification_channel::NotificationChannel, service_error::job_service_error::JobServiceError,
+};
+
+/// ジョブを実行するインスタンスを決めるリースの名前
+pub const JOB_LEASE_NAME: &str = "job_runner";
+
+/// リマインダーとまとめ、レベルアップの通知をバックグラウンドで実行するサービス実装
+/// 複数のインスタンスで起動した場合は、リースを持つ1つのインスタンスだけがジョブを実行する
+#[derive(Debug, Clone)]
+pub struct JobService<J, M, N>
+where
+    J: JobRepository,
+    M: DailyMissionRepository,
+    N: NotificationChannel,
+{
+    job_repo: J,
+    mission_repo: M,
+    channel: N,
+}
+
+impl<J, M, N> JobService<J, M, N>
+where
+    J: JobRepository,
+    M: DailyMissionRepository,
+    N: NotificationChannel,
+{
+    pub fn new(job_repo: J, mission_repo: M, channel: N) -> Self {
+        Self {
+            job_repo,
+            mission_repo,
+            channel,
+        }
+    }
+
+    /// ジョブの登録と実行を1回行い、実行したジョブの数を返す
+    /// holderはインスタンスの識別子で、lease_secondsは実行の間隔より十分に長くする
+    pub async fn run_once(
+        &self,
+        holder: &str,
+        lease_seconds: u32,
+    ) -> Result<usize, JobServiceError> {
+        if !self
+            .job_repo
+            .acquire_lease(JOB_LEASE_NAME, holder, lease_seconds)
+            .await?
+        {
+            return Ok(0);
+        }
+        self.job_repo.refresh_utc_offsets().await?;
+        self.job_repo.plan_jobs(JOB_GRACE_SECONDS).await?;
+        let jobs = self
+            .job_repo
+            .claim_due(holder, JOB_BATCH_SIZE, JOB_GRACE_SECONDS)
+            .await?;
+        for job in &jobs {
+            // 1つのジョブの失敗で他のジョブを止めないよう、失敗はジョブごとに記録する
+            match self.dispatch(job).await {
+                Ok(true) => self.job_repo.finish(job.id, JobStatus::Done, None).await?,
+                Ok(false) => {
+                    self.job_repo
+                        .finish(job.id, JobStatus::Skipped, None)
+                        .await?
+                }
+                Err(e) if job.can_retry() => {
+                    self.job_repo
+                        .retry(job.id, retry_delay_seconds(job.attempts), &e.to_string())
+                        .await?
+                }
+                Err(e) => {
+                    self.job_repo
+                        .finish(job.id, JobStatus::Failed, Some(&e.to_string()))
+                        .await?
+                }
+            }
+        }
+        self.job_repo.purge(JOB_RETENTION_DAYS).await?;
+        Ok(jobs.len())
+    }
+
+    /// ジョブの通知を送る
+    /// 通知する必要がない場合はfalseを返す
+    async fn dispatch(&self, job: &ScheduledJob) -> Result<bool, JobServiceError> {
+        let notification = match (job.kind, &job.mission_id) {
+            (JobKind::Reminder, Some(mission_id)) => {
+                match self.mission_repo.find_by_id(mission_id, &job.user_id).await {
+                    Ok(mission) => Notification::reminder(&mission),
+                    // ジョブの登録後にミッションが削除された
+                    Err(RepositoryError::NotFound) => None,
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            (JobKind::Reminder, None) => None,
+            (JobKind::Summary, _) => {
+                let missions = self.mission_repo.find_by_user_id(&job.user_id).await?;
+                Notification::summary(&missions)
+            }
+            (JobKind::LevelUp, _) => match (job.new_level, job.total_exp) {
+                (Some(new_level), Some(total_exp)) => {
+                    Some(Notification::level_up(&job.user_id, new_level, total_exp))
+                }
+                _ => None,
+            },
+        };
+        match notification {
+            Some(notification) => {
+                self.channel.send(&notification).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    entity::{
+        leaderboard::{
+            Leaderboard, LeaderboardCursor, LeaderboardEntry, LeaderboardMetric, LeaderboardPage,
+            LeaderboardQuery,
+        },
+        level_curve::LevelCurve,
+        token::Token,
+        user_id::UserId,
+    },
+    repository::leaderboard_repository::LeaderboardRepository,
+};
+
+use super::{
+    level_convert::LevelConvert, service_error::leaderboard_service_error::LeaderboardServiceError,
+    token_service::TokenService,
+};
+
+/// ランキングのサービス実装
+/// ページングは(score, user_id)のカーソルで行うため、ページが深くなってもOFFSETのように遅くならない
+#[derive(Debug, Clone)]
+pub struct LeaderboardService<T, L, C>
+where
+    T: TokenService,
+    L: LeaderboardRepository,
+    C: LevelConvert,
+{
+    token_service: T,
+    leaderboard_repo: L,
+    converter: C,
+}
+
+impl<T, L, C> LeaderboardService<T, L, C>
+where
+    T: TokenService,
+    L: LeaderboardRepository,
+    C: LevelConvert,
+{
+    pub fn new(token_service: T, leaderboard_repo: L, converter: C) -> Self {
+        Self {
+            token_service,
+            leaderboard_repo,
+            converter,
+        }
+    }
+
+    pub async fn find(
+        &self,
+        token: Token,
+        query: LeaderboardQuery,
+    ) -> Result<Leaderboard, LeaderboardServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let page =
+            LeaderboardPage::try_from(query).map_err(LeaderboardServiceError::InvalidQuery)?;
+
+        let mut entries = self.leaderboard_repo.find_entries(&user_id, &page).await?;
+        // limitより多く取得できた場合は続きのページが存在する
+        let next_cursor = if entries.len() > page.limit as usize {
+            entries.truncate(page.limit as usize);
+            entries.last().map(|entry| {
+                LeaderboardCursor {
+                    score: entry.score,
+                    user_id: entry.user_id.clone(),
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
+
+        // レベルは経験値の順に並ぶため、ページを取得してから経験値をレベルに変換する
+        let curve = match page.metric {
+            LeaderboardMetric::Level => Some(self.converter.curve()?),
+            _ => None,
+        };
+        if let Some(curve) = &curve {
+            for entry in entries.iter_mut() {
+                let (level, _) = curve.to_level_with_remain(entry.score);
+                entry.score = i64::from(level);
+            }
+        }
+        self.assign_ranks(&user_id, &page, curve.as_deref(), &mut entries)
+            .await?;
+
+        Ok(Leaderboard {
+            metric: page.metric,
+            scope: page.scope,
+            entries,
+            next_cursor,
+        })
+    }
+
+    // 順位は自分より大きいscoreのユーザー数 + 1で求める(同じレベルのユーザーも同じ順位になる)
+    // ページは(score DESC, user_id)の順に連続しているため、先頭のscoreより大きいユーザー数と先頭のscore以上のユーザー数だけを数え、
+    // 先頭と異なるscoreの順位はページ内の位置から求める
+    async fn assign_ranks(
+        &self,
+        user_id: &UserId,
+        page: &LeaderboardPage,
+        curve: Option<&LevelCurve>,
+        entries: &mut [LeaderboardEntry],
+    ) -> Result<(), LeaderboardServiceError> {
+        let Some(first) = entries.first().map(|entry| entry.score) else {
+            return Ok(());
+        };
+        let above_first = self
+            .count_at_least(user_id, page, curve, first.saturating_add(1))
+            .await?;
+        // 先頭と同じscoreの行の数
+        let first_len = entries
+            .iter()
+            .take_while(|entry| entry.score == first)
+            .count();
+        let at_least_first = if first_len < entries.len() {
+            self.count_at_least(user_id, page, curve, first).await?
+        } else {
+            0
+        };
+
+        let mut rank = above_first + 1;
+        let mut previous = first;
+        for (i, entry) in entries.iter_mut().enumerate() {
+            if entry.score != previous {
+                rank = at_least_first + (i - first_len) as u64 + 1;
+                previous = entry.score;
+            }
+            entry.rank = rank;
+        }
+        Ok(())
+    }
+
+    // ランキングの対象のうち、scoreがscore以上のユーザーの数を取得する
+    // レベルのランキングではレベルに到達するのに必要な経験値で数える
+    async fn count_at_least(
+        &self,
+        user_id: &UserId,
+        page: &LeaderboardPage,
+        curve: Option<&LevelCurve>,
+        score: i64,
+    ) -> Result<u64, LeaderboardServiceError> {
+        let score = match curve {
+            Some(curve) => match u32::try_from(score)
+                .ok()
+                .and_then(|level| curve.exp_to_reach(level))
+            {
+                Some(exp) => exp,
+                // 最大レベルを超えるレベルに到達しているユーザーはいない
+                None => return Ok(0),
+            },
+            None => score,
+        };
+        Ok(self
+            .leaderboard_repo
+            .count_at_least(user_id, page.metric, page.scope, score)
+            .await?)
+    }
+}
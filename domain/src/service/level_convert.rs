@@ -1,7 +1,26 @@
+use std::sync::Arc;
+
+use crate::entity::level_curve::LevelCurve;
+
+use super::service_error::level_curve_error::LevelCurveError;
+
 // 経験値とレベル間での振る舞いを担うトレイト
 pub trait LevelConvert {
+    // 現在のレベルカーブを取得する
+    // 起動時にreload()で読み込んでいない場合はエラーを返す
+    fn curve(&self) -> Result<Arc<LevelCurve>, LevelCurveError>;
+
+    // レベルカーブを設定から読み込み直す
+    // 読み込んだ設定が不正な場合はエラーを返し、現在のレベルカーブを使い続ける
+    fn reload(&self) -> Result<Arc<LevelCurve>, LevelCurveError>;
+
     // 所持経験値をレベルに変換する
     // レベルアップに必要な経験値を計算するメソッド
     // 戻り値は(現在のレベル, レベルアップに必要な経験値量)
-    fn to_level_with_remain(&self, experience_point: i64) -> (u32, Option<u32>);
+    fn to_level_with_remain(
+        &self,
+        experience_point: i64,
+    ) -> Result<(u32, Option<u32>), LevelCurveError> {
+        Ok(self.curve()?.to_level_with_remain(experience_point))
+    }
 }
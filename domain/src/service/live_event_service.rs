@@ -0,0 +1,38 @@
+use crate::entity::token::Token;
+
+use super::{
+    event_subscriber::EventSubscriber,
+    service_error::live_event_service_error::LiveEventServiceError, token_service::TokenService,
+};
+
+/// 他の端末やスクリプトによる変更をクライアントに届けるため、ドメインイベントを購読するサービス実装
+/// 同じユーザーのクライアントはそれぞれ購読し、すべてのクライアントに同じイベントが届く
+#[derive(Debug, Clone)]
+pub struct LiveEventService<T, S>
+where
+    T: TokenService,
+    S: EventSubscriber,
+{
+    token_service: T,
+    event_subscriber: S,
+}
+
+impl<T, S> LiveEventService<T, S>
+where
+    T: TokenService,
+    S: EventSubscriber,
+{
+    pub fn new(token_service: T, event_subscriber: S) -> Self {
+        Self {
+            token_service,
+            event_subscriber,
+        }
+    }
+
+    /// 認証したユーザーのドメインイベントを購読する
+    /// トークンは購読を始める時に検証し、購読中に期限が切れても接続は維持する
+    pub fn subscribe(&self, token: Token) -> Result<S::Subscription, LiveEventServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        Ok(self.event_subscriber.subscribe_user(&user_id))
+    }
+}
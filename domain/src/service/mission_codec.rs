@@ -0,0 +1,24 @@
+use crate::entity::{
+    daily_mission_input::DailyMissionInput,
+    mission_transfer::{MissionFormat, MissionRecord},
+};
+
+use super::service_error::mission_codec_error::MissionCodecError;
+
+// ミッションの取り込み/書き出しのファイルを変換するトレイト
+pub trait MissionCodec {
+    // ファイルを行ごとのPayloadに変換する
+    // 行の変換に失敗した場合はその行のみErrにし、他の行の変換は続ける
+    fn decode(
+        &self,
+        format: MissionFormat,
+        body: &[u8],
+    ) -> Result<Vec<Result<DailyMissionInput, String>>, MissionCodecError>;
+
+    // ミッションの定義をファイルに変換する
+    fn encode(
+        &self,
+        format: MissionFormat,
+        records: &[MissionRecord],
+    ) -> Result<Vec<u8>, MissionCodecError>;
+}
@@ -0,0 +1,140 @@
+use sqlx::{MySql, Transaction};
+use validator::Validate;
+
+use crate::{
+    entity::{
+        daily_mission_builder::DailyMissionBuilder,
+        daily_mission_id::DailyMissionId,
+        mission_transfer::{ImportReport, ImportRowError, MissionFormat, MAX_IMPORT_ROWS},
+        token::Token,
+    },
+    repository::daily_mission_repository::DailyMissionRepository,
+};
+
+use super::{
+    mission_codec::MissionCodec,
+    service_error::mission_transfer_service_error::MissionTransferServiceError,
+    token_service::TokenService, uuid_service::UUIDService,
+};
+
+/// デイリーミッションの一括取り込み/書き出しのサービス実装
+#[derive(Debug, Clone)]
+pub struct MissionTransferService<T, U, M, C>
+where
+    T: TokenService,
+    U: UUIDService,
+    M: DailyMissionRepository,
+    C: MissionCodec,
+{
+    token_service: T,
+    uuid_service: U,
+    mission_repo: M,
+    codec: C,
+}
+
+impl<T, U, M, C> MissionTransferService<T, U, M, C>
+where
+    T: TokenService,
+    U: UUIDService,
+    M: DailyMissionRepository,
+    C: MissionCodec,
+{
+    pub fn new(token_service: T, uuid_service: U, mission_repo: M, codec: C) -> Self {
+        Self {
+            token_service,
+            uuid_service,
+            mission_repo,
+            codec,
+        }
+    }
+
+    /// ファイルのミッションをまとめて登録する
+    /// 1行でもエラーがある場合やdry-runの場合は登録せずに結果だけを返す
+    /// 上限の確認と保存を同じトランザクションで行うため、すべて登録されるか1件も登録されないかのどちらかになる
+    pub async fn import(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        format: MissionFormat,
+        body: &[u8],
+        dry_run: bool,
+    ) -> Result<ImportReport, MissionTransferServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let rows = self.codec.decode(format, body)?;
+        if rows.len() > MAX_IMPORT_ROWS {
+            return Err(MissionTransferServiceError::TooManyRows(MAX_IMPORT_ROWS));
+        }
+
+        // 行ごとにDailyMissionInputのバリデーションを行う
+        let total = rows.len();
+        let mut inputs = Vec::with_capacity(total);
+        let mut errors = Vec::new();
+        for (index, row) in rows.into_iter().enumerate() {
+            let result = row.and_then(|input| match input.validate() {
+                Ok(_) => Ok(input),
+                Err(e) => Err(e.to_string()),
+            });
+            match result {
+                Ok(input) => inputs.push(input),
+                Err(message) => errors.push(ImportRowError {
+                    row: index + 1,
+                    message,
+                }),
+            }
+        }
+
+        // ユーザーの行ロックを取得するため、コミットまで他の登録は待たされる
+        let capacity = self.mission_repo.find_capacity(tx, &user_id).await?;
+        let existing = self.mission_repo.count(tx, &user_id).await?.max(0) as u32;
+        let exceeds_capacity = existing as usize + inputs.len() > capacity as usize;
+
+        let mut report = ImportReport {
+            dry_run,
+            applied: false,
+            total,
+            valid: inputs.len(),
+            capacity,
+            existing,
+            exceeds_capacity,
+            errors,
+            mission_ids: Vec::new(),
+        };
+        if dry_run || !report.errors.is_empty() {
+            return Ok(report);
+        }
+        if exceeds_capacity {
+            return Err(MissionTransferServiceError::OverCapacity(capacity));
+        }
+
+        for input in inputs {
+            let mission_id = DailyMissionId(self.uuid_service.generate());
+            let mission = DailyMissionBuilder::new()
+                .user_id(&user_id)
+                .mission_id(&mission_id)
+                .title(&input.title)
+                .description(&input.description)
+                .schedule(&input.schedule)
+                .target_quantity(&input.target_quantity)
+                .unit(&input.unit)
+                .difficulty(&input.difficulty)
+                .exp_weight(&input.exp_weight)
+                .build();
+            self.mission_repo.create(tx, &mission).await?;
+            report.mission_ids.push(mission_id);
+        }
+        report.applied = true;
+        Ok(report)
+    }
+
+    /// ユーザーのすべてのミッションを指定した形式で書き出す
+    pub async fn export(
+        &self,
+        token: Token,
+        format: MissionFormat,
+    ) -> Result<Vec<u8>, MissionTransferServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let records = self.mission_repo.find_records(&user_id).await?;
+        let body = self.codec.encode(format, &records)?;
+        Ok(body)
+    }
+}
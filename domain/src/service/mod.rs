@@ -1,9 +1,34 @@
+pub mod achievement_service;
+pub mod admin_service;
 pub mod auth_service;
+pub mod calendar_service;
 pub mod daily_mission_service;
+pub mod event_outbox_service;
+pub mod event_publisher;
+pub mod event_subscriber;
+pub mod exp_reward_policy;
+pub mod friend_service;
+pub mod group_service;
+pub mod job_service;
+pub mod leaderboard_service;
 pub mod level_convert;
+pub mod live_event_service;
+pub mod mission_codec;
+pub mod mission_transfer_service;
+pub mod notification_channel;
 pub mod password_hash_service;
+pub mod push_sender;
+pub mod push_service;
+pub mod quest_service;
+pub mod reminder_service;
 pub mod service_error;
+pub mod stats_service;
+pub mod streak_service;
+pub mod template_service;
 pub mod token_service;
 pub mod user_exp_service;
 pub mod user_service;
 pub mod uuid_service;
+pub mod webhook_delivery_service;
+pub mod webhook_sender;
+pub mod webhook_service;
@@ -0,0 +1,14 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::notification::Notification;
+
+use super::service_error::notification_error::NotificationError;
+
+// ユーザーに通知を送るトレイト
+// 送信の方法(ログ、Web Pushなど)は実装ごとに切り替える
+pub trait NotificationChannel {
+    fn send<'a>(
+        &'a self,
+        notification: &'a Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotificationError>> + Send + 'a>>;
+}
@@ -0,0 +1,18 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::{notification::Notification, push_subscription::PushSubscription};
+
+use super::service_error::push_error::PushError;
+
+// Web Pushのメッセージを送るトレイト
+// ペイロードの暗号化(RFC 8291)とVAPIDによるアプリケーションサーバーの認証(RFC 8292)は実装が行う
+pub trait PushSender {
+    /// ブラウザで購読する際に使うVAPIDの公開鍵(base64url)
+    fn public_key(&self) -> Result<String, PushError>;
+
+    fn send<'a>(
+        &'a self,
+        subscription: &'a PushSubscription,
+        notification: &'a Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), PushError>> + Send + 'a>>;
+}
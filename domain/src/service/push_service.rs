@@ -0,0 +1,162 @@
+use std::{future::Future, pin::Pin};
+
+use chrono::Utc;
+use validator::Validate;
+
+use crate::{
+    entity::{
+        notification::Notification,
+        push_subscription::{PushSubscription, PushSubscriptionInput, VapidPublicKey},
+        token::Token,
+    },
+    repository::{push_repository::PushRepository, repository_error::RepositoryError},
+};
+
+use super::{
+    notification_channel::NotificationChannel,
+    push_sender::PushSender,
+    service_error::{
+        notification_error::NotificationError, push_error::PushError,
+        push_service_error::PushServiceError,
+    },
+    token_service::TokenService,
+    uuid_service::UUIDService,
+};
+
+/// Web Pushの購読の管理と、購読している端末への通知のサービス実装
+/// NotificationChannelとして、リマインダーやレベルアップの通知に使う
+#[derive(Debug, Clone)]
+pub struct PushService<T, U, P, S>
+where
+    T: TokenService,
+    U: UUIDService,
+    P: PushRepository,
+    S: PushSender,
+{
+    token_service: T,
+    uuid_service: U,
+    push_repo: P,
+    sender: S,
+}
+
+impl<T, U, P, S> PushService<T, U, P, S>
+where
+    T: TokenService,
+    U: UUIDService,
+    P: PushRepository,
+    S: PushSender,
+{
+    pub fn new(token_service: T, uuid_service: U, push_repo: P, sender: S) -> Self {
+        Self {
+            token_service,
+            uuid_service,
+            push_repo,
+            sender,
+        }
+    }
+
+    /// ブラウザで購読する際に使うVAPIDの公開鍵を取得する
+    pub fn vapid_public_key(&self) -> Result<VapidPublicKey, PushServiceError> {
+        Ok(VapidPublicKey {
+            public_key: self.sender.public_key()?,
+        })
+    }
+
+    /// 端末の購読を登録する
+    pub async fn subscribe(
+        &self,
+        token: Token,
+        input: PushSubscriptionInput,
+    ) -> Result<PushSubscription, PushServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        input.validate().map_err(PushServiceError::Validate)?;
+        let subscription = PushSubscription {
+            subscription_id: self.uuid_service.generate(),
+            user_id,
+            expires_at: input.expires_at(),
+            endpoint: input.endpoint,
+            p256dh: input.keys.p256dh,
+            auth: input.keys.auth,
+            device_name: input.device_name,
+            created_at: Utc::now().naive_utc(),
+        };
+        let subscription = self.push_repo.save(&subscription).await?;
+        Ok(subscription)
+    }
+
+    /// 登録している購読を取得する
+    pub async fn find_subscriptions(
+        &self,
+        token: Token,
+    ) -> Result<Vec<PushSubscription>, PushServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let subscriptions = self.push_repo.find_by_user_id(&user_id).await?;
+        Ok(subscriptions)
+    }
+
+    /// 端末の購読を解除する
+    pub async fn unsubscribe(
+        &self,
+        token: Token,
+        subscription_id: String,
+    ) -> Result<(), PushServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self.push_repo.delete(&subscription_id, &user_id).await? {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    /// ユーザーが購読しているすべての端末に通知を送る
+    /// 期限切れや解除された購読は削除し、どの端末にも送れなかった場合だけエラーを返す
+    pub async fn deliver(&self, notification: &Notification) -> Result<(), NotificationError> {
+        let to_err = |e: RepositoryError| NotificationError::Delivery(e.to_string());
+        let now = Utc::now().naive_utc();
+        let subscriptions = self
+            .push_repo
+            .find_by_user_id(&notification.user_id)
+            .await
+            .map_err(to_err)?;
+        let mut delivered = false;
+        let mut last_error = None;
+        for subscription in subscriptions {
+            if subscription.is_expired(now) {
+                self.push_repo
+                    .delete_by_endpoint(&subscription.endpoint)
+                    .await
+                    .map_err(to_err)?;
+                continue;
+            }
+            match self.sender.send(&subscription, notification).await {
+                Ok(()) => delivered = true,
+                Err(PushError::Gone) | Err(PushError::InvalidSubscription(_)) => {
+                    self.push_repo
+                        .delete_by_endpoint(&subscription.endpoint)
+                        .await
+                        .map_err(to_err)?;
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        // 一部の端末に送れた場合に再試行すると、同じ通知が重複して届くためエラーにしない
+        match last_error {
+            Some(e) if !delivered => Err(NotificationError::Delivery(e.to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T, U, P, S> NotificationChannel for PushService<T, U, P, S>
+where
+    T: TokenService + Sync,
+    U: UUIDService + Sync,
+    P: PushRepository + Sync,
+    S: PushSender + Sync,
+{
+    fn send<'a>(
+        &'a self,
+        notification: &'a Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotificationError>> + Send + 'a>> {
+        Box::pin(self.deliver(notification))
+    }
+}
@@ -0,0 +1,170 @@
+use sqlx::{MySql, Transaction};
+use validator::Validate;
+
+use crate::{
+    entity::{
+        quest::Quest,
+        quest_builder::QuestBuilder,
+        quest_id::QuestId,
+        quest_input::{QuestInput, SubtaskCheckInput},
+        token::Token,
+        user_id::UserId,
+    },
+    repository::{quest_repository::QuestRepository, repository_error::RepositoryError},
+};
+
+use super::{
+    service_error::quest_service_error::QuestServiceError, token_service::TokenService,
+    uuid_service::UUIDService,
+};
+
+#[derive(Debug, Clone)]
+pub struct QuestService<T, U, Q>
+where
+    T: TokenService,
+    U: UUIDService,
+    Q: QuestRepository,
+{
+    token_service: T,
+    uuid_service: U,
+    quest_repo: Q,
+}
+
+impl<T, U, Q> QuestService<T, U, Q>
+where
+    T: TokenService,
+    U: UUIDService,
+    Q: QuestRepository,
+{
+    pub fn new(token_service: T, uuid_service: U, quest_repo: Q) -> Self {
+        Self {
+            token_service,
+            uuid_service,
+            quest_repo,
+        }
+    }
+
+    pub async fn create(
+        &self,
+        token: Token,
+        quest_payload: QuestInput,
+    ) -> Result<QuestId, QuestServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        quest_payload
+            .validate()
+            .map_err(QuestServiceError::Validate)?;
+
+        let quest_id = QuestId(self.uuid_service.generate());
+        let quest = build_quest(&user_id, &quest_id, &quest_payload);
+        let quest_id = self.quest_repo.create(&quest).await?;
+        Ok(quest_id)
+    }
+
+    pub async fn find_by_id(
+        &self,
+        token: Token,
+        quest_id: QuestId,
+    ) -> Result<Quest, QuestServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let quest = self.quest_repo.find_by_id(&quest_id, &user_id).await?;
+        Ok(quest)
+    }
+
+    pub async fn find_all(&self, token: Token) -> Result<Vec<Quest>, QuestServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let quests = self.quest_repo.find_by_user_id(&user_id).await?;
+        Ok(quests)
+    }
+
+    /// 達成済みのクエストは変更できない
+    pub async fn update(
+        &self,
+        token: Token,
+        quest_id: QuestId,
+        quest_payload: QuestInput,
+    ) -> Result<(), QuestServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        quest_payload
+            .validate()
+            .map_err(QuestServiceError::Validate)?;
+        let stored_quest = self.quest_repo.find_by_id(&quest_id, &user_id).await?;
+        if stored_quest.is_complete() {
+            return Err(QuestServiceError::AlreadyCompleted);
+        }
+
+        let quest = build_quest(&user_id, &quest_id, &quest_payload);
+        self.quest_repo.update(&quest, &user_id).await?;
+        Ok(())
+    }
+
+    /// サブタスクのチェックを変更する
+    /// 達成済みのクエストのサブタスクは変更できない
+    pub async fn update_subtask(
+        &self,
+        token: Token,
+        quest_id: QuestId,
+        subtask_id: i64,
+        check_payload: SubtaskCheckInput,
+    ) -> Result<(), QuestServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let quest = self.quest_repo.find_by_id(&quest_id, &user_id).await?;
+        if quest.is_complete() {
+            return Err(QuestServiceError::AlreadyCompleted);
+        }
+        self.quest_repo
+            .update_subtask(&quest_id, subtask_id, &user_id, check_payload.is_complete)
+            .await?;
+        Ok(())
+    }
+
+    /// クエストを達成にする
+    /// 経験値の付与とともにトランザクションで処理するため、Transaction型を引数に取っている
+    pub async fn set_complete_true(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        quest_id: QuestId,
+    ) -> Result<(), QuestServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let quest = self.quest_repo.find_by_id(&quest_id, &user_id).await?;
+        if quest.is_complete() {
+            return Err(QuestServiceError::AlreadyCompleted);
+        }
+        if !quest.is_subtasks_complete() {
+            return Err(QuestServiceError::SubtasksIncomplete);
+        }
+
+        // 確認後に同時に達成された場合は、リポジトリの条件によって更新されない
+        match self
+            .quest_repo
+            .set_complete_true(tx, &quest_id, &user_id)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(QuestServiceError::AlreadyCompleted),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub async fn delete(&self, token: Token, quest_id: QuestId) -> Result<(), QuestServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.quest_repo.delete(&quest_id, &user_id).await?;
+        Ok(())
+    }
+}
+
+fn build_quest(user_id: &UserId, quest_id: &QuestId, quest_payload: &QuestInput) -> Quest {
+    let subtasks: Vec<(String, bool)> = quest_payload
+        .subtasks
+        .iter()
+        .map(|s| (s.title.to_owned(), s.is_complete))
+        .collect();
+    QuestBuilder::new()
+        .user_id(user_id)
+        .quest_id(quest_id)
+        .title(&quest_payload.title)
+        .description(&quest_payload.description)
+        .due_date(&quest_payload.due_date)
+        .subtasks(&subtasks)
+        .build()
+}
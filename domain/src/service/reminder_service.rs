@@ -0,0 +1,78 @@
+use sqlx::{MySql, Transaction};
+
+use crate::{
+    entity::{
+        daily_mission_id::DailyMissionId,
+        reminder::{ReminderInput, ReminderSettings},
+        token::Token,
+    },
+    repository::reminder_repository::ReminderRepository,
+};
+
+use super::{
+    service_error::reminder_service_error::ReminderServiceError, token_service::TokenService,
+};
+
+/// リマインダーとまとめの時刻の設定のサービス実装
+/// 通知の送信はJobServiceが行う
+#[derive(Debug, Clone)]
+pub struct ReminderService<T, R>
+where
+    T: TokenService,
+    R: ReminderRepository,
+{
+    token_service: T,
+    reminder_repo: R,
+}
+
+impl<T, R> ReminderService<T, R>
+where
+    T: TokenService,
+    R: ReminderRepository,
+{
+    pub fn new(token_service: T, reminder_repo: R) -> Self {
+        Self {
+            token_service,
+            reminder_repo,
+        }
+    }
+
+    /// 通知の設定を取得する
+    pub async fn find_settings(
+        &self,
+        token: Token,
+    ) -> Result<ReminderSettings, ReminderServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let settings = self.reminder_repo.find_settings(&user_id).await?;
+        Ok(settings)
+    }
+
+    /// ミッションのリマインダーの時刻を変更する
+    pub async fn set_mission_reminder(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        mission_id: DailyMissionId,
+        input: ReminderInput,
+    ) -> Result<(), ReminderServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.reminder_repo
+            .set_mission_reminder(tx, &mission_id, &user_id, input.time)
+            .await?;
+        Ok(())
+    }
+
+    /// まとめの時刻を変更する
+    pub async fn set_summary_time(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        input: ReminderInput,
+    ) -> Result<(), ReminderServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.reminder_repo
+            .set_summary_time(tx, &user_id, input.time)
+            .await?;
+        Ok(())
+    }
+}
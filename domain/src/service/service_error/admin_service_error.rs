@@ -0,0 +1,32 @@
+use thiserror::Error;
+use validator::ValidationErrors;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::{level_curve_error::LevelCurveError, token_service_error::TokenServiceError};
+
+#[derive(Debug, Clone, Error)]
+pub enum AdminServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Permission denied")]
+    Forbidden,
+    #[error("Validation error: {0}")]
+    Validation(ValidationErrors),
+    #[error("Invalid level curve: {0}")]
+    InvalidLevelCurve(LevelCurveError),
+}
+
+impl From<TokenServiceError> for AdminServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for AdminServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::token_service_error::TokenServiceError;
+
+#[derive(Debug, Clone, Error)]
+pub enum CalendarServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+}
+
+impl From<TokenServiceError> for CalendarServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for CalendarServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
@@ -11,10 +11,20 @@ pub enum DailyMissionServiceError {
     AuthError(TokenServiceError),
     #[error("Repository error: {0}")]
     RepositoryError(RepositoryError),
-    #[error("Stored Daily Mission is full")]
-    OverCapacity,
+    #[error("Stored Daily Mission is full (limit: {0})")]
+    OverCapacity(u32),
+    #[error("Daily Mission is not due today")]
+    NotDue,
+    #[error("Daily Mission has no target quantity")]
+    NotQuantitative,
+    #[error("Daily Mission with a target quantity is completed by recording progress")]
+    ProgressRequired,
+    #[error("Daily Mission is already completed today")]
+    AlreadyCompleted,
     #[error("Validation error: {0}")]
     Validate(ValidationErrors),
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
     #[error("Unknown error: {0}")]
     UnknownError(String),
 }
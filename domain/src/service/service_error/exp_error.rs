@@ -2,7 +2,7 @@ use thiserror::Error;
 
 use crate::repository::repository_error::RepositoryError;
 
-use super::token_service_error::TokenServiceError;
+use super::{level_curve_error::LevelCurveError, token_service_error::TokenServiceError};
 
 #[derive(Debug, Clone, Error)]
 pub enum ExpServiceError {
@@ -10,8 +10,10 @@ pub enum ExpServiceError {
     AuthError(TokenServiceError),
     #[error("Repository error: {0}")]
     RepositoryError(RepositoryError),
-    #[error("Experience point is max: {0}")]
-    DetectedExpOverflow(String),
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+    #[error("Level curve error: {0}")]
+    LevelCurve(LevelCurveError),
 }
 
 impl From<TokenServiceError> for ExpServiceError {
@@ -25,3 +27,9 @@ impl From<RepositoryError> for ExpServiceError {
         Self::RepositoryError(value)
     }
 }
+
+impl From<LevelCurveError> for ExpServiceError {
+    fn from(value: LevelCurveError) -> Self {
+        Self::LevelCurve(value)
+    }
+}
@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::token_service_error::TokenServiceError;
+
+#[derive(Debug, Clone, Error)]
+pub enum FriendServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Cannot send a friend request to yourself")]
+    SelfRequest,
+    #[error("Already friends")]
+    AlreadyFriends,
+    #[error("Friend request already sent")]
+    AlreadyRequested,
+    /// 相手がフレンド申請を受け付けていない、またはどちらかがブロックしている
+    /// ブロックされていることが分からないよう、区別せずに返す
+    #[error("The user does not accept friend requests")]
+    RequestRejected,
+}
+
+impl From<TokenServiceError> for FriendServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for FriendServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
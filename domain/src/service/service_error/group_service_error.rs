@@ -0,0 +1,45 @@
+use thiserror::Error;
+use validator::ValidationErrors;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::token_service_error::TokenServiceError;
+
+#[derive(Debug, Clone, Error)]
+pub enum GroupServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Validation error: {0}")]
+    Validate(ValidationErrors),
+    /// グループのメンバーでない、または役割の権限が足りない
+    #[error("Not permitted in the group")]
+    Forbidden,
+    #[error("The user is already a member of the group")]
+    AlreadyMember,
+    #[error("The group is full (limit: {0})")]
+    GroupFull(u32),
+    #[error("The number of group missions is fulled (limit: {0})")]
+    MissionOverCapacity(u32),
+    #[error("The group mission is already completed today")]
+    AlreadyCompleted,
+    /// オーナーはグループを削除する以外に抜けることができない
+    #[error("The owner cannot leave the group")]
+    OwnerCannotLeave,
+    /// オーナーへの変更やオーナーの役割の変更はできない
+    #[error("Invalid role change")]
+    InvalidRole,
+}
+
+impl From<TokenServiceError> for GroupServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for GroupServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::notification_error::NotificationError;
+
+#[derive(Debug, Clone, Error)]
+pub enum JobServiceError {
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Notification error: {0}")]
+    NotificationError(NotificationError),
+}
+
+impl From<RepositoryError> for JobServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
+
+impl From<NotificationError> for JobServiceError {
+    fn from(value: NotificationError) -> Self {
+        Self::NotificationError(value)
+    }
+}
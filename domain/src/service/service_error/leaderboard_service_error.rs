@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::{level_curve_error::LevelCurveError, token_service_error::TokenServiceError};
+
+#[derive(Debug, Clone, Error)]
+pub enum LeaderboardServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+    #[error("Level curve error: {0}")]
+    LevelCurve(LevelCurveError),
+}
+
+impl From<TokenServiceError> for LeaderboardServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for LeaderboardServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
+
+impl From<LevelCurveError> for LeaderboardServiceError {
+    fn from(value: LevelCurveError) -> Self {
+        Self::LevelCurve(value)
+    }
+}
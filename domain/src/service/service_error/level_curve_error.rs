@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// レベルカーブの定義が不正な場合のエラー
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LevelCurveError {
+    #[error("Failed to load level curve: {0}")]
+    Load(String),
+    #[error("Level curve is not loaded")]
+    NotLoaded,
+    #[error("Level curve has no levels")]
+    Empty,
+    #[error("Level curve has too many levels: {0} (max {1})")]
+    TooManyLevels(usize, u32),
+    #[error("Levels must start at 1 and be consecutive: expected level {expected}, found {found}")]
+    NonConsecutiveLevel { expected: u32, found: u32 },
+    #[error("Required exp must be positive and strictly increasing: level {0}")]
+    NotIncreasing(u32),
+    #[error("Invalid formula parameter: {0}")]
+    InvalidParameter(String),
+}
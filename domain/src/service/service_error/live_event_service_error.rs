@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+use super::token_service_error::TokenServiceError;
+
+#[derive(Debug, Clone, Error)]
+pub enum LiveEventServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+}
+
+impl From<TokenServiceError> for LiveEventServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+/// 取り込み/書き出しのファイルの変換のエラー
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MissionCodecError {
+    /// ファイル全体を読み込めない場合(行ごとのエラーは取り込みの結果として返す)
+    #[error("Malformed file: {0}")]
+    Malformed(String),
+    #[error("Failed to encode: {0}")]
+    Encode(String),
+}
@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::{mission_codec_error::MissionCodecError, token_service_error::TokenServiceError};
+
+#[derive(Debug, Clone, Error)]
+pub enum MissionTransferServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Codec error: {0}")]
+    CodecError(MissionCodecError),
+    #[error("Stored Daily Mission is full (limit: {0})")]
+    OverCapacity(u32),
+    #[error("Too many rows to import (limit: {0})")]
+    TooManyRows(usize),
+}
+
+impl From<TokenServiceError> for MissionTransferServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for MissionTransferServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
+
+impl From<MissionCodecError> for MissionTransferServiceError {
+    fn from(value: MissionCodecError) -> Self {
+        Self::CodecError(value)
+    }
+}
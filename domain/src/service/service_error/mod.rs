@@ -1,6 +1,28 @@
+pub mod achievement_service_error;
+pub mod admin_service_error;
 pub mod auth_service_error;
+pub mod calendar_service_error;
 pub mod daily_mission_service_error;
 pub mod exp_error;
+pub mod friend_service_error;
+pub mod group_service_error;
 pub mod hash_error;
+pub mod job_service_error;
+pub mod leaderboard_service_error;
+pub mod level_curve_error;
+pub mod live_event_service_error;
+pub mod mission_codec_error;
+pub mod mission_transfer_service_error;
+pub mod notification_error;
+pub mod push_error;
+pub mod push_service_error;
+pub mod quest_service_error;
+pub mod reminder_service_error;
+pub mod stats_service_error;
+pub mod streak_service_error;
+pub mod subscription_error;
+pub mod template_service_error;
 pub mod token_service_error;
 pub mod user_service_error;
+pub mod webhook_error;
+pub mod webhook_service_error;
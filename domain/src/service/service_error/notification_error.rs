@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+/// 通知の送信のエラー
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum NotificationError {
+    /// 一時的な失敗の場合も含むため、ジョブは再試行する
+    #[error("Failed to deliver notification: {0}")]
+    Delivery(String),
+}
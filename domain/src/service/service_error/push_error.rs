@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+/// Web Pushの送信のエラー
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PushError {
+    /// 購読が解除された、または期限が切れた(プッシュサービスが404/410を返した)
+    #[error("The push subscription has expired")]
+    Gone,
+    /// 購読の鍵が不正で暗号化できない
+    #[error("Invalid push subscription: {0}")]
+    InvalidSubscription(String),
+    /// ネットワークのエラーやプッシュサービスの一時的なエラー
+    #[error("Failed to send push message: {0}")]
+    Delivery(String),
+}
@@ -0,0 +1,36 @@
+use thiserror::Error;
+use validator::ValidationErrors;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::{push_error::PushError, token_service_error::TokenServiceError};
+
+#[derive(Debug, Clone, Error)]
+pub enum PushServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Validation error: {0}")]
+    Validate(ValidationErrors),
+    #[error("Push error: {0}")]
+    PushError(PushError),
+}
+
+impl From<TokenServiceError> for PushServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for PushServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
+
+impl From<PushError> for PushServiceError {
+    fn from(value: PushError) -> Self {
+        Self::PushError(value)
+    }
+}
@@ -0,0 +1,32 @@
+use thiserror::Error;
+use validator::ValidationErrors;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::token_service_error::TokenServiceError;
+
+#[derive(Debug, Clone, Error)]
+pub enum QuestServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Quest is already completed")]
+    AlreadyCompleted,
+    #[error("Quest has incomplete subtasks")]
+    SubtasksIncomplete,
+    #[error("Validation error: {0}")]
+    Validate(ValidationErrors),
+}
+
+impl From<TokenServiceError> for QuestServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for QuestServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+use validator::ValidationErrors;
+
+use crate::repository::repository_error::RepositoryError;
+
+use super::token_service_error::TokenServiceError;
+
+#[derive(Debug, Clone, Error)]
+pub enum StreakServiceError {
+    #[error("Authentication failed: {0}")]
+    AuthError(TokenServiceError),
+    #[error("Repository error: {0}")]
+    RepositoryError(RepositoryError),
+    #[error("Validation error: {0}")]
+    Validation(ValidationErrors),
+    #[error("Invalid vacation: {0}")]
+    InvalidVacation(String),
+}
+
+impl From<TokenServiceError> for StreakServiceError {
+    fn from(value: TokenServiceError) -> Self {
+        Self::AuthError(value)
+    }
+}
+
+impl From<RepositoryError> for StreakServiceError {
+    fn from(value: RepositoryError) -> Self {
+        Self::RepositoryError(value)
+    }
+}
@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+/// ドメインイベントの購読のエラー
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SubscriptionError {
+    /// 購読者の処理が遅れ、古いイベントが破棄された(破棄された数)
+    #[error("{0} events were dropped")]
+    Lagged(u64),
+    /// 配信元が終了し、これ以上イベントは届かない
+    #[error("The event stream was closed")]
+    Closed,
+}
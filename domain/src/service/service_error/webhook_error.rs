@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+/// Webhookの送信のエラー
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum WebhookError {
+    /// 受信した側が2xx以外を返した
+    #[error("The endpoint responded with {status_code}: {body}")]
+    Status { status_code: u16, body: String },
+    /// 接続できなかった、またはタイムアウトした
+    #[error("Failed to deliver webhook: {0}")]
+    Delivery(String),
+}
+
+impl WebhookError {
+    /// 受信した側が返したステータスコード
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Status { status_code, .. } => Some(*status_code),
+            Self::Delivery(_) => None,
+        }
+    }
+}
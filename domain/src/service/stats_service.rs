@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    entity::{
+        stats::{
+            CompletionCount, DateRange, Heatmap, HeatmapQuery, MissionRate, MissionRates,
+            PeriodTotals, StatsMission, StatsQuery, StatsSummary, WeekdayStats, WeeklyExp,
+            WeeklyExpQuery,
+        },
+        token::Token,
+        user_id::UserId,
+    },
+    repository::stats_repository::StatsRepository,
+};
+
+use super::{service_error::stats_service_error::StatsServiceError, token_service::TokenService};
+
+/// 完了の記録と経験値の集計から統計を求めるサービス実装
+/// 期間はユーザーのタイムゾーンにおける今日で終わる日数で指定する
+#[derive(Debug, Clone)]
+pub struct StatsService<T, S>
+where
+    T: TokenService,
+    S: StatsRepository,
+{
+    token_service: T,
+    stats_repo: S,
+}
+
+impl<T, S> StatsService<T, S>
+where
+    T: TokenService,
+    S: StatsRepository,
+{
+    pub fn new(token_service: T, stats_repo: S) -> Self {
+        Self {
+            token_service,
+            stats_repo,
+        }
+    }
+
+    /// 日ごとの完了の数と獲得した経験値(カレンダーのヒートマップ用)
+    pub async fn heatmap(
+        &self,
+        token: Token,
+        query: HeatmapQuery,
+    ) -> Result<Heatmap, StatsServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let days = query.period().map_err(StatsServiceError::InvalidQuery)?;
+        let range = DateRange::ending(self.stats_repo.today(&user_id).await?, days);
+        let stats = self
+            .stats_repo
+            .find_daily_stats(&user_id, range.from, range.to)
+            .await?;
+        Ok(Heatmap::new(range, stats))
+    }
+
+    /// ミッションごとの完了率と、直前の同じ長さの期間との比較
+    pub async fn mission_rates(
+        &self,
+        token: Token,
+        query: StatsQuery,
+    ) -> Result<MissionRates, StatsServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let days = query.period().map_err(StatsServiceError::InvalidQuery)?;
+        let current = DateRange::ending(self.stats_repo.today(&user_id).await?, days);
+        let previous = current.previous();
+        let missions = self
+            .completion_counts(&user_id, current, previous)
+            .await?
+            .iter()
+            .map(|(mission, current, previous)| MissionRate::new(mission, *current, *previous))
+            .collect();
+        Ok(MissionRates {
+            current,
+            previous,
+            missions,
+        })
+    }
+
+    /// 曜日ごとの完了の数と、最も完了している曜日
+    pub async fn weekdays(
+        &self,
+        token: Token,
+        query: StatsQuery,
+    ) -> Result<WeekdayStats, StatsServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let days = query.period().map_err(StatsServiceError::InvalidQuery)?;
+        let range = DateRange::ending(self.stats_repo.today(&user_id).await?, days);
+        let stats = self
+            .stats_repo
+            .find_daily_stats(&user_id, range.from, range.to)
+            .await?;
+        Ok(WeekdayStats::new(range, &stats))
+    }
+
+    /// 週ごとに獲得した経験値(今週を含む古い順)
+    pub async fn weekly_exp(
+        &self,
+        token: Token,
+        query: WeeklyExpQuery,
+    ) -> Result<Vec<WeeklyExp>, StatsServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let weeks = query.period().map_err(StatsServiceError::InvalidQuery)?;
+        let range = WeeklyExp::range(self.stats_repo.today(&user_id).await?, weeks);
+        let stats = self
+            .stats_repo
+            .find_daily_stats(&user_id, range.from, range.to)
+            .await?;
+        Ok(WeeklyExp::group(range, &stats))
+    }
+
+    /// 期間の完了の数、獲得した経験値、完了率の合計と、直前の同じ長さの期間との比較
+    pub async fn summary(
+        &self,
+        token: Token,
+        query: StatsQuery,
+    ) -> Result<StatsSummary, StatsServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let days = query.period().map_err(StatsServiceError::InvalidQuery)?;
+        let current = DateRange::ending(self.stats_repo.today(&user_id).await?, days);
+        let previous = current.previous();
+        let stats = self
+            .stats_repo
+            .find_daily_stats(&user_id, previous.from, current.to)
+            .await?;
+        let (current_count, previous_count) = self
+            .completion_counts(&user_id, current, previous)
+            .await?
+            .into_iter()
+            .fold(
+                (CompletionCount::default(), CompletionCount::default()),
+                |(c, p), (_, current, previous)| (c.merge(current), p.merge(previous)),
+            );
+        Ok(StatsSummary::new(
+            PeriodTotals::new(current, &stats, current_count),
+            PeriodTotals::new(previous, &stats, previous_count),
+        ))
+    }
+
+    // 現在のミッションごとに、2つの期間の完了の数を数える
+    // 完了の記録と凍結された日は2つの期間の分をまとめて取得する
+    async fn completion_counts(
+        &self,
+        user_id: &UserId,
+        current: DateRange,
+        previous: DateRange,
+    ) -> Result<Vec<(StatsMission, CompletionCount, CompletionCount)>, StatsServiceError> {
+        let missions = self.stats_repo.find_missions(user_id).await?;
+        let mut completed: HashMap<_, HashSet<_>> = HashMap::new();
+        for completion in self
+            .stats_repo
+            .find_completion_dates(user_id, previous.from, current.to)
+            .await?
+        {
+            completed
+                .entry(completion.mission_id)
+                .or_default()
+                .insert(completion.date);
+        }
+        let frozen: HashSet<_> = self
+            .stats_repo
+            .find_frozen_dates(user_id, previous.from, current.to)
+            .await?
+            .into_iter()
+            .collect();
+
+        let none = HashSet::new();
+        Ok(missions
+            .into_iter()
+            .map(|mission| {
+                let dates = completed.get(&mission.mission_id).unwrap_or(&none);
+                let current = CompletionCount::count(&mission, current, dates, &frozen);
+                let previous = CompletionCount::count(&mission, previous, dates, &frozen);
+                (mission, current, previous)
+            })
+            .collect())
+    }
+}
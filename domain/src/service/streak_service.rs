@@ -0,0 +1,354 @@
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use sqlx::{MySql, Transaction};
+use validator::Validate;
+
+use crate::{
+    entity::{
+        all_clear_bonus::AllClearBonus,
+        mission_completion::MissionCompletion,
+        mission_schedule::MissionSchedule,
+        streak::{Streak, StreakStats},
+        token::Token,
+        user_id::UserId,
+        vacation::{StreakFreezes, Vacation, VacationInput},
+    },
+    repository::streak_repository::StreakRepository,
+};
+
+use super::{service_error::streak_service_error::StreakServiceError, token_service::TokenService};
+
+/// すべてのミッションを完了した日の連続記録がこの日数に達するごとにストリークフリーズを1つ付与する
+pub const FREEZE_EARN_INTERVAL: u32 = 7;
+/// 所持できるストリークフリーズの最大数
+pub const MAX_STREAK_FREEZES: u32 = 3;
+
+/// 連続達成記録(ストリーク)のサービス
+/// 完了時に増分で更新しておくことで、読み込みはO(1)で行える
+#[derive(Debug, Clone)]
+pub struct StreakService<T, S>
+where
+    T: TokenService,
+    S: StreakRepository,
+{
+    token_service: T,
+    streak_repo: S,
+    // その日に実施するミッションをすべて完了した時のボーナス経験値(0以下の場合は付与しない)
+    all_clear_bonus: i64,
+}
+
+impl<T, S> StreakService<T, S>
+where
+    T: TokenService,
+    S: StreakRepository,
+{
+    pub fn new(token_service: T, streak_repo: S, all_clear_bonus: i64) -> Self {
+        Self {
+            token_service,
+            streak_repo,
+            all_clear_bonus,
+        }
+    }
+
+    /// 同じユーザーの完了処理を直列化するため、ユーザーの行をロックする
+    /// 完了・進捗・取り消しのトランザクションの最初に呼び出すことで、
+    /// 同時に最後のミッションを完了してもボーナスの判定が食い違わないようにする
+    pub async fn lock_user(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+    ) -> Result<(), StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.streak_repo.lock_user(tx, &user_id).await?;
+        Ok(())
+    }
+
+    /// その日に実施するミッションをすべて完了していて、まだ全完了ボーナスを付与していなければ付与するボーナスを返す
+    /// 経験値の加算は呼び出し側で同じトランザクションで行い、実際に加算した量をsave_all_clear_bonus()で記録する
+    pub async fn find_all_clear_bonus(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        date: NaiveDate,
+    ) -> Result<Option<AllClearBonus>, StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if self.all_clear_bonus <= 0
+            || !self.streak_repo.is_all_complete(tx, &user_id, date).await?
+            || self
+                .streak_repo
+                .exists_all_clear_bonus(tx, &user_id, date)
+                .await?
+        {
+            return Ok(None);
+        }
+        Ok(Some(AllClearBonus {
+            date,
+            exp_awarded: self.all_clear_bonus,
+        }))
+    }
+
+    /// 付与した全完了ボーナスを記録し、1日1回だけ付与されるようにする
+    /// 取り消しで戻す量と一致させるため、exp_awardedには実際に加算した経験値を指定する
+    pub async fn save_all_clear_bonus(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        bonus: &AllClearBonus,
+    ) -> Result<(), StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.streak_repo
+            .save_all_clear_bonus(tx, &user_id, bonus)
+            .await?;
+        Ok(())
+    }
+
+    /// 完了の取り消しで全完了でなくなった場合は、その日に付与した全完了ボーナスを取り消す
+    /// 取り消した場合のみボーナスを返す。経験値の減算は呼び出し側で同じトランザクションで行う
+    pub async fn revert_all_clear_bonus(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        date: NaiveDate,
+    ) -> Result<Option<AllClearBonus>, StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if self.streak_repo.is_all_complete(tx, &user_id, date).await? {
+            return Ok(None);
+        }
+        let bonus = self
+            .streak_repo
+            .delete_all_clear_bonus(tx, &user_id, date)
+            .await?;
+        Ok(bonus)
+    }
+
+    /// ミッションの完了を連続記録に反映し、更新後のミッションの連続記録を返す
+    /// DailyMissionService::set_complete_true()とともにトランザクションで処理するため、Transaction型を引数に取っている
+    pub async fn record_completion(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        completion: &MissionCompletion,
+    ) -> Result<Streak, StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let date = completion.date;
+
+        // 1. ミッションごとの連続記録
+        // スケジュール上の実施日でない日は達成しなくても途切れない
+        // 記録が無い場合(機能追加前のミッション)は完了履歴から再計算する
+        let schedule = self
+            .streak_repo
+            .find_schedule(tx, &completion.mission_id)
+            .await?;
+        let streak = match self
+            .streak_repo
+            .find_mission_streak(tx, &completion.mission_id)
+            .await?
+        {
+            Some(mut streak) => {
+                let mut frozen = self
+                    .frozen_dates(tx, &user_id, streak.last_date, date)
+                    .await?;
+                // 達成しなかった日をすべてストリークフリーズで補える場合は消費して途切れないようにする
+                let missed: Vec<_> = {
+                    let is_frozen = |d| frozen.contains(&d);
+                    let skips =
+                        schedule.skips(streak.last_date, date, streak.week_count, &is_frozen);
+                    streak
+                        .missed_days(date, &skips)
+                        .take(MAX_STREAK_FREEZES as usize + 1)
+                        .collect()
+                };
+                // 週の回数が足りない場合は、補っても満たせなければ消費しない
+                let is_saved = !streak.last_date.is_some_and(|last| {
+                    schedule.is_quota_missed(last, date, streak.week_count, |d| {
+                        frozen.contains(&d) || missed.contains(&d)
+                    })
+                });
+                if !missed.is_empty()
+                    && is_saved
+                    && missed.len() <= self.streak_repo.find_freezes(tx, &user_id).await? as usize
+                {
+                    self.streak_repo
+                        .consume_freezes(tx, &user_id, &missed)
+                        .await?;
+                    frozen.extend(missed);
+                }
+                streak.advance_on_schedule(date, &schedule, |d| frozen.contains(&d));
+                streak
+            }
+            None => {
+                let dates = self
+                    .streak_repo
+                    .find_completed_dates(tx, &completion.mission_id)
+                    .await?;
+                let frozen = self.frozen_dates(tx, &user_id, None, date).await?;
+                Streak::from_dates_on_schedule(dates, &schedule, |d| frozen.contains(&d))
+            }
+        };
+        self.streak_repo
+            .save_mission_streak(tx, &completion.mission_id, &streak)
+            .await?;
+
+        // 2. 実施するミッションをすべて完了した日の連続記録
+        // どのミッションも実施日でない日は飛ばして評価する
+        // ミッションごとの記録で消費したストリークフリーズもここで反映される
+        if self.streak_repo.is_all_complete(tx, &user_id, date).await? {
+            let schedules = self.streak_repo.find_schedules(tx, &user_id).await?;
+            let (streak, advanced) = match self.streak_repo.find_user_streak(tx, &user_id).await? {
+                Some(mut streak) => {
+                    let before = streak.last_date;
+                    let frozen = self.frozen_dates(tx, &user_id, before, date).await?;
+                    streak.advance(date, |d| {
+                        frozen.contains(&d) || MissionSchedule::is_day_off(&schedules, d)
+                    });
+                    (streak, streak.last_date != before)
+                }
+                None => {
+                    let dates = self.streak_repo.find_all_clear_dates(tx, &user_id).await?;
+                    let frozen = self.frozen_dates(tx, &user_id, None, date).await?;
+                    let streak = Streak::from_dates(dates, |d| {
+                        frozen.contains(&d) || MissionSchedule::is_day_off(&schedules, d)
+                    });
+                    (streak, false)
+                }
+            };
+            self.streak_repo
+                .save_user_streak(tx, &user_id, &streak)
+                .await?;
+
+            // 3. 連続記録がFREEZE_EARN_INTERVAL日に達するごとにストリークフリーズを付与する
+            if advanced && streak.current % FREEZE_EARN_INTERVAL == 0 {
+                self.streak_repo
+                    .add_freeze(tx, &user_id, date, MAX_STREAK_FREEZES)
+                    .await?;
+            }
+        }
+        Ok(streak)
+    }
+
+    /// ミッションの完了の取り消しを連続記録に反映する
+    /// DailyMissionService::undo_complete()で完了記録を削除した後に、同じトランザクションで呼び出す
+    /// 消費したストリークフリーズは戻らない
+    pub async fn revert_completion(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        completion: &MissionCompletion,
+    ) -> Result<(), StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let date = completion.date;
+
+        // 1. ミッションごとの連続記録は残りの完了履歴から再計算する
+        let schedule = self
+            .streak_repo
+            .find_schedule(tx, &completion.mission_id)
+            .await?;
+        let dates = self
+            .streak_repo
+            .find_completed_dates(tx, &completion.mission_id)
+            .await?;
+        let frozen = self.frozen_dates(tx, &user_id, None, date).await?;
+        let streak = Streak::from_dates_on_schedule(dates, &schedule, |d| frozen.contains(&d));
+        self.streak_repo
+            .save_mission_streak(tx, &completion.mission_id, &streak)
+            .await?;
+
+        // 2. すべてのミッションを完了した日の連続記録は、取り消した日を含む場合のみ再計算する
+        let Some(before) = self.streak_repo.find_user_streak(tx, &user_id).await? else {
+            return Ok(());
+        };
+        if before.last_date != Some(date) {
+            return Ok(());
+        }
+        let schedules = self.streak_repo.find_schedules(tx, &user_id).await?;
+        let dates = self.streak_repo.find_all_clear_dates(tx, &user_id).await?;
+        let streak = Streak::from_dates(dates, |d| {
+            frozen.contains(&d) || MissionSchedule::is_day_off(&schedules, d)
+        });
+        self.streak_repo
+            .save_user_streak(tx, &user_id, &streak)
+            .await?;
+
+        // 3. 取り消した日にストリークフリーズを付与していた場合は、そのフリーズだけを取り除く
+        // 上限に達していて付与しなかった場合と、すでに消費した場合は何もしない
+        self.streak_repo.remove_freeze(tx, &user_id, date).await?;
+        Ok(())
+    }
+
+    pub async fn find_stats(&self, token: Token) -> Result<StreakStats, StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let stats = self.streak_repo.find_stats(&user_id).await?;
+        Ok(stats)
+    }
+
+    /// 休暇モードを設定する
+    /// 過去の日付を含む期間は設定できない
+    pub async fn create_vacation(
+        &self,
+        token: Token,
+        input: VacationInput,
+    ) -> Result<Vacation, StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        input.validate().map_err(StreakServiceError::Validation)?;
+
+        let today = self.streak_repo.find_current_date(&user_id).await?;
+        if input.start_date < today {
+            return Err(StreakServiceError::InvalidVacation(format!(
+                "startDate must be on or after {}",
+                today
+            )));
+        }
+        let vacation = self.streak_repo.create_vacation(&user_id, &input).await?;
+        Ok(vacation)
+    }
+
+    /// 今日以降の休暇モードの期間を取得する
+    pub async fn find_vacations(&self, token: Token) -> Result<Vec<Vacation>, StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let today = self.streak_repo.find_current_date(&user_id).await?;
+        let vacations = self.streak_repo.find_vacations(&user_id, today).await?;
+        Ok(vacations)
+    }
+
+    /// 休暇モードを取り消す
+    /// 期間中の場合は昨日までの分は残る
+    pub async fn cancel_vacation(
+        &self,
+        token: Token,
+        vacation_id: i64,
+    ) -> Result<(), StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let today = self.streak_repo.find_current_date(&user_id).await?;
+        self.streak_repo
+            .cancel_vacation(&user_id, vacation_id, today)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn find_freezes(&self, token: Token) -> Result<StreakFreezes, StreakServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let available = self.streak_repo.find_available_freezes(&user_id).await?;
+        Ok(StreakFreezes {
+            available,
+            max: MAX_STREAK_FREEZES,
+        })
+    }
+
+    async fn frozen_dates(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        user_id: &UserId,
+        from: Option<NaiveDate>,
+        to: NaiveDate,
+    ) -> Result<HashSet<NaiveDate>, StreakServiceError> {
+        let frozen = self
+            .streak_repo
+            .find_frozen_days(tx, user_id, from, Some(to))
+            .await?
+            .into_iter()
+            .map(|d| d.date)
+            .collect();
+        Ok(frozen)
+    }
+}
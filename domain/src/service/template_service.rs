@@ -0,0 +1,212 @@
+use chrono::Utc;
+use validator::Validate;
+
+use crate::{
+    entity::{
+        daily_mission_id::DailyMissionId,
+        daily_mission_input::DailyMissionInput,
+        mission_template::{
+            normalize_share_code, share_code_from_uuid, MissionTemplate, SharedTemplate,
+        },
+        mission_template_id::MissionTemplateId,
+        token::Token,
+        user_id::UserId,
+    },
+    repository::{
+        admin_repository::AdminRepository, repository_error::RepositoryError,
+        template_repository::TemplateRepository,
+    },
+};
+
+use super::{
+    service_error::template_service_error::TemplateServiceError, token_service::TokenService,
+    uuid_service::UUIDService,
+};
+
+/// ミッションのテンプレートのサービス実装
+/// テンプレートからのミッションの作成はDailyMissionService::createで行うため、
+/// ここではテンプレートをDailyMissionInputに変換して返す
+#[derive(Debug, Clone)]
+pub struct TemplateService<T, U, M, A>
+where
+    T: TokenService,
+    U: UUIDService,
+    M: TemplateRepository,
+    A: AdminRepository,
+{
+    token_service: T,
+    uuid_service: U,
+    template_repo: M,
+    admin_repo: A,
+}
+
+impl<T, U, M, A> TemplateService<T, U, M, A>
+where
+    T: TokenService,
+    U: UUIDService,
+    M: TemplateRepository,
+    A: AdminRepository,
+{
+    pub fn new(token_service: T, uuid_service: U, template_repo: M, admin_repo: A) -> Self {
+        Self {
+            token_service,
+            uuid_service,
+            template_repo,
+            admin_repo,
+        }
+    }
+
+    /// カタログのテンプレートを取得する
+    pub async fn find_catalog(
+        &self,
+        token: Token,
+    ) -> Result<Vec<MissionTemplate>, TemplateServiceError> {
+        self.token_service.verify(token)?;
+        let templates = self.template_repo.find_curated().await?;
+        Ok(templates)
+    }
+
+    /// 共有コードからテンプレートを取得する
+    pub async fn find_shared(
+        &self,
+        token: Token,
+        share_code: String,
+    ) -> Result<MissionTemplate, TemplateServiceError> {
+        self.token_service.verify(token)?;
+        let template = self
+            .template_repo
+            .find_by_share_code(&normalize_share_code(&share_code))
+            .await?;
+        Ok(template)
+    }
+
+    /// 自分が公開したテンプレートを取得する
+    pub async fn find_mine(
+        &self,
+        token: Token,
+    ) -> Result<Vec<MissionTemplate>, TemplateServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let templates = self.template_repo.find_by_author(&user_id).await?;
+        Ok(templates)
+    }
+
+    /// カタログのテンプレートからデイリーミッションの作成に使うPayloadを作る
+    pub async fn instantiate_curated(
+        &self,
+        token: Token,
+        template_id: MissionTemplateId,
+    ) -> Result<DailyMissionInput, TemplateServiceError> {
+        self.token_service.verify(token)?;
+        let template = self.template_repo.find_curated_by_id(&template_id).await?;
+        Ok(template.to_input())
+    }
+
+    /// 共有コードのテンプレートからデイリーミッションの作成に使うPayloadを作る
+    pub async fn instantiate_shared(
+        &self,
+        token: Token,
+        share_code: String,
+    ) -> Result<DailyMissionInput, TemplateServiceError> {
+        let template = self.find_shared(token, share_code).await?;
+        Ok(template.to_input())
+    }
+
+    /// 自分のデイリーミッションを共有コード付きのテンプレートとして公開する
+    /// 公開した時点の内容を保存するため、元のミッションを変更してもテンプレートは変わらない
+    pub async fn share(
+        &self,
+        token: Token,
+        mission_id: DailyMissionId,
+    ) -> Result<SharedTemplate, TemplateServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let template_id = MissionTemplateId(self.uuid_service.generate());
+        let share_code = share_code_from_uuid(&self.uuid_service.generate());
+        self.template_repo
+            .share_mission(&user_id, &mission_id, &template_id, &share_code)
+            .await?;
+        Ok(SharedTemplate {
+            template_id,
+            share_code,
+        })
+    }
+
+    /// 自分が公開したテンプレートの公開をやめる
+    pub async fn unshare(
+        &self,
+        token: Token,
+        share_code: String,
+    ) -> Result<(), TemplateServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self
+            .template_repo
+            .delete_shared(&user_id, &normalize_share_code(&share_code))
+            .await?
+        {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    /// カタログのテンプレートを作成する(管理者のみ)
+    pub async fn create_curated(
+        &self,
+        token: Token,
+        input: DailyMissionInput,
+    ) -> Result<MissionTemplateId, TemplateServiceError> {
+        self.verify_admin(token).await?;
+        input.validate().map_err(TemplateServiceError::Validate)?;
+        let template = build_template(MissionTemplateId(self.uuid_service.generate()), input);
+        self.template_repo.create_curated(&template).await?;
+        Ok(template.template_id)
+    }
+
+    /// カタログのテンプレートを更新する(管理者のみ)
+    pub async fn update_curated(
+        &self,
+        token: Token,
+        template_id: MissionTemplateId,
+        input: DailyMissionInput,
+    ) -> Result<(), TemplateServiceError> {
+        self.verify_admin(token).await?;
+        input.validate().map_err(TemplateServiceError::Validate)?;
+        let template = build_template(template_id, input);
+        self.template_repo.update_curated(&template).await?;
+        Ok(())
+    }
+
+    /// カタログのテンプレートを削除する(管理者のみ)
+    pub async fn delete_curated(
+        &self,
+        token: Token,
+        template_id: MissionTemplateId,
+    ) -> Result<(), TemplateServiceError> {
+        self.verify_admin(token).await?;
+        if !self.template_repo.delete_curated(&template_id).await? {
+            return Err(RepositoryError::NotFound.into());
+        }
+        Ok(())
+    }
+
+    async fn verify_admin(&self, token: Token) -> Result<UserId, TemplateServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        if !self.admin_repo.is_admin(&user_id).await? {
+            return Err(TemplateServiceError::Forbidden);
+        }
+        Ok(user_id)
+    }
+}
+
+fn build_template(template_id: MissionTemplateId, input: DailyMissionInput) -> MissionTemplate {
+    MissionTemplate {
+        template_id,
+        title: input.title,
+        description: input.description,
+        schedule: input.schedule,
+        target_quantity: input.target_quantity,
+        unit: input.unit,
+        difficulty: input.difficulty,
+        exp_weight: input.exp_weight,
+        share_code: None,
+        created_at: Utc::now().naive_utc(),
+    }
+}
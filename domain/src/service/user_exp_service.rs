@@ -1,7 +1,18 @@
+use std::sync::Arc;
+
+use chrono::Utc;
 use sqlx::{MySql, Transaction};
 
 use crate::{
-    entity::{token::Token, user_id::UserId, user_level::UserLevel},
+    entity::{
+        domain_event::{DomainEvent, ExpChanged, LevelUp},
+        exp_reward::ExpReward,
+        exp_transaction::{ExpHistory, ExpHistoryPage, ExpHistoryQuery, NewExpTransaction},
+        level_curve::LevelCurve,
+        token::Token,
+        user_id::UserId,
+        user_level::UserLevel,
+    },
     repository::user_exp_repository::UserExpRepository,
 };
 
@@ -55,21 +66,111 @@ where
 
         // ここでレベルに変換
         // LevelConvertの実装が担っている
-        let exp_with_level = UserLevel::new(exp, &self.level_converter);
+        let exp_with_level = UserLevel::new(exp, &self.level_converter)?;
         Ok(exp_with_level)
     }
 
     // ユーザーの経験値を追加する(delta値)
+    // 増減は台帳に追記され、user_expの経験値にも同じトランザクションで反映される
+    // 上限(レベルカーブの最大レベルの必要経験値)を超える分は切り捨てられ、すでに上限に達している場合は0になる
+    // 戻り値には増減前後のレベルが含まれる
     pub async fn add_experience<'a>(
         &'a self,
         tx: &'a mut Transaction<'_, MySql>,
         token: Token,
-        additional_exp: i64,
-    ) -> Result<(), ExpServiceError> {
+        transaction: NewExpTransaction,
+    ) -> Result<ExpReward, ExpServiceError> {
         let user_id = self.token_service.verify(token)?;
-        // TODO: ユーザーが持つ経験値を取得しオーバーフローしないか検証する
-        //       経験値が最大であったらエラーを返す
-        self.exp_repo.add_exp(tx, &user_id, additional_exp).await?;
-        Ok(())
+        self.add_experience_to(tx, &user_id, transaction).await
+    }
+
+    // 指定したユーザーの経験値を追加する(delta値)
+    // グループミッションのボーナスのように、認証済みのユーザーの操作で他のユーザーに付与する場合に使う
+    pub async fn add_experience_to<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        transaction: NewExpTransaction,
+    ) -> Result<ExpReward, ExpServiceError> {
+        let max_exp = self.level_converter.curve()?.max_exp();
+        let change = self
+            .exp_repo
+            .add_transaction(tx, user_id, &transaction, max_exp)
+            .await?;
+        Ok(ExpReward::new(&change, &self.level_converter)?)
+    }
+
+    // 経験値の増減のExpChangedイベントと、レベルが上がっていた場合はLevelUpイベントを作る
+    // EventOutboxServiceで配信を記録するため、経験値の追加と同じトランザクションでコミットの前に呼び出す
+    pub fn reward_events(
+        &self,
+        token: Token,
+        reward: &ExpReward,
+    ) -> Result<Vec<DomainEvent>, ExpServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        Ok(self.reward_events_of(user_id, reward))
+    }
+
+    // 指定したユーザーの経験値の増減とレベルアップのイベントを作る
+    pub fn reward_events_of(&self, user_id: UserId, reward: &ExpReward) -> Vec<DomainEvent> {
+        let occurred_at = Utc::now().naive_utc();
+        let mut events = Vec::new();
+        if reward.exp_gained != 0 {
+            events.push(DomainEvent::ExpChanged(ExpChanged {
+                user_id: user_id.clone(),
+                amount: reward.exp_gained,
+                total_exp: reward.total_exp,
+                level: reward.new_level,
+                occurred_at,
+            }));
+        }
+        if reward.leveled_up {
+            events.push(DomainEvent::LevelUp(LevelUp {
+                user_id,
+                previous_level: reward.previous_level,
+                new_level: reward.new_level,
+                total_exp: reward.total_exp,
+                occurred_at,
+            }));
+        }
+        events
+    }
+
+    // 現在のレベルカーブを取得する(クライアントに公開するためトークンは不要)
+    pub fn level_curve(&self) -> Result<Arc<LevelCurve>, ExpServiceError> {
+        Ok(self.level_converter.curve()?)
+    }
+
+    // 経験値の増減の履歴を新しい順に取得する
+    pub async fn history(
+        &self,
+        token: Token,
+        query: ExpHistoryQuery,
+    ) -> Result<ExpHistory, ExpServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let page = ExpHistoryPage::try_from(query).map_err(ExpServiceError::InvalidQuery)?;
+
+        let mut transactions = self.exp_repo.find_transactions(&user_id, &page).await?;
+        // limitより多く取得できた場合は次のページが存在する
+        let next_cursor = if transactions.len() > page.limit as usize {
+            transactions.truncate(page.limit as usize);
+            transactions.last().map(|t| t.transaction_id.to_string())
+        } else {
+            None
+        };
+        Ok(ExpHistory {
+            transactions,
+            next_cursor,
+        })
+    }
+
+    // 台帳の合計からuser_expの経験値を再計算する(運用コマンド用のためトークンは不要)
+    // user_idがNoneの場合はすべてのユーザーが対象
+    pub async fn rebuild_projection(
+        &self,
+        user_id: Option<UserId>,
+    ) -> Result<u64, ExpServiceError> {
+        let rebuilt = self.exp_repo.rebuild(user_id.as_ref()).await?;
+        Ok(rebuilt)
     }
 }
@@ -3,7 +3,8 @@ use validator::Validate;
 
 use crate::{
     entity::{
-        token::Token, user::User, user_builder::UserBuilder, user_id::UserId, user_info::UserInfo,
+        privacy_settings::PrivacySettings, time_zone_input::TimeZoneInput, token::Token,
+        user::User, user_builder::UserBuilder, user_id::UserId, user_info::UserInfo,
         user_input::UserInput,
     },
     repository::user_repository::UserRepository,
@@ -79,7 +80,8 @@ where
     pub async fn get_user_info(&self, token: Token) -> Result<UserInfo, UserServiceError> {
         let user_id = self.token_service.verify(token)?;
         let user = self.user_repo.find_by_id(&user_id).await?;
-        Ok(user.into())
+        let privacy = self.user_repo.find_privacy_settings(&user_id).await?;
+        Ok(UserInfo::new(user, privacy))
     }
 
     /// ユーザー名を変更する
@@ -102,6 +104,33 @@ where
         Ok(())
     }
 
+    /// ユーザーのタイムゾーンを変更する
+    /// 今日の日付(ミッションのリセットや連続記録)の判定に使用される
+    pub async fn update_time_zone(
+        &self,
+        token: Token,
+        time_zone: TimeZoneInput,
+    ) -> Result<(), UserServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        time_zone.validate().map_err(UserServiceError::Validation)?;
+        let tz = time_zone.tz().ok_or(UserServiceError::InvalidData)?;
+        self.user_repo.update_time_zone(&user_id, tz).await?;
+        Ok(())
+    }
+
+    /// ランキングとフレンド申請の公開設定を変更する
+    pub async fn update_privacy_settings(
+        &self,
+        token: Token,
+        settings: PrivacySettings,
+    ) -> Result<(), UserServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.user_repo
+            .update_privacy_settings(&user_id, &settings)
+            .await?;
+        Ok(())
+    }
+
     pub async fn delete_user(&self, token: Token) -> Result<(), UserServiceError> {
         // トークンを持っているか検証
         let user_id = self.token_service.verify(token)?;
@@ -0,0 +1,126 @@
+use futures_util::future::join_all;
+
+use crate::{
+    entity::{
+        scheduled_job::retry_delay_seconds,
+        webhook::{
+            DeliveryStatus, PendingDelivery, DELIVERY_BATCH_SIZE, DELIVERY_CONCURRENCY,
+            DELIVERY_RETENTION_DAYS,
+        },
+    },
+    repository::{
+        job_repository::JobRepository, repository_error::RepositoryError,
+        webhook_repository::WebhookRepository,
+    },
+};
+
+use super::webhook_sender::WebhookSender;
+
+/// 配信を送信するインスタンスを決めるリースの名前
+pub const WEBHOOK_LEASE_NAME: &str = "webhook_runner";
+
+/// Webhookの配信のキューをバックグラウンドで送信するサービス実装
+/// 配信はEventOutboxServiceがイベントと同じトランザクションでDBに登録するため、
+/// 送信に失敗した場合や再起動した場合も再試行される
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryService<J, W, S>
+where
+    J: JobRepository,
+    W: WebhookRepository,
+    S: WebhookSender,
+{
+    job_repo: J,
+    webhook_repo: W,
+    sender: S,
+}
+
+impl<J, W, S> WebhookDeliveryService<J, W, S>
+where
+    J: JobRepository,
+    W: WebhookRepository,
+    S: WebhookSender,
+{
+    pub fn new(job_repo: J, webhook_repo: W, sender: S) -> Self {
+        Self {
+            job_repo,
+            webhook_repo,
+            sender,
+        }
+    }
+
+    /// 送信予定の時刻を過ぎた配信を1回送信し、送信した数を返す
+    /// 1回に送信するのはDELIVERY_BATCH_SIZE件までで、DELIVERY_CONCURRENCY件ずつ同時に送信する
+    /// holderはインスタンスの識別子で、lease_secondsは実行の間隔とDELIVERY_TIMEOUT_SECONDSより十分に長くする
+    pub async fn run_once(
+        &self,
+        holder: &str,
+        lease_seconds: u32,
+    ) -> Result<usize, RepositoryError> {
+        if !self
+            .job_repo
+            .acquire_lease(WEBHOOK_LEASE_NAME, holder, lease_seconds)
+            .await?
+        {
+            return Ok(0);
+        }
+        let deliveries = self
+            .webhook_repo
+            .claim_due(holder, DELIVERY_BATCH_SIZE)
+            .await?;
+        let mut sent = 0;
+        for chunk in deliveries.chunks(DELIVERY_CONCURRENCY) {
+            // 送信に時間がかかってもリースが切れないよう、同時に送信する配信ごとにリースを延長する
+            // 他のインスタンスに引き継がれていた場合は、残りの配信を戻して送信を止める
+            if !self
+                .job_repo
+                .acquire_lease(WEBHOOK_LEASE_NAME, holder, lease_seconds)
+                .await?
+            {
+                self.webhook_repo.release(holder).await?;
+                return Ok(sent);
+            }
+            // 応答の遅い送信先があっても他の配信を待たせないよう、まとめて送信する
+            let results = join_all(chunk.iter().map(|delivery| self.deliver(delivery))).await;
+            results.into_iter().collect::<Result<Vec<_>, _>>()?;
+            sent += chunk.len();
+        }
+        self.webhook_repo.purge(DELIVERY_RETENTION_DAYS).await?;
+        Ok(sent)
+    }
+
+    // 1つの送信先の失敗で他の配信を止めないよう、失敗は配信ごとに記録する
+    async fn deliver(&self, delivery: &PendingDelivery) -> Result<(), RepositoryError> {
+        match self.sender.send(delivery).await {
+            Ok(status_code) => {
+                self.webhook_repo
+                    .finish(
+                        delivery.id,
+                        DeliveryStatus::Succeeded,
+                        Some(status_code),
+                        None,
+                    )
+                    .await
+            }
+            Err(e) if delivery.can_retry() => {
+                self.webhook_repo
+                    .retry(
+                        delivery.id,
+                        retry_delay_seconds(delivery.attempts),
+                        e.status_code(),
+                        &e.to_string(),
+                    )
+                    .await
+            }
+            Err(e) => {
+                self.webhook_repo
+                    .finish(
+                        delivery.id,
+                        DeliveryStatus::Failed,
+                        e.status_code(),
+                        Some(&e.to_string()),
+                    )
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use std::{future::Future, pin::Pin};
+
+use crate::entity::webhook::PendingDelivery;
+
+use super::service_error::webhook_error::WebhookError;
+
+// Webhookの配信を送信するトレイト
+// 受信した側が検証できるよう、実装はWebhookの秘密でペイロードに署名する
+pub trait WebhookSender {
+    /// 受信した側が2xxを返した場合はそのステータスコードを返す
+    fn send<'a>(
+        &'a self,
+        delivery: &'a PendingDelivery,
+    ) -> Pin<Box<dyn Future<Output = Result<u16, WebhookError>> + Send + 'a>>;
+}
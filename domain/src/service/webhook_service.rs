@@ -0,0 +1,172 @@
+use chrono::Utc;
+use sqlx::{MySql, Transaction};
+use validator::Validate;
+
+use crate::{
+    entity::{
+        token::Token,
+        webhook::{
+            Webhook, WebhookDelivery, WebhookInput, WebhookSecret, DELIVERY_LOG_LIMIT, MAX_WEBHOOKS,
+        },
+    },
+    repository::webhook_repository::WebhookRepository,
+};
+
+use super::{
+    service_error::webhook_service_error::WebhookServiceError, token_service::TokenService,
+    uuid_service::UUIDService,
+};
+
+/// Webhookの登録と配信の記録のサービス実装
+/// 配信の登録と送信はWebhookDeliveryServiceで行う
+#[derive(Debug, Clone)]
+pub struct WebhookService<T, U, W>
+where
+    T: TokenService,
+    U: UUIDService,
+    W: WebhookRepository,
+{
+    token_service: T,
+    uuid_service: U,
+    webhook_repo: W,
+}
+
+impl<T, U, W> WebhookService<T, U, W>
+where
+    T: TokenService,
+    U: UUIDService,
+    W: WebhookRepository,
+{
+    pub fn new(token_service: T, uuid_service: U, webhook_repo: W) -> Self {
+        Self {
+            token_service,
+            uuid_service,
+            webhook_repo,
+        }
+    }
+
+    /// 登録しているWebhookを取得する
+    pub async fn find_all(&self, token: Token) -> Result<Vec<Webhook>, WebhookServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let webhooks = self.webhook_repo.find_by_user_id(&user_id).await?;
+        Ok(webhooks)
+    }
+
+    /// Webhookを登録し、署名の秘密とともに返す
+    // 上限の確認と保存を同じトランザクションで処理するため、Transaction型を引数に取っている
+    pub async fn create(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        token: Token,
+        input: WebhookInput,
+    ) -> Result<WebhookSecret, WebhookServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        input.validate().map_err(WebhookServiceError::Validate)?;
+        if self.webhook_repo.count(tx, &user_id).await? >= MAX_WEBHOOKS {
+            return Err(WebhookServiceError::OverCapacity(MAX_WEBHOOKS));
+        }
+        let webhook = Webhook {
+            webhook_id: self.uuid_service.generate(),
+            user_id,
+            url: input.url,
+            secret: self.generate_secret(),
+            events: input.events,
+            active: input.active,
+            created_at: Utc::now().naive_utc(),
+        };
+        self.webhook_repo.create(tx, &webhook).await?;
+        Ok(WebhookSecret {
+            secret: webhook.secret.clone(),
+            webhook,
+        })
+    }
+
+    /// 送信先、購読するイベント、有効かどうかを更新する
+    /// 送信待ちの配信も更新後の送信先に送る
+    pub async fn update(
+        &self,
+        token: Token,
+        webhook_id: String,
+        input: WebhookInput,
+    ) -> Result<Webhook, WebhookServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        input.validate().map_err(WebhookServiceError::Validate)?;
+        let mut webhook = self.webhook_repo.find_by_id(&webhook_id, &user_id).await?;
+        webhook.url = input.url;
+        webhook.events = input.events;
+        webhook.active = input.active;
+        self.webhook_repo.update(&webhook).await?;
+        Ok(webhook)
+    }
+
+    /// 署名の秘密を再発行する(古い秘密による署名はすぐに使われなくなる)
+    pub async fn rotate_secret(
+        &self,
+        token: Token,
+        webhook_id: String,
+    ) -> Result<WebhookSecret, WebhookServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        let mut webhook = self.webhook_repo.find_by_id(&webhook_id, &user_id).await?;
+        webhook.secret = self.generate_secret();
+        self.webhook_repo
+            .update_secret(&webhook_id, &user_id, &webhook.secret)
+            .await?;
+        Ok(WebhookSecret {
+            secret: webhook.secret.clone(),
+            webhook,
+        })
+    }
+
+    pub async fn delete(
+        &self,
+        token: Token,
+        webhook_id: String,
+    ) -> Result<(), WebhookServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.webhook_repo.delete(&webhook_id, &user_id).await?;
+        Ok(())
+    }
+
+    /// Webhookの配信の記録を新しい順に取得する
+    pub async fn find_deliveries(
+        &self,
+        token: Token,
+        webhook_id: String,
+    ) -> Result<Vec<WebhookDelivery>, WebhookServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        // 他のユーザーのWebhookや存在しないWebhookの場合はNotFoundを返す
+        self.webhook_repo.find_by_id(&webhook_id, &user_id).await?;
+        let deliveries = self
+            .webhook_repo
+            .find_deliveries(&webhook_id, DELIVERY_LOG_LIMIT)
+            .await?;
+        Ok(deliveries)
+    }
+
+    /// 配信と同じ内容を新しい配信として送り直す
+    /// 元の配信の記録はそのまま残し、新しい配信はすぐに送信待ちになる
+    pub async fn redeliver(
+        &self,
+        token: Token,
+        webhook_id: String,
+        delivery_id: String,
+    ) -> Result<WebhookDelivery, WebhookServiceError> {
+        let user_id = self.token_service.verify(token)?;
+        self.webhook_repo.find_by_id(&webhook_id, &user_id).await?;
+        let delivery = self
+            .webhook_repo
+            .redeliver(&webhook_id, &delivery_id, &self.uuid_service.generate())
+            .await?;
+        Ok(delivery)
+    }
+
+    // 推測されないよう、2つのUUIDを連結して十分な長さの乱数にする
+    fn generate_secret(&self) -> String {
+        format!(
+            "whsec_{}{}",
+            self.uuid_service.generate(),
+            self.uuid_service.generate()
+        )
+        .replace('-', "")
+    }
+}
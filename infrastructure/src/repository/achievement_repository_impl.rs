@@ -0,0 +1,250 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        achievement::{Achievement, AchievementStats, UserAchievement},
+        user_id::UserId,
+    },
+    repository::{
+        achievement_repository::AchievementRepository, repository_error::RepositoryError,
+    },
+};
+use sqlx::{MySql, MySqlPool, Transaction};
+
+use super::to_repo_err;
+
+#[derive(Debug, Clone)]
+pub struct AchievementRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl AchievementRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl AchievementRepository for AchievementRepositoryImpl {
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<UserAchievement>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let achievements = sqlx::query_as(
+                r#"
+                    SELECT a.achievement_id, a.title, a.description, a.metric, a.threshold,
+                    a.bonus_exp, u.unlocked_at
+                    FROM achievements AS a
+                    LEFT JOIN user_achievements AS u
+                    ON u.achievement_id = a.achievement_id AND u.user_id = ?
+                    ORDER BY a.id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(achievements)
+        })
+    }
+
+    fn find_locked<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Achievement>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let achievements = sqlx::query_as(
+                r#"
+                    SELECT achievement_id, title, description, metric, threshold, bonus_exp
+                    FROM achievements AS a
+                    WHERE NOT EXISTS (
+                        SELECT 1 FROM user_achievements AS u
+                        WHERE u.achievement_id = a.achievement_id AND u.user_id = ?
+                    )
+                    ORDER BY id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(achievements)
+        })
+    }
+
+    fn find_stats<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<AchievementStats, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let stats = sqlx::query_as(
+                r#"
+                    SELECT
+                    (
+                        SELECT COUNT(*)
+                        FROM mission_completed AS c
+                        INNER JOIN daily_mission AS m ON m.mission_id = c.mission_id
+                        WHERE m.user_id = ?
+                    ) AS total_completions,
+                    (
+                        SELECT CAST(COALESCE(MAX(s.longest_streak), 0) AS SIGNED)
+                        FROM mission_streak AS s
+                        INNER JOIN daily_mission AS m ON m.mission_id = s.mission_id
+                        WHERE m.user_id = ?
+                    ) AS longest_mission_streak,
+                    (
+                        SELECT CAST(COALESCE(MAX(longest_streak), 0) AS SIGNED)
+                        FROM user_streak
+                        WHERE user_id = ?
+                    ) AS longest_all_clear_streak
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(&user_id.0)
+            .bind(&user_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(stats)
+        })
+    }
+
+    fn unlock<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        achievement_id: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 主キー(user_id, achievement_id)が重複する場合は無視されるため、解除は一度だけ行われる
+            let result = sqlx::query(
+                r#"
+                    INSERT IGNORE INTO user_achievements
+                    (user_id, achievement_id, unlocked_at)
+                    VALUES
+                    (?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(achievement_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() == 1)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::user_id::UserId, repository::achievement_repository::AchievementRepository,
+    };
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+
+    use crate::repository::achievement_repository_impl::AchievementRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_achievement_unlock() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+        let repo = AchievementRepositoryImpl::new(pool.clone());
+
+        // ミッションを1回完了する
+        let mission_id = gen_random_str();
+        sqlx::query("INSERT INTO daily_mission (user_id, mission_id, title) VALUES (?, ?, ?)")
+            .bind(&user_id.0)
+            .bind(&mission_id)
+            .bind("title")
+            .execute(&pool)
+            .await?;
+        sqlx::query(
+            "INSERT INTO mission_completed (mission_id, user_id, date) VALUES (?, ?, CURDATE())",
+        )
+        .bind(&mission_id)
+        .bind(&user_id.0)
+        .execute(&pool)
+        .await?;
+
+        let mut tx = pool.begin().await?;
+        let stats = repo.find_stats(&mut tx, &user_id).await?;
+        assert_eq!(stats.total_completions, 1);
+        assert_eq!(stats.longest_mission_streak, 0);
+        let locked = repo.find_locked(&mut tx, &user_id).await?;
+        let first = locked
+            .iter()
+            .find(|a| a.is_satisfied(&stats, 1))
+            .expect("first_completion must be satisfied");
+        assert_eq!(first.achievement_id, "first_completion");
+        // 解除は一度だけ
+        assert!(
+            repo.unlock(&mut tx, &user_id, &first.achievement_id)
+                .await?
+        );
+        assert!(
+            !repo
+                .unlock(&mut tx, &user_id, &first.achievement_id)
+                .await?
+        );
+        assert_eq!(
+            repo.find_locked(&mut tx, &user_id).await?.len(),
+            locked.len() - 1
+        );
+        tx.commit().await?;
+
+        let achievements = repo.find_by_user_id(&user_id).await?;
+        assert!(achievements.iter().all(
+            |a| a.unlocked_at.is_some() == (a.achievement.achievement_id == "first_completion")
+        ));
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::user_id::UserId,
+    repository::{admin_repository::AdminRepository, repository_error::RepositoryError},
+};
+use sqlx::MySqlPool;
+
+use super::to_repo_err;
+
+#[derive(Debug, Clone)]
+pub struct AdminRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl AdminRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl AdminRepository for AdminRepositoryImpl {
+    fn is_admin<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let is_admin: Option<bool> = sqlx::query_scalar(
+                r#"
+                    SELECT is_admin FROM users
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(is_admin.unwrap_or_default())
+        })
+    }
+
+    fn save_plan<'a>(
+        &'a self,
+        plan_id: &'a str,
+        mission_capacity: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO plan
+                    (plan_id, mission_capacity)
+                    VALUES
+                    (?, ?)
+                    ON DUPLICATE KEY UPDATE
+                    mission_capacity = VALUES(mission_capacity)
+                "#,
+            )
+            .bind(plan_id)
+            .bind(mission_capacity)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn update_user_plan<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        plan_id: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 存在しないプランの場合は更新しない
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE users
+                    SET plan_id = ?
+                    WHERE user_id = ?
+                    AND (? IS NULL OR EXISTS (SELECT 1 FROM plan WHERE plan_id = ?))
+                "#,
+            )
+            .bind(plan_id)
+            .bind(&user_id.0)
+            .bind(plan_id)
+            .bind(plan_id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    fn update_user_capacity<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_capacity: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE users
+                    SET mission_capacity = ?
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(mission_capacity)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            daily_mission_builder::DailyMissionBuilder, daily_mission_id::DailyMissionId,
+            user_id::UserId,
+        },
+        repository::{
+            admin_repository::AdminRepository, daily_mission_repository::DailyMissionRepository,
+        },
+    };
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+
+    use crate::repository::{
+        admin_repository_impl::AdminRepositoryImpl,
+        daily_mission_repository_impl::DailyMissionRepositoryImpl,
+    };
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_capacity_priority() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+
+        let admin_repo = AdminRepositoryImpl::new(pool.clone());
+        let mission_repo = DailyMissionRepositoryImpl::new(pool.clone());
+        assert!(!admin_repo.is_admin(&user_id).await?);
+
+        // 存在しないプランは設定できない
+        let plan_id = gen_random_str();
+        assert!(admin_repo
+            .update_user_plan(&user_id, Some(&plan_id))
+            .await
+            .is_err());
+
+        admin_repo.save_plan(&plan_id, Some(10)).await?;
+        admin_repo
+            .update_user_plan(&user_id, Some(&plan_id))
+            .await?;
+        let mut tx = pool.begin().await?;
+        assert_eq!(mission_repo.find_capacity(&mut tx, &user_id).await?, 10);
+        tx.commit().await?;
+
+        // ユーザーごとの設定はプランより優先する
+        admin_repo.update_user_capacity(&user_id, Some(3)).await?;
+        let mut tx = pool.begin().await?;
+        assert_eq!(mission_repo.find_capacity(&mut tx, &user_id).await?, 3);
+        let mission = DailyMissionBuilder::new()
+            .user_id(&user_id)
+            .mission_id(&DailyMissionId(gen_random_str()))
+            .title("title")
+            .build();
+        mission_repo.create(&mut tx, &mission).await?;
+        assert_eq!(mission_repo.count(&mut tx, &user_id).await?, 1);
+        tx.commit().await?;
+
+        delete_test_user(pool.clone(), &user_id.0).await?;
+        sqlx::query("DELETE FROM plan WHERE plan_id = ?")
+            .bind(&plan_id)
+            .execute(&pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
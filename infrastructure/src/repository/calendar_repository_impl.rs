@@ -0,0 +1,333 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        calendar::{CalendarCompletion, CalendarFeed, CalendarMission},
+        daily_mission_id::DailyMissionId,
+        user_id::UserId,
+    },
+    repository::{calendar_repository::CalendarRepository, repository_error::RepositoryError},
+};
+use sqlx::{
+    types::chrono::{NaiveDate, NaiveDateTime, Utc},
+    MySqlPool, Row,
+};
+
+use super::{local_to_utc, schedule_from_row, to_repo_err, user_time_zone, utc_to_local};
+
+#[derive(Debug, Clone)]
+pub struct CalendarRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl CalendarRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl CalendarRepository for CalendarRepositoryImpl {
+    fn find_feed<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<CalendarFeed, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let feed = sqlx::query_as(
+                r#"
+                    SELECT feed_token, created_at FROM calendar_feeds
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(feed)
+        })
+    }
+
+    fn save_feed<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        feed_token: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<CalendarFeed, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO calendar_feeds
+                    (user_id, feed_token, created_at)
+                    VALUES
+                    (?, ?, UTC_TIMESTAMP())
+                    ON DUPLICATE KEY UPDATE
+                    feed_token = VALUES(feed_token),
+                    created_at = VALUES(created_at)
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(feed_token)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            self.find_feed(user_id).await
+        })
+    }
+
+    fn delete_feed<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM calendar_feeds
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn find_user_by_token<'a>(
+        &'a self,
+        feed_token: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<UserId, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let row = sqlx::query(
+                r#"
+                    SELECT user_id FROM calendar_feeds
+                    WHERE feed_token = ?
+                "#,
+            )
+            .bind(feed_token)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(UserId(row.try_get("user_id").map_err(to_repo_err)?))
+        })
+    }
+
+    fn find_missions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<CalendarMission>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            // 登録した日はユーザーのタイムゾーンの日付にする
+            let tz = user_time_zone(&self.pool, user_id).await?;
+            let rows = sqlx::query(
+                r#"
+                    SELECT
+                    daily_mission.mission_id,
+                    daily_mission.title,
+                    daily_mission.descriptions,
+                    daily_mission.schedule_type,
+                    daily_mission.schedule_value,
+                    daily_mission.schedule_start,
+                    daily_mission.created_at
+                    FROM daily_mission
+                    WHERE daily_mission.user_id = ?
+                    ORDER BY daily_mission.id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            rows.iter()
+                .map(|row| {
+                    let created_date = utc_to_local(tz, row.try_get("created_at")?).date();
+                    let start: Option<NaiveDate> = row.try_get("schedule_start")?;
+                    Ok(CalendarMission {
+                        mission_id: DailyMissionId(row.try_get("mission_id")?),
+                        title: row.try_get("title")?,
+                        description: row.try_get("descriptions")?,
+                        schedule: schedule_from_row(row)?,
+                        start_date: start.unwrap_or(created_date),
+                    })
+                })
+                .collect::<Result<_, sqlx::Error>>()
+                .map_err(to_repo_err)
+        })
+    }
+
+    fn find_completions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        days: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<CalendarCompletion>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let tz = user_time_zone(&self.pool, user_id).await?;
+            let today = utc_to_local(tz, Utc::now().naive_utc()).date();
+            // completed_atはユーザーのタイムゾーンの日時のため、その時点のオフセットでUTCに戻して返す
+            let rows = sqlx::query(
+                r#"
+                    SELECT
+                    mission_completed.id,
+                    daily_mission.title,
+                    mission_completed.date,
+                    mission_completed.completed_at
+                    FROM mission_completed
+                    JOIN daily_mission ON mission_completed.mission_id = daily_mission.mission_id
+                    WHERE daily_mission.user_id = ?
+                    AND mission_completed.date > DATE_SUB(?, INTERVAL ? DAY)
+                    ORDER BY mission_completed.date, mission_completed.id
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(today)
+            .bind(days)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            rows.iter()
+                .map(|row| {
+                    let completed_at: Option<NaiveDateTime> = row.try_get("completed_at")?;
+                    Ok(CalendarCompletion {
+                        completion_id: row.try_get("id")?,
+                        title: row.try_get("title")?,
+                        date: row.try_get("date")?,
+                        completed_at: completed_at.map(|local| local_to_utc(tz, local)),
+                    })
+                })
+                .collect::<Result<_, sqlx::Error>>()
+                .map_err(to_repo_err)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::user_id::UserId,
+        repository::{calendar_repository::CalendarRepository, repository_error::RepositoryError},
+    };
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+
+    use crate::repository::calendar_repository_impl::CalendarRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_feed_lifecycle() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+        let repo = CalendarRepositoryImpl::new(pool.clone());
+
+        assert!(matches!(
+            repo.find_feed(&user_id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        let first = gen_random_str();
+        repo.save_feed(&user_id, &first).await?;
+        assert_eq!(repo.find_user_by_token(&first).await?, user_id);
+
+        // 再発行すると古いfeed_tokenは使えなくなる
+        let second = gen_random_str();
+        let feed = repo.save_feed(&user_id, &second).await?;
+        assert_eq!(feed.feed_token, second);
+        assert!(repo.find_user_by_token(&first).await.is_err());
+
+        assert!(repo.delete_feed(&user_id).await?);
+        assert!(repo.find_user_by_token(&second).await.is_err());
+        assert!(!repo.delete_feed(&user_id).await?);
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_missions_and_completions() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let mission_id = gen_random_str();
+        create_user(pool.clone(), &user_id.0).await?;
+        sqlx::query(
+            r#"
+                INSERT INTO daily_mission
+                (user_id, mission_id, title, descriptions)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&user_id.0)
+        .bind(&mission_id)
+        .bind("test_title")
+        .bind("test_description")
+        .execute(&pool)
+        .await?;
+        // 今日と、フィードの期間外の完了
+        sqlx::query(
+            r#"
+                INSERT INTO mission_completed
+                (mission_id, user_id, date, completed_at)
+                VALUES
+                (?, ?, DATE(DATE_ADD(UTC_TIMESTAMP(), INTERVAL 9 HOUR)), DATE_ADD(UTC_TIMESTAMP(), INTERVAL 9 HOUR)),
+                (?, ?, '2000-01-01', NULL)
+            "#,
+        )
+        .bind(&mission_id)
+        .bind(&user_id.0)
+        .bind(&mission_id)
+        .bind(&user_id.0)
+        .execute(&pool)
+        .await?;
+        let repo = CalendarRepositoryImpl::new(pool.clone());
+
+        let missions = repo.find_missions(&user_id).await?;
+        assert_eq!(missions.len(), 1);
+        assert_eq!(missions[0].title, "test_title");
+        let completions = repo.find_completions(&user_id, 90).await?;
+        assert_eq!(completions.len(), 1);
+        assert!(completions[0].completed_at.is_some());
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
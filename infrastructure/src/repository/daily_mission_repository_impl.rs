@@ -1,14 +1,39 @@
-use std::{future::Future, pin::Pin};
+use std::{future::Future, pin::Pin, sync::LazyLock};
 
 use domain::{
-    entity::{daily_mission::DailyMission, daily_mission_id::DailyMissionId, user_id::UserId},
+    entity::{
+        daily_mission::{DailyMission, MissionTitle},
+        daily_mission_id::DailyMissionId,
+        frozen_day::FrozenDay,
+        history_query::HistoryPage,
+        mission_completion::MissionCompletion,
+        mission_difficulty::MissionDifficulty,
+        mission_progress::MissionProgress,
+        mission_schedule::MissionSchedule,
+        mission_transfer::MissionRecord,
+        streak::Streak,
+        user_id::UserId,
+    },
     repository::{
         daily_mission_repository::DailyMissionRepository, repository_error::RepositoryError,
     },
 };
-use sqlx::{mysql::MySqlRow, prelude::FromRow, types::chrono::{FixedOffset, NaiveDate, Utc}, MySql, MySqlPool, Row, Transaction};
+use sqlx::{
+    mysql::MySqlRow, prelude::FromRow, types::chrono::NaiveDate, MySql, MySqlPool, Row, Transaction,
+};
 
-use super::to_repo_err;
+use super::{
+    add_daily_stats, current_date, current_datetime, find_frozen_days, is_duplicate_entry,
+    schedule_from_row, to_repo_err, StreakContext,
+};
+
+// ユーザーごと・プランごとの設定が無い場合のミッションの登録上限
+static DEFAULT_MISSION_CAPACITY: LazyLock<u32> = LazyLock::new(|| {
+    dotenvy::var("MISSION_CAPACITY")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(7)
+});
 
 #[derive(Debug, Clone)]
 pub struct DailyMissionRepositoryImpl {
@@ -24,22 +49,31 @@ impl DailyMissionRepositoryImpl {
 impl DailyMissionRepository for DailyMissionRepositoryImpl {
     fn create<'a>(
         &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
         builder: &'a DailyMission,
     ) -> Pin<Box<dyn Future<Output = Result<DailyMissionId, RepositoryError>> + Send + 'a>> {
         Box::pin(async move {
+            let (schedule_type, schedule_value, schedule_start) = builder.schedule.to_columns();
             let affected_len = sqlx::query(
                 r#"
                 INSERT INTO daily_mission
-                (user_id, mission_id, title, descriptions)
+                (user_id, mission_id, title, descriptions, schedule_type, schedule_value, schedule_start, target_quantity, unit, difficulty, exp_weight)
                 VALUES
-                (?, ?, ?, ?)
+                (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
             )
             .bind(&builder.user_id.0)
             .bind(&builder.mission_id.0)
             .bind(&builder.title)
             .bind(&builder.description)
-            .execute(&self.pool)
+            .bind(schedule_type)
+            .bind(schedule_value)
+            .bind(schedule_start)
+            .bind(builder.target_quantity)
+            .bind(&builder.unit)
+            .bind(builder.difficulty.as_str())
+            .bind(builder.exp_weight)
+            .execute(&mut **tx)
             .await
             .map_err(to_repo_err)?
             .rows_affected();
@@ -56,6 +90,7 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
 
     fn count<'a>(
         &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<i32, RepositoryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -67,7 +102,7 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
                 "#,
             )
             .bind(&user_id.0)
-            .fetch_one(&self.pool)
+            .fetch_one(&mut **tx)
             .await
             .map_err(to_repo_err)?;
 
@@ -76,16 +111,51 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
         })
     }
 
+    fn find_capacity<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // ユーザーの行をロックすることで、同じユーザーのミッションの登録を直列化する
+            let row = sqlx::query(
+                r#"
+                    SELECT
+                    users.mission_capacity AS user_capacity,
+                    plan.mission_capacity AS plan_capacity
+                    FROM users
+                    LEFT JOIN plan
+                    ON users.plan_id = plan.plan_id
+                    WHERE users.user_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+
+            let user_capacity: Option<i32> = row.try_get("user_capacity").map_err(to_repo_err)?;
+            let plan_capacity: Option<i32> = row.try_get("plan_capacity").map_err(to_repo_err)?;
+            let capacity = user_capacity
+                .or(plan_capacity)
+                .map(|c| c.max(0) as u32)
+                .unwrap_or(*DEFAULT_MISSION_CAPACITY);
+            Ok(capacity)
+        })
+    }
+
     // 今日のミッションを取得する
     fn find_by_id<'a>(
         &'a self,
         mission_id: &'a DailyMissionId,
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<DailyMission, RepositoryError>> + Send + 'a>> {
-        // 今日の日付を取得する(日本時間)
-        let current_date = current_date_jp();
         Box::pin(async move {
-            // daily_mission tableとmission_completed tableをJOINして
+            let mut conn = self.pool.acquire().await.map_err(to_repo_err)?;
+            // 今日の日付を取得する(ユーザーのタイムゾーン)
+            let current_date = current_date(&mut *conn, user_id).await?;
+            // daily_mission tableとmission_completed table、mission_streak tableをJOINして
             // DailyMissionRow型としてDBから取得し、DailyMissionに変換する
             let mission: DailyMissionRow = sqlx::query_as(
                 r#"
@@ -94,35 +164,78 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
                 daily_mission.mission_id,
                 daily_mission.title, 
                 daily_mission.descriptions AS description,
-                mission_completed.date
+                daily_mission.schedule_type,
+                daily_mission.schedule_value,
+                daily_mission.schedule_start,
+                daily_mission.target_quantity,
+                daily_mission.unit,
+                daily_mission.difficulty,
+                daily_mission.exp_weight,
+                mission_completed.date,
+                mission_progress.quantity AS progress,
+                mission_streak.current_streak,
+                mission_streak.longest_streak,
+                mission_streak.last_date,
+                mission_streak.week_count
                 FROM daily_mission
                 LEFT JOIN mission_completed
                 ON daily_mission.mission_id = mission_completed.mission_id
                 AND mission_completed.date = ?
+                LEFT JOIN mission_progress
+                ON daily_mission.mission_id = mission_progress.mission_id
+                AND mission_progress.date = ?
+                LEFT JOIN mission_streak
+                ON daily_mission.mission_id = mission_streak.mission_id
                 WHERE daily_mission.mission_id = ?
                 AND
                 daily_mission.user_id = ?
                 "#,
             )
-            .bind(&current_date)
+            .bind(current_date)
+            .bind(current_date)
             .bind(&mission_id.0)
             .bind(&user_id.0)
-            .fetch_one(&self.pool)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(to_repo_err)?;
+            let context =
+                StreakContext::load(&mut conn, user_id, current_date, mission.streak.last_date)
+                    .await?;
+            Ok(mission.into_mission(&context))
+        })
+    }
+
+    // 今日実施するミッションすべてを取得する
+    fn find_titles<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionTitle>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let titles = sqlx::query_as(
+                r#"
+                    SELECT mission_id, title
+                    FROM daily_mission
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&mut **tx)
             .await
             .map_err(to_repo_err)?;
-            Ok(mission.into())
+            Ok(titles)
         })
     }
 
-    // 今日のミッションすべてを取得する
     fn find_by_user_id<'a>(
         &'a self,
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<DailyMission>, RepositoryError>> + Send + 'a>> {
-        let current_date = current_date_jp();
-        // daily_mission tableとmission_completed tableをJOINして
+        // daily_mission tableとmission_completed table、mission_streak tableをJOINして
         // DailyMissionRowをDBから取得しDailyMissionに変換する
         Box::pin(async move {
+            let mut conn = self.pool.acquire().await.map_err(to_repo_err)?;
+            let current_date = current_date(&mut *conn, user_id).await?;
             let missions: Vec<DailyMissionRow> = sqlx::query_as(
                 r#"
                     SELECT
@@ -130,20 +243,67 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
                     daily_mission.mission_id,
                     daily_mission.title, 
                     daily_mission.descriptions AS description,
-                    mission_completed.date
+                    daily_mission.schedule_type,
+                    daily_mission.schedule_value,
+                    daily_mission.schedule_start,
+                    daily_mission.target_quantity,
+                    daily_mission.unit,
+                    daily_mission.difficulty,
+                    daily_mission.exp_weight,
+                    mission_completed.date,
+                    mission_progress.quantity AS progress,
+                    mission_streak.current_streak,
+                    mission_streak.longest_streak,
+                    mission_streak.last_date,
+                    mission_streak.week_count
                     FROM daily_mission
                     LEFT JOIN mission_completed
                     ON daily_mission.mission_id = mission_completed.mission_id
                     AND mission_completed.date = ?
+                    LEFT JOIN mission_progress
+                    ON daily_mission.mission_id = mission_progress.mission_id
+                    AND mission_progress.date = ?
+                    LEFT JOIN mission_streak
+                    ON daily_mission.mission_id = mission_streak.mission_id
                     WHERE daily_mission.user_id = ?
                 "#,
             )
-            .bind(&current_date)
+            .bind(current_date)
+            .bind(current_date)
+            .bind(&user_id.0)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(to_repo_err)?;
+            let since = missions.iter().filter_map(|m| m.streak.last_date).min();
+            let context = StreakContext::load(&mut conn, user_id, current_date, since).await?;
+            Ok(missions
+                .into_iter()
+                .map(|f| f.into_mission(&context))
+                .filter(|m| m.is_due)
+                .collect())
+        })
+    }
+
+    fn find_records<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionRecord>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let records = sqlx::query_as(
+                r#"
+                    SELECT title, descriptions, schedule_type, schedule_value, schedule_start,
+                    target_quantity, unit, difficulty, exp_weight
+                    FROM daily_mission
+                    WHERE user_id = ?
+                    ORDER BY id
+                "#,
+            )
             .bind(&user_id.0)
             .fetch_all(&self.pool)
             .await
             .map_err(to_repo_err)?;
-            Ok(missions.into_iter().map(|f| f.into()).collect())
+            Ok(records)
         })
     }
 
@@ -153,17 +313,32 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
         user_id: &'a UserId,
     ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
         Box::pin(async move {
+            let (schedule_type, schedule_value, schedule_start) = mission.schedule.to_columns();
             let affected_len = sqlx::query(
                 r#"
                 UPDATE daily_mission
                 SET
                 title = ?,
-                descriptions = ?
+                descriptions = ?,
+                schedule_type = ?,
+                schedule_value = ?,
+                schedule_start = ?,
+                target_quantity = ?,
+                unit = ?,
+                difficulty = ?,
+                exp_weight = ?
                 WHERE mission_id = ? && user_id = ?
                 "#,
             )
             .bind(&mission.title)
             .bind(&mission.description)
+            .bind(schedule_type)
+            .bind(schedule_value)
+            .bind(schedule_start)
+            .bind(mission.target_quantity)
+            .bind(&mission.unit)
+            .bind(mission.difficulty.as_str())
+            .bind(mission.exp_weight)
             .bind(&mission.mission_id.0)
             .bind(&user_id.0)
             .execute(&self.pool)
@@ -184,21 +359,185 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
         &self,
         tx: &'a mut Transaction<'_, MySql>,
         mission_id: &'a DailyMissionId,
-        _user_id: &'a UserId,
-    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
-        // 今日の日付を取得する(日本時間)
-        let current_date = current_date_jp();
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<MissionCompletion>, RepositoryError>> + Send + 'a>>
+    {
         Box::pin(async move {
-            let affected_len = sqlx::query(
+            // 現在の日時を取得する(ユーザーのタイムゾーン)
+            let completed_at = current_datetime(&mut **tx, user_id).await?;
+            let current_date = completed_at.date();
+            // ユーザーが所有するミッションの場合のみ挿入する
+            // 今日すでに完了している場合は一意制約(mission_id, date)によって挿入されない
+            // 付与する経験値は連続記録の更新後に計算して保存する
+            let result = sqlx::query(
                 r#"
                 INSERT INTO mission_completed
-                (mission_id, date)
-                VALUES
-                (?, ?)
+                (mission_id, user_id, date, completed_at)
+                SELECT mission_id, user_id, ?, ?
+                FROM daily_mission
+                WHERE mission_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(current_date)
+            .bind(completed_at)
+            .bind(&mission_id.0)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await;
+            let result = match result {
+                Ok(result) => result,
+                Err(e) if is_duplicate_entry(&e) => return Ok(None),
+                Err(e) => return Err(to_repo_err(e)),
+            };
+
+            if result.rows_affected() == 1 {
+                add_daily_stats(tx, user_id, current_date, 1, 0).await?;
+                Ok(Some(MissionCompletion {
+                    completion_id: result.last_insert_id() as i64,
+                    mission_id: mission_id.to_owned(),
+                    date: current_date,
+                    completed_at: Some(completed_at),
+                    exp_awarded: 0,
+                }))
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    // 今日の進捗を加算する
+    fn add_progress<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        user_id: &'a UserId,
+        amount: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionProgress, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let current_date = current_date(&mut **tx, user_id).await?;
+            // ユーザーが所有するミッションの場合のみ挿入または加算する
+            // 目標は記録時点の値で上書きし、累計はINTの範囲に収める
+            let affected_len = sqlx::query(
+                r#"
+                INSERT INTO mission_progress
+                (mission_id, date, quantity, target_quantity)
+                SELECT mission_id, ?, ?, target_quantity
+                FROM daily_mission
+                WHERE mission_id = ? AND user_id = ? AND target_quantity IS NOT NULL
+                ON DUPLICATE KEY UPDATE
+                quantity = LEAST(mission_progress.quantity + ?, 2147483647),
+                target_quantity = daily_mission.target_quantity
+                "#,
+            )
+            .bind(current_date)
+            .bind(amount)
+            .bind(&mission_id.0)
+            .bind(&user_id.0)
+            .bind(amount)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            // 挿入の場合は1、更新の場合は2
+            if affected_len == 0 {
+                return Err(RepositoryError::NotFound);
+            }
+
+            // 行ロックを取得した後の最新の値を読むため、ロック読み取りを使用する
+            let progress = sqlx::query_as(
+                r#"
+                SELECT
+                mission_progress.mission_id,
+                mission_progress.date,
+                mission_progress.quantity,
+                mission_progress.target_quantity,
+                mission_progress.completion_undone,
+                EXISTS (
+                    SELECT 1
+                    FROM mission_completed
+                    WHERE mission_completed.mission_id = mission_progress.mission_id
+                    AND mission_completed.date = mission_progress.date
+                    FOR SHARE
+                ) AS is_complete
+                FROM mission_progress
+                WHERE mission_progress.mission_id = ?
+                AND mission_progress.date = ?
+                FOR UPDATE
                 "#,
             )
             .bind(&mission_id.0)
-            .bind(&current_date)
+            .bind(current_date)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(progress)
+        })
+    }
+
+    fn find_progress<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_id: Option<&'a DailyMissionId>,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionProgress>, RepositoryError>> + Send + 'a>>
+    {
+        let mission_id = mission_id.map(|id| id.0.as_str());
+        Box::pin(async move {
+            let progress = sqlx::query_as(
+                r#"
+                    SELECT
+                    mission_progress.mission_id,
+                    mission_progress.date,
+                    mission_progress.quantity,
+                    mission_progress.target_quantity,
+                    mission_progress.completion_undone,
+                    EXISTS (
+                        SELECT 1
+                        FROM mission_completed
+                        WHERE mission_completed.mission_id = mission_progress.mission_id
+                        AND mission_completed.date = mission_progress.date
+                    ) AS is_complete
+                    FROM mission_progress
+                    INNER JOIN daily_mission
+                    ON daily_mission.mission_id = mission_progress.mission_id
+                    WHERE daily_mission.user_id = ?
+                    AND (? IS NULL OR mission_progress.mission_id = ?)
+                    AND (? IS NULL OR mission_progress.date >= ?)
+                    AND (? IS NULL OR mission_progress.date <= ?)
+                    ORDER BY mission_progress.date DESC, mission_progress.mission_id
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(mission_id)
+            .bind(mission_id)
+            .bind(from)
+            .bind(from)
+            .bind(to)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(progress)
+        })
+    }
+
+    fn save_exp_awarded<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        completion_id: i64,
+        exp_awarded: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                UPDATE mission_completed
+                SET exp_awarded = ?
+                WHERE id = ?
+                "#,
+            )
+            .bind(exp_awarded)
+            .bind(completion_id)
             .execute(&mut **tx)
             .await
             .map_err(to_repo_err)?
@@ -212,6 +551,141 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
         })
     }
 
+    // 今日の完了を取り消す
+    fn undo_complete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionCompletion, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let current_date = current_date(&mut **tx, user_id).await?;
+            // 同時に取り消しても経験値を一度だけ戻すように、行ロックを取得してから削除する
+            let completion: MissionCompletion = sqlx::query_as(
+                r#"
+                SELECT
+                mission_completed.id,
+                mission_completed.mission_id,
+                mission_completed.date,
+                mission_completed.completed_at,
+                mission_completed.exp_awarded
+                FROM mission_completed
+                INNER JOIN daily_mission
+                ON daily_mission.mission_id = mission_completed.mission_id
+                WHERE mission_completed.mission_id = ?
+                AND daily_mission.user_id = ?
+                AND mission_completed.date = ?
+                ORDER BY mission_completed.id DESC
+                LIMIT 1
+                FOR UPDATE
+                "#,
+            )
+            .bind(&mission_id.0)
+            .bind(&user_id.0)
+            .bind(current_date)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+
+            sqlx::query(
+                r#"
+                DELETE FROM mission_completed
+                WHERE id = ?
+                "#,
+            )
+            .bind(completion.completion_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            add_daily_stats(tx, user_id, completion.date, -1, 0).await?;
+            // 今日の進捗は履歴として残し、目標に達したままの進捗で次の記録の時に再び完了にならないよう印を付ける
+            sqlx::query(
+                r#"
+                UPDATE mission_progress
+                SET completion_undone = TRUE
+                WHERE mission_id = ? AND date = ?
+                "#,
+            )
+            .bind(&mission_id.0)
+            .bind(current_date)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(completion)
+        })
+    }
+
+    fn find_completions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_id: Option<&'a DailyMissionId>,
+        page: &'a HistoryPage,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionCompletion>, RepositoryError>> + Send + 'a>>
+    {
+        let mission_id = mission_id.map(|id| id.0.as_str());
+        Box::pin(async move {
+            // 指定された条件だけをクエリに含め、(user_id, date, id)または(mission_id, date)の
+            // インデックスを範囲で辿れるようにする
+            // カーソルはdateの範囲で絞り込んでから同じ日付のidを比較する
+            let mut conditions = String::new();
+            if mission_id.is_some() {
+                conditions.push_str(" AND mission_id = ?");
+            }
+            if page.from.is_some() {
+                conditions.push_str(" AND date >= ?");
+            }
+            if page.to.is_some() {
+                conditions.push_str(" AND date <= ?");
+            }
+            if page.cursor.is_some() {
+                conditions.push_str(" AND date <= ? AND (date < ? OR id < ?)");
+            }
+            let sql = format!(
+                r#"
+                    SELECT id, mission_id, date, completed_at, exp_awarded
+                    FROM mission_completed
+                    WHERE user_id = ?{conditions}
+                    ORDER BY date DESC, id DESC
+                    LIMIT ?
+                "#
+            );
+            let mut query = sqlx::query_as(&sql).bind(&user_id.0);
+            if let Some(mission_id) = mission_id {
+                query = query.bind(mission_id);
+            }
+            if let Some(from) = page.from {
+                query = query.bind(from);
+            }
+            if let Some(to) = page.to {
+                query = query.bind(to);
+            }
+            if let Some(cursor) = page.cursor {
+                query = query
+                    .bind(cursor.date)
+                    .bind(cursor.date)
+                    .bind(cursor.completion_id);
+            }
+            let completions = query
+                .bind(page.limit + 1)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(to_repo_err)?;
+            Ok(completions)
+        })
+    }
+
+    fn find_frozen_days<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<FrozenDay>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut conn = self.pool.acquire().await.map_err(to_repo_err)?;
+            find_frozen_days(&mut conn, user_id, from, to).await
+        })
+    }
+
     fn delete<'a>(
         &'a self,
         mission_id: &'a DailyMissionId,
@@ -240,14 +714,9 @@ impl DailyMissionRepository for DailyMissionRepositoryImpl {
     }
 }
 
-static JP_OFFSET: i32 = 9 * 3600;
-fn current_date_jp() -> NaiveDate {
-    let jp_tz = FixedOffset::east_opt(JP_OFFSET).unwrap();
-    Utc::now().with_timezone(&jp_tz).date_naive()
-}
-
 /// DBのテーブルをJOINしたDBからのrowデータ
-/// daily_missionテーブルとmission_completedをLEFT JOINしている
+/// daily_missionテーブルとmission_completed、mission_streakをLEFT JOINしている
+/// 週の達成日数はmission_streakに保存している値を使い、読み込み時に再集計しない
 /// DailyMissionはDailyMissionRowによって生成できる
 #[derive(Debug, Clone)]
 struct DailyMissionRow {
@@ -256,30 +725,74 @@ struct DailyMissionRow {
     title: String,
     description: Option<String>,
     have_complete: Option<NaiveDate>,
+    schedule: MissionSchedule,
+    target_quantity: Option<u32>,
+    unit: Option<String>,
+    /// 今日の進捗の累計(進捗が無い場合はNULL)
+    progress: Option<u32>,
+    difficulty: MissionDifficulty,
+    exp_weight: Option<u32>,
+    streak: Streak,
 }
 
 impl<'r> FromRow<'r, MySqlRow> for DailyMissionRow {
     fn from_row(row: &'r MySqlRow) -> Result<Self, sqlx::Error> {
-       Ok(
-        DailyMissionRow {
+        // 一度も完了していないミッションはmission_streakの行が存在しない
+        let current: Option<i32> = row.try_get("current_streak")?;
+        let longest: Option<i32> = row.try_get("longest_streak")?;
+        Ok(DailyMissionRow {
             user_id: UserId(row.try_get("user_id")?),
             mission_id: DailyMissionId(row.try_get("mission_id")?),
             title: row.try_get("title")?,
             description: row.try_get("description")?,
             have_complete: row.try_get("date")?,
-        }
-       ) 
+            schedule: schedule_from_row(row)?,
+            target_quantity: row
+                .try_get::<Option<i32>, _>("target_quantity")?
+                .map(|q| q.max(0) as u32),
+            unit: row.try_get("unit")?,
+            progress: row
+                .try_get::<Option<i32>, _>("progress")?
+                .map(|q| q.max(0) as u32),
+            difficulty: MissionDifficulty::from_column(row.try_get("difficulty")?)
+                .map_err(|e| sqlx::Error::Decode(e.into()))?,
+            exp_weight: row
+                .try_get::<Option<i32>, _>("exp_weight")?
+                .map(|w| w.max(0) as u32),
+            streak: Streak {
+                current: current.unwrap_or_default().max(0) as u32,
+                longest: longest.unwrap_or_default().max(0) as u32,
+                last_date: row.try_get("last_date")?,
+                week_count: row
+                    .try_get::<Option<i32>, _>("week_count")?
+                    .unwrap_or_default()
+                    .max(0) as u32,
+            },
+        })
     }
 }
 
-impl From<DailyMissionRow> for DailyMission {
-    fn from(value: DailyMissionRow) -> Self {
+impl DailyMissionRow {
+    /// ユーザーのタイムゾーンにおける今日の時点のDailyMissionに変換する
+    fn into_mission(self, context: &StreakContext) -> DailyMission {
+        let streak = context.summary(&self.streak, &self.schedule);
+        let week_count = self.streak.week_count_before(context.today);
+        let is_due = self.schedule.is_due(context.today, week_count);
         DailyMission {
-            user_id: value.user_id,
-            mission_id: value.mission_id,
-            title: value.title,
-            description: value.description,
-            is_complete: value.have_complete.is_some(),  
+            user_id: self.user_id,
+            mission_id: self.mission_id,
+            title: self.title,
+            description: self.description,
+            is_complete: self.have_complete.is_some(),
+            schedule: self.schedule,
+            is_due,
+            target_quantity: self.target_quantity,
+            unit: self.unit,
+            progress: self.progress.unwrap_or_default(),
+            difficulty: self.difficulty,
+            exp_weight: self.exp_weight,
+            current_streak: streak.current_streak,
+            longest_streak: streak.longest_streak,
         }
     }
 }
@@ -290,12 +803,17 @@ mod test {
 
     use domain::{
         entity::{
-            daily_mission::DailyMission, daily_mission_builder::DailyMissionBuilder,
-            daily_mission_id::DailyMissionId, user_id::UserId,
+            daily_mission::DailyMission,
+            daily_mission_builder::DailyMissionBuilder,
+            daily_mission_id::DailyMissionId,
+            history_query::{HistoryCursor, HistoryPage},
+            mission_difficulty::MissionDifficulty,
+            mission_schedule::MissionSchedule,
+            user_id::UserId,
         },
         repository::daily_mission_repository::DailyMissionRepository,
     };
-    use sqlx::MySqlPool;
+    use sqlx::{types::chrono::NaiveDate, MySqlPool};
     use uuid::Uuid;
 
     use crate::repository::daily_mission_repository_impl::DailyMissionRepositoryImpl;
@@ -312,9 +830,12 @@ mod test {
         create_test_user(&user_id).await?;
 
         let daily_mission = gen_daily_mission(&user_id, Some("hi"));
-        let service = DailyMissionRepositoryImpl::new(gen_pool().await?);
+        let pool = gen_pool().await?;
+        let service = DailyMissionRepositoryImpl::new(pool.clone());
 
-        let daily_id = service.create(&daily_mission).await?;
+        let mut tx = pool.begin().await?;
+        let daily_id = service.create(&mut tx, &daily_mission).await?;
+        tx.commit().await?;
         assert_eq!(daily_id, daily_mission.mission_id);
 
         service.delete(&daily_id, &UserId(user_id.clone())).await?;
@@ -327,14 +848,17 @@ mod test {
         let user_id = format!("{}_{}", USER_ID, gen_random_string());
         create_test_user(&user_id).await?;
 
-        let service = DailyMissionRepositoryImpl::new(gen_pool().await?);
+        let pool = gen_pool().await?;
+        let service = DailyMissionRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
         for _ in 0..10 {
             let daily_mission = gen_daily_mission(&user_id, Some("hi"));
-            service.create(&daily_mission).await?;
+            service.create(&mut tx, &daily_mission).await?;
         }
-        
-        let count = service.count(&UserId(user_id.clone())).await?;
+
+        let count = service.count(&mut tx, &UserId(user_id.clone())).await?;
         assert_eq!(count, 10);
+        tx.commit().await?;
 
         delete_test_user(&user_id).await?;
         Ok(())
@@ -414,6 +938,65 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_daily_find_by_user_id_with_schedule() -> MyResult<()> {
+        let user_id = format!("{}_{}", USER_ID, gen_random_string());
+        create_test_user(&user_id).await?;
+
+        let pool = gen_pool().await?;
+
+        let daily = gen_daily_mission(&user_id, None);
+        create_daily_batch(pool.clone(), daily.clone()).await?;
+        // まだ始まっていないスケジュールのミッションは今日実施しない
+        let schedule = MissionSchedule::EveryNDays {
+            interval: 2,
+            start_date: NaiveDate::from_ymd_opt(2999, 1, 1).unwrap(),
+        };
+        let mut scheduled = gen_daily_mission(&user_id, None);
+        scheduled.schedule = schedule.clone();
+        create_daily_batch(pool.clone(), scheduled.clone()).await?;
+
+        let service = DailyMissionRepositoryImpl::new(pool);
+        let missions = service.find_by_user_id(&UserId(user_id.clone())).await?;
+        assert_eq!(missions, vec![daily]);
+
+        let mission = service
+            .find_by_id(&scheduled.mission_id, &UserId(user_id.clone()))
+            .await?;
+        assert_eq!(mission.schedule, schedule);
+        assert!(!mission.is_due);
+
+        delete_test_user(&user_id).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_daily_find_records() -> MyResult<()> {
+        let user_id = format!("{}_{}", USER_ID, gen_random_string());
+        create_test_user(&user_id).await?;
+
+        let pool = gen_pool().await?;
+
+        let daily = gen_daily_mission(&user_id, None);
+        create_daily_batch(pool.clone(), daily.clone()).await?;
+        // 今日実施しないミッションも書き出しの対象になる
+        let mut scheduled = gen_daily_mission(&user_id, None);
+        scheduled.schedule = MissionSchedule::EveryNDays {
+            interval: 2,
+            start_date: NaiveDate::from_ymd_opt(2999, 1, 1).unwrap(),
+        };
+        create_daily_batch(pool.clone(), scheduled.clone()).await?;
+
+        let service = DailyMissionRepositoryImpl::new(pool);
+        let records = service.find_records(&UserId(user_id.clone())).await?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].title, daily.title);
+        assert_eq!(records[1].schedule, scheduled.schedule);
+
+        delete_test_user(&user_id).await?;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_daily_update() -> MyResult<()> {
         let user_id = format!("{}_{}", USER_ID, gen_random_string());
@@ -469,6 +1052,14 @@ mod test {
 
         assert_ne!(returned_mission.is_complete, mission.is_complete);
 
+        // 同じ日に2回目の完了は記録されない
+        let mut tx = pool.begin().await?;
+        let res = service
+            .set_complete_true(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        tx.commit().await?;
+        assert!(res.is_none());
+
         service
             .delete(&mission.mission_id, &UserId(user_id.clone()))
             .await?;
@@ -476,6 +1067,191 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_daily_add_progress() -> MyResult<()> {
+        let user_id = format!("{}_{}", USER_ID, gen_random_string());
+        create_test_user(&user_id).await?;
+
+        let pool = gen_pool().await?;
+
+        let mut mission = gen_daily_mission(&user_id, None);
+        mission.target_quantity = Some(30);
+        mission.unit = Some("pages".to_string());
+        create_daily_batch(pool.clone(), mission.clone()).await?;
+        // 数量目標の無いミッションには進捗を記録できない
+        let boolean_mission = gen_daily_mission(&user_id, None);
+        create_daily_batch(pool.clone(), boolean_mission.clone()).await?;
+
+        let service = DailyMissionRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        let progress = service
+            .add_progress(&mut tx, &mission.mission_id, &UserId(user_id.clone()), 10)
+            .await?;
+        assert_eq!(progress.quantity, 10);
+        assert_eq!(progress.target_quantity, 30);
+        assert!(!progress.is_complete);
+        // 同じ日の進捗は加算される
+        let progress = service
+            .add_progress(&mut tx, &mission.mission_id, &UserId(user_id.clone()), 25)
+            .await?;
+        assert_eq!(progress.quantity, 35);
+        service
+            .set_complete_true(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        let result = service
+            .add_progress(
+                &mut tx,
+                &boolean_mission.mission_id,
+                &UserId(user_id.clone()),
+                1,
+            )
+            .await;
+        assert!(result.is_err());
+        tx.commit().await?;
+
+        let returned_mission = service
+            .find_by_id(&mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        assert_eq!(returned_mission.progress, 35);
+        assert_eq!(returned_mission.unit, mission.unit);
+        assert!(returned_mission.is_complete);
+
+        // 日ごとの進捗は履歴として残る
+        let progress = service
+            .find_progress(&UserId(user_id.clone()), None, None, None)
+            .await?;
+        assert_eq!(progress.len(), 1);
+        assert_eq!(progress[0].quantity, 35);
+        assert!(progress[0].is_complete);
+        assert!(!progress[0].completion_undone);
+
+        // 完了を取り消しても進捗は残り、取り消したことが記録される
+        let mut tx = pool.begin().await?;
+        service
+            .undo_complete(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        let progress = service
+            .add_progress(&mut tx, &mission.mission_id, &UserId(user_id.clone()), 5)
+            .await?;
+        tx.commit().await?;
+        assert_eq!(progress.quantity, 40);
+        assert!(!progress.is_complete);
+        assert!(progress.completion_undone);
+
+        delete_test_user(&user_id).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_daily_undo_complete() -> MyResult<()> {
+        let user_id = format!("{}_{}", USER_ID, gen_random_string());
+        create_test_user(&user_id).await?;
+
+        let pool = gen_pool().await?;
+
+        let mut mission = gen_daily_mission(&user_id, None);
+        mission.difficulty = MissionDifficulty::Hard;
+        create_daily_batch(pool.clone(), mission.clone()).await?;
+
+        let service = DailyMissionRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        let completion = service
+            .set_complete_true(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await?
+            .ok_or("already completed")?;
+        assert!(completion.completed_at.is_some());
+        service
+            .save_exp_awarded(&mut tx, completion.completion_id, 4)
+            .await?;
+        tx.commit().await?;
+
+        let returned_mission = service
+            .find_by_id(&mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        assert_eq!(returned_mission.difficulty, MissionDifficulty::Hard);
+        assert!(returned_mission.is_complete);
+
+        // 取り消すと付与した経験値を含む完了記録が返る
+        let mut tx = pool.begin().await?;
+        let undone = service
+            .undo_complete(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        assert_eq!(undone.completion_id, completion.completion_id);
+        assert_eq!(undone.exp_awarded, 4);
+        // 二度目は取り消せない
+        let result = service
+            .undo_complete(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await;
+        assert!(result.is_err());
+        tx.commit().await?;
+
+        let returned_mission = service
+            .find_by_id(&mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        assert!(!returned_mission.is_complete);
+
+        delete_test_user(&user_id).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_daily_find_completions() -> MyResult<()> {
+        let user_id = format!("{}_{}", USER_ID, gen_random_string());
+        create_test_user(&user_id).await?;
+
+        let pool = gen_pool().await?;
+
+        let mission = gen_daily_mission(&user_id, None);
+        let other_mission = gen_daily_mission(&user_id, None);
+        create_daily_batch(pool.clone(), mission.clone()).await?;
+        create_daily_batch(pool.clone(), other_mission.clone()).await?;
+
+        let service = DailyMissionRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        service
+            .set_complete_true(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        service
+            .set_complete_true(&mut tx, &other_mission.mission_id, &UserId(user_id.clone()))
+            .await?;
+        tx.commit().await?;
+
+        let page = HistoryPage {
+            from: None,
+            to: None,
+            cursor: None,
+            limit: 10,
+        };
+        // ミッションを指定した場合はそのミッションの履歴のみ
+        let completions = service
+            .find_completions(&UserId(user_id.clone()), Some(&mission.mission_id), &page)
+            .await?;
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].mission_id, mission.mission_id);
+
+        // 指定しない場合はユーザーのすべてのミッションの履歴
+        let completions = service
+            .find_completions(&UserId(user_id.clone()), None, &page)
+            .await?;
+        assert_eq!(completions.len(), 2);
+
+        // カーソル以降のみ取得される
+        let page = HistoryPage {
+            cursor: Some(HistoryCursor {
+                date: completions[0].date,
+                completion_id: completions[0].completion_id,
+            }),
+            ..page
+        };
+        let next = service
+            .find_completions(&UserId(user_id.clone()), None, &page)
+            .await?;
+        assert_eq!(next, completions[1..]);
+
+        delete_test_user(&user_id).await?;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_daily_delete() -> MyResult<()> {
         let user_id = format!("{}_{}", USER_ID, gen_random_string());
@@ -491,9 +1267,10 @@ mod test {
             .delete(&mission.mission_id, &UserId(user_id.clone()))
             .await?;
 
-        if let Ok(_) = service
+        if service
             .find_by_id(&mission.mission_id, &UserId(user_id.clone()))
             .await
+            .is_ok()
         {
             delete_test_user(&user_id).await?;
             panic!("daily mission must be not exist, but exist");
@@ -550,7 +1327,7 @@ mod test {
 
         DailyMissionBuilder::new()
             .user_id(&UserId(user_id.to_string()))
-            .mission_id(&DailyMissionId(format!("{}", random_string)))
+            .mission_id(&DailyMissionId(random_string.to_string()))
             .title(&format!("title_{}", random_string))
             .description(&description.map(|e| e.to_owned()))
             .build()
@@ -559,7 +1336,7 @@ mod test {
     fn helper_update_mission(daily_mission: &mut DailyMission) {
         daily_mission.title = "updated".to_string();
 
-        if let Some(_) = daily_mission.description {
+        if daily_mission.description.is_some() {
             daily_mission.description = None
         } else {
             daily_mission.description = Some("updated".to_string());
@@ -569,8 +1346,10 @@ mod test {
     }
 
     async fn create_daily_batch(pool: MySqlPool, mission: DailyMission) -> MyResult<()> {
-        let service = DailyMissionRepositoryImpl::new(pool);
-        service.create(&mission).await?;
+        let service = DailyMissionRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        service.create(&mut tx, &mission).await?;
+        tx.commit().await?;
         Ok(())
     }
 }
@@ -0,0 +1,313 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        friend::{Friend, FriendStatus},
+        user_id::UserId,
+    },
+    repository::{friend_repository::FriendRepository, repository_error::RepositoryError},
+};
+use sqlx::{MySql, MySqlPool, Transaction};
+
+use super::to_repo_err;
+
+#[derive(Debug, Clone)]
+pub struct FriendRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl FriendRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl FriendRepository for FriendRepositoryImpl {
+    fn lock_users<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 申請し合った場合にデッドロックしないよう、常にuser_idの順にロックする
+            let locked: Vec<String> = sqlx::query_scalar(
+                r#"
+                    SELECT user_id FROM users
+                    WHERE user_id IN (?, ?)
+                    ORDER BY user_id
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(&other_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(locked.len() as u64)
+        })
+    }
+
+    fn find_status<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<FriendStatus>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let status: Option<String> = sqlx::query_scalar(
+                r#"
+                    SELECT status FROM friendships
+                    WHERE user_id = ? AND friend_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(&other_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            status
+                .map(|s| FriendStatus::from_column(&s).map_err(RepositoryError::InvalidData))
+                .transpose()
+        })
+    }
+
+    fn accepts_requests<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let accepts = sqlx::query_scalar(
+                r#"
+                    SELECT allow_friend_requests FROM users
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(accepts)
+        })
+    }
+
+    fn save<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+        status: FriendStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO friendships
+                    (user_id, friend_id, status, created_at)
+                    VALUES
+                    (?, ?, ?, UTC_TIMESTAMP())
+                    ON DUPLICATE KEY UPDATE
+                    status = VALUES(status),
+                    created_at = VALUES(created_at)
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(&other_id.0)
+            .bind(status.as_str())
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        other_id: &'a UserId,
+        status: FriendStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                    DELETE FROM friendships
+                    WHERE user_id = ? AND friend_id = ? AND status = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(&other_id.0)
+            .bind(status.as_str())
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            Ok(affected_len == 1)
+        })
+    }
+
+    fn find_by_status<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        status: FriendStatus,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Friend>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let friends = sqlx::query_as(
+                r#"
+                    SELECT u.user_id, u.user_name, f.created_at
+                    FROM friendships AS f
+                    INNER JOIN users AS u ON u.user_id = f.friend_id
+                    WHERE f.user_id = ? AND f.status = ?
+                    ORDER BY u.user_name, u.user_id
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(status.as_str())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(friends)
+        })
+    }
+
+    fn find_incoming_requests<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Friend>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let requests = sqlx::query_as(
+                r#"
+                    SELECT u.user_id, u.user_name, f.created_at
+                    FROM friendships AS f
+                    INNER JOIN users AS u ON u.user_id = f.user_id
+                    WHERE f.friend_id = ? AND f.status = ?
+                    ORDER BY f.created_at DESC
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(FriendStatus::Pending.as_str())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(requests)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{friend::FriendStatus, user_id::UserId},
+        repository::friend_repository::FriendRepository,
+    };
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+
+    use crate::repository::friend_repository_impl::FriendRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_friendship() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let other_id = UserId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+        create_user(pool.clone(), &other_id.0).await?;
+        let repo = FriendRepositoryImpl::new(pool.clone());
+
+        let mut tx = pool.begin().await?;
+        assert_eq!(repo.lock_users(&mut tx, &user_id, &other_id).await?, 2);
+        assert!(repo.accepts_requests(&mut tx, &other_id).await?);
+        assert_eq!(repo.find_status(&mut tx, &user_id, &other_id).await?, None);
+
+        repo.save(&mut tx, &user_id, &other_id, FriendStatus::Pending)
+            .await?;
+        assert_eq!(
+            repo.find_status(&mut tx, &user_id, &other_id).await?,
+            Some(FriendStatus::Pending)
+        );
+        tx.commit().await?;
+
+        // 送った申請と受け取った申請
+        let outgoing = repo.find_by_status(&user_id, FriendStatus::Pending).await?;
+        assert_eq!(outgoing.len(), 1);
+        assert_eq!(outgoing[0].user_id, other_id);
+        let incoming = repo.find_incoming_requests(&other_id).await?;
+        assert_eq!(incoming.len(), 1);
+        assert_eq!(incoming[0].user_id, user_id);
+
+        // 承認すると両方向のフレンドになる
+        let mut tx = pool.begin().await?;
+        repo.save(&mut tx, &user_id, &other_id, FriendStatus::Accepted)
+            .await?;
+        repo.save(&mut tx, &other_id, &user_id, FriendStatus::Accepted)
+            .await?;
+        tx.commit().await?;
+        assert_eq!(
+            repo.find_by_status(&other_id, FriendStatus::Accepted)
+                .await?
+                .len(),
+            1
+        );
+        assert!(repo.find_incoming_requests(&other_id).await?.is_empty());
+
+        // statusが一致しない場合は削除されない
+        let mut tx = pool.begin().await?;
+        assert!(
+            !repo
+                .delete(&mut tx, &user_id, &other_id, FriendStatus::Pending)
+                .await?
+        );
+        assert!(
+            repo.delete(&mut tx, &user_id, &other_id, FriendStatus::Accepted)
+                .await?
+        );
+        tx.commit().await?;
+
+        delete_test_user(pool.clone(), &user_id.0).await?;
+        delete_test_user(pool, &other_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
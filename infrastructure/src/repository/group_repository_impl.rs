@@ -0,0 +1,856 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        group::{Group, GroupInvite, GroupMember, GroupRole},
+        group_id::GroupId,
+        group_mission::{GroupMission, GroupMissionCompletion},
+        group_mission_id::GroupMissionId,
+        user_id::UserId,
+    },
+    repository::{group_repository::GroupRepository, repository_error::RepositoryError},
+};
+use sqlx::{types::chrono::NaiveDate, MySql, MySqlPool, Row, Transaction};
+
+use super::{current_date, to_repo_err};
+
+#[derive(Debug, Clone)]
+pub struct GroupRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl GroupRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+// 所属しているグループの一覧と1件の取得で共通のSELECT
+static GROUP_SELECT: &str = r#"
+    SELECT g.group_id, g.name, m.role, g.created_at,
+    (SELECT COUNT(*) FROM group_members AS c WHERE c.group_id = g.group_id) AS member_count
+    FROM user_groups AS g
+    INNER JOIN group_members AS m ON m.group_id = g.group_id
+    WHERE m.user_id = ?
+"#;
+
+impl GroupRepository for GroupRepositoryImpl {
+    fn create<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        name: &'a str,
+        owner_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO user_groups
+                    (group_id, name, created_at)
+                    VALUES
+                    (?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(name)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+
+            sqlx::query(
+                r#"
+                    INSERT INTO group_members
+                    (group_id, user_id, role, joined_at)
+                    VALUES
+                    (?, ?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&owner_id.0)
+            .bind(GroupRole::Owner.as_str())
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    DELETE FROM user_groups WHERE group_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn lock_group<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    SELECT group_id FROM user_groups
+                    WHERE group_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&group_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .ok_or(RepositoryError::NotFound)?;
+
+            let count: i64 = sqlx::query_scalar(
+                r#"
+                    SELECT COUNT(*) FROM group_members
+                    WHERE group_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(count as u32)
+        })
+    }
+
+    fn find_role<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<GroupRole>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let role: Option<String> = sqlx::query_scalar(
+                r#"
+                    SELECT role FROM group_members
+                    WHERE group_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&user_id.0)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            role.map(|r| GroupRole::from_column(&r).map_err(RepositoryError::InvalidData))
+                .transpose()
+        })
+    }
+
+    fn find_role_in<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<GroupRole>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let role: Option<String> = sqlx::query_scalar(
+                r#"
+                    SELECT role FROM group_members
+                    WHERE group_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&user_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            role.map(|r| GroupRole::from_column(&r).map_err(RepositoryError::InvalidData))
+                .transpose()
+        })
+    }
+
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Group>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let groups = sqlx::query_as(&format!("{} ORDER BY g.created_at, g.id", GROUP_SELECT))
+                .bind(&user_id.0)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(to_repo_err)?;
+            Ok(groups)
+        })
+    }
+
+    fn find_by_id<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Group, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let group = sqlx::query_as(&format!("{} AND g.group_id = ?", GROUP_SELECT))
+                .bind(&user_id.0)
+                .bind(&group_id.0)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(to_repo_err)?;
+            Ok(group)
+        })
+    }
+
+    fn find_members<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupMember>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let members = sqlx::query_as(
+                r#"
+                    SELECT m.user_id, u.user_name, m.role, m.joined_at
+                    FROM group_members AS m
+                    INNER JOIN users AS u ON u.user_id = m.user_id
+                    WHERE m.group_id = ?
+                    ORDER BY m.joined_at, m.user_id
+                "#,
+            )
+            .bind(&group_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(members)
+        })
+    }
+
+    fn find_member_ids<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<UserId>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let ids: Vec<String> = sqlx::query_scalar(
+                r#"
+                    SELECT user_id FROM group_members
+                    WHERE group_id = ?
+                    ORDER BY user_id
+                "#,
+            )
+            .bind(&group_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(ids.into_iter().map(UserId).collect())
+        })
+    }
+
+    fn add_member<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+        role: GroupRole,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO group_members
+                    (group_id, user_id, role, joined_at)
+                    VALUES
+                    (?, ?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&user_id.0)
+            .bind(role.as_str())
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn update_role<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+        role: GroupRole,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    UPDATE group_members
+                    SET role = ?
+                    WHERE group_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(role.as_str())
+            .bind(&group_id.0)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn remove_member<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM group_members
+                    WHERE group_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn save_invite<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+        invited_by: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 招待するユーザーが存在しない場合は1行も挿入されない
+            let result = sqlx::query(
+                r#"
+                    INSERT INTO group_invites
+                    (group_id, user_id, invited_by, created_at)
+                    SELECT ?, user_id, ?, UTC_TIMESTAMP()
+                    FROM users
+                    WHERE user_id = ?
+                    ON DUPLICATE KEY UPDATE
+                    invited_by = VALUES(invited_by), created_at = VALUES(created_at)
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&invited_by.0)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    fn delete_invite<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM group_invites
+                    WHERE group_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn find_invites<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupInvite>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let invites = sqlx::query_as(
+                r#"
+                    SELECT i.group_id, g.name AS group_name, i.invited_by,
+                    u.user_name AS invited_by_name, i.created_at
+                    FROM group_invites AS i
+                    INNER JOIN user_groups AS g ON g.group_id = i.group_id
+                    INNER JOIN users AS u ON u.user_id = i.invited_by
+                    WHERE i.user_id = ?
+                    ORDER BY i.created_at DESC
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(invites)
+        })
+    }
+
+    fn count_missions<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let count: i64 = sqlx::query_scalar(
+                r#"
+                    SELECT COUNT(*) FROM group_missions
+                    WHERE group_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(count as u32)
+        })
+    }
+
+    fn create_mission<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission: &'a GroupMission,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO group_missions
+                    (mission_id, group_id, title, descriptions, bonus_exp, created_at)
+                    VALUES
+                    (?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&mission.mission_id.0)
+            .bind(&mission.group_id.0)
+            .bind(&mission.title)
+            .bind(&mission.descriptions)
+            .bind(mission.bonus_exp)
+            .bind(mission.created_at)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn find_missions<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupMission>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let missions = sqlx::query_as(
+                r#"
+                    SELECT mission_id, group_id, title, descriptions, bonus_exp, created_at
+                    FROM group_missions
+                    WHERE group_id = ?
+                    ORDER BY id
+                "#,
+            )
+            .bind(&group_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(missions)
+        })
+    }
+
+    fn delete_mission<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        mission_id: &'a GroupMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM group_missions
+                    WHERE group_id = ? AND mission_id = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&mission_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn lock_mission<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        group_id: &'a GroupId,
+        mission_id: &'a GroupMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<GroupMission, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // ロックを伴う読み込みでは一貫性読み取りのスナップショットが作られないため、
+            // ロックを待っている間に完了した他のメンバーの完了記録もこの後の読み込みで見える
+            let mission = sqlx::query_as(
+                r#"
+                    SELECT mission_id, group_id, title, descriptions, bonus_exp, created_at
+                    FROM group_missions
+                    WHERE group_id = ? AND mission_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(&mission_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(mission)
+        })
+    }
+
+    fn save_completion<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        completion: &'a GroupMissionCompletion,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    INSERT IGNORE INTO group_mission_completions
+                    (mission_id, user_id, date, exp_awarded, completed_at)
+                    VALUES
+                    (?, ?, ?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&completion.mission_id.0)
+            .bind(&completion.user_id.0)
+            .bind(completion.date)
+            .bind(completion.exp_awarded)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn find_completed_members<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a GroupMissionId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<UserId>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let ids: Vec<String> = sqlx::query_scalar(
+                r#"
+                    SELECT c.user_id
+                    FROM group_mission_completions AS c
+                    INNER JOIN group_missions AS gm ON gm.mission_id = c.mission_id
+                    INNER JOIN group_members AS m
+                    ON m.group_id = gm.group_id AND m.user_id = c.user_id
+                    WHERE c.mission_id = ? AND c.date = ?
+                    ORDER BY c.completed_at, c.user_id
+                "#,
+            )
+            .bind(&mission_id.0)
+            .bind(date)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(ids.into_iter().map(UserId).collect())
+        })
+    }
+
+    fn find_completions<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        date: NaiveDate,
+    ) -> Pin<
+        Box<dyn Future<Output = Result<Vec<GroupMissionCompletion>, RepositoryError>> + Send + 'a>,
+    > {
+        Box::pin(async move {
+            let rows = sqlx::query(
+                r#"
+                    SELECT c.mission_id, c.user_id, c.date, c.exp_awarded
+                    FROM group_mission_completions AS c
+                    INNER JOIN group_missions AS gm ON gm.mission_id = c.mission_id
+                    INNER JOIN group_members AS m
+                    ON m.group_id = gm.group_id AND m.user_id = c.user_id
+                    WHERE gm.group_id = ? AND c.date = ?
+                    ORDER BY c.completed_at, c.user_id
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(date)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            rows.into_iter()
+                .map(|row| {
+                    Ok(GroupMissionCompletion {
+                        mission_id: GroupMissionId(row.try_get("mission_id")?),
+                        user_id: UserId(row.try_get("user_id")?),
+                        date: row.try_get("date")?,
+                        exp_awarded: row.try_get("exp_awarded")?,
+                    })
+                })
+                .collect::<Result<_, sqlx::Error>>()
+                .map_err(to_repo_err)
+        })
+    }
+
+    fn save_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a GroupMissionId,
+        date: NaiveDate,
+        exp_awarded: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    INSERT IGNORE INTO group_mission_bonus
+                    (mission_id, date, exp_awarded, created_at)
+                    VALUES
+                    (?, ?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&mission_id.0)
+            .bind(date)
+            .bind(exp_awarded)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn find_bonus_missions<'a>(
+        &'a self,
+        group_id: &'a GroupId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<GroupMissionId>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let ids: Vec<String> = sqlx::query_scalar(
+                r#"
+                    SELECT b.mission_id
+                    FROM group_mission_bonus AS b
+                    INNER JOIN group_missions AS gm ON gm.mission_id = b.mission_id
+                    WHERE gm.group_id = ? AND b.date = ?
+                "#,
+            )
+            .bind(&group_id.0)
+            .bind(date)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(ids.into_iter().map(GroupMissionId).collect())
+        })
+    }
+
+    fn find_current_date<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move { current_date(&self.pool, user_id).await })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            group::GroupRole, group_id::GroupId, group_mission::GroupMission,
+            group_mission::GroupMissionCompletion, group_mission_id::GroupMissionId,
+            user_id::UserId,
+        },
+        repository::{group_repository::GroupRepository, repository_error::RepositoryError},
+    };
+    use sqlx::{types::chrono::Utc, MySqlPool};
+    use uuid::Uuid;
+
+    use crate::repository::group_repository_impl::GroupRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_group_members() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let owner_id = UserId(gen_random_str());
+        let member_id = UserId(gen_random_str());
+        create_user(pool.clone(), &owner_id.0).await?;
+        create_user(pool.clone(), &member_id.0).await?;
+        let repo = GroupRepositoryImpl::new(pool.clone());
+        let group_id = GroupId(gen_random_str());
+
+        let mut tx = pool.begin().await?;
+        repo.create(&mut tx, &group_id, "test_group", &owner_id)
+            .await?;
+        assert_eq!(repo.lock_group(&mut tx, &group_id).await?, 1);
+        repo.save_invite(&mut tx, &group_id, &member_id, &owner_id)
+            .await?;
+        // 再招待は日時の更新になる
+        repo.save_invite(&mut tx, &group_id, &member_id, &owner_id)
+            .await?;
+        // 存在しないユーザーは招待できない
+        assert!(matches!(
+            repo.save_invite(&mut tx, &group_id, &UserId(gen_random_str()), &owner_id)
+                .await,
+            Err(RepositoryError::NotFound)
+        ));
+        tx.commit().await?;
+
+        let invites = repo.find_invites(&member_id).await?;
+        assert_eq!(invites.len(), 1);
+        assert_eq!(invites[0].group_name, "test_group");
+
+        let mut tx = pool.begin().await?;
+        assert!(repo.delete_invite(&mut tx, &group_id, &member_id).await?);
+        repo.add_member(&mut tx, &group_id, &member_id, GroupRole::Member)
+            .await?;
+        repo.update_role(&mut tx, &group_id, &member_id, GroupRole::Admin)
+            .await?;
+        tx.commit().await?;
+
+        assert_eq!(
+            repo.find_role(&group_id, &member_id).await?,
+            Some(GroupRole::Admin)
+        );
+        let group = repo.find_by_id(&group_id, &member_id).await?;
+        assert_eq!(group.member_count, 2);
+        assert_eq!(group.role, GroupRole::Admin);
+        assert_eq!(repo.find_by_user_id(&owner_id).await?.len(), 1);
+        assert_eq!(repo.find_members(&group_id).await?.len(), 2);
+
+        let mut tx = pool.begin().await?;
+        assert!(repo.remove_member(&mut tx, &group_id, &member_id).await?);
+        assert!(!repo.remove_member(&mut tx, &group_id, &member_id).await?);
+        repo.delete(&mut tx, &group_id).await?;
+        tx.commit().await?;
+        assert!(repo.find_role(&group_id, &owner_id).await?.is_none());
+
+        delete_test_user(pool.clone(), &owner_id.0).await?;
+        delete_test_user(pool, &member_id.0).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_group_mission_completion() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let owner_id = UserId(gen_random_str());
+        let member_id = UserId(gen_random_str());
+        create_user(pool.clone(), &owner_id.0).await?;
+        create_user(pool.clone(), &member_id.0).await?;
+        let repo = GroupRepositoryImpl::new(pool.clone());
+        let group_id = GroupId(gen_random_str());
+        let mission = GroupMission {
+            mission_id: GroupMissionId(gen_random_str()),
+            group_id: group_id.clone(),
+            title: "test_mission".to_string(),
+            descriptions: None,
+            bonus_exp: 10,
+            created_at: Utc::now().naive_utc(),
+        };
+        let date = repo.find_current_date(&owner_id).await?;
+
+        let mut tx = pool.begin().await?;
+        repo.create(&mut tx, &group_id, "test_group", &owner_id)
+            .await?;
+        repo.add_member(&mut tx, &group_id, &member_id, GroupRole::Member)
+            .await?;
+        repo.create_mission(&mut tx, &mission).await?;
+        assert_eq!(repo.count_missions(&mut tx, &group_id).await?, 1);
+        tx.commit().await?;
+
+        let mut tx = pool.begin().await?;
+        repo.lock_mission(&mut tx, &group_id, &mission.mission_id)
+            .await?;
+        let completion = GroupMissionCompletion {
+            mission_id: mission.mission_id.clone(),
+            user_id: owner_id.clone(),
+            date,
+            exp_awarded: 2,
+        };
+        assert!(repo.save_completion(&mut tx, &completion).await?);
+        // 同じ日には一度しか完了できない
+        assert!(!repo.save_completion(&mut tx, &completion).await?);
+        assert_eq!(
+            repo.find_completed_members(&mut tx, &mission.mission_id, date)
+                .await?,
+            vec![owner_id.clone()]
+        );
+        assert!(
+            repo.save_bonus(&mut tx, &mission.mission_id, date, 10)
+                .await?
+        );
+        assert!(
+            !repo
+                .save_bonus(&mut tx, &mission.mission_id, date, 10)
+                .await?
+        );
+        tx.commit().await?;
+
+        assert_eq!(repo.find_completions(&group_id, date).await?.len(), 1);
+        assert_eq!(
+            repo.find_bonus_missions(&group_id, date).await?,
+            vec![mission.mission_id.clone()]
+        );
+
+        let mut tx = pool.begin().await?;
+        assert!(
+            repo.delete_mission(&mut tx, &group_id, &mission.mission_id)
+                .await?
+        );
+        repo.delete(&mut tx, &group_id).await?;
+        tx.commit().await?;
+
+        delete_test_user(pool.clone(), &owner_id.0).await?;
+        delete_test_user(pool, &member_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
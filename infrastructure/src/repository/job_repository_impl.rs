@@ -0,0 +1,579 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        domain_event::LevelUp,
+        scheduled_job::{JobStatus, ScheduledJob, MAX_JOB_ATTEMPTS},
+    },
+    repository::{job_repository::JobRepository, repository_error::RepositoryError},
+};
+use sqlx::{types::chrono::Utc, MySql, MySqlPool, Row, Transaction};
+
+use super::{parse_time_zone, to_repo_err, utc_offset_at};
+
+// 実行中のままこの秒数を過ぎたジョブは、実行していたインスタンスが停止したとみなして実行待ちに戻す
+static JOB_STUCK_SECONDS: u32 = 600;
+
+#[derive(Debug, Clone)]
+pub struct JobRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl JobRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl JobRepository for JobRepositoryImpl {
+    fn acquire_lease<'a>(
+        &'a self,
+        name: &'a str,
+        holder: &'a str,
+        ttl_seconds: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // holderを先に更新するため、expires_atの条件では更新後のholderと比較される
+            // 自分のリースか期限切れのリースの場合だけ、holderとexpires_atを置き換える
+            sqlx::query(
+                r#"
+                    INSERT INTO job_leases
+                    (name, holder, expires_at)
+                    VALUES
+                    (?, ?, DATE_ADD(UTC_TIMESTAMP(), INTERVAL ? SECOND))
+                    ON DUPLICATE KEY UPDATE
+                    holder = IF(
+                        holder = VALUES(holder) OR expires_at < UTC_TIMESTAMP(),
+                        VALUES(holder),
+                        holder
+                    ),
+                    expires_at = IF(holder = VALUES(holder), VALUES(expires_at), expires_at)
+                "#,
+            )
+            .bind(name)
+            .bind(holder)
+            .bind(ttl_seconds)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            let row = sqlx::query(
+                r#"
+                    SELECT holder FROM job_leases
+                    WHERE name = ?
+                "#,
+            )
+            .bind(name)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            let current: String = row.try_get("holder").map_err(to_repo_err)?;
+            Ok(current == holder)
+        })
+    }
+
+    fn refresh_utc_offsets<'a>(
+        &'a self,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // MySQLのタイムゾーンのテーブルに依存しないよう、オフセットはタイムゾーンごとにアプリケーションで求める
+            let time_zones: Vec<String> = sqlx::query_scalar(
+                r#"
+                    SELECT DISTINCT time_zone FROM users
+                "#,
+            )
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            let now = Utc::now().naive_utc();
+            let mut updated = 0;
+            for time_zone in time_zones {
+                let utc_offset = utc_offset_at(parse_time_zone(&time_zone), now);
+                updated += sqlx::query(
+                    r#"
+                        UPDATE users
+                        SET utc_offset = ?
+                        WHERE time_zone = ?
+                        AND utc_offset <> ?
+                    "#,
+                )
+                .bind(utc_offset)
+                .bind(&time_zone)
+                .bind(utc_offset)
+                .execute(&self.pool)
+                .await
+                .map_err(to_repo_err)?
+                .rows_affected();
+            }
+            Ok(updated)
+        })
+    }
+
+    fn plan_jobs<'a>(
+        &'a self,
+        grace_seconds: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // job_keyに今日の日付を含めるため、同じ日のジョブは一度だけ登録される
+            // 時刻はユーザーのタイムゾーンのため、run_atはUTCに変換して保存する
+            let reminders = sqlx::query(
+                r#"
+                    INSERT IGNORE INTO scheduled_jobs
+                    (job_key, kind, user_id, mission_id, local_date, run_at, created_at, updated_at)
+                    SELECT
+                    CONCAT('reminder:', planned.mission_id, ':', planned.local_date),
+                    'reminder',
+                    planned.user_id,
+                    planned.mission_id,
+                    planned.local_date,
+                    planned.run_at,
+                    UTC_TIMESTAMP(),
+                    UTC_TIMESTAMP()
+                    FROM (
+                        SELECT
+                        daily_mission.user_id,
+                        daily_mission.mission_id,
+                        DATE(DATE_ADD(UTC_TIMESTAMP(), INTERVAL users.utc_offset SECOND))
+                        AS local_date,
+                        DATE_SUB(
+                            TIMESTAMP(
+                                DATE(DATE_ADD(UTC_TIMESTAMP(), INTERVAL users.utc_offset SECOND)),
+                                daily_mission.reminder_time
+                            ),
+                            INTERVAL users.utc_offset SECOND
+                        ) AS run_at
+                        FROM daily_mission
+                        JOIN users ON daily_mission.user_id = users.user_id
+                        WHERE daily_mission.reminder_time IS NOT NULL
+                    ) AS planned
+                    WHERE planned.run_at >= DATE_SUB(UTC_TIMESTAMP(), INTERVAL ? SECOND)
+                "#,
+            )
+            .bind(grace_seconds)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            let summaries = sqlx::query(
+                r#"
+                    INSERT IGNORE INTO scheduled_jobs
+                    (job_key, kind, user_id, mission_id, local_date, run_at, created_at, updated_at)
+                    SELECT
+                    CONCAT('summary:', planned.user_id, ':', planned.local_date),
+                    'summary',
+                    planned.user_id,
+                    NULL,
+                    planned.local_date,
+                    planned.run_at,
+                    UTC_TIMESTAMP(),
+                    UTC_TIMESTAMP()
+                    FROM (
+                        SELECT
+                        user_id,
+                        DATE(DATE_ADD(UTC_TIMESTAMP(), INTERVAL utc_offset SECOND)) AS local_date,
+                        DATE_SUB(
+                            TIMESTAMP(
+                                DATE(DATE_ADD(UTC_TIMESTAMP(), INTERVAL utc_offset SECOND)),
+                                summary_time
+                            ),
+                            INTERVAL utc_offset SECOND
+                        ) AS run_at
+                        FROM users
+                        WHERE summary_time IS NOT NULL
+                    ) AS planned
+                    WHERE planned.run_at >= DATE_SUB(UTC_TIMESTAMP(), INTERVAL ? SECOND)
+                "#,
+            )
+            .bind(grace_seconds)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            Ok(reminders + summaries)
+        })
+    }
+
+    fn enqueue_level_up<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        event: &'a LevelUp,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 取り消して再びレベルが上がった場合も通知するよう、job_keyはレベルアップごとに異なる値にする
+            // 期限切れと判定されないよう、local_dateはユーザーのタイムゾーンにおける今日の日付にする
+            sqlx::query(
+                r#"
+                    INSERT INTO scheduled_jobs
+                    (job_key, kind, user_id, local_date, run_at, new_level, total_exp,
+                    created_at, updated_at)
+                    SELECT
+                    CONCAT('level_up:', UUID()),
+                    'level_up',
+                    user_id,
+                    DATE(DATE_ADD(UTC_TIMESTAMP(), INTERVAL utc_offset SECOND)),
+                    UTC_TIMESTAMP(),
+                    ?,
+                    ?,
+                    UTC_TIMESTAMP(),
+                    UTC_TIMESTAMP()
+                    FROM users
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(event.new_level)
+            .bind(event.total_exp)
+            .bind(&event.user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn claim_due<'a>(
+        &'a self,
+        holder: &'a str,
+        limit: u32,
+        grace_seconds: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<ScheduledJob>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 期限を過ぎたジョブと、対象の日付が今日(ユーザーのタイムゾーン)でなくなったジョブは実行しない
+            sqlx::query(
+                r#"
+                    UPDATE scheduled_jobs
+                    JOIN users ON scheduled_jobs.user_id = users.user_id
+                    SET
+                    scheduled_jobs.status = 'skipped',
+                    scheduled_jobs.last_error = 'expired',
+                    scheduled_jobs.updated_at = UTC_TIMESTAMP()
+                    WHERE scheduled_jobs.status = 'pending'
+                    AND (
+                        scheduled_jobs.run_at < DATE_SUB(UTC_TIMESTAMP(), INTERVAL ? SECOND)
+                        OR scheduled_jobs.local_date
+                        < DATE(DATE_ADD(UTC_TIMESTAMP(), INTERVAL users.utc_offset SECOND))
+                    )
+                "#,
+            )
+            .bind(grace_seconds)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            // 停止したインスタンスが実行中のまま残したジョブを戻す(再試行の上限に達した場合は失敗にする)
+            sqlx::query(
+                r#"
+                    UPDATE scheduled_jobs
+                    SET
+                    status = IF(attempts >= ?, 'failed', 'pending'),
+                    locked_by = NULL,
+                    updated_at = UTC_TIMESTAMP()
+                    WHERE status = 'running'
+                    AND updated_at < DATE_SUB(UTC_TIMESTAMP(), INTERVAL ? SECOND)
+                "#,
+            )
+            .bind(MAX_JOB_ATTEMPTS)
+            .bind(JOB_STUCK_SECONDS)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+
+            // リースが切れた直後に他のインスタンスと重なった場合でも同じジョブを取得しないよう、
+            // 他のトランザクションがロックしている行は飛ばす
+            let mut tx = self.pool.begin().await.map_err(to_repo_err)?;
+            let mut jobs: Vec<ScheduledJob> = sqlx::query_as(
+                r#"
+                    SELECT
+                    id, kind, user_id, mission_id, local_date, run_at, new_level, total_exp,
+                    attempts
+                    FROM scheduled_jobs
+                    WHERE status = 'pending'
+                    AND run_at <= UTC_TIMESTAMP()
+                    ORDER BY run_at, id
+                    LIMIT ?
+                    FOR UPDATE SKIP LOCKED
+                "#,
+            )
+            .bind(limit)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(to_repo_err)?;
+            for job in jobs.iter_mut() {
+                sqlx::query(
+                    r#"
+                        UPDATE scheduled_jobs
+                        SET
+                        status = 'running',
+                        locked_by = ?,
+                        attempts = attempts + 1,
+                        updated_at = UTC_TIMESTAMP()
+                        WHERE id = ?
+                    "#,
+                )
+                .bind(holder)
+                .bind(job.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(to_repo_err)?;
+                job.attempts += 1;
+            }
+            tx.commit().await.map_err(to_repo_err)?;
+            Ok(jobs)
+        })
+    }
+
+    fn finish<'a>(
+        &'a self,
+        job_id: i64,
+        status: JobStatus,
+        error: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    UPDATE scheduled_jobs
+                    SET
+                    status = ?,
+                    last_error = ?,
+                    locked_by = NULL,
+                    updated_at = UTC_TIMESTAMP()
+                    WHERE id = ?
+                "#,
+            )
+            .bind(status.as_str())
+            .bind(error)
+            .bind(job_id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn retry<'a>(
+        &'a self,
+        job_id: i64,
+        delay_seconds: u32,
+        error: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    UPDATE scheduled_jobs
+                    SET
+                    status = 'pending',
+                    run_at = DATE_ADD(UTC_TIMESTAMP(), INTERVAL ? SECOND),
+                    last_error = ?,
+                    locked_by = NULL,
+                    updated_at = UTC_TIMESTAMP()
+                    WHERE id = ?
+                "#,
+            )
+            .bind(delay_seconds)
+            .bind(error)
+            .bind(job_id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn purge<'a>(
+        &'a self,
+        days: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM scheduled_jobs
+                    WHERE status IN ('done', 'skipped', 'failed')
+                    AND updated_at < DATE_SUB(UTC_TIMESTAMP(), INTERVAL ? DAY)
+                "#,
+            )
+            .bind(days)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            domain_event::LevelUp,
+            scheduled_job::{JobKind, JobStatus},
+            user_id::UserId,
+        },
+        repository::job_repository::JobRepository,
+    };
+    use sqlx::{types::chrono::Utc, MySqlPool, Row};
+    use uuid::Uuid;
+
+    use crate::repository::job_repository_impl::JobRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_acquire_lease() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let repo = JobRepositoryImpl::new(pool.clone());
+        let name = gen_random_str();
+        let (first, second) = (gen_random_str(), gen_random_str());
+
+        assert!(repo.acquire_lease(&name, &first, 60).await?);
+        // 有効なリースは他のインスタンスが取得できず、持ち主は延長できる
+        assert!(!repo.acquire_lease(&name, &second, 60).await?);
+        assert!(repo.acquire_lease(&name, &first, 60).await?);
+
+        // 期限切れのリースは他のインスタンスが引き継ぐ
+        sqlx::query(
+            r#"
+                UPDATE job_leases
+                SET expires_at = DATE_SUB(UTC_TIMESTAMP(), INTERVAL 1 SECOND)
+                WHERE name = ?
+            "#,
+        )
+        .bind(&name)
+        .execute(&pool)
+        .await?;
+        assert!(repo.acquire_lease(&name, &second, 60).await?);
+        assert!(!repo.acquire_lease(&name, &first, 60).await?);
+
+        sqlx::query("DELETE FROM job_leases WHERE name = ?")
+            .bind(&name)
+            .execute(&pool)
+            .await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_plan_and_claim() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = gen_random_str();
+        let holder = gen_random_str();
+        create_user(pool.clone(), &user_id).await?;
+        // まとめの時刻を現在の時刻(ユーザーのタイムゾーン)にする
+        sqlx::query(
+            r#"
+                UPDATE users
+                SET summary_time = TIME(DATE_ADD(UTC_TIMESTAMP(), INTERVAL utc_offset SECOND))
+                WHERE user_id = ?
+            "#,
+        )
+        .bind(&user_id)
+        .execute(&pool)
+        .await?;
+        let repo = JobRepositoryImpl::new(pool.clone());
+
+        assert!(repo.plan_jobs(3600).await? >= 1);
+        // 同じ日のジョブは再び登録しない
+        repo.plan_jobs(3600).await?;
+        let jobs = repo.claim_due(&holder, 1000, 3600).await?;
+        let job = jobs
+            .iter()
+            .find(|job| job.user_id.0 == user_id)
+            .expect("summary job is not claimed");
+        assert_eq!(job.kind, JobKind::Summary);
+        assert_eq!(job.attempts, 1);
+
+        // 再試行するジョブは待つ間は取得されない
+        repo.retry(job.id, 3600, "test_error").await?;
+        let jobs = repo.claim_due(&holder, 1000, 3600).await?;
+        assert!(jobs.iter().all(|job| job.user_id.0 != user_id));
+
+        repo.finish(job.id, JobStatus::Done, None).await?;
+        let row = sqlx::query(
+            r#"
+                SELECT COUNT(*) AS count, MAX(status) AS status
+                FROM scheduled_jobs
+                WHERE user_id = ?
+            "#,
+        )
+        .bind(&user_id)
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(row.try_get::<i64, _>("count")?, 1);
+        assert_eq!(row.try_get::<String, _>("status")?, "done");
+
+        delete_test_user(pool, &user_id).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_level_up() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = gen_random_str();
+        let holder = gen_random_str();
+        create_user(pool.clone(), &user_id).await?;
+        let repo = JobRepositoryImpl::new(pool.clone());
+        let event = LevelUp {
+            user_id: UserId(user_id.clone()),
+            previous_level: 1,
+            new_level: 2,
+            total_exp: 10,
+            occurred_at: Utc::now().naive_utc(),
+        };
+
+        // ロールバックしたレベルアップは通知しない
+        let mut tx = pool.begin().await?;
+        repo.enqueue_level_up(&mut tx, &event).await?;
+        tx.rollback().await?;
+        let jobs = repo.claim_due(&holder, 1000, 3600).await?;
+        assert!(jobs.iter().all(|job| job.user_id.0 != user_id));
+
+        let mut tx = pool.begin().await?;
+        repo.enqueue_level_up(&mut tx, &event).await?;
+        repo.enqueue_level_up(&mut tx, &event).await?;
+        tx.commit().await?;
+        let jobs = repo.claim_due(&holder, 1000, 3600).await?;
+        let jobs: Vec<_> = jobs.iter().filter(|job| job.user_id.0 == user_id).collect();
+        assert_eq!(jobs.len(), 2);
+        assert_eq!(jobs[0].kind, JobKind::LevelUp);
+        assert_eq!(jobs[0].new_level, Some(2));
+        assert_eq!(jobs[0].total_exp, Some(10));
+
+        delete_test_user(pool, &user_id).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,465 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        leaderboard::{LeaderboardEntry, LeaderboardMetric, LeaderboardPage, LeaderboardScope},
+        stats::week_start,
+        user_id::UserId,
+    },
+    repository::{
+        leaderboard_repository::LeaderboardRepository, repository_error::RepositoryError,
+    },
+};
+use sqlx::{mysql::MySqlArguments, query::QueryAs, types::chrono::NaiveDate, MySql, MySqlPool};
+
+use super::{current_date, to_repo_err};
+
+// ランキングの対象の行と、scoreとして使用する列
+// conditionでランキングに含めない行を除く
+struct RankingSource {
+    table: &'static str,
+    score: &'static str,
+    condition: &'static str,
+}
+
+impl RankingSource {
+    // scopeのランキングに含める行を取得するクエリ
+    // extraには行の条件に続けるAND句、ORDER BY句、LIMIT句を渡す
+    // バインドする値はbind_rows()の後にextraの値
+    fn rows(&self, scope: LeaderboardScope, extra: &str) -> String {
+        let Self {
+            table,
+            score,
+            condition,
+        } = self;
+        match scope {
+            // 公開設定が有効なユーザー全体を(score DESC, user_id)のインデックスで辿る
+            LeaderboardScope::Global => format!(
+                r#"
+                    SELECT s.user_id, u.user_name, CAST({score} AS SIGNED) AS score
+                    FROM {table}
+                    INNER JOIN users AS u ON u.user_id = s.user_id
+                    WHERE {condition}
+                    AND u.show_on_global_leaderboard
+                    {extra}
+                "#
+            ),
+            // 閲覧しているユーザーのフレンド(friendships)と自分の行だけを読む
+            // 自分は公開設定に関わらず常に含める
+            LeaderboardScope::Friends => format!(
+                r#"
+                    SELECT s.user_id, u.user_name, CAST({score} AS SIGNED) AS score
+                    FROM (
+                        SELECT f.friend_id AS user_id
+                        FROM friendships AS f
+                        WHERE f.user_id = ?
+                        AND f.status = 'accepted'
+                        UNION
+                        SELECT ?
+                    ) AS m
+                    INNER JOIN {table} ON s.user_id = m.user_id
+                    INNER JOIN users AS u ON u.user_id = s.user_id
+                    WHERE {condition}
+                    AND (u.user_id = ? OR u.show_on_friend_leaderboard)
+                    {extra}
+                "#
+            ),
+        }
+    }
+}
+
+// RankingSource::rows()のクエリのextraより前の値をバインドする
+// 週間ランキングの場合はconditionに週の月曜日をバインドする
+fn bind_rows<'q, O>(
+    query: QueryAs<'q, MySql, O, MySqlArguments>,
+    scope: LeaderboardScope,
+    user_id: &'q UserId,
+    week: Option<NaiveDate>,
+) -> QueryAs<'q, MySql, O, MySqlArguments> {
+    let query = match scope {
+        LeaderboardScope::Global => query,
+        LeaderboardScope::Friends => query.bind(&user_id.0).bind(&user_id.0),
+    };
+    let query = match week {
+        Some(week) => query.bind(week),
+        None => query,
+    };
+    match scope {
+        LeaderboardScope::Global => query,
+        LeaderboardScope::Friends => query.bind(&user_id.0),
+    }
+}
+
+// 累計の経験値
+static EXP_SOURCE: RankingSource = RankingSource {
+    table: "user_exp AS s",
+    score: "s.experience_points",
+    condition: "s.experience_points IS NOT NULL",
+};
+
+// 閲覧しているユーザーのタイムゾーンにおける今週の獲得経験値
+// バインドする値は週の月曜日
+// 台帳の導入時の開始残高は今週獲得したものではないため、週ごとの集計には含まれない
+static WEEKLY_EXP_SOURCE: RankingSource = RankingSource {
+    table: "user_weekly_exp AS s",
+    score: "s.exp_gained",
+    condition: "s.week_start = ? AND s.exp_gained > 0",
+};
+
+// すべてのミッションを完了した日の現在の連続日数
+// ユーザーのタイムゾーンで昨日までに達成しているか、休暇モードで途切れていない場合のみ含める
+// (ストリークフリーズは次に完了した時に消費されるため、ここでは考慮しない)
+static STREAK_SOURCE: RankingSource = RankingSource {
+    table: "user_streak AS s",
+    score: "s.current_streak",
+    condition: r#"
+        s.current_streak > 0
+        AND (
+            s.last_date >= DATE(UTC_TIMESTAMP() + INTERVAL u.utc_offset SECOND) - INTERVAL 1 DAY
+            OR EXISTS (
+                SELECT 1 FROM vacation AS v
+                WHERE v.user_id = u.user_id
+                AND v.start_date <= s.last_date + INTERVAL 1 DAY
+                AND v.end_date >= DATE(UTC_TIMESTAMP() + INTERVAL u.utc_offset SECOND) - INTERVAL 1 DAY
+            )
+        )
+    "#,
+};
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl LeaderboardRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+
+    // 指標に対応するランキングの対象と、週間ランキングの場合は閲覧しているユーザーの今週の月曜日を取得する
+    async fn source(
+        &self,
+        user_id: &UserId,
+        metric: LeaderboardMetric,
+    ) -> Result<(&'static RankingSource, Option<NaiveDate>), RepositoryError> {
+        match metric {
+            LeaderboardMetric::TotalExp | LeaderboardMetric::Level => Ok((&EXP_SOURCE, None)),
+            LeaderboardMetric::WeeklyExp => {
+                let today = current_date(&self.pool, user_id).await?;
+                Ok((&WEEKLY_EXP_SOURCE, Some(week_start(today))))
+            }
+            LeaderboardMetric::Streak => Ok((&STREAK_SOURCE, None)),
+        }
+    }
+}
+
+impl LeaderboardRepository for LeaderboardRepositoryImpl {
+    fn find_entries<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        page: &'a LeaderboardPage,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<LeaderboardEntry>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let (source, week) = self.source(user_id, page.metric).await?;
+            let score = source.score;
+            let limit = page.limit + 1;
+            // 順位はページを取得してからサービスで計算するため、ここではカーソルより後ろの行だけを読む
+            // 範囲の条件でインデックスを辿れるよう、scopeとカーソルの有無ごとに別のクエリにする
+            let entries = match (page.scope, &page.cursor) {
+                (scope, None) => {
+                    let sql =
+                        source.rows(scope, &format!("ORDER BY {score} DESC, s.user_id LIMIT ?"));
+                    bind_rows(sqlx::query_as(&sql), scope, user_id, week)
+                        .bind(limit)
+                        .fetch_all(&self.pool)
+                        .await
+                }
+                // カーソルと同じscoreの残りの行と、scoreがより小さい行をそれぞれインデックスで辿って合わせる
+                (LeaderboardScope::Global, Some(cursor)) => {
+                    let same = source.rows(
+                        LeaderboardScope::Global,
+                        &format!("AND {score} = ? AND s.user_id > ? ORDER BY s.user_id LIMIT ?"),
+                    );
+                    let lower = source.rows(
+                        LeaderboardScope::Global,
+                        &format!("AND {score} < ? ORDER BY {score} DESC, s.user_id LIMIT ?"),
+                    );
+                    let sql = format!(
+                        r#"
+                            SELECT user_id, user_name, score
+                            FROM (({same}) UNION ALL ({lower})) AS ranked
+                            ORDER BY score DESC, user_id
+                            LIMIT ?
+                        "#
+                    );
+                    let query = bind_rows(
+                        sqlx::query_as(&sql),
+                        LeaderboardScope::Global,
+                        user_id,
+                        week,
+                    )
+                    .bind(cursor.score)
+                    .bind(&cursor.user_id.0)
+                    .bind(limit);
+                    bind_rows(query, LeaderboardScope::Global, user_id, week)
+                        .bind(cursor.score)
+                        .bind(limit)
+                        .bind(limit)
+                        .fetch_all(&self.pool)
+                        .await
+                }
+                // フレンドのランキングは対象の行が少ないため、読んだ行をカーソルで絞り込む
+                (LeaderboardScope::Friends, Some(cursor)) => {
+                    let sql = source.rows(
+                        LeaderboardScope::Friends,
+                        &format!(
+                            r#"
+                                AND ({score} < ? OR ({score} = ? AND s.user_id > ?))
+                                ORDER BY {score} DESC, s.user_id
+                                LIMIT ?
+                            "#
+                        ),
+                    );
+                    bind_rows(
+                        sqlx::query_as(&sql),
+                        LeaderboardScope::Friends,
+                        user_id,
+                        week,
+                    )
+                    .bind(cursor.score)
+                    .bind(cursor.score)
+                    .bind(&cursor.user_id.0)
+                    .bind(limit)
+                    .fetch_all(&self.pool)
+                    .await
+                }
+            }
+            .map_err(to_repo_err)?;
+            Ok(entries)
+        })
+    }
+
+    fn count_at_least<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        metric: LeaderboardMetric,
+        scope: LeaderboardScope,
+        score: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let (source, week) = self.source(user_id, metric).await?;
+            let rows = source.rows(scope, &format!("AND {} >= ?", source.score));
+            let sql = format!("SELECT COUNT(*) FROM ({rows}) AS ranked");
+            let (count,): (i64,) = bind_rows(sqlx::query_as(&sql), scope, user_id, week)
+                .bind(score)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(to_repo_err)?;
+            Ok(count as u64)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            leaderboard::{
+                LeaderboardCursor, LeaderboardMetric, LeaderboardPage, LeaderboardScope,
+            },
+            stats::week_start,
+            user_id::UserId,
+        },
+        repository::leaderboard_repository::LeaderboardRepository,
+    };
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+
+    use crate::repository::{current_date, leaderboard_repository_impl::LeaderboardRepositoryImpl};
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_friend_leaderboard() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let friend_id = UserId(gen_random_str());
+        let stranger_id = UserId(gen_random_str());
+        for (id, exp) in [(&user_id, 30), (&friend_id, 50), (&stranger_id, 50)] {
+            create_user(pool.clone(), &id.0).await?;
+            sqlx::query("INSERT INTO user_exp (user_id, experience_points) VALUES (?, ?)")
+                .bind(&id.0)
+                .bind(exp)
+                .execute(&pool)
+                .await?;
+        }
+        for (from, to) in [(&user_id, &friend_id), (&friend_id, &user_id)] {
+            sqlx::query(
+                "INSERT INTO friendships (user_id, friend_id, status, created_at) VALUES (?, ?, 'accepted', UTC_TIMESTAMP())",
+            )
+            .bind(&from.0)
+            .bind(&to.0)
+            .execute(&pool)
+            .await?;
+        }
+        let repo = LeaderboardRepositoryImpl::new(pool.clone());
+
+        // フレンド以外は含まれず、limit + 1件まで返す
+        let mut page = LeaderboardPage {
+            metric: LeaderboardMetric::TotalExp,
+            scope: LeaderboardScope::Friends,
+            cursor: None,
+            limit: 1,
+        };
+        let entries = repo.find_entries(&user_id, &page).await?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].score, 50);
+        assert_eq!(entries[0].user_id, friend_id);
+        assert_eq!(entries[1].score, 30);
+
+        // カーソルより後ろの行だけを返す
+        page.cursor = Some(LeaderboardCursor {
+            score: entries[0].score,
+            user_id: entries[0].user_id.clone(),
+        });
+        let entries = repo.find_entries(&user_id, &page).await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].user_id, user_id);
+        assert_eq!(
+            repo.count_at_least(
+                &user_id,
+                LeaderboardMetric::TotalExp,
+                LeaderboardScope::Friends,
+                40
+            )
+            .await?,
+            1
+        );
+
+        // フレンドのランキングに表示しない設定の場合は自分だけになる
+        sqlx::query("UPDATE users SET show_on_friend_leaderboard = FALSE WHERE user_id = ?")
+            .bind(&friend_id.0)
+            .execute(&pool)
+            .await?;
+        page.cursor = None;
+        let entries = repo.find_entries(&user_id, &page).await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].user_id, user_id);
+
+        for id in [user_id, friend_id, stranger_id] {
+            delete_test_user(pool.clone(), &id.0).await?;
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_weekly_leaderboard() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let friend_id = UserId(gen_random_str());
+        for id in [&user_id, &friend_id] {
+            create_user(pool.clone(), &id.0).await?;
+        }
+        for (from, to) in [(&user_id, &friend_id), (&friend_id, &user_id)] {
+            sqlx::query(
+                "INSERT INTO friendships (user_id, friend_id, status, created_at) VALUES (?, ?, 'accepted', UTC_TIMESTAMP())",
+            )
+            .bind(&from.0)
+            .bind(&to.0)
+            .execute(&pool)
+            .await?;
+        }
+        // 閲覧しているユーザーの今週と先週の獲得経験値
+        let this_week = week_start(current_date(&pool, &user_id).await?);
+        let last_week = week_start(this_week.pred_opt().unwrap());
+        for (id, week, exp) in [
+            (&user_id, this_week, 20),
+            (&friend_id, this_week, 40),
+            (&friend_id, last_week, 100),
+        ] {
+            sqlx::query(
+                "INSERT INTO user_weekly_exp (user_id, week_start, exp_gained) VALUES (?, ?, ?)",
+            )
+            .bind(&id.0)
+            .bind(week)
+            .bind(exp)
+            .execute(&pool)
+            .await?;
+        }
+        let repo = LeaderboardRepositoryImpl::new(pool.clone());
+
+        // 先週の獲得経験値は含まない
+        let page = LeaderboardPage {
+            metric: LeaderboardMetric::WeeklyExp,
+            scope: LeaderboardScope::Friends,
+            cursor: None,
+            limit: 10,
+        };
+        let entries = repo.find_entries(&user_id, &page).await?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            (entries[0].user_id.clone(), entries[0].score),
+            (friend_id.clone(), 40)
+        );
+        assert_eq!(
+            (entries[1].user_id.clone(), entries[1].score),
+            (user_id.clone(), 20)
+        );
+        assert_eq!(
+            repo.count_at_least(
+                &user_id,
+                LeaderboardMetric::WeeklyExp,
+                LeaderboardScope::Friends,
+                21
+            )
+            .await?,
+            1
+        );
+
+        for id in [user_id, friend_id] {
+            delete_test_user(pool.clone(), &id.0).await?;
+        }
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
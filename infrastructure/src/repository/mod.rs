@@ -1,9 +1,40 @@
-use domain::repository::repository_error::RepositoryError;
-use sqlx::Error;
+use std::collections::{BTreeMap, HashSet};
 
+use chrono_tz::Tz;
+use domain::{
+    entity::{
+        frozen_day::{FrozenDay, FrozenReason},
+        mission_schedule::MissionSchedule,
+        stats::week_start,
+        streak::{Streak, StreakSummary},
+        user_id::UserId,
+        vacation::Vacation,
+    },
+    repository::repository_error::RepositoryError,
+};
+use sqlx::{
+    mysql::{MySqlDatabaseError, MySqlRow},
+    types::chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc},
+    Error, Executor, MySql, MySqlConnection, Row,
+};
+
+pub mod achievement_repository_impl;
+pub mod admin_repository_impl;
+pub mod calendar_repository_impl;
 pub mod daily_mission_repository_impl;
+pub mod friend_repository_impl;
+pub mod group_repository_impl;
+pub mod job_repository_impl;
+pub mod leaderboard_repository_impl;
+pub mod push_repository_impl;
+pub mod quest_repository_impl;
+pub mod reminder_repository_impl;
+pub mod stats_repository_impl;
+pub mod streak_repository_impl;
+pub mod template_repository_impl;
 pub mod user_exp_repository_impl;
 pub mod user_repository_impl;
+pub mod webhook_repository_impl;
 
 fn to_repo_err(e: sqlx::error::Error) -> RepositoryError {
     match e {
@@ -12,3 +43,330 @@ fn to_repo_err(e: sqlx::error::Error) -> RepositoryError {
         e => RepositoryError::DatabaseError(e.to_string()),
     }
 }
+
+/// 一意制約に違反したエラー(MySQLのエラー1062: Duplicate entry)かどうか
+fn is_duplicate_entry(e: &Error) -> bool {
+    match e {
+        Error::Database(e) => e
+            .try_downcast_ref::<MySqlDatabaseError>()
+            .is_some_and(|e| e.number() == 1062),
+        _ => false,
+    }
+}
+
+// users.time_zoneが不正な値の場合に使用する(日本時間)
+static DEFAULT_TIME_ZONE: Tz = Tz::Asia__Tokyo;
+
+/// ユーザーのタイムゾーン(users.time_zone)における今日の日付を取得する
+async fn current_date<'e, E>(executor: E, user_id: &UserId) -> Result<NaiveDate, RepositoryError>
+where
+    E: Executor<'e, Database = MySql>,
+{
+    Ok(current_datetime(executor, user_id).await?.date())
+}
+
+/// ユーザーのタイムゾーン(users.time_zone)における現在の日時を取得する
+async fn current_datetime<'e, E>(
+    executor: E,
+    user_id: &UserId,
+) -> Result<NaiveDateTime, RepositoryError>
+where
+    E: Executor<'e, Database = MySql>,
+{
+    let tz = user_time_zone(executor, user_id).await?;
+    Ok(Utc::now().with_timezone(&tz).naive_local())
+}
+
+/// ユーザーのタイムゾーンを取得する
+/// オフセットは日時ごとに求めるため、夏時間の切り替えも反映される
+async fn user_time_zone<'e, E>(executor: E, user_id: &UserId) -> Result<Tz, RepositoryError>
+where
+    E: Executor<'e, Database = MySql>,
+{
+    let row = sqlx::query(
+        r#"
+            SELECT time_zone FROM users
+            WHERE user_id = ?
+        "#,
+    )
+    .bind(&user_id.0)
+    .fetch_one(executor)
+    .await
+    .map_err(to_repo_err)?;
+    let name: String = row.try_get("time_zone").map_err(to_repo_err)?;
+    Ok(parse_time_zone(&name))
+}
+
+/// users.time_zoneのタイムゾーン名を解釈する
+fn parse_time_zone(name: &str) -> Tz {
+    name.parse().unwrap_or(DEFAULT_TIME_ZONE)
+}
+
+/// UTCからのオフセット(秒)
+fn utc_offset_at(tz: Tz, utc: NaiveDateTime) -> i32 {
+    (utc_to_local(tz, utc) - utc).num_seconds() as i32
+}
+
+/// UTCの日時をタイムゾーンの日時に変換する
+fn utc_to_local(tz: Tz, utc: NaiveDateTime) -> NaiveDateTime {
+    tz.from_utc_datetime(&utc).naive_local()
+}
+
+/// タイムゾーンの日時をUTCに変換する
+/// 夏時間の終了で重複する時刻は早い方とし、開始で存在しない時刻はその日時をUTCとみなした時点のオフセットで変換する
+fn local_to_utc(tz: Tz, local: NaiveDateTime) -> NaiveDateTime {
+    match tz.from_local_datetime(&local).earliest() {
+        Some(datetime) => datetime.naive_utc(),
+        None => {
+            let guess = tz.from_utc_datetime(&local);
+            local - (guess.naive_local() - guess.naive_utc())
+        }
+    }
+}
+
+/// ユーザーの日ごとの集計(user_daily_stats)に完了の数と獲得した経験値を加算する
+/// 獲得した経験値は週ごとの集計(user_weekly_exp)にも加算する
+/// dateはユーザーのタイムゾーンにおける日付で、取り消した場合は負の値を渡す
+async fn add_daily_stats(
+    conn: &mut MySqlConnection,
+    user_id: &UserId,
+    date: NaiveDate,
+    completions: i32,
+    exp_gained: i64,
+) -> Result<(), RepositoryError> {
+    // 集計の前に記録された完了を取り消した場合も負の数にならないようにする
+    sqlx::query(
+        r#"
+            INSERT INTO user_daily_stats
+            (user_id, date, completions, exp_gained)
+            VALUES
+            (?, ?, GREATEST(?, 0), ?)
+            ON DUPLICATE KEY UPDATE
+            completions = GREATEST(user_daily_stats.completions + ?, 0),
+            exp_gained = user_daily_stats.exp_gained + VALUES(exp_gained)
+        "#,
+    )
+    .bind(&user_id.0)
+    .bind(date)
+    .bind(completions)
+    .bind(exp_gained)
+    .bind(completions)
+    .execute(&mut *conn)
+    .await
+    .map_err(to_repo_err)?;
+    if exp_gained != 0 {
+        sqlx::query(
+            r#"
+                INSERT INTO user_weekly_exp
+                (user_id, week_start, exp_gained)
+                VALUES
+                (?, ?, ?)
+                ON DUPLICATE KEY UPDATE
+                exp_gained = user_weekly_exp.exp_gained + VALUES(exp_gained)
+            "#,
+        )
+        .bind(&user_id.0)
+        .bind(week_start(date))
+        .bind(exp_gained)
+        .execute(conn)
+        .await
+        .map_err(to_repo_err)?;
+    }
+    Ok(())
+}
+
+/// daily_missionのschedule_type, schedule_value, schedule_startからスケジュールを復元する
+fn schedule_from_row(row: &MySqlRow) -> Result<MissionSchedule, Error> {
+    MissionSchedule::from_columns(
+        row.try_get("schedule_type")?,
+        row.try_get("schedule_value")?,
+        row.try_get("schedule_start")?,
+    )
+    .map_err(|e| Error::Decode(e.into()))
+}
+
+/// ユーザーのfromからtoまで(両端を含む)の凍結された日を日付順に取得する
+/// 休暇モードの期間とストリークフリーズを消費した日が対象で、重複する場合は休暇モードを優先する
+async fn find_frozen_days(
+    conn: &mut MySqlConnection,
+    user_id: &UserId,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<Vec<FrozenDay>, RepositoryError> {
+    let vacations: Vec<Vacation> = sqlx::query_as(
+        r#"
+            SELECT id, start_date, end_date
+            FROM vacation
+            WHERE user_id = ?
+            AND (? IS NULL OR end_date >= ?)
+            AND (? IS NULL OR start_date <= ?)
+        "#,
+    )
+    .bind(&user_id.0)
+    .bind(from)
+    .bind(from)
+    .bind(to)
+    .bind(to)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(to_repo_err)?;
+
+    let rows = sqlx::query(
+        r#"
+            SELECT date
+            FROM frozen_day
+            WHERE user_id = ?
+            AND (? IS NULL OR date >= ?)
+            AND (? IS NULL OR date <= ?)
+        "#,
+    )
+    .bind(&user_id.0)
+    .bind(from)
+    .bind(from)
+    .bind(to)
+    .bind(to)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(to_repo_err)?;
+
+    let in_range = |d: &NaiveDate| from.is_none_or(|f| *d >= f) && to.is_none_or(|t| *d <= t);
+    let mut days = BTreeMap::new();
+    for row in rows {
+        let date: NaiveDate = row.try_get("date").map_err(to_repo_err)?;
+        days.insert(date, FrozenReason::Freeze);
+    }
+    for vacation in &vacations {
+        vacation.days().filter(in_range).for_each(|d| {
+            days.insert(d, FrozenReason::Vacation);
+        });
+    }
+    Ok(days
+        .into_iter()
+        .map(|(date, reason)| FrozenDay { date, reason })
+        .collect())
+}
+
+/// 所持しているストリークフリーズの数
+async fn find_available_freezes(
+    conn: &mut MySqlConnection,
+    user_id: &UserId,
+) -> Result<u32, RepositoryError> {
+    let available: Option<i32> = sqlx::query_scalar(
+        r#"
+            SELECT available FROM streak_freeze
+            WHERE user_id = ?
+        "#,
+    )
+    .bind(&user_id.0)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(to_repo_err)?;
+    Ok(available.unwrap_or_default().max(0) as u32)
+}
+
+/// 今日の時点での連続日数を評価するための情報
+/// (凍結された日と所持しているストリークフリーズ)
+struct StreakContext {
+    today: NaiveDate,
+    frozen: HashSet<NaiveDate>,
+    spare_freezes: u32,
+}
+
+impl StreakContext {
+    /// sinceは評価するストリークのうち最も古いlast_date
+    async fn load(
+        conn: &mut MySqlConnection,
+        user_id: &UserId,
+        today: NaiveDate,
+        since: Option<NaiveDate>,
+    ) -> Result<Self, RepositoryError> {
+        // 昨日以降に達成している場合は凍結された日を調べる必要がない
+        let frozen = match since {
+            Some(since) if since.succ_opt() < Some(today) => {
+                find_frozen_days(conn, user_id, Some(since), Some(today))
+                    .await?
+                    .into_iter()
+                    .map(|d| d.date)
+                    .collect()
+            }
+            _ => HashSet::new(),
+        };
+        let spare_freezes = find_available_freezes(conn, user_id).await?;
+        Ok(Self {
+            today,
+            frozen,
+            spare_freezes,
+        })
+    }
+
+    /// ミッションごとの連続記録
+    fn summary(&self, streak: &Streak, schedule: &MissionSchedule) -> StreakSummary {
+        streak.summary_on_schedule(
+            schedule,
+            self.today,
+            |d| self.frozen.contains(&d),
+            self.spare_freezes,
+        )
+    }
+
+    /// すべてのミッションを完了した日の連続記録
+    /// どのミッションも実施日でない日は飛ばして評価する
+    fn all_clear_summary(&self, streak: &Streak, schedules: &[MissionSchedule]) -> StreakSummary {
+        streak.summary_at(
+            self.today,
+            |d| self.frozen.contains(&d) || MissionSchedule::is_day_off(schedules, d),
+            self.spare_freezes,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono_tz::Tz;
+    use sqlx::types::chrono::{NaiveDate, NaiveDateTime};
+
+    use super::{local_to_utc, parse_time_zone, utc_offset_at, utc_to_local};
+
+    fn datetime(m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_time_zone_offset_by_date() {
+        // 2026-03-08に夏時間(UTC-4)が始まり、2026-11-01に終わる(UTC-5)
+        let tz = Tz::America__New_York;
+        assert_eq!(utc_offset_at(tz, datetime(1, 15, 12, 0)), -5 * 3600);
+        assert_eq!(utc_offset_at(tz, datetime(7, 15, 12, 0)), -4 * 3600);
+        // UTCの3:30は夏時間では前日の23:30、冬時間では前日の22:30
+        assert_eq!(
+            utc_to_local(tz, datetime(7, 15, 3, 30)),
+            datetime(7, 14, 23, 30)
+        );
+        assert_eq!(
+            utc_to_local(tz, datetime(1, 15, 3, 30)),
+            datetime(1, 14, 22, 30)
+        );
+
+        assert_eq!(
+            local_to_utc(tz, datetime(7, 14, 23, 30)),
+            datetime(7, 15, 3, 30)
+        );
+        // 重複する時刻は早い方(夏時間)
+        assert_eq!(
+            local_to_utc(tz, datetime(11, 1, 1, 30)),
+            datetime(11, 1, 5, 30)
+        );
+        // 存在しない時刻も変換できる
+        assert_eq!(
+            local_to_utc(tz, datetime(3, 8, 2, 30)),
+            datetime(3, 8, 7, 30)
+        );
+
+        // 不正な値は日本時間
+        assert_eq!(parse_time_zone("Etc/GMT-9"), Tz::Etc__GMTMinus9);
+        assert_eq!(parse_time_zone("+09:00"), Tz::Asia__Tokyo);
+    }
+}
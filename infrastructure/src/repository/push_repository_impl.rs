@@ -0,0 +1,231 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{push_subscription::PushSubscription, user_id::UserId},
+    repository::{push_repository::PushRepository, repository_error::RepositoryError},
+};
+use sqlx::MySqlPool;
+
+use super::to_repo_err;
+
+#[derive(Debug, Clone)]
+pub struct PushRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl PushRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl PushRepository for PushRepositoryImpl {
+    fn save<'a>(
+        &'a self,
+        subscription: &'a PushSubscription,
+    ) -> Pin<Box<dyn Future<Output = Result<PushSubscription, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 同じ端末で購読し直した場合や、端末を別のユーザーが使うようになった場合は上書きする
+            sqlx::query(
+                r#"
+                    INSERT INTO push_subscriptions
+                    (subscription_id, user_id, endpoint, p256dh, auth, device_name, expires_at, created_at)
+                    VALUES
+                    (?, ?, ?, ?, ?, ?, ?, ?)
+                    ON DUPLICATE KEY UPDATE
+                    user_id = VALUES(user_id),
+                    p256dh = VALUES(p256dh),
+                    auth = VALUES(auth),
+                    device_name = VALUES(device_name),
+                    expires_at = VALUES(expires_at)
+                "#,
+            )
+            .bind(&subscription.subscription_id)
+            .bind(&subscription.user_id.0)
+            .bind(&subscription.endpoint)
+            .bind(&subscription.p256dh)
+            .bind(&subscription.auth)
+            .bind(&subscription.device_name)
+            .bind(subscription.expires_at)
+            .bind(subscription.created_at)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            let saved = sqlx::query_as(
+                r#"
+                    SELECT
+                    subscription_id, user_id, endpoint, p256dh, auth, device_name, expires_at, created_at
+                    FROM push_subscriptions
+                    WHERE endpoint = ?
+                "#,
+            )
+            .bind(&subscription.endpoint)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(saved)
+        })
+    }
+
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PushSubscription>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let subscriptions = sqlx::query_as(
+                r#"
+                    SELECT
+                    subscription_id, user_id, endpoint, p256dh, auth, device_name, expires_at, created_at
+                    FROM push_subscriptions
+                    WHERE user_id = ?
+                    ORDER BY id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(subscriptions)
+        })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        subscription_id: &'a str,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM push_subscriptions
+                    WHERE subscription_id = ?
+                    AND user_id = ?
+                "#,
+            )
+            .bind(subscription_id)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn delete_by_endpoint<'a>(
+        &'a self,
+        endpoint: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    DELETE FROM push_subscriptions
+                    WHERE endpoint = ?
+                "#,
+            )
+            .bind(endpoint)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{push_subscription::PushSubscription, user_id::UserId},
+        repository::push_repository::PushRepository,
+    };
+    use sqlx::{types::chrono::Utc, MySqlPool};
+    use uuid::Uuid;
+
+    use crate::repository::push_repository_impl::PushRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_subscription_lifecycle() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let other_id = UserId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+        create_user(pool.clone(), &other_id.0).await?;
+        let repo = PushRepositoryImpl::new(pool.clone());
+        let subscription = PushSubscription {
+            subscription_id: gen_random_str(),
+            user_id: user_id.clone(),
+            endpoint: format!("https://push.example.com/{}", gen_random_str()),
+            p256dh: "test_p256dh".to_string(),
+            auth: "test_auth".to_string(),
+            device_name: Some("laptop".to_string()),
+            expires_at: None,
+            created_at: Utc::now().naive_utc(),
+        };
+        let saved = repo.save(&subscription).await?;
+        assert_eq!(saved.subscription_id, subscription.subscription_id);
+
+        // 同じendpointを別のユーザーが登録すると持ち主が変わる
+        let resubscribed = PushSubscription {
+            subscription_id: gen_random_str(),
+            user_id: other_id.clone(),
+            auth: "new_auth".to_string(),
+            ..subscription.clone()
+        };
+        let saved = repo.save(&resubscribed).await?;
+        assert_eq!(saved.subscription_id, subscription.subscription_id);
+        assert_eq!(saved.auth, "new_auth");
+        assert!(repo.find_by_user_id(&user_id).await?.is_empty());
+        assert_eq!(repo.find_by_user_id(&other_id).await?.len(), 1);
+
+        // 持ち主以外は削除できない
+        assert!(!repo.delete(&saved.subscription_id, &user_id).await?);
+        repo.delete_by_endpoint(&subscription.endpoint).await?;
+        assert!(repo.find_by_user_id(&other_id).await?.is_empty());
+
+        delete_test_user(pool.clone(), &user_id.0).await?;
+        delete_test_user(pool, &other_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
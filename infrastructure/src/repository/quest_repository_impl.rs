@@ -0,0 +1,541 @@
+use std::{collections::HashMap, future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        quest::{Quest, Subtask},
+        quest_id::QuestId,
+        user_id::UserId,
+    },
+    repository::{quest_repository::QuestRepository, repository_error::RepositoryError},
+};
+use sqlx::{
+    mysql::MySqlRow, prelude::FromRow, types::chrono::NaiveDate, MySql, MySqlConnection, MySqlPool,
+    Row, Transaction,
+};
+
+use super::{current_date, to_repo_err};
+
+#[derive(Debug, Clone)]
+pub struct QuestRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl QuestRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl QuestRepository for QuestRepositoryImpl {
+    fn create<'a>(
+        &'a self,
+        quest: &'a Quest,
+    ) -> Pin<Box<dyn Future<Output = Result<QuestId, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // クエストとサブタスクをまとめて保存する
+            let mut tx = self.pool.begin().await.map_err(to_repo_err)?;
+            let affected_len = sqlx::query(
+                r#"
+                INSERT INTO quest
+                (user_id, quest_id, title, descriptions, due_date)
+                VALUES
+                (?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&quest.user_id.0)
+            .bind(&quest.quest_id.0)
+            .bind(&quest.title)
+            .bind(&quest.description)
+            .bind(quest.due_date)
+            .execute(&mut *tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len != 1 {
+                return Err(RepositoryError::DatabaseError(
+                    "Failed to insert".to_string(),
+                ));
+            }
+            insert_subtasks(&mut tx, &quest.quest_id, &quest.subtasks).await?;
+            tx.commit().await.map_err(to_repo_err)?;
+            Ok(quest.quest_id.to_owned())
+        })
+    }
+
+    fn find_by_id<'a>(
+        &'a self,
+        quest_id: &'a QuestId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Quest, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let quest: QuestRow = sqlx::query_as(
+                r#"
+                SELECT
+                user_id,
+                quest_id,
+                title,
+                descriptions AS description,
+                due_date,
+                completed_date
+                FROM quest
+                WHERE quest_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&quest_id.0)
+            .bind(&user_id.0)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+
+            let subtasks: Vec<Subtask> = sqlx::query_as(
+                r#"
+                SELECT id, title, is_complete
+                FROM quest_subtask
+                WHERE quest_id = ?
+                ORDER BY id
+                "#,
+            )
+            .bind(&quest_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(quest.into_quest(subtasks))
+        })
+    }
+
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Quest>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let quests: Vec<QuestRow> = sqlx::query_as(
+                r#"
+                    SELECT
+                    user_id,
+                    quest_id,
+                    title,
+                    descriptions AS description,
+                    due_date,
+                    completed_date
+                    FROM quest
+                    WHERE user_id = ?
+                    ORDER BY
+                    completed_date IS NOT NULL,
+                    due_date IS NULL,
+                    due_date,
+                    id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+
+            // ユーザーのすべてのサブタスクを一度に取得し、クエストごとに振り分ける
+            let rows = sqlx::query(
+                r#"
+                    SELECT
+                    quest_subtask.id,
+                    quest_subtask.quest_id,
+                    quest_subtask.title,
+                    quest_subtask.is_complete
+                    FROM quest_subtask
+                    INNER JOIN quest
+                    ON quest.quest_id = quest_subtask.quest_id
+                    WHERE quest.user_id = ?
+                    ORDER BY quest_subtask.id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            let mut subtasks: HashMap<String, Vec<Subtask>> = HashMap::new();
+            for row in rows {
+                let quest_id: String = row.try_get("quest_id").map_err(to_repo_err)?;
+                let subtask = Subtask::from_row(&row).map_err(to_repo_err)?;
+                subtasks.entry(quest_id).or_default().push(subtask);
+            }
+
+            Ok(quests
+                .into_iter()
+                .map(|q| {
+                    let subtasks = subtasks.remove(&q.quest_id.0).unwrap_or_default();
+                    q.into_quest(subtasks)
+                })
+                .collect())
+        })
+    }
+
+    fn update<'a>(
+        &'a self,
+        quest: &'a Quest,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // クエストの変更とサブタスクの置き換えをまとめて行う
+            let mut tx = self.pool.begin().await.map_err(to_repo_err)?;
+            let affected_len = sqlx::query(
+                r#"
+                UPDATE quest
+                SET
+                title = ?,
+                descriptions = ?,
+                due_date = ?
+                WHERE quest_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&quest.title)
+            .bind(&quest.description)
+            .bind(quest.due_date)
+            .bind(&quest.quest_id.0)
+            .bind(&user_id.0)
+            .execute(&mut *tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            if affected_len != 1 {
+                return Err(RepositoryError::NotFound);
+            }
+
+            sqlx::query(
+                r#"
+                DELETE FROM quest_subtask
+                WHERE quest_id = ?
+                "#,
+            )
+            .bind(&quest.quest_id.0)
+            .execute(&mut *tx)
+            .await
+            .map_err(to_repo_err)?;
+            insert_subtasks(&mut tx, &quest.quest_id, &quest.subtasks).await?;
+            tx.commit().await.map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn update_subtask<'a>(
+        &'a self,
+        quest_id: &'a QuestId,
+        subtask_id: i64,
+        user_id: &'a UserId,
+        is_complete: bool,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // ユーザーが所有する未達成のクエストのサブタスクのみ変更する
+            let affected_len = sqlx::query(
+                r#"
+                UPDATE quest_subtask
+                INNER JOIN quest
+                ON quest.quest_id = quest_subtask.quest_id
+                SET quest_subtask.is_complete = ?
+                WHERE quest_subtask.id = ?
+                AND quest.quest_id = ?
+                AND quest.user_id = ?
+                AND quest.completed_date IS NULL
+                "#,
+            )
+            .bind(is_complete)
+            .bind(subtask_id)
+            .bind(&quest_id.0)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    fn set_complete_true<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        quest_id: &'a QuestId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let current_date = current_date(&mut **tx, user_id).await?;
+            // 同時に達成しても一度だけ更新されるように、未達成の条件を含める
+            let affected_len = sqlx::query(
+                r#"
+                UPDATE quest
+                SET completed_date = ?
+                WHERE quest_id = ? AND user_id = ?
+                AND completed_date IS NULL
+                AND NOT EXISTS (
+                    SELECT 1 FROM quest_subtask
+                    WHERE quest_subtask.quest_id = quest.quest_id
+                    AND quest_subtask.is_complete = FALSE
+                )
+                "#,
+            )
+            .bind(current_date)
+            .bind(&quest_id.0)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(current_date)
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        quest_id: &'a QuestId,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                DELETE FROM quest
+                WHERE quest_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&quest_id.0)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+}
+
+async fn insert_subtasks(
+    conn: &mut MySqlConnection,
+    quest_id: &QuestId,
+    subtasks: &[Subtask],
+) -> Result<(), RepositoryError> {
+    for subtask in subtasks {
+        sqlx::query(
+            r#"
+            INSERT INTO quest_subtask
+            (quest_id, title, is_complete)
+            VALUES
+            (?, ?, ?)
+            "#,
+        )
+        .bind(&quest_id.0)
+        .bind(&subtask.title)
+        .bind(subtask.is_complete)
+        .execute(&mut *conn)
+        .await
+        .map_err(to_repo_err)?;
+    }
+    Ok(())
+}
+
+/// questテーブルの1行
+/// サブタスクは別に取得してQuestに変換する
+#[derive(Debug, Clone)]
+struct QuestRow {
+    user_id: UserId,
+    quest_id: QuestId,
+    title: String,
+    description: Option<String>,
+    due_date: Option<NaiveDate>,
+    completed_date: Option<NaiveDate>,
+}
+
+impl<'r> FromRow<'r, MySqlRow> for QuestRow {
+    fn from_row(row: &'r MySqlRow) -> Result<Self, sqlx::Error> {
+        Ok(QuestRow {
+            user_id: UserId(row.try_get("user_id")?),
+            quest_id: QuestId(row.try_get("quest_id")?),
+            title: row.try_get("title")?,
+            description: row.try_get("description")?,
+            due_date: row.try_get("due_date")?,
+            completed_date: row.try_get("completed_date")?,
+        })
+    }
+}
+
+impl QuestRow {
+    fn into_quest(self, subtasks: Vec<Subtask>) -> Quest {
+        Quest {
+            user_id: self.user_id,
+            quest_id: self.quest_id,
+            title: self.title,
+            description: self.description,
+            due_date: self.due_date,
+            completed_date: self.completed_date,
+            subtasks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{quest::Quest, quest_builder::QuestBuilder, quest_id::QuestId, user_id::UserId},
+        repository::quest_repository::QuestRepository,
+    };
+    use sqlx::{types::chrono::NaiveDate, MySqlPool};
+    use uuid::Uuid;
+
+    use crate::repository::quest_repository_impl::QuestRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    static USER_ID: &str = "my_user_id";
+
+    #[tokio::test]
+    async fn test_quest_create_and_find() -> MyResult<()> {
+        let user_id = format!("{}_{}", USER_ID, gen_random_string());
+        create_test_user(&user_id).await?;
+
+        let pool = gen_pool().await?;
+        let service = QuestRepositoryImpl::new(pool);
+
+        let quest = gen_quest(&user_id, NaiveDate::from_ymd_opt(2999, 1, 1));
+        let no_due_quest = gen_quest(&user_id, None);
+        service.create(&no_due_quest).await?;
+        service.create(&quest).await?;
+
+        let returned_quest = service
+            .find_by_id(&quest.quest_id, &UserId(user_id.clone()))
+            .await?;
+        assert_eq!(returned_quest.title, quest.title);
+        assert_eq!(returned_quest.due_date, quest.due_date);
+        assert_eq!(returned_quest.subtasks.len(), 2);
+        assert_eq!(returned_quest.subtasks[0].title, "first");
+
+        // 期限のあるクエストが先に並ぶ
+        let quests = service.find_by_user_id(&UserId(user_id.clone())).await?;
+        assert_eq!(quests.len(), 2);
+        assert_eq!(quests[0].quest_id, quest.quest_id);
+        assert_eq!(quests[1].subtasks.len(), 2);
+
+        delete_test_user(&user_id).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_quest_complete_once() -> MyResult<()> {
+        let user_id = format!("{}_{}", USER_ID, gen_random_string());
+        create_test_user(&user_id).await?;
+
+        let pool = gen_pool().await?;
+        let service = QuestRepositoryImpl::new(pool.clone());
+
+        let quest = gen_quest(&user_id, None);
+        service.create(&quest).await?;
+
+        // サブタスクが残っている場合は達成できない
+        let mut tx = pool.begin().await?;
+        let result = service
+            .set_complete_true(&mut tx, &quest.quest_id, &UserId(user_id.clone()))
+            .await;
+        assert!(result.is_err());
+        tx.rollback().await?;
+
+        let stored_quest = service
+            .find_by_id(&quest.quest_id, &UserId(user_id.clone()))
+            .await?;
+        for subtask in stored_quest.subtasks {
+            service
+                .update_subtask(
+                    &quest.quest_id,
+                    subtask.subtask_id,
+                    &UserId(user_id.clone()),
+                    true,
+                )
+                .await?;
+        }
+
+        let mut tx = pool.begin().await?;
+        service
+            .set_complete_true(&mut tx, &quest.quest_id, &UserId(user_id.clone()))
+            .await?;
+        // 二度目は達成にならない
+        let result = service
+            .set_complete_true(&mut tx, &quest.quest_id, &UserId(user_id.clone()))
+            .await;
+        assert!(result.is_err());
+        tx.commit().await?;
+
+        let returned_quest = service
+            .find_by_id(&quest.quest_id, &UserId(user_id.clone()))
+            .await?;
+        assert!(returned_quest.is_complete());
+
+        service
+            .delete(&quest.quest_id, &UserId(user_id.clone()))
+            .await?;
+        delete_test_user(&user_id).await?;
+        Ok(())
+    }
+
+    // Helper methods
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("TEST_DB_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_string() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_test_user(user_id: &str) -> MyResult<()> {
+        let pool = gen_pool().await?;
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind("test_user_name")
+        .bind(format!("test_user_email_{}", user_id))
+        .bind("test_password")
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(user_id: &str) -> MyResult<()> {
+        let pool = gen_pool().await?;
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    fn gen_quest(user_id: &str, due_date: Option<NaiveDate>) -> Quest {
+        let random_string = Uuid::new_v4().to_string();
+
+        QuestBuilder::new()
+            .user_id(&UserId(user_id.to_string()))
+            .quest_id(&QuestId(random_string.to_string()))
+            .title(&format!("title_{}", random_string))
+            .due_date(&due_date)
+            .subtasks(&[("first".to_string(), false), ("second".to_string(), false)])
+            .build()
+    }
+}
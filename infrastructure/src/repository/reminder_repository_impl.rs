@@ -0,0 +1,245 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        daily_mission_id::DailyMissionId,
+        reminder::{MissionReminder, ReminderSettings},
+        user_id::UserId,
+    },
+    repository::{reminder_repository::ReminderRepository, repository_error::RepositoryError},
+};
+use sqlx::{types::chrono::NaiveTime, MySql, MySqlPool, Row, Transaction};
+
+use super::to_repo_err;
+
+#[derive(Debug, Clone)]
+pub struct ReminderRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl ReminderRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl ReminderRepository for ReminderRepositoryImpl {
+    fn find_settings<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<ReminderSettings, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let row = sqlx::query(
+                r#"
+                    SELECT summary_time FROM users
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            let missions: Vec<MissionReminder> = sqlx::query_as(
+                r#"
+                    SELECT mission_id, title, reminder_time
+                    FROM daily_mission
+                    WHERE user_id = ?
+                    ORDER BY id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(ReminderSettings {
+                summary_time: row.try_get("summary_time").map_err(to_repo_err)?,
+                missions,
+            })
+        })
+    }
+
+    fn set_mission_reminder<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        user_id: &'a UserId,
+        time: Option<NaiveTime>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected = sqlx::query(
+                r#"
+                    UPDATE daily_mission
+                    SET reminder_time = ?
+                    WHERE mission_id = ?
+                    AND user_id = ?
+                "#,
+            )
+            .bind(time)
+            .bind(&mission_id.0)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            if affected == 0 {
+                return Err(RepositoryError::NotFound);
+            }
+            // 通知済みのジョブは残すため、今日すでに通知した場合は時刻を変えても再び通知しない
+            sqlx::query(
+                r#"
+                    DELETE FROM scheduled_jobs
+                    WHERE mission_id = ?
+                    AND status = 'pending'
+                "#,
+            )
+            .bind(&mission_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn set_summary_time<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        time: Option<NaiveTime>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected = sqlx::query(
+                r#"
+                    UPDATE users
+                    SET summary_time = ?
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(time)
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            if affected == 0 {
+                return Err(RepositoryError::NotFound);
+            }
+            sqlx::query(
+                r#"
+                    DELETE FROM scheduled_jobs
+                    WHERE user_id = ?
+                    AND kind = 'summary'
+                    AND status = 'pending'
+                "#,
+            )
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{daily_mission_id::DailyMissionId, user_id::UserId},
+        repository::{reminder_repository::ReminderRepository, repository_error::RepositoryError},
+    };
+    use sqlx::{types::chrono::NaiveTime, MySqlPool};
+    use uuid::Uuid;
+
+    use crate::repository::reminder_repository_impl::ReminderRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_reminder_settings() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let mission_id = DailyMissionId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+        sqlx::query(
+            r#"
+                INSERT INTO daily_mission
+                (user_id, mission_id, title, descriptions)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&user_id.0)
+        .bind(&mission_id.0)
+        .bind("test_title")
+        .bind("test_description")
+        .execute(&pool)
+        .await?;
+        let repo = ReminderRepositoryImpl::new(pool.clone());
+        let time = NaiveTime::from_hms_opt(8, 30, 0);
+
+        let mut tx = pool.begin().await?;
+        repo.set_mission_reminder(&mut tx, &mission_id, &user_id, time)
+            .await?;
+        repo.set_summary_time(&mut tx, &user_id, time).await?;
+        assert!(matches!(
+            repo.set_mission_reminder(&mut tx, &DailyMissionId(gen_random_str()), &user_id, time)
+                .await,
+            Err(RepositoryError::NotFound)
+        ));
+        tx.commit().await?;
+
+        let settings = repo.find_settings(&user_id).await?;
+        assert_eq!(settings.summary_time, time);
+        assert_eq!(settings.missions.len(), 1);
+        assert_eq!(settings.missions[0].reminder_time, time);
+
+        let mut tx = pool.begin().await?;
+        repo.set_mission_reminder(&mut tx, &mission_id, &user_id, None)
+            .await?;
+        tx.commit().await?;
+        let settings = repo.find_settings(&user_id).await?;
+        assert_eq!(settings.missions[0].reminder_time, None);
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
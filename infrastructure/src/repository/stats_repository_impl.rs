@@ -0,0 +1,267 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        daily_mission_id::DailyMissionId,
+        stats::{CompletionDate, DailyStat, StatsMission},
+        user_id::UserId,
+    },
+    repository::{repository_error::RepositoryError, stats_repository::StatsRepository},
+};
+use sqlx::{types::chrono::NaiveDate, MySqlPool, Row};
+
+use super::{
+    current_date, find_frozen_days, schedule_from_row, to_repo_err, user_time_zone, utc_to_local,
+};
+
+#[derive(Debug, Clone)]
+pub struct StatsRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl StatsRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl StatsRepository for StatsRepositoryImpl {
+    fn today<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move { current_date(&self.pool, user_id).await })
+    }
+
+    fn find_daily_stats<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<DailyStat>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 完了の記録や台帳を集計せず、日ごとに集計済みの行を主キーの範囲で取得する
+            let stats = sqlx::query_as(
+                r#"
+                    SELECT date, completions, exp_gained
+                    FROM user_daily_stats
+                    WHERE user_id = ?
+                    AND date BETWEEN ? AND ?
+                    AND (completions > 0 OR exp_gained <> 0)
+                    ORDER BY date
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(stats)
+        })
+    }
+
+    fn find_missions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<StatsMission>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // created_atが追加される前のミッションは最初に完了した日から集計する
+            // 登録した日はユーザーのタイムゾーンの日付にする
+            let tz = user_time_zone(&self.pool, user_id).await?;
+            let rows = sqlx::query(
+                r#"
+                    SELECT
+                    daily_mission.mission_id,
+                    daily_mission.title,
+                    daily_mission.schedule_type,
+                    daily_mission.schedule_value,
+                    daily_mission.schedule_start,
+                    daily_mission.created_at,
+                    (
+                        SELECT MIN(mission_completed.date)
+                        FROM mission_completed
+                        WHERE mission_completed.mission_id = daily_mission.mission_id
+                    ) AS first_date
+                    FROM daily_mission
+                    WHERE daily_mission.user_id = ?
+                    ORDER BY daily_mission.id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            rows.iter()
+                .map(|row| {
+                    let created_date = utc_to_local(tz, row.try_get("created_at")?).date();
+                    let first_date: Option<NaiveDate> = row.try_get("first_date")?;
+                    Ok(StatsMission {
+                        mission_id: DailyMissionId(row.try_get("mission_id")?),
+                        title: row.try_get("title")?,
+                        schedule: schedule_from_row(row)?,
+                        started_on: first_date.map_or(created_date, |d| d.min(created_date)),
+                    })
+                })
+                .collect::<Result<_, sqlx::Error>>()
+                .map_err(to_repo_err)
+        })
+    }
+
+    fn find_completion_dates<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<CompletionDate>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            // (mission_id, date)のインデックスを使用して取得する
+            let dates = sqlx::query_as(
+                r#"
+                    SELECT DISTINCT
+                    mission_completed.mission_id,
+                    mission_completed.date
+                    FROM mission_completed
+                    JOIN daily_mission ON mission_completed.mission_id = daily_mission.mission_id
+                    WHERE daily_mission.user_id = ?
+                    AND mission_completed.date BETWEEN ? AND ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(dates)
+        })
+    }
+
+    fn find_frozen_dates<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<NaiveDate>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut conn = self.pool.acquire().await.map_err(to_repo_err)?;
+            let days = find_frozen_days(&mut conn, user_id, Some(from), Some(to)).await?;
+            Ok(days.into_iter().map(|d| d.date).collect())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{daily_mission_id::DailyMissionId, user_id::UserId},
+        repository::{
+            daily_mission_repository::DailyMissionRepository, stats_repository::StatsRepository,
+        },
+    };
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+
+    use crate::repository::{
+        daily_mission_repository_impl::DailyMissionRepositoryImpl,
+        stats_repository_impl::StatsRepositoryImpl,
+    };
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_daily_stats_follow_completions() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let mission_id = DailyMissionId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+        sqlx::query(
+            r#"
+                INSERT INTO daily_mission
+                (user_id, mission_id, title, descriptions)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&user_id.0)
+        .bind(&mission_id.0)
+        .bind("test_title")
+        .bind("test_description")
+        .execute(&pool)
+        .await?;
+        let repo = StatsRepositoryImpl::new(pool.clone());
+        let mission_repo = DailyMissionRepositoryImpl::new(pool.clone());
+        let today = repo.today(&user_id).await?;
+
+        // 完了すると同じトランザクションで今日の集計に加算される
+        let mut tx = pool.begin().await?;
+        mission_repo
+            .set_complete_true(&mut tx, &mission_id, &user_id)
+            .await?;
+        tx.commit().await?;
+        let stats = repo.find_daily_stats(&user_id, today, today).await?;
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].completions, 1);
+        let dates = repo.find_completion_dates(&user_id, today, today).await?;
+        assert_eq!(dates.len(), 1);
+        assert_eq!(dates[0].mission_id, mission_id);
+        let missions = repo.find_missions(&user_id).await?;
+        assert_eq!(missions.len(), 1);
+        assert!(missions[0].started_on <= today);
+
+        // 取り消すと集計からも差し引かれ、完了も経験値もない日は返さない
+        let mut tx = pool.begin().await?;
+        mission_repo
+            .undo_complete(&mut tx, &mission_id, &user_id)
+            .await?;
+        tx.commit().await?;
+        assert!(repo
+            .find_daily_stats(&user_id, today, today)
+            .await?
+            .is_empty());
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,1137 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    future::Future,
+    pin::Pin,
+};
+
+use domain::{
+    entity::{
+        all_clear_bonus::AllClearBonus,
+        daily_mission_id::DailyMissionId,
+        frozen_day::FrozenDay,
+        mission_schedule::{is_same_week, MissionSchedule},
+        streak::{MissionStreak, Streak, StreakStats},
+        user_id::UserId,
+        vacation::{Vacation, VacationInput},
+    },
+    repository::{repository_error::RepositoryError, streak_repository::StreakRepository},
+};
+use sqlx::{types::chrono::NaiveDate, MySql, MySqlPool, Row, Transaction};
+
+use super::{
+    current_date, find_available_freezes, find_frozen_days, schedule_from_row, to_repo_err,
+    StreakContext,
+};
+
+#[derive(Debug, Clone)]
+pub struct StreakRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl StreakRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl StreakRepository for StreakRepositoryImpl {
+    fn find_mission_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Streak>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let streak = sqlx::query_as(
+                r#"
+                    SELECT current_streak, longest_streak, last_date, week_count
+                    FROM mission_streak
+                    WHERE mission_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&mission_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(streak)
+        })
+    }
+
+    fn find_completed_dates<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<NaiveDate>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let rows = sqlx::query(
+                r#"
+                    SELECT DISTINCT date
+                    FROM mission_completed
+                    WHERE mission_id = ?
+                    ORDER BY date
+                "#,
+            )
+            .bind(&mission_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            rows.iter()
+                .map(|row| row.try_get("date").map_err(to_repo_err))
+                .collect()
+        })
+    }
+
+    fn find_schedule<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionSchedule, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let row = sqlx::query(
+                r#"
+                    SELECT schedule_type, schedule_value, schedule_start
+                    FROM daily_mission
+                    WHERE mission_id = ?
+                "#,
+            )
+            .bind(&mission_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            schedule_from_row(&row).map_err(to_repo_err)
+        })
+    }
+
+    fn find_schedules<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionSchedule>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let rows = sqlx::query(
+                r#"
+                    SELECT schedule_type, schedule_value, schedule_start
+                    FROM daily_mission
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            rows.iter()
+                .map(|row| schedule_from_row(row).map_err(to_repo_err))
+                .collect()
+        })
+    }
+
+    fn save_mission_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        mission_id: &'a DailyMissionId,
+        streak: &'a Streak,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO mission_streak
+                    (mission_id, current_streak, longest_streak, last_date, week_count)
+                    VALUES
+                    (?, ?, ?, ?, ?)
+                    ON DUPLICATE KEY UPDATE
+                    current_streak = VALUES(current_streak),
+                    longest_streak = VALUES(longest_streak),
+                    last_date = VALUES(last_date),
+                    week_count = VALUES(week_count)
+                "#,
+            )
+            .bind(&mission_id.0)
+            .bind(streak.current)
+            .bind(streak.longest)
+            .bind(streak.last_date)
+            .bind(streak.week_count)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn is_all_complete<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // dateに実施するミッションに完了記録が無いものが存在しなければすべて完了している
+            // 週の達成日数はmission_streakに保存したものを使う
+            let rows = sqlx::query(
+                r#"
+                    SELECT
+                    daily_mission.schedule_type,
+                    daily_mission.schedule_value,
+                    daily_mission.schedule_start,
+                    EXISTS (
+                        SELECT 1
+                        FROM mission_completed AS done
+                        WHERE done.mission_id = daily_mission.mission_id
+                        AND done.date = ?
+                    ) AS is_complete,
+                    mission_streak.last_date,
+                    mission_streak.week_count
+                    FROM daily_mission
+                    LEFT JOIN mission_streak
+                    ON daily_mission.mission_id = mission_streak.mission_id
+                    WHERE daily_mission.user_id = ?
+                "#,
+            )
+            .bind(date)
+            .bind(&user_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+
+            for row in rows {
+                let is_complete: i64 = row.try_get("is_complete").map_err(to_repo_err)?;
+                let streak = Streak {
+                    last_date: row.try_get("last_date").map_err(to_repo_err)?,
+                    week_count: row
+                        .try_get::<Option<i32>, _>("week_count")
+                        .map_err(to_repo_err)?
+                        .unwrap_or_default()
+                        .max(0) as u32,
+                    ..Default::default()
+                };
+                let schedule = schedule_from_row(&row).map_err(to_repo_err)?;
+                if is_complete == 0 && schedule.is_due(date, streak.week_count_before(date)) {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        })
+    }
+
+    fn find_user_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Streak>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let streak = sqlx::query_as(
+                r#"
+                    SELECT current_streak, longest_streak, last_date
+                    FROM user_streak
+                    WHERE user_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(streak)
+        })
+    }
+
+    fn find_all_clear_dates<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<NaiveDate>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 現在登録されているミッションのスケジュールで、各日に実施するミッションを判定する
+            let rows = sqlx::query(
+                r#"
+                    SELECT mission_id, schedule_type, schedule_value, schedule_start
+                    FROM daily_mission
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            let schedules = rows
+                .iter()
+                .map(|row| Ok((row.try_get("mission_id")?, schedule_from_row(row)?)))
+                .collect::<Result<HashMap<String, MissionSchedule>, sqlx::Error>>()
+                .map_err(to_repo_err)?;
+
+            let rows = sqlx::query(
+                r#"
+                    SELECT DISTINCT mission_completed.mission_id, mission_completed.date
+                    FROM mission_completed
+                    INNER JOIN daily_mission
+                    ON daily_mission.mission_id = mission_completed.mission_id
+                    WHERE daily_mission.user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            let mut completed: BTreeMap<NaiveDate, HashSet<String>> = BTreeMap::new();
+            for row in rows {
+                let date = row.try_get("date").map_err(to_repo_err)?;
+                let mission_id = row.try_get("mission_id").map_err(to_repo_err)?;
+                completed.entry(date).or_default().insert(mission_id);
+            }
+
+            // 日付順に走査し、ミッションごとにISO週の達成日数を数えながら判定する
+            let mut week_counts: HashMap<&str, (NaiveDate, u32)> = HashMap::new();
+            let mut dates = Vec::new();
+            for (date, missions) in &completed {
+                let is_all_clear = schedules.iter().all(|(mission_id, schedule)| {
+                    let week_count = match week_counts.get(mission_id.as_str()) {
+                        Some((last, count)) if is_same_week(*last, *date) => *count,
+                        _ => 0,
+                    };
+                    missions.contains(mission_id) || !schedule.is_due(*date, week_count)
+                });
+                if is_all_clear {
+                    dates.push(*date);
+                }
+                for mission_id in missions {
+                    let entry = week_counts.entry(mission_id.as_str()).or_insert((*date, 0));
+                    *entry = match entry {
+                        (last, count) if is_same_week(*last, *date) => (*date, *count + 1),
+                        _ => (*date, 1),
+                    };
+                }
+            }
+            Ok(dates)
+        })
+    }
+
+    fn save_user_streak<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        streak: &'a Streak,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO user_streak
+                    (user_id, current_streak, longest_streak, last_date)
+                    VALUES
+                    (?, ?, ?, ?)
+                    ON DUPLICATE KEY UPDATE
+                    current_streak = VALUES(current_streak),
+                    longest_streak = VALUES(longest_streak),
+                    last_date = VALUES(last_date)
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(streak.current)
+            .bind(streak.longest)
+            .bind(streak.last_date)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn find_stats<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<StreakStats, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut conn = self.pool.acquire().await.map_err(to_repo_err)?;
+            let current_date = current_date(&mut *conn, user_id).await?;
+
+            let all_clear: Option<Streak> = sqlx::query_as(
+                r#"
+                    SELECT current_streak, longest_streak, last_date
+                    FROM user_streak
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(to_repo_err)?;
+            let all_clear = all_clear.unwrap_or_default();
+
+            let rows = sqlx::query(
+                r#"
+                    SELECT
+                    daily_mission.mission_id,
+                    daily_mission.title,
+                    daily_mission.schedule_type,
+                    daily_mission.schedule_value,
+                    daily_mission.schedule_start,
+                    mission_streak.current_streak,
+                    mission_streak.longest_streak,
+                    mission_streak.last_date,
+                    mission_streak.week_count
+                    FROM daily_mission
+                    LEFT JOIN mission_streak
+                    ON daily_mission.mission_id = mission_streak.mission_id
+                    WHERE daily_mission.user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(to_repo_err)?;
+
+            let missions = rows
+                .iter()
+                .map(|row| {
+                    // 一度も完了していないミッションはmission_streakの行が存在しない
+                    let current: Option<i32> = row.try_get("current_streak")?;
+                    let longest: Option<i32> = row.try_get("longest_streak")?;
+                    let streak = Streak {
+                        current: current.unwrap_or_default().max(0) as u32,
+                        longest: longest.unwrap_or_default().max(0) as u32,
+                        last_date: row.try_get("last_date")?,
+                        week_count: row
+                            .try_get::<Option<i32>, _>("week_count")?
+                            .unwrap_or_default()
+                            .max(0) as u32,
+                    };
+                    Ok(MissionStreakRow {
+                        mission_id: DailyMissionId(row.try_get("mission_id")?),
+                        title: row.try_get("title")?,
+                        schedule: schedule_from_row(row)?,
+                        streak,
+                    })
+                })
+                .collect::<Result<Vec<_>, sqlx::Error>>()
+                .map_err(to_repo_err)?;
+
+            let since = missions
+                .iter()
+                .map(|m| m.streak.last_date)
+                .chain([all_clear.last_date])
+                .flatten()
+                .min();
+            let context = StreakContext::load(&mut conn, user_id, current_date, since).await?;
+
+            let schedules: Vec<_> = missions.iter().map(|m| m.schedule.clone()).collect();
+            Ok(StreakStats {
+                all_clear: context.all_clear_summary(&all_clear, &schedules),
+                missions: missions
+                    .into_iter()
+                    .map(|m| MissionStreak {
+                        streak: context.summary(&m.streak, &m.schedule),
+                        mission_id: m.mission_id,
+                        title: m.title,
+                    })
+                    .collect(),
+            })
+        })
+    }
+
+    fn find_current_date<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<NaiveDate, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move { current_date(&self.pool, user_id).await })
+    }
+
+    fn find_frozen_days<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<FrozenDay>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move { find_frozen_days(tx, user_id, from, to).await })
+    }
+
+    fn find_freezes<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let available: Option<i32> = sqlx::query_scalar(
+                r#"
+                    SELECT available FROM streak_freeze
+                    WHERE user_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(available.unwrap_or_default().max(0) as u32)
+        })
+    }
+
+    fn consume_freezes<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        dates: &'a [NaiveDate],
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            if dates.is_empty() {
+                return Ok(());
+            }
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE streak_freeze
+                    SET available = available - ?
+                    WHERE user_id = ?
+                    AND available >= ?
+                "#,
+            )
+            .bind(dates.len() as i32)
+            .bind(&user_id.0)
+            .bind(dates.len() as i32)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            if affected_len != 1 {
+                return Err(RepositoryError::NotFound);
+            }
+
+            for date in dates {
+                sqlx::query(
+                    r#"
+                        INSERT IGNORE INTO frozen_day
+                        (user_id, date)
+                        VALUES
+                        (?, ?)
+                    "#,
+                )
+                .bind(&user_id.0)
+                .bind(date)
+                .execute(&mut **tx)
+                .await
+                .map_err(to_repo_err)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn add_freeze<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+        max: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 上限に達していて付与しなかった場合は、取り消しで取り除かないよう記録しない
+            let mut added = sqlx::query(
+                r#"
+                    UPDATE streak_freeze
+                    SET available = available + 1
+                    WHERE user_id = ?
+                    AND available < ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(max)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected()
+                == 1;
+            if !added && max > 0 {
+                // 初めて付与する場合は行を作る(行があって上限に達している場合は何もしない)
+                added = sqlx::query(
+                    r#"
+                        INSERT IGNORE INTO streak_freeze
+                        (user_id, available)
+                        VALUES
+                        (?, 1)
+                    "#,
+                )
+                .bind(&user_id.0)
+                .execute(&mut **tx)
+                .await
+                .map_err(to_repo_err)?
+                .rows_affected()
+                    == 1;
+            }
+            if added {
+                sqlx::query(
+                    r#"
+                        INSERT IGNORE INTO streak_freeze_earned
+                        (user_id, date, created_at)
+                        VALUES
+                        (?, ?, UTC_TIMESTAMP())
+                    "#,
+                )
+                .bind(&user_id.0)
+                .bind(date)
+                .execute(&mut **tx)
+                .await
+                .map_err(to_repo_err)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn remove_freeze<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let earned = sqlx::query(
+                r#"
+                    DELETE FROM streak_freeze_earned
+                    WHERE user_id = ? AND date = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(date)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            if earned == 0 {
+                return Ok(());
+            }
+            // 付与したフリーズをすでに消費している場合は、所持している数が0のため取り除かない
+            sqlx::query(
+                r#"
+                    UPDATE streak_freeze
+                    SET available = available - 1
+                    WHERE user_id = ?
+                    AND available > 0
+                "#,
+            )
+            .bind(&user_id.0)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn lock_user<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // ロックを伴う読み込みでは一貫性読み取りのスナップショットが作られないため、
+            // ロックを待っている間に完了した他のトランザクションの変更もこの後の読み込みで見える
+            sqlx::query(
+                r#"
+                    SELECT user_id FROM users
+                    WHERE user_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .ok_or(RepositoryError::NotFound)?;
+            Ok(())
+        })
+    }
+
+    fn exists_all_clear_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let exists: bool = sqlx::query_scalar(
+                r#"
+                    SELECT EXISTS (
+                        SELECT 1
+                        FROM all_clear_bonus
+                        WHERE user_id = ? AND date = ?
+                    )
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(date)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(exists)
+        })
+    }
+
+    fn save_all_clear_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        bonus: &'a AllClearBonus,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    INSERT IGNORE INTO all_clear_bonus
+                    (user_id, date, exp_awarded, created_at)
+                    VALUES
+                    (?, ?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(bonus.date)
+            .bind(bonus.exp_awarded)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() == 1)
+        })
+    }
+
+    fn delete_all_clear_bonus<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        date: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<AllClearBonus>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let exp_awarded: Option<i64> = sqlx::query_scalar(
+                r#"
+                    SELECT exp_awarded FROM all_clear_bonus
+                    WHERE user_id = ? AND date = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(date)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            let Some(exp_awarded) = exp_awarded else {
+                return Ok(None);
+            };
+
+            sqlx::query(
+                r#"
+                    DELETE FROM all_clear_bonus
+                    WHERE user_id = ? AND date = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(date)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(Some(AllClearBonus { date, exp_awarded }))
+        })
+    }
+
+    fn find_available_freezes<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let mut conn = self.pool.acquire().await.map_err(to_repo_err)?;
+            find_available_freezes(&mut conn, user_id).await
+        })
+    }
+
+    fn create_vacation<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        input: &'a VacationInput,
+    ) -> Pin<Box<dyn Future<Output = Result<Vacation, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    INSERT INTO vacation
+                    (user_id, start_date, end_date)
+                    VALUES
+                    (?, ?, ?)
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(input.start_date)
+            .bind(input.end_date)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(Vacation {
+                vacation_id: result.last_insert_id() as i64,
+                start_date: input.start_date,
+                end_date: input.end_date,
+            })
+        })
+    }
+
+    fn find_vacations<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        since: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Vacation>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let vacations = sqlx::query_as(
+                r#"
+                    SELECT id, start_date, end_date
+                    FROM vacation
+                    WHERE user_id = ?
+                    AND end_date >= ?
+                    ORDER BY start_date
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(since)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(vacations)
+        })
+    }
+
+    fn cancel_vacation<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        vacation_id: i64,
+        today: NaiveDate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // まだ始まっていない(今日から始まる)期間は削除する
+            let deleted = sqlx::query(
+                r#"
+                    DELETE FROM vacation
+                    WHERE id = ?
+                    AND user_id = ?
+                    AND start_date >= ?
+                "#,
+            )
+            .bind(vacation_id)
+            .bind(&user_id.0)
+            .bind(today)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            if deleted == 1 {
+                return Ok(());
+            }
+
+            // 期間中の場合は昨日で終わらせる
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE vacation
+                    SET end_date = ?
+                    WHERE id = ?
+                    AND user_id = ?
+                    AND start_date < ?
+                    AND end_date >= ?
+                "#,
+            )
+            .bind(today.pred_opt())
+            .bind(vacation_id)
+            .bind(&user_id.0)
+            .bind(today)
+            .bind(today)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            match affected_len {
+                1 => Ok(()),
+                _ => Err(RepositoryError::NotFound),
+            }
+        })
+    }
+}
+
+/// find_stats()で取得するミッションごとの連続記録
+struct MissionStreakRow {
+    mission_id: DailyMissionId,
+    title: String,
+    schedule: MissionSchedule,
+    streak: Streak,
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            all_clear_bonus::AllClearBonus,
+            daily_mission::DailyMission,
+            daily_mission_builder::DailyMissionBuilder,
+            daily_mission_id::DailyMissionId,
+            frozen_day::{FrozenDay, FrozenReason},
+            streak::Streak,
+            user_id::UserId,
+            vacation::VacationInput,
+        },
+        repository::{
+            daily_mission_repository::DailyMissionRepository, streak_repository::StreakRepository,
+        },
+    };
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+
+    use crate::repository::{
+        daily_mission_repository_impl::DailyMissionRepositoryImpl,
+        streak_repository_impl::StreakRepositoryImpl,
+    };
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_save_and_find_mission_streak() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = gen_random_str();
+        create_user(pool.clone(), &user_id).await?;
+        let mission = gen_daily_mission(&user_id);
+        let mut tx = pool.begin().await?;
+        DailyMissionRepositoryImpl::new(pool.clone())
+            .create(&mut tx, &mission)
+            .await?;
+        tx.commit().await?;
+
+        let repo = StreakRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        // 完了前は記録が無い
+        let streak = repo
+            .find_mission_streak(&mut tx, &mission.mission_id)
+            .await?;
+        assert_eq!(streak, None);
+
+        let completion = DailyMissionRepositoryImpl::new(pool.clone())
+            .set_complete_true(&mut tx, &mission.mission_id, &UserId(user_id.clone()))
+            .await?
+            .ok_or("already completed")?;
+        let dates = repo
+            .find_completed_dates(&mut tx, &mission.mission_id)
+            .await?;
+        assert_eq!(dates, vec![completion.date]);
+
+        let expected = Streak::from_dates(dates, |_| false);
+        repo.save_mission_streak(&mut tx, &mission.mission_id, &expected)
+            .await?;
+        let streak = repo
+            .find_mission_streak(&mut tx, &mission.mission_id)
+            .await?;
+        assert_eq!(streak, Some(expected));
+        // 前回の達成日を含む週の達成日数も保存される
+        let week_count: i32 =
+            sqlx::query_scalar("SELECT week_count FROM mission_streak WHERE mission_id = ?")
+                .bind(&mission.mission_id.0)
+                .fetch_one(&mut *tx)
+                .await?;
+        assert_eq!(week_count, 1);
+        tx.commit().await?;
+
+        delete_test_user(pool, &user_id).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_is_all_complete() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = gen_random_str();
+        create_user(pool.clone(), &user_id).await?;
+        let mission_repo = DailyMissionRepositoryImpl::new(pool.clone());
+        let first = gen_daily_mission(&user_id);
+        let second = gen_daily_mission(&user_id);
+        let mut tx = pool.begin().await?;
+        mission_repo.create(&mut tx, &first).await?;
+        mission_repo.create(&mut tx, &second).await?;
+        tx.commit().await?;
+
+        let repo = StreakRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        let completion = mission_repo
+            .set_complete_true(&mut tx, &first.mission_id, &UserId(user_id.clone()))
+            .await?
+            .ok_or("already completed")?;
+        assert!(
+            !repo
+                .is_all_complete(&mut tx, &UserId(user_id.clone()), completion.date)
+                .await?
+        );
+
+        mission_repo
+            .set_complete_true(&mut tx, &second.mission_id, &UserId(user_id.clone()))
+            .await?;
+        assert!(
+            repo.is_all_complete(&mut tx, &UserId(user_id.clone()), completion.date)
+                .await?
+        );
+        let dates = repo
+            .find_all_clear_dates(&mut tx, &UserId(user_id.clone()))
+            .await?;
+        assert_eq!(dates, vec![completion.date]);
+        tx.commit().await?;
+
+        let stats = repo.find_stats(&UserId(user_id.clone())).await?;
+        assert_eq!(stats.missions.len(), 2);
+
+        delete_test_user(pool, &user_id).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_vacation_and_freezes() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = gen_random_str();
+        create_user(pool.clone(), &user_id).await?;
+        let user_id = UserId(user_id);
+
+        let repo = StreakRepositoryImpl::new(pool.clone());
+        let today = repo.find_current_date(&user_id).await?;
+        let input = VacationInput {
+            start_date: today,
+            end_date: today.iter_days().nth(2).unwrap(),
+        };
+        let vacation = repo.create_vacation(&user_id, &input).await?;
+        assert_eq!(
+            repo.find_vacations(&user_id, today).await?,
+            vec![vacation.clone()]
+        );
+
+        let mut tx = pool.begin().await?;
+        let frozen = repo
+            .find_frozen_days(&mut tx, &user_id, Some(today), None)
+            .await?;
+        assert_eq!(frozen.len(), 3);
+        assert!(frozen.iter().all(|d| d.reason == FrozenReason::Vacation));
+
+        // ストリークフリーズは最大数を超えて付与されない
+        assert_eq!(repo.find_freezes(&mut tx, &user_id).await?, 0);
+        let earned_dates: Vec<_> = today.iter_days().take(3).collect();
+        for &date in &earned_dates {
+            repo.add_freeze(&mut tx, &user_id, date, 2).await?;
+        }
+        assert_eq!(repo.find_freezes(&mut tx, &user_id).await?, 2);
+        // 上限に達して付与しなかった日の取り消しでは取り除かれない
+        repo.remove_freeze(&mut tx, &user_id, earned_dates[2])
+            .await?;
+        assert_eq!(repo.find_freezes(&mut tx, &user_id).await?, 2);
+
+        let yesterday = today.pred_opt().unwrap();
+        repo.consume_freezes(&mut tx, &user_id, &[yesterday])
+            .await?;
+        assert_eq!(repo.find_freezes(&mut tx, &user_id).await?, 1);
+        // 付与した日ごとに一度だけ取り除かれ、消費済みの場合は何もしない
+        repo.remove_freeze(&mut tx, &user_id, earned_dates[0])
+            .await?;
+        repo.remove_freeze(&mut tx, &user_id, earned_dates[0])
+            .await?;
+        assert_eq!(repo.find_freezes(&mut tx, &user_id).await?, 0);
+        repo.remove_freeze(&mut tx, &user_id, earned_dates[1])
+            .await?;
+        assert_eq!(repo.find_freezes(&mut tx, &user_id).await?, 0);
+        let frozen = repo
+            .find_frozen_days(&mut tx, &user_id, Some(yesterday), Some(yesterday))
+            .await?;
+        assert_eq!(
+            frozen,
+            vec![FrozenDay {
+                date: yesterday,
+                reason: FrozenReason::Freeze
+            }]
+        );
+        tx.commit().await?;
+
+        repo.cancel_vacation(&user_id, vacation.vacation_id, today)
+            .await?;
+        assert!(repo.find_vacations(&user_id, today).await?.is_empty());
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_all_clear_bonus() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = gen_random_str();
+        create_user(pool.clone(), &user_id).await?;
+        let user_id = UserId(user_id);
+
+        let repo = StreakRepositoryImpl::new(pool.clone());
+        let today = repo.find_current_date(&user_id).await?;
+        let bonus = AllClearBonus {
+            date: today,
+            exp_awarded: 5,
+        };
+        let mut tx = pool.begin().await?;
+        repo.lock_user(&mut tx, &user_id).await?;
+        assert!(
+            !repo
+                .exists_all_clear_bonus(&mut tx, &user_id, today)
+                .await?
+        );
+        // 同じ日のボーナスは一度しか記録されない
+        assert!(repo.save_all_clear_bonus(&mut tx, &user_id, &bonus).await?);
+        assert!(
+            repo.exists_all_clear_bonus(&mut tx, &user_id, today)
+                .await?
+        );
+        assert!(!repo.save_all_clear_bonus(&mut tx, &user_id, &bonus).await?);
+
+        assert_eq!(
+            repo.delete_all_clear_bonus(&mut tx, &user_id, today)
+                .await?,
+            Some(bonus)
+        );
+        assert_eq!(
+            repo.delete_all_clear_bonus(&mut tx, &user_id, today)
+                .await?,
+            None
+        );
+        tx.commit().await?;
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    fn gen_daily_mission(user_id: &str) -> DailyMission {
+        let random_string = gen_random_str();
+        DailyMissionBuilder::new()
+            .user_id(&UserId(user_id.to_string()))
+            .mission_id(&DailyMissionId(random_string.clone()))
+            .title(&format!("title_{}", random_string))
+            .build()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,399 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        daily_mission_id::DailyMissionId, mission_template::MissionTemplate,
+        mission_template_id::MissionTemplateId, user_id::UserId,
+    },
+    repository::{repository_error::RepositoryError, template_repository::TemplateRepository},
+};
+use sqlx::MySqlPool;
+
+use super::to_repo_err;
+
+#[derive(Debug, Clone)]
+pub struct TemplateRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl TemplateRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+// テンプレートの取得で共通のSELECT
+static TEMPLATE_SELECT: &str = r#"
+    SELECT template_id, title, descriptions, schedule_type, schedule_value, schedule_start,
+    target_quantity, unit, difficulty, exp_weight, share_code, created_at
+    FROM mission_templates
+"#;
+
+impl TemplateRepository for TemplateRepositoryImpl {
+    fn find_curated<'a>(
+        &'a self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionTemplate>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let templates = sqlx::query_as(&format!(
+                "{} WHERE is_curated = TRUE ORDER BY id",
+                TEMPLATE_SELECT
+            ))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(templates)
+        })
+    }
+
+    fn find_curated_by_id<'a>(
+        &'a self,
+        template_id: &'a MissionTemplateId,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionTemplate, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let template = sqlx::query_as(&format!(
+                "{} WHERE is_curated = TRUE AND template_id = ?",
+                TEMPLATE_SELECT
+            ))
+            .bind(&template_id.0)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(template)
+        })
+    }
+
+    fn find_by_share_code<'a>(
+        &'a self,
+        share_code: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<MissionTemplate, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let template = sqlx::query_as(&format!(
+                "{} WHERE is_curated = FALSE AND share_code = ?",
+                TEMPLATE_SELECT
+            ))
+            .bind(share_code)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(template)
+        })
+    }
+
+    fn find_by_author<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<MissionTemplate>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let templates = sqlx::query_as(&format!(
+                "{} WHERE is_curated = FALSE AND author_id = ? ORDER BY id DESC",
+                TEMPLATE_SELECT
+            ))
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(templates)
+        })
+    }
+
+    fn create_curated<'a>(
+        &'a self,
+        template: &'a MissionTemplate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let (schedule_type, schedule_value, schedule_start) = template.schedule.to_columns();
+            sqlx::query(
+                r#"
+                    INSERT INTO mission_templates
+                    (template_id, is_curated, title, descriptions, schedule_type, schedule_value,
+                    schedule_start, target_quantity, unit, difficulty, exp_weight, created_at)
+                    VALUES
+                    (?, TRUE, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&template.template_id.0)
+            .bind(&template.title)
+            .bind(&template.description)
+            .bind(schedule_type)
+            .bind(schedule_value)
+            .bind(schedule_start)
+            .bind(template.target_quantity)
+            .bind(&template.unit)
+            .bind(template.difficulty.as_str())
+            .bind(template.exp_weight)
+            .bind(template.created_at)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn update_curated<'a>(
+        &'a self,
+        template: &'a MissionTemplate,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let (schedule_type, schedule_value, schedule_start) = template.schedule.to_columns();
+            // 変更がない場合もrows_affectedは一致した行数になる(CLIENT_FOUND_ROWS)
+            let result = sqlx::query(
+                r#"
+                    UPDATE mission_templates
+                    SET
+                    title = ?,
+                    descriptions = ?,
+                    schedule_type = ?,
+                    schedule_value = ?,
+                    schedule_start = ?,
+                    target_quantity = ?,
+                    unit = ?,
+                    difficulty = ?,
+                    exp_weight = ?
+                    WHERE template_id = ? AND is_curated = TRUE
+                "#,
+            )
+            .bind(&template.title)
+            .bind(&template.description)
+            .bind(schedule_type)
+            .bind(schedule_value)
+            .bind(schedule_start)
+            .bind(template.target_quantity)
+            .bind(&template.unit)
+            .bind(template.difficulty.as_str())
+            .bind(template.exp_weight)
+            .bind(&template.template_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    fn delete_curated<'a>(
+        &'a self,
+        template_id: &'a MissionTemplateId,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM mission_templates
+                    WHERE template_id = ? AND is_curated = TRUE
+                "#,
+            )
+            .bind(&template_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+
+    fn share_mission<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        mission_id: &'a DailyMissionId,
+        template_id: &'a MissionTemplateId,
+        share_code: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // ミッションが存在しない場合は1行も挿入されない
+            let result = sqlx::query(
+                r#"
+                    INSERT INTO mission_templates
+                    (template_id, is_curated, author_id, share_code, title, descriptions,
+                    schedule_type, schedule_value, schedule_start, target_quantity, unit,
+                    difficulty, exp_weight, created_at)
+                    SELECT ?, FALSE, user_id, ?, title, descriptions,
+                    schedule_type, schedule_value, schedule_start, target_quantity, unit,
+                    difficulty, exp_weight, UTC_TIMESTAMP()
+                    FROM daily_mission
+                    WHERE mission_id = ? AND user_id = ?
+                "#,
+            )
+            .bind(&template_id.0)
+            .bind(share_code)
+            .bind(&mission_id.0)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    fn delete_shared<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        share_code: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<bool, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM mission_templates
+                    WHERE share_code = ? AND author_id = ? AND is_curated = FALSE
+                "#,
+            )
+            .bind(share_code)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected() > 0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            daily_mission_id::DailyMissionId, mission_difficulty::MissionDifficulty,
+            mission_schedule::MissionSchedule, mission_template::MissionTemplate,
+            mission_template_id::MissionTemplateId, user_id::UserId,
+        },
+        repository::{repository_error::RepositoryError, template_repository::TemplateRepository},
+    };
+    use sqlx::{types::chrono::Utc, MySqlPool};
+    use uuid::Uuid;
+
+    use crate::repository::template_repository_impl::TemplateRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[tokio::test]
+    async fn test_curated_template() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let repo = TemplateRepositoryImpl::new(pool);
+        let mut template = MissionTemplate {
+            template_id: MissionTemplateId(gen_random_str()),
+            title: "test_template".to_string(),
+            description: None,
+            schedule: MissionSchedule::TimesPerWeek { times: 3 },
+            target_quantity: Some(30),
+            unit: Some("pages".to_string()),
+            difficulty: MissionDifficulty::Hard,
+            exp_weight: None,
+            share_code: None,
+            created_at: Utc::now().naive_utc(),
+        };
+        repo.create_curated(&template).await?;
+        let stored = repo.find_curated_by_id(&template.template_id).await?;
+        assert_eq!(stored.schedule, template.schedule);
+        assert_eq!(stored.target_quantity, Some(30));
+
+        template.title = "updated".to_string();
+        repo.update_curated(&template).await?;
+        assert!(repo
+            .find_curated()
+            .await?
+            .iter()
+            .any(|t| t.title == "updated"));
+
+        assert!(repo.delete_curated(&template.template_id).await?);
+        assert!(matches!(
+            repo.find_curated_by_id(&template.template_id).await,
+            Err(RepositoryError::NotFound)
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_share_mission() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = UserId(gen_random_str());
+        let mission_id = DailyMissionId(gen_random_str());
+        create_user(pool.clone(), &user_id.0).await?;
+        sqlx::query(
+            r#"
+                INSERT INTO daily_mission
+                (user_id, mission_id, title, descriptions)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(&user_id.0)
+        .bind(&mission_id.0)
+        .bind("test_title")
+        .bind("test_description")
+        .execute(&pool)
+        .await?;
+        let repo = TemplateRepositoryImpl::new(pool.clone());
+        let template_id = MissionTemplateId(gen_random_str());
+        let share_code = gen_random_str()[..12].to_ascii_uppercase();
+
+        repo.share_mission(&user_id, &mission_id, &template_id, &share_code)
+            .await?;
+        let shared = repo.find_by_share_code(&share_code).await?;
+        assert_eq!(shared.title, "test_title");
+        assert_eq!(shared.share_code, Some(share_code.clone()));
+        assert_eq!(repo.find_by_author(&user_id).await?.len(), 1);
+        // 共有したテンプレートはカタログには含まれない
+        assert!(repo.find_curated_by_id(&template_id).await.is_err());
+
+        // 他のユーザーのミッションは共有できない
+        assert!(matches!(
+            repo.share_mission(
+                &UserId(gen_random_str()),
+                &mission_id,
+                &MissionTemplateId(gen_random_str()),
+                "OTHER"
+            )
+            .await,
+            Err(RepositoryError::NotFound)
+        ));
+
+        assert!(repo.delete_shared(&user_id, &share_code).await?);
+        assert!(!repo.delete_shared(&user_id, &share_code).await?);
+
+        delete_test_user(pool, &user_id.0).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
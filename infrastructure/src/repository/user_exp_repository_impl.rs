@@ -1,12 +1,16 @@
 use std::{future::Future, pin::Pin};
 
 use domain::{
-    entity::{user_exp::UserExp, user_id::UserId},
+    entity::{
+        exp_transaction::{ExpHistoryPage, ExpTransaction, NewExpTransaction},
+        user_exp::{ExpChange, UserExp},
+        user_id::UserId,
+    },
     repository::{repository_error::RepositoryError, user_exp_repository::UserExpRepository},
 };
 use sqlx::{MySql, MySqlPool, Transaction};
 
-use crate::repository::to_repo_err;
+use crate::repository::{add_daily_stats, current_date, to_repo_err};
 
 #[derive(Debug, Clone)]
 pub struct UserExpRepositoryImpl {
@@ -68,31 +72,131 @@ impl UserExpRepository for UserExpRepositoryImpl {
         })
     }
 
-    fn add_exp<'a>(
+    fn add_transaction<'a>(
         &'a self,
         tx: &'a mut Transaction<'_, MySql>,
         user_id: &'a UserId,
-        additional_exp: i64,
-    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        transaction: &'a NewExpTransaction,
+        max_exp: i64,
+    ) -> Pin<Box<dyn Future<Output = Result<ExpChange, RepositoryError>> + Send + 'a>> {
         Box::pin(async move {
-            let result = sqlx::query(
+            // user_expの行をロックし、台帳と合計が同じ順序で記録されるようにする
+            let before: i64 = sqlx::query_scalar(
+                r#"
+                    SELECT experience_points
+                    FROM user_exp
+                    WHERE user_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(to_repo_err)?
+            .ok_or(RepositoryError::NotFound)?;
+
+            let change = ExpChange {
+                before,
+                after: UserExp::saturating_apply(before, transaction.amount, max_exp),
+                requested: transaction.amount,
+            };
+            if change.applied() == 0 {
+                return Ok(change);
+            }
+
+            sqlx::query(
                 r#"
                     UPDATE user_exp
-                    SET experience_points = experience_points + ?
+                    SET experience_points = ?
                     WHERE user_id = ?
                 "#,
             )
-            .bind(additional_exp)
+            .bind(change.after)
             .bind(&user_id.0)
             .execute(&mut **tx)
             .await
             .map_err(to_repo_err)?;
 
-            if result.rows_affected() == 1 {
-                Ok(())
-            } else {
-                Err(RepositoryError::NotFound)
-            }
+            sqlx::query(
+                r#"
+                    INSERT INTO exp_transactions
+                    (user_id, amount, reason, mission_id, completion_id, quest_id,
+                    achievement_id, group_mission_id, created_at)
+                    VALUES
+                    (?, ?, ?, ?, ?, ?, ?, ?, UTC_TIMESTAMP())
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(change.applied())
+            .bind(transaction.reason.as_str())
+            .bind(transaction.mission_id.as_ref().map(|id| &id.0))
+            .bind(transaction.completion_id)
+            .bind(transaction.quest_id.as_ref().map(|id| &id.0))
+            .bind(transaction.achievement_id.as_deref())
+            .bind(transaction.group_mission_id.as_ref().map(|id| &id.0))
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            // 統計のため、ユーザーのタイムゾーンにおける今日の集計にも加算する
+            let today = current_date(&mut **tx, user_id).await?;
+            add_daily_stats(tx, user_id, today, 0, change.applied()).await?;
+            Ok(change)
+        })
+    }
+
+    fn find_transactions<'a>(
+        &'a self,
+        user_id: &'a UserId,
+        page: &'a ExpHistoryPage,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<ExpTransaction>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let transactions = sqlx::query_as(
+                r#"
+                    SELECT id, amount, reason, mission_id, completion_id, quest_id,
+                    achievement_id, group_mission_id, created_at
+                    FROM exp_transactions
+                    WHERE user_id = ?
+                    AND (? IS NULL OR id < ?)
+                    ORDER BY id DESC
+                    LIMIT ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .bind(page.cursor)
+            .bind(page.cursor)
+            .bind(page.limit + 1)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(transactions)
+        })
+    }
+
+    fn rebuild<'a>(
+        &'a self,
+        user_id: Option<&'a UserId>,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let user_id = user_id.map(|id| &id.0);
+            let result = sqlx::query(
+                r#"
+                    UPDATE user_exp AS e
+                    SET e.experience_points = (
+                        SELECT COALESCE(SUM(t.amount), 0)
+                        FROM exp_transactions AS t
+                        WHERE t.user_id = e.user_id
+                    )
+                    WHERE (? IS NULL OR e.user_id = ?)
+                "#,
+            )
+            .bind(user_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+
+            Ok(result.rows_affected())
         })
     }
 }
@@ -100,7 +204,13 @@ impl UserExpRepository for UserExpRepositoryImpl {
 #[cfg(test)]
 mod test {
     use domain::{
-        entity::{user_builder::UserBuilder, user_exp::UserExp, user_id::UserId},
+        entity::{
+            exp_transaction::{ExpHistoryPage, ExpReason, NewExpTransaction},
+            quest_id::QuestId,
+            user_builder::UserBuilder,
+            user_exp::UserExp,
+            user_id::UserId,
+        },
         repository::{user_exp_repository::UserExpRepository, user_repository::UserRepository},
     };
     use sqlx::MySqlPool;
@@ -110,6 +220,9 @@ mod test {
         user_exp_repository_impl::UserExpRepositoryImpl, user_repository_impl::UserRepositoryImpl,
     };
 
+    // テストで使う経験値の上限
+    const MAX_EXP: i64 = 10_000;
+
     type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
     #[tokio::test]
     async fn test_user_exp_init() -> MyResult<()> {
@@ -145,7 +258,7 @@ mod test {
             .init_exp(&mut tx, &UserId(user_id_str.clone()))
             .await;
         tx.commit().await?;
-        if let Ok(_) = res {
+        if res.is_ok() {
             panic!("init exp must be error due to no users, but initialized");
         }
         Ok(())
@@ -192,8 +305,13 @@ mod test {
         let init_exp = repo.find_by_user_id(&UserId(user_id_str.clone())).await?;
         // add
         let mut tx = pool.begin().await?;
-        repo.add_exp(&mut tx, &UserId(user_id_str.clone()), additional_exp)
-            .await?;
+        repo.add_transaction(
+            &mut tx,
+            &UserId(user_id_str.clone()),
+            &NewExpTransaction::quest_complete(&QuestId(gen_random_str()), additional_exp),
+            MAX_EXP,
+        )
+        .await?;
         tx.commit().await?;
         //find
         let added_exp = repo.find_by_user_id(&UserId(user_id_str.clone())).await?;
@@ -203,7 +321,124 @@ mod test {
             added_exp.experience_points,
             init_exp.experience_points + additional_exp
         );
+        // 台帳に追記されているか
+        let page = ExpHistoryPage {
+            cursor: None,
+            limit: 10,
+        };
+        let transactions = repo
+            .find_transactions(&UserId(user_id_str.clone()), &page)
+            .await?;
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].amount, additional_exp);
+        assert_eq!(transactions[0].reason, ExpReason::QuestComplete);
         //delete
+        delete_test_user(&user_id_str).await?;
+        Ok(())
+    }
+
+    // 上限を超える経験値は飽和し、台帳には実際に反映された量が記録されるかのテスト
+    #[tokio::test]
+    async fn test_user_exp_add_saturates() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id_str = gen_random_str();
+        let user_id = UserId(user_id_str.clone());
+        create_user(pool.clone(), &user_id_str).await?;
+        let repo = UserExpRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        repo.init_exp(&mut tx, &user_id).await?;
+        let first = repo
+            .add_transaction(
+                &mut tx,
+                &user_id,
+                &NewExpTransaction::quest_complete(&QuestId(gen_random_str()), i64::MAX),
+                MAX_EXP,
+            )
+            .await?;
+        let second = repo
+            .add_transaction(
+                &mut tx,
+                &user_id,
+                &NewExpTransaction::quest_complete(&QuestId(gen_random_str()), 10),
+                MAX_EXP,
+            )
+            .await?;
+        tx.commit().await?;
+
+        assert_eq!(first.after, MAX_EXP);
+        assert_eq!(second.applied(), 0);
+        let transactions = repo
+            .find_transactions(
+                &user_id,
+                &ExpHistoryPage {
+                    cursor: None,
+                    limit: 10,
+                },
+            )
+            .await?;
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].amount, MAX_EXP);
+        delete_test_user(&user_id_str).await?;
+        Ok(())
+    }
+
+    // 台帳の合計からuser_expを再計算できるかのテスト
+    #[tokio::test]
+    async fn test_user_exp_rebuild() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id_str = gen_random_str();
+        let user_id = UserId(user_id_str.clone());
+        create_user(pool.clone(), &user_id_str).await?;
+        let repo = UserExpRepositoryImpl::new(pool.clone());
+        let mut tx = pool.begin().await?;
+        repo.init_exp(&mut tx, &user_id).await?;
+        for amount in [10, -3, 5] {
+            repo.add_transaction(
+                &mut tx,
+                &user_id,
+                &NewExpTransaction::quest_complete(&QuestId(gen_random_str()), amount),
+                MAX_EXP,
+            )
+            .await?;
+        }
+        tx.commit().await?;
+
+        // キャッシュをずらしてから再計算する
+        sqlx::query("UPDATE user_exp SET experience_points = 0 WHERE user_id = ?")
+            .bind(&user_id_str)
+            .execute(&pool)
+            .await?;
+        let rebuilt = repo.rebuild(Some(&user_id)).await?;
+        assert_eq!(rebuilt, 1);
+        let exp = repo.find_by_user_id(&user_id).await?;
+        assert_eq!(exp.experience_points, 12);
+
+        // 新しい順で、cursorより古いものだけを返す
+        let first = repo
+            .find_transactions(
+                &user_id,
+                &ExpHistoryPage {
+                    cursor: None,
+                    limit: 1,
+                },
+            )
+            .await?;
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].amount, 5);
+        let rest = repo
+            .find_transactions(
+                &user_id,
+                &ExpHistoryPage {
+                    cursor: Some(first[0].transaction_id),
+                    limit: 10,
+                },
+            )
+            .await?;
+        assert_eq!(
+            rest.iter().map(|t| t.amount).collect::<Vec<_>>(),
+            vec![-3, 10]
+        );
+        delete_test_user(&user_id_str).await?;
         Ok(())
     }
 
@@ -258,7 +493,7 @@ mod test {
                 (?, ?, ?, ?)
             "#,
         )
-        .bind(format!("{}", user_id))
+        .bind(user_id.to_string())
         .bind(format!("test_name_{}", user_id))
         .bind(format!("test_email_{}", user_id))
         .bind(format!("test_pwd_{}", user_id))
@@ -274,7 +509,7 @@ mod test {
                 DELETE FROM users WHERE user_id = ?
             "#,
         )
-        .bind(format!("{}", user_id))
+        .bind(user_id.to_string())
         .execute(&pool)
         .await?;
         Ok(())
@@ -288,7 +523,7 @@ mod test {
                 WHERE user_id = ?
             "#,
         )
-        .bind(format!("{}", user_id))
+        .bind(user_id.to_string())
         .fetch_one(&pool)
         .await?;
         Ok(exp)
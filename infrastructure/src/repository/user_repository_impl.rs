@@ -1,12 +1,15 @@
 use std::{future::Future, pin::Pin};
 
+use chrono_tz::Tz;
 use domain::{
-    entity::{user::User, user_builder::UserBuilder, user_id::UserId},
+    entity::{
+        privacy_settings::PrivacySettings, user::User, user_builder::UserBuilder, user_id::UserId,
+    },
     repository::{repository_error::RepositoryError, user_repository::UserRepository},
 };
-use sqlx::{MySql, MySqlPool, Row, Transaction};
+use sqlx::{types::chrono::Utc, MySql, MySqlPool, Row, Transaction};
 
-use super::to_repo_err;
+use super::{to_repo_err, utc_offset_at};
 
 #[derive(Debug, Clone)]
 pub struct UserRepositoryImpl {
@@ -123,6 +126,98 @@ impl UserRepository for UserRepositoryImpl {
         })
     }
 
+    fn update_time_zone<'a>(
+        &'a self,
+        id: &'a UserId,
+        time_zone: Tz,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // utc_offsetは複数のユーザーをまとめて扱うSQLのための現在のオフセット
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE users
+                    SET time_zone = ?, utc_offset = ?
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(time_zone.name())
+            .bind(utc_offset_at(time_zone, Utc::now().naive_utc()))
+            .bind(&id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    fn find_privacy_settings<'a>(
+        &'a self,
+        id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<PrivacySettings, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let row = sqlx::query(
+                r#"
+                    SELECT show_on_global_leaderboard, show_on_friend_leaderboard,
+                    allow_friend_requests
+                    FROM users
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&id.0)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(PrivacySettings {
+                show_on_global_leaderboard: row
+                    .try_get("show_on_global_leaderboard")
+                    .map_err(to_repo_err)?,
+                show_on_friend_leaderboard: row
+                    .try_get("show_on_friend_leaderboard")
+                    .map_err(to_repo_err)?,
+                allow_friend_requests: row.try_get("allow_friend_requests").map_err(to_repo_err)?,
+            })
+        })
+    }
+
+    fn update_privacy_settings<'a>(
+        &'a self,
+        id: &'a UserId,
+        settings: &'a PrivacySettings,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE users
+                    SET
+                    show_on_global_leaderboard = ?,
+                    show_on_friend_leaderboard = ?,
+                    allow_friend_requests = ?
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(settings.show_on_global_leaderboard)
+            .bind(settings.show_on_friend_leaderboard)
+            .bind(settings.allow_friend_requests)
+            .bind(&id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
     fn delete<'a>(
         &'a self,
         id: &'a UserId,
@@ -179,7 +274,10 @@ impl UserRepository for UserRepositoryImpl {
 #[cfg(test)]
 mod test {
     use domain::{
-        entity::{user::User, user_builder::UserBuilder, user_id::UserId},
+        entity::{
+            privacy_settings::PrivacySettings, user::User, user_builder::UserBuilder,
+            user_id::UserId,
+        },
         repository::user_repository::UserRepository,
     };
     use sqlx::MySqlPool;
@@ -287,6 +385,28 @@ mod test {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_privacy_settings() -> MyResult<()> {
+        let (user_id, builder) = builder();
+        create_user_batch(user_id.clone(), builder).await?;
+
+        let service = UserRepositoryImpl::new(gen_pool().await?);
+        // デフォルトではすべて公開されている
+        let settings = service.find_privacy_settings(&user_id).await?;
+        assert_eq!(settings, PrivacySettings::default());
+
+        let updated = PrivacySettings {
+            show_on_global_leaderboard: false,
+            show_on_friend_leaderboard: true,
+            allow_friend_requests: false,
+        };
+        service.update_privacy_settings(&user_id, &updated).await?;
+        assert_eq!(service.find_privacy_settings(&user_id).await?, updated);
+
+        service.delete(&user_id).await?;
+        Ok(())
+    }
+
     // Helper methods
     fn builder() -> (UserId, UserBuilder) {
         let random_string = Uuid::new_v4().to_string();
@@ -0,0 +1,732 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::{
+        user_id::UserId,
+        webhook::{
+            DeliveryStatus, PendingDelivery, Webhook, WebhookDelivery, WebhookPayload,
+            MAX_DELIVERY_ATTEMPTS,
+        },
+    },
+    repository::{repository_error::RepositoryError, webhook_repository::WebhookRepository},
+};
+use sqlx::{MySql, MySqlPool, Row, Transaction};
+
+use super::to_repo_err;
+
+// 送信中のままこの秒数を過ぎた配信は、送信していたインスタンスが停止したとみなして送信待ちに戻す
+static DELIVERY_STUCK_SECONDS: u32 = 600;
+
+#[derive(Debug, Clone)]
+pub struct WebhookRepositoryImpl {
+    pool: MySqlPool,
+}
+
+impl WebhookRepositoryImpl {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+impl WebhookRepository for WebhookRepositoryImpl {
+    fn count<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<u32, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // ユーザーの行をロックすることで、同じユーザーのWebhookの登録を直列化する
+            sqlx::query(
+                r#"
+                    SELECT user_id FROM users
+                    WHERE user_id = ?
+                    FOR UPDATE
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            let row = sqlx::query(
+                r#"
+                    SELECT COUNT(*) AS count FROM webhooks
+                    WHERE user_id = ?
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            let count: i64 = row.try_get("count").map_err(to_repo_err)?;
+            Ok(count.max(0) as u32)
+        })
+    }
+
+    fn create<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        webhook: &'a Webhook,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    INSERT INTO webhooks
+                    (webhook_id, user_id, url, secret, events, is_active, created_at)
+                    VALUES
+                    (?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&webhook.webhook_id)
+            .bind(&webhook.user_id.0)
+            .bind(&webhook.url)
+            .bind(&webhook.secret)
+            .bind(webhook.events_column())
+            .bind(webhook.active)
+            .bind(webhook.created_at)
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn find_by_user_id<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Webhook>, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let webhooks = sqlx::query_as(
+                r#"
+                    SELECT webhook_id, user_id, url, secret, events, is_active, created_at
+                    FROM webhooks
+                    WHERE user_id = ?
+                    ORDER BY id
+                "#,
+            )
+            .bind(&user_id.0)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(webhooks)
+        })
+    }
+
+    fn find_by_id<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<Webhook, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let webhook = sqlx::query_as(
+                r#"
+                    SELECT webhook_id, user_id, url, secret, events, is_active, created_at
+                    FROM webhooks
+                    WHERE webhook_id = ?
+                    AND user_id = ?
+                "#,
+            )
+            .bind(webhook_id)
+            .bind(&user_id.0)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(webhook)
+        })
+    }
+
+    fn update<'a>(
+        &'a self,
+        webhook: &'a Webhook,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE webhooks
+                    SET
+                    url = ?,
+                    events = ?,
+                    is_active = ?
+                    WHERE webhook_id = ?
+                    AND user_id = ?
+                "#,
+            )
+            .bind(&webhook.url)
+            .bind(webhook.events_column())
+            .bind(webhook.active)
+            .bind(&webhook.webhook_id)
+            .bind(&webhook.user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    fn update_secret<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        user_id: &'a UserId,
+        secret: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                    UPDATE webhooks
+                    SET secret = ?
+                    WHERE webhook_id = ?
+                    AND user_id = ?
+                "#,
+            )
+            .bind(secret)
+            .bind(webhook_id)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    fn delete<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        user_id: &'a UserId,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 配信の記録は外部キーのON DELETE CASCADEで削除される
+            let affected_len = sqlx::query(
+                r#"
+                    DELETE FROM webhooks
+                    WHERE webhook_id = ?
+                    AND user_id = ?
+                "#,
+            )
+            .bind(webhook_id)
+            .bind(&user_id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+
+            if affected_len == 1 {
+                Ok(())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        })
+    }
+
+    fn enqueue<'a>(
+        &'a self,
+        tx: &'a mut Transaction<'_, MySql>,
+        user_id: &'a UserId,
+        payload: &'a WebhookPayload<'a>,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let body = serde_json::to_string(payload)
+                .map_err(|e| RepositoryError::InvalidData(e.to_string()))?;
+            // Webhookごとに異なるdelivery_idを付ける
+            let result = sqlx::query(
+                r#"
+                    INSERT INTO webhook_deliveries
+                    (delivery_id, webhook_id, event, payload, next_attempt_at, created_at, updated_at)
+                    SELECT
+                    UUID(),
+                    webhook_id,
+                    ?,
+                    ?,
+                    UTC_TIMESTAMP(),
+                    UTC_TIMESTAMP(),
+                    UTC_TIMESTAMP()
+                    FROM webhooks
+                    WHERE user_id = ?
+                    AND is_active = TRUE
+                    AND FIND_IN_SET(?, events) > 0
+                "#,
+            )
+            .bind(payload.event.as_str())
+            .bind(&body)
+            .bind(&user_id.0)
+            .bind(payload.event.as_str())
+            .execute(&mut **tx)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected())
+        })
+    }
+
+    fn find_deliveries<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        limit: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<WebhookDelivery>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            let deliveries = sqlx::query_as(
+                r#"
+                    SELECT
+                    delivery_id, event, status, attempts, payload, last_status_code, last_error,
+                    next_attempt_at, created_at, delivered_at
+                    FROM webhook_deliveries
+                    WHERE webhook_id = ?
+                    ORDER BY id DESC
+                    LIMIT ?
+                "#,
+            )
+            .bind(webhook_id)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(deliveries)
+        })
+    }
+
+    fn redeliver<'a>(
+        &'a self,
+        webhook_id: &'a str,
+        delivery_id: &'a str,
+        new_delivery_id: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<WebhookDelivery, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let affected_len = sqlx::query(
+                r#"
+                    INSERT INTO webhook_deliveries
+                    (delivery_id, webhook_id, event, payload, next_attempt_at, created_at, updated_at)
+                    SELECT
+                    ?,
+                    webhook_id,
+                    event,
+                    payload,
+                    UTC_TIMESTAMP(),
+                    UTC_TIMESTAMP(),
+                    UTC_TIMESTAMP()
+                    FROM webhook_deliveries
+                    WHERE delivery_id = ?
+                    AND webhook_id = ?
+                "#,
+            )
+            .bind(new_delivery_id)
+            .bind(delivery_id)
+            .bind(webhook_id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?
+            .rows_affected();
+            if affected_len != 1 {
+                return Err(RepositoryError::NotFound);
+            }
+            let delivery = sqlx::query_as(
+                r#"
+                    SELECT
+                    delivery_id, event, status, attempts, payload, last_status_code, last_error,
+                    next_attempt_at, created_at, delivered_at
+                    FROM webhook_deliveries
+                    WHERE delivery_id = ?
+                "#,
+            )
+            .bind(new_delivery_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(delivery)
+        })
+    }
+
+    fn claim_due<'a>(
+        &'a self,
+        holder: &'a str,
+        limit: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PendingDelivery>, RepositoryError>> + Send + 'a>>
+    {
+        Box::pin(async move {
+            // 停止したインスタンスが送信中のまま残した配信を戻す(再試行の上限に達した場合は失敗にする)
+            sqlx::query(
+                r#"
+                    UPDATE webhook_deliveries
+                    SET
+                    status = IF(attempts >= ?, 'failed', 'pending'),
+                    locked_by = NULL,
+                    updated_at = UTC_TIMESTAMP()
+                    WHERE status = 'running'
+                    AND updated_at < DATE_SUB(UTC_TIMESTAMP(), INTERVAL ? SECOND)
+                "#,
+            )
+            .bind(MAX_DELIVERY_ATTEMPTS)
+            .bind(DELIVERY_STUCK_SECONDS)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+
+            // リースが切れた直後に他のインスタンスと重なった場合でも同じ配信を取得しないよう、
+            // 他のトランザクションがロックしている行は飛ばす
+            // 無効にしたWebhookの配信は、有効に戻すまで送信待ちのままにする
+            let mut tx = self.pool.begin().await.map_err(to_repo_err)?;
+            let mut deliveries: Vec<PendingDelivery> = sqlx::query_as(
+                r#"
+                    SELECT
+                    webhook_deliveries.id,
+                    webhook_deliveries.delivery_id,
+                    webhooks.url,
+                    webhooks.secret,
+                    webhook_deliveries.event,
+                    webhook_deliveries.payload,
+                    webhook_deliveries.attempts
+                    FROM webhook_deliveries
+                    JOIN webhooks ON webhook_deliveries.webhook_id = webhooks.webhook_id
+                    WHERE webhook_deliveries.status = 'pending'
+                    AND webhook_deliveries.next_attempt_at <= UTC_TIMESTAMP()
+                    AND webhooks.is_active = TRUE
+                    ORDER BY webhook_deliveries.next_attempt_at, webhook_deliveries.id
+                    LIMIT ?
+                    FOR UPDATE OF webhook_deliveries SKIP LOCKED
+                "#,
+            )
+            .bind(limit)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(to_repo_err)?;
+            for delivery in deliveries.iter_mut() {
+                sqlx::query(
+                    r#"
+                        UPDATE webhook_deliveries
+                        SET
+                        status = 'running',
+                        locked_by = ?,
+                        attempts = attempts + 1,
+                        updated_at = UTC_TIMESTAMP()
+                        WHERE id = ?
+                    "#,
+                )
+                .bind(holder)
+                .bind(delivery.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(to_repo_err)?;
+                delivery.attempts += 1;
+            }
+            tx.commit().await.map_err(to_repo_err)?;
+            Ok(deliveries)
+        })
+    }
+
+    fn release<'a>(
+        &'a self,
+        holder: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    UPDATE webhook_deliveries
+                    SET
+                    status = 'pending',
+                    locked_by = NULL,
+                    attempts = GREATEST(attempts - 1, 0),
+                    updated_at = UTC_TIMESTAMP()
+                    WHERE status = 'running'
+                    AND locked_by = ?
+                "#,
+            )
+            .bind(holder)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected())
+        })
+    }
+
+    fn finish<'a>(
+        &'a self,
+        id: i64,
+        status: DeliveryStatus,
+        status_code: Option<u16>,
+        error: Option<&'a str>,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    UPDATE webhook_deliveries
+                    SET
+                    status = ?,
+                    last_status_code = ?,
+                    last_error = ?,
+                    locked_by = NULL,
+                    delivered_at = IF(? = 'succeeded', UTC_TIMESTAMP(), NULL),
+                    updated_at = UTC_TIMESTAMP()
+                    WHERE id = ?
+                "#,
+            )
+            .bind(status.as_str())
+            .bind(status_code)
+            .bind(error)
+            .bind(status.as_str())
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn retry<'a>(
+        &'a self,
+        id: i64,
+        delay_seconds: u32,
+        status_code: Option<u16>,
+        error: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<(), RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            sqlx::query(
+                r#"
+                    UPDATE webhook_deliveries
+                    SET
+                    status = 'pending',
+                    next_attempt_at = DATE_ADD(UTC_TIMESTAMP(), INTERVAL ? SECOND),
+                    last_status_code = ?,
+                    last_error = ?,
+                    locked_by = NULL,
+                    updated_at = UTC_TIMESTAMP()
+                    WHERE id = ?
+                "#,
+            )
+            .bind(delay_seconds)
+            .bind(status_code)
+            .bind(error)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(())
+        })
+    }
+
+    fn purge<'a>(
+        &'a self,
+        days: u32,
+    ) -> Pin<Box<dyn Future<Output = Result<u64, RepositoryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let result = sqlx::query(
+                r#"
+                    DELETE FROM webhook_deliveries
+                    WHERE status IN ('succeeded', 'failed')
+                    AND updated_at < DATE_SUB(UTC_TIMESTAMP(), INTERVAL ? DAY)
+                "#,
+            )
+            .bind(days)
+            .execute(&self.pool)
+            .await
+            .map_err(to_repo_err)?;
+            Ok(result.rows_affected())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            daily_mission_id::DailyMissionId,
+            domain_event::{DomainEvent, LevelUp, MissionCompleted},
+            user_id::UserId,
+            webhook::{DeliveryStatus, Webhook, WebhookEvent, WebhookPayload},
+        },
+        repository::webhook_repository::WebhookRepository,
+    };
+    use sqlx::{
+        types::chrono::{NaiveDate, Utc},
+        MySqlPool,
+    };
+    use uuid::Uuid;
+
+    use crate::repository::webhook_repository_impl::WebhookRepositoryImpl;
+
+    type MyResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+    #[test]
+    fn test_payload_json() -> MyResult<()> {
+        let event = DomainEvent::MissionCompleted(MissionCompleted {
+            user_id: UserId("test_user".to_string()),
+            mission_id: DailyMissionId("test_mission".to_string()),
+            title: "Read 30 pages".to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 10, 20).unwrap(),
+            exp_awarded: 2,
+            occurred_at: NaiveDate::from_ymd_opt(2026, 10, 20)
+                .unwrap()
+                .and_hms_opt(1, 2, 3)
+                .unwrap(),
+        });
+        let json: serde_json::Value = serde_json::to_value(WebhookPayload::new(&event).unwrap())?;
+        assert_eq!(json["event"], "mission.completed");
+        assert_eq!(json["occurredAt"], "2026-10-20T01:02:03");
+        // dataはイベントの内容だけで、DomainEventのtypeでは囲まない
+        assert_eq!(json["data"]["missionId"], "test_mission");
+        assert_eq!(json["data"]["expAwarded"], 2);
+        assert!(json["data"].get("type").is_none());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_and_redeliver() -> MyResult<()> {
+        let pool = gen_pool().await?;
+        let user_id = gen_random_str();
+        let holder = gen_random_str();
+        create_user(pool.clone(), &user_id).await?;
+        let repo = WebhookRepositoryImpl::new(pool.clone());
+        let user = UserId(user_id.clone());
+
+        let webhook = Webhook {
+            webhook_id: gen_random_str(),
+            user_id: user.clone(),
+            url: "https://example.com/hook".to_string(),
+            secret: "test_secret".to_string(),
+            events: vec![WebhookEvent::LevelUp],
+            active: true,
+            created_at: Utc::now().naive_utc(),
+        };
+        let mut tx = pool.begin().await?;
+        assert_eq!(repo.count(&mut tx, &user).await?, 0);
+        repo.create(&mut tx, &webhook).await?;
+        tx.commit().await?;
+        let found = repo.find_by_id(&webhook.webhook_id, &user).await?;
+        assert_eq!(found.events, webhook.events);
+        assert_eq!(found.secret, webhook.secret);
+
+        // 購読しているイベントだけが登録される
+        let level_up = DomainEvent::LevelUp(LevelUp {
+            user_id: user.clone(),
+            previous_level: 1,
+            new_level: 2,
+            total_exp: 10,
+            occurred_at: Utc::now().naive_utc(),
+        });
+        let completed = DomainEvent::MissionCompleted(MissionCompleted {
+            user_id: user.clone(),
+            mission_id: DailyMissionId(gen_random_str()),
+            title: "test".to_string(),
+            date: Utc::now().date_naive(),
+            exp_awarded: 1,
+            occurred_at: Utc::now().naive_utc(),
+        });
+        let mut tx = pool.begin().await?;
+        assert_eq!(
+            repo.enqueue(&mut tx, &user, &WebhookPayload::new(&level_up).unwrap())
+                .await?,
+            1
+        );
+        assert_eq!(
+            repo.enqueue(&mut tx, &user, &WebhookPayload::new(&completed).unwrap())
+                .await?,
+            0
+        );
+        tx.commit().await?;
+
+        let claimed = repo.claim_due(&holder, 1000).await?;
+        let delivery = claimed
+            .iter()
+            .find(|d| d.url == webhook.url && d.secret == webhook.secret)
+            .expect("delivery is not claimed");
+        assert_eq!(delivery.event, WebhookEvent::LevelUp);
+        assert_eq!(delivery.attempts, 1);
+
+        // 送信せずに戻した配信は、実行回数を数えずに再び取得される
+        assert!(repo.release(&holder).await? >= 1);
+        let claimed = repo.claim_due(&holder, 1000).await?;
+        let delivery = claimed
+            .iter()
+            .find(|d| d.id == delivery.id)
+            .expect("released delivery is not claimed again");
+        assert_eq!(delivery.attempts, 1);
+
+        // 再試行する配信は待つ間は取得されない
+        repo.retry(delivery.id, 3600, Some(503), "test_error")
+            .await?;
+        let claimed = repo.claim_due(&holder, 1000).await?;
+        assert!(claimed.iter().all(|d| d.id != delivery.id));
+        repo.finish(delivery.id, DeliveryStatus::Succeeded, Some(200), None)
+            .await?;
+
+        let deliveries = repo.find_deliveries(&webhook.webhook_id, 10).await?;
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].status, DeliveryStatus::Succeeded);
+        assert_eq!(deliveries[0].last_status_code, Some(200));
+        assert!(deliveries[0].delivered_at.is_some());
+
+        // 送り直すと同じペイロードの新しい配信が登録される
+        let new_delivery_id = gen_random_str();
+        let redelivered = repo
+            .redeliver(
+                &webhook.webhook_id,
+                &deliveries[0].delivery_id,
+                &new_delivery_id,
+            )
+            .await?;
+        assert_eq!(redelivered.delivery_id, new_delivery_id);
+        assert_eq!(redelivered.status, DeliveryStatus::Pending);
+        assert_eq!(redelivered.payload, deliveries[0].payload);
+        assert!(repo
+            .redeliver(&webhook.webhook_id, &gen_random_str(), &gen_random_str())
+            .await
+            .is_err());
+
+        repo.delete(&webhook.webhook_id, &user).await?;
+        assert!(repo
+            .find_deliveries(&webhook.webhook_id, 10)
+            .await?
+            .is_empty());
+
+        delete_test_user(pool, &user_id).await?;
+        Ok(())
+    }
+
+    async fn gen_pool() -> MyResult<MySqlPool> {
+        let database_url = dotenvy::var("DATABASE_URL")?;
+        let pool = MySqlPool::connect(&database_url).await?;
+        Ok(pool)
+    }
+
+    fn gen_random_str() -> String {
+        Uuid::new_v4().to_string()
+    }
+
+    async fn create_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                INSERT INTO users
+                (user_id, user_name, email, password_hash)
+                VALUES
+                (?, ?, ?, ?)
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("test_name_{}", user_id))
+        .bind(format!("test_email_{}", user_id))
+        .bind(format!("test_pwd_{}", user_id))
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn delete_test_user(pool: MySqlPool, user_id: &str) -> MyResult<()> {
+        sqlx::query(
+            r#"
+                DELETE FROM users WHERE user_id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::{future::Future, pin::Pin, sync::LazyLock};
+
+use domain::{
+    entity::{domain_event::DomainEvent, user_id::UserId},
+    service::{
+        event_publisher::EventPublisher,
+        event_subscriber::{EventSubscriber, EventSubscription},
+        service_error::subscription_error::SubscriptionError,
+    },
+};
+use tokio::sync::broadcast::{self, error::RecvError, Receiver, Sender};
+
+// 購読者の処理が遅れた場合に保持しておくイベントの数
+// これを超えると古いイベントから破棄され、購読者はLaggedエラーを受け取る
+static EVENT_CAPACITY: usize = 1024;
+
+// プロセス内のイベントバス
+// サービスはリクエストごとに生成されるため、送信側はプロセスで1つだけ保持する
+static EVENT_BUS: LazyLock<Sender<DomainEvent>> =
+    LazyLock::new(|| broadcast::channel(EVENT_CAPACITY).0);
+
+/// tokioのbroadcastチャンネルを使ったEventPublisherとEventSubscriberの実装
+/// イベントは発行したインスタンスの中だけで配信される
+#[derive(Debug, Clone)]
+pub struct EventPublisherImpl;
+
+impl EventPublisherImpl {
+    /// 以降に発行されるドメインイベントを購読する
+    pub fn subscribe() -> Receiver<DomainEvent> {
+        EVENT_BUS.subscribe()
+    }
+}
+
+impl EventPublisher for EventPublisherImpl {
+    fn publish(&self, event: DomainEvent) {
+        // 購読者がいない場合はErrになるが、イベントは破棄してよい
+        let _ = EVENT_BUS.send(event);
+    }
+}
+
+impl EventSubscriber for EventPublisherImpl {
+    type Subscription = UserEventSubscription;
+
+    fn subscribe_user(&self, user_id: &UserId) -> Self::Subscription {
+        UserEventSubscription::new(user_id.clone(), Self::subscribe())
+    }
+}
+
+/// 1人のユーザーのイベントだけを受け取る購読
+/// すべてのユーザーのイベントを受信し、他のユーザーのものは読み飛ばす
+#[derive(Debug)]
+pub struct UserEventSubscription {
+    user_id: UserId,
+    rx: Receiver<DomainEvent>,
+}
+
+impl UserEventSubscription {
+    fn new(user_id: UserId, rx: Receiver<DomainEvent>) -> Self {
+        Self { user_id, rx }
+    }
+}
+
+impl EventSubscription for UserEventSubscription {
+    fn recv<'a>(
+        &'a mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<DomainEvent, SubscriptionError>> + Send + 'a>> {
+        Box::pin(async move {
+            loop {
+                match self.rx.recv().await {
+                    Ok(event) if *event.user_id() == self.user_id => return Ok(event),
+                    Ok(_) => continue,
+                    // 破棄されたイベントに他のユーザーのものが含まれていても区別できないため、そのまま返す
+                    Err(RecvError::Lagged(skipped)) => {
+                        return Err(SubscriptionError::Lagged(skipped))
+                    }
+                    Err(RecvError::Closed) => return Err(SubscriptionError::Closed),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        entity::{
+            domain_event::{DomainEvent, LevelUp},
+            user_id::UserId,
+        },
+        service::{
+            event_publisher::EventPublisher, event_subscriber::EventSubscription,
+            service_error::subscription_error::SubscriptionError,
+        },
+    };
+    use sqlx::types::chrono::Utc;
+    use tokio::sync::broadcast;
+
+    use super::{EventPublisherImpl, UserEventSubscription};
+
+    #[tokio::test]
+    async fn test_publish_and_subscribe() {
+        let mut rx = EventPublisherImpl::subscribe();
+        let event = DomainEvent::LevelUp(LevelUp {
+            user_id: UserId("test_user".to_string()),
+            previous_level: 1,
+            new_level: 2,
+            total_exp: 10,
+            occurred_at: Utc::now().naive_utc(),
+        });
+        EventPublisherImpl.publish(event.clone());
+        assert_eq!(rx.recv().await.unwrap(), event);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_user() {
+        // 他のテストのイベントが混ざらないよう、プロセスのイベントバスとは別のチャンネルを使う
+        let (tx, rx) = broadcast::channel(2);
+        let mut subscription =
+            UserEventSubscription::new(UserId("test_subscriber".to_string()), rx);
+        let occurred_at = Utc::now().naive_utc();
+        let gen_event = |user: &str, new_level: u32| {
+            DomainEvent::LevelUp(LevelUp {
+                user_id: UserId(user.to_string()),
+                previous_level: new_level - 1,
+                new_level,
+                total_exp: 10,
+                occurred_at,
+            })
+        };
+        // 他のユーザーのイベントは届かない
+        tx.send(gen_event("other_user", 2)).unwrap();
+        tx.send(gen_event("test_subscriber", 3)).unwrap();
+        assert_eq!(
+            subscription.recv().await,
+            Ok(gen_event("test_subscriber", 3))
+        );
+
+        // 保持できる数を超えた場合は破棄された数を返し、以降のイベントは届く
+        for level in 4..=6 {
+            tx.send(gen_event("test_subscriber", level)).unwrap();
+        }
+        assert_eq!(subscription.recv().await, Err(SubscriptionError::Lagged(1)));
+        assert_eq!(
+            subscription.recv().await,
+            Ok(gen_event("test_subscriber", 5))
+        );
+
+        drop(tx);
+        assert_eq!(
+            subscription.recv().await.unwrap(),
+            gen_event("test_subscriber", 6)
+        );
+        assert_eq!(subscription.recv().await, Err(SubscriptionError::Closed));
+    }
+}
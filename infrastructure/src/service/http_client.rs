@@ -0,0 +1,345 @@
+use std::{
+    io::{self, Read, Write},
+    net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs},
+    sync::{Arc, LazyLock},
+    time::{Duration, Instant},
+};
+
+use domain::entity::webhook::is_public_address;
+use rustls::{pki_types::ServerName, ClientConfig, ClientConnection, RootCertStore, StreamOwned};
+use url::{Host, Url};
+
+// 接続、送信、受信のそれぞれのタイムアウト
+// リクエスト全体の時間はpost()のtimeoutで制限する
+static HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 保持するレスポンスのボディの最大のバイト数
+static MAX_RESPONSE_BYTES: u64 = 64 * 1024;
+
+// webpki-rootsのルート証明書で検証するTLSの設定
+static TLS_CONFIG: LazyLock<Arc<ClientConfig>> = LazyLock::new(|| {
+    let roots = RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let config =
+        ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .expect("cannot build tls config")
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+    Arc::new(config)
+});
+
+/// HTTPのレスポンス
+#[derive(Debug, Clone)]
+pub(crate) struct HttpResponse {
+    pub(crate) status: u16,
+    pub(crate) body: Vec<u8>,
+}
+
+/// 送信先の制限
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Destination {
+    /// 制限しない(テストでローカルの受信先に送る場合のみ使う)
+    #[cfg(test)]
+    Any,
+    /// httpsで、名前解決したアドレスがすべてグローバルに到達できる送信先のみ
+    /// 利用者が指定したURLに送る場合に使い、内部のサービスに届かないようにする
+    Public,
+}
+
+/// urlにPOSTでbodyを送る
+/// 通知の送信など1回だけのリクエストに使うため、HTTP/1.1で送って接続は使い回さない
+/// リダイレクトには従わず、3xxのレスポンスもそのまま返す
+/// 少しずつ応答する送信先に送信を止められないよう、接続からレスポンスの受信までをtimeoutで打ち切る
+pub(crate) async fn post(
+    url: &str,
+    headers: Vec<(&'static str, String)>,
+    body: Vec<u8>,
+    destination: Destination,
+    timeout: Duration,
+) -> Result<HttpResponse, String> {
+    let url = Url::parse(url).map_err(|e| e.to_string())?;
+    let deadline = Instant::now() + timeout;
+    let request = tokio::task::spawn_blocking(move || {
+        post_blocking(&url, &headers, &body, destination, deadline)
+    });
+    match tokio::time::timeout(timeout, request).await {
+        Ok(result) => result.map_err(|e| e.to_string())?,
+        Err(_) => Err(format!("request timed out after {}s", timeout.as_secs())),
+    }
+}
+
+fn post_blocking(
+    url: &Url,
+    headers: &[(&'static str, String)],
+    body: &[u8],
+    destination: Destination,
+    deadline: Instant,
+) -> Result<HttpResponse, String> {
+    if destination == Destination::Public && url.scheme() != "https" {
+        return Err(format!("unsupported scheme: {}", url.scheme()));
+    }
+    let (addrs, server_name) = resolve(url)?;
+    // 確認したアドレスに直接接続するため、確認した後に名前解決の結果が変わっても影響を受けない
+    if destination == Destination::Public {
+        if let Some(addr) = addrs.iter().find(|a| !is_public_address(&a.ip())) {
+            return Err(format!("destination address is not public: {}", addr.ip()));
+        }
+    }
+    let addr = *addrs.first().ok_or("cannot resolve host")?;
+    let stream = TcpStream::connect_timeout(&addr, HTTP_TIMEOUT).map_err(|e| e.to_string())?;
+    let stream = DeadlineStream { stream, deadline };
+
+    // IPv6のアドレスはHostヘッダーでも[]で囲む(host_strは[]を含む)
+    let host = url.host_str().ok_or("url has no host")?;
+    let mut request = format!(
+        "POST {}{} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        url.path(),
+        url.query().map(|q| format!("?{}", q)).unwrap_or_default(),
+        // 既定以外のポートの場合はHostヘッダーにポートを含める
+        match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        },
+        body.len()
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    request.push_str("\r\n");
+    let mut request = request.into_bytes();
+    request.extend_from_slice(body);
+
+    let response = match url.scheme() {
+        "http" => exchange(stream, &request)?,
+        "https" => {
+            let conn = ClientConnection::new(TLS_CONFIG.clone(), server_name)
+                .map_err(|e| e.to_string())?;
+            exchange(StreamOwned::new(conn, stream), &request)?
+        }
+        scheme => return Err(format!("unsupported scheme: {}", scheme)),
+    };
+    parse_response(&response)
+}
+
+// 接続するアドレスとTLSで検証するサーバー名
+// IPアドレスのホストは名前解決せず、そのアドレスに接続してIPアドレスの証明書で検証する
+fn resolve(url: &Url) -> Result<(Vec<SocketAddr>, ServerName<'static>), String> {
+    let port = url.port_or_known_default().ok_or("url has no port")?;
+    let ip = match url.host().ok_or("url has no host")? {
+        Host::Domain(domain) => {
+            let addrs = (domain, port)
+                .to_socket_addrs()
+                .map_err(|e| e.to_string())?
+                .collect();
+            let server_name =
+                ServerName::try_from(domain.to_string()).map_err(|e| e.to_string())?;
+            return Ok((addrs, server_name));
+        }
+        Host::Ipv4(ip) => IpAddr::V4(ip),
+        Host::Ipv6(ip) => IpAddr::V6(ip),
+    };
+    Ok((
+        vec![SocketAddr::new(ip, port)],
+        ServerName::IpAddress(ip.into()),
+    ))
+}
+
+// 読み書きのたびに残りの時間をタイムアウトに設定し、deadlineを過ぎた場合はエラーにするTCPの接続
+struct DeadlineStream {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl DeadlineStream {
+    fn remaining(&self) -> io::Result<Duration> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "request timed out"));
+        }
+        Ok(remaining.min(HTTP_TIMEOUT))
+    }
+}
+
+impl Read for DeadlineStream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.set_read_timeout(Some(self.remaining()?))?;
+        self.stream.read(buf)
+    }
+}
+
+impl Write for DeadlineStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.set_write_timeout(Some(self.remaining()?))?;
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+// リクエストを送り、サーバーが接続を閉じるまでレスポンスを読む
+fn exchange<S: Read + Write>(mut stream: S, request: &[u8]) -> Result<Vec<u8>, String> {
+    stream.write_all(request).map_err(|e| e.to_string())?;
+    stream.flush().map_err(|e| e.to_string())?;
+    let mut response = vec![];
+    let result = stream.take(MAX_RESPONSE_BYTES).read_to_end(&mut response);
+    // close_notifyを送らずに接続を閉じるサーバーもあるため、読めた分があればレスポンスとして扱う
+    match result {
+        Ok(_) => Ok(response),
+        Err(_) if !response.is_empty() => Ok(response),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+// ステータスラインとボディを取り出す
+// ヘッダーはTransfer-Encodingのみを使い、chunkedの場合はボディを復号する
+fn parse_response(response: &[u8]) -> Result<HttpResponse, String> {
+    let status = response
+        .split(|&b| b == b'\n')
+        .next()
+        .and_then(|line| std::str::from_utf8(line).ok())
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse().ok())
+        .ok_or("malformed http response")?;
+    let Some(header_end) = response.windows(4).position(|w| w == b"\r\n\r\n") else {
+        return Ok(HttpResponse {
+            status,
+            body: vec![],
+        });
+    };
+    let is_chunked = String::from_utf8_lossy(&response[..header_end])
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_once(':'))
+        .any(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding")
+                && value.to_ascii_lowercase().contains("chunked")
+        });
+    let body = &response[header_end + 4..];
+    let body = if is_chunked {
+        decode_chunked(body)
+    } else {
+        body.to_vec()
+    };
+    Ok(HttpResponse { status, body })
+}
+
+// chunkedのボディを復号する
+// レスポンスは最大のバイト数で切り詰めるため、途中で終わっている場合は読めた分までを返す
+fn decode_chunked(mut body: &[u8]) -> Vec<u8> {
+    let mut decoded = vec![];
+    while let Some(line_end) = body.windows(2).position(|w| w == b"\r\n") {
+        // 拡張(;以降)は使わない
+        let size = std::str::from_utf8(&body[..line_end])
+            .ok()
+            .and_then(|line| line.split(';').next())
+            .and_then(|size| usize::from_str_radix(size.trim(), 16).ok());
+        let Some(size) = size.filter(|size| *size > 0) else {
+            break;
+        };
+        let chunk = &body[line_end + 2..];
+        decoded.extend_from_slice(&chunk[..size.min(chunk.len())]);
+        if chunk.len() < size + 2 {
+            break;
+        }
+        body = &chunk[size + 2..];
+    }
+    decoded
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::Write,
+        net::{IpAddr, Ipv6Addr, SocketAddr, TcpListener},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use rustls::pki_types::ServerName;
+    use url::Url;
+
+    use super::{parse_response, post, resolve, Destination};
+
+    #[test]
+    fn test_parse_response() {
+        let response = parse_response(b"HTTP/1.1 410 Gone\r\nContent-Length: 2\r\n\r\nno").unwrap();
+        assert_eq!(response.status, 410);
+        assert_eq!(response.body, b"no");
+        assert!(parse_response(b"garbage").is_err());
+
+        // chunkedのボディは復号する
+        let response = parse_response(
+            b"HTTP/1.1 500 Internal Server Error\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nWiki\r\n6;ext=1\r\npedia \r\n0\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(response.body, b"Wikipedia ");
+        // 途中で切り詰められた場合は読めた分までを返す
+        let response =
+            parse_response(b"HTTP/1.1 200 OK\r\ntransfer-encoding: Chunked\r\n\r\n4\r\nWi")
+                .unwrap();
+        assert_eq!(response.body, b"Wi");
+    }
+
+    #[test]
+    fn test_resolve_ip_literal() {
+        // IPv6のアドレスは[]を除いて、名前解決せずに接続する
+        let url = Url::parse("https://[2001:4860:4860::8888]/hook").unwrap();
+        let (addrs, server_name) = resolve(&url).unwrap();
+        let ip = IpAddr::V6(Ipv6Addr::new(0x2001, 0x4860, 0x4860, 0, 0, 0, 0, 0x8888));
+        assert_eq!(addrs, vec![SocketAddr::new(ip, 443)]);
+        assert_eq!(server_name, ServerName::IpAddress(ip.into()));
+    }
+
+    #[tokio::test]
+    async fn test_post_times_out_on_slow_response() {
+        // ヘッダーを1バイトずつ送り続ける受信先でも、timeoutで打ち切る
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            for _ in 0..50 {
+                if stream.write_all(b"H").is_err() {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(100));
+            }
+        });
+        let started = Instant::now();
+        let result = post(
+            &url,
+            vec![],
+            vec![],
+            Destination::Any,
+            Duration::from_secs(1),
+        )
+        .await;
+        assert!(result.is_err());
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[tokio::test]
+    async fn test_post_rejects_non_public_destination() {
+        // 接続する前に拒否する
+        for url in [
+            "http://example.com/hook",
+            "https://127.0.0.1:3306/",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/hook",
+            "https://localhost/hook",
+        ] {
+            let result = post(
+                url,
+                vec![],
+                vec![],
+                Destination::Public,
+                Duration::from_secs(1),
+            )
+            .await;
+            assert!(result.is_err(), "{}", url);
+        }
+    }
+}
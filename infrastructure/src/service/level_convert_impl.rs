@@ -1,6 +1,12 @@
-use std::{collections::BTreeMap, sync::LazyLock};
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, PoisonError, RwLock},
+};
 
-use domain::service::level_convert::LevelConvert;
+use domain::{
+    entity::level_curve::{LevelCurve, LevelFormula},
+    service::{level_convert::LevelConvert, service_error::level_curve_error::LevelCurveError},
+};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -9,69 +15,130 @@ struct ExpTable {
     exp: u32,
 }
 
-// CSVにある経験値テーブルをBTreeMapとして取得し、実行時は保持している
-// 最適化していない状態で取り出し(get)は200ns程
-static EXP_TABLE: LazyLock<BTreeMap<u32, u32>> = LazyLock::new(|| {
-    let file_path = dotenvy::var("FILE_PATH").expect("Failed to get exp_table file path");
-    let mut map = BTreeMap::new();
-    let mut rdr = csv::Reader::from_path(file_path).expect("failed to open exp_table file");
-    rdr.deserialize::<ExpTable>().for_each(|r| {
-        let record = r.expect("failed to decode exp_table");
-        map.insert(record.level, record.exp);
-    });
-    map
-});
+// 現在のレベルカーブ
+// 起動時(またはreload時)に読み込み、実行時は保持している
+// 読み込み直しても参照中のカーブには影響しないようArcで共有する
+static LEVEL_CURVE: RwLock<Option<Arc<LevelCurve>>> = RwLock::new(None);
+
+// プロセスの起動時に与えられた環境変数
+// dotenvy::varは.envの値をプロセスの環境変数に書き込むため、書き込まれる前の状態を保持しておく
+// 起動時に最初のreload()で初期化される
+static PROCESS_ENV: LazyLock<HashMap<String, String>> =
+    LazyLock::new(|| std::env::vars().collect());
+
+// LEVEL_CURVE=formulaの場合のデフォルトのパラメータ
+// exp_table.csvと同じカーブになる
+static DEFAULT_BASE: f64 = 10.0;
+static DEFAULT_EXPONENT: f64 = 1.5;
+static DEFAULT_MAX_LEVEL: u32 = 100;
 
 /// ユーザーの経験値をLevelに変換する
-/// 指数関数的にレベルアップに必要な経験値が上昇する
-/// 以下のような計算式になっている
-/// Required_exp = 10 * Level^1.5
-/// 1.5の倍率で経験値が必要になる
-/// ただし必要経験値(Required_exp)は整数値に丸めておりcsv形式で取り出すことができる
-/// csv は (current level, required exp to level up)　を意味している
+/// レベルカーブは以下のどちらかで定義する
+/// - LEVEL_CURVE=table(デフォルト): FILE_PATHのCSVファイル
+///   csv は (current level, required exp to level up) を意味している
+/// - LEVEL_CURVE=formula: Required_exp = LEVEL_BASE * Level^LEVEL_EXPONENT (整数値に丸める)
+///   最大レベルはLEVEL_MAX
+///
+/// デフォルトのexp_table.csvはRequired_exp = 10 * Level^1.5で、1.5の倍率で経験値が必要になる
 #[derive(Debug, Clone)]
 pub struct LevelConvertImpl;
 
 impl LevelConvert for LevelConvertImpl {
-    fn to_level_with_remain(&self, experience_point: i64) -> (u32, Option<u32>) {
-        let mut current_level = 1;
-        let mut required_exp = None;
-        // 現在のレベルを調べる
-        // 線形探索している[O(n)]
-        for (l, exp) in EXP_TABLE.iter() {
-            if (experience_point as u32) < *exp {
-                required_exp = Some(*exp);
-                break;
-            }
-            current_level = l + 1;
-        }
-        // レベルが100がMax
-        // 100より大きい場合は100にする
-        if current_level > 100 {
-            current_level = 100;
+    fn curve(&self) -> Result<Arc<LevelCurve>, LevelCurveError> {
+        LEVEL_CURVE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .ok_or(LevelCurveError::NotLoaded)
+    }
+
+    fn reload(&self) -> Result<Arc<LevelCurve>, LevelCurveError> {
+        let curve = Arc::new(load_curve()?);
+        *LEVEL_CURVE.write().unwrap_or_else(PoisonError::into_inner) = Some(curve.clone());
+        Ok(curve)
+    }
+}
+
+// 設定からレベルカーブを読み込む
+fn load_curve() -> Result<LevelCurve, LevelCurveError> {
+    match config_var("LEVEL_CURVE").as_deref() {
+        None | Some("table") => {
+            let file_path = config_var("FILE_PATH")
+                .ok_or_else(|| LevelCurveError::Load("FILE_PATH is not set".to_string()))?;
+            LevelCurve::from_table(read_table(&file_path)?)
         }
-        // レベルアップに必要な経験値量を計算
-        let remain = required_exp.map(|exp| exp - experience_point as u32);
-        (current_level, remain)
+        Some("formula") => LevelCurve::from_formula(LevelFormula {
+            base: parse_var("LEVEL_BASE", DEFAULT_BASE)?,
+            exponent: parse_var("LEVEL_EXPONENT", DEFAULT_EXPONENT)?,
+            max_level: parse_var("LEVEL_MAX", DEFAULT_MAX_LEVEL)?,
+        }),
+        Some(v) => Err(LevelCurveError::Load(format!(
+            "LEVEL_CURVE must be `table` or `formula`, got `{}`",
+            v
+        ))),
+    }
+}
+
+fn read_table(file_path: &str) -> Result<Vec<(u32, u32)>, LevelCurveError> {
+    let mut rdr = csv::Reader::from_path(file_path)
+        .map_err(|e| LevelCurveError::Load(format!("{}: {}", file_path, e)))?;
+    rdr.deserialize::<ExpTable>()
+        .map(|r| {
+            r.map(|record| (record.level, record.exp))
+                .map_err(|e| LevelCurveError::Load(format!("{}: {}", file_path, e)))
+        })
+        .collect()
+}
+
+fn parse_var<T>(key: &str, default: T) -> Result<T, LevelCurveError>
+where
+    T: std::str::FromStr,
+{
+    match config_var(key) {
+        Some(v) => v
+            .parse()
+            .map_err(|_| LevelCurveError::InvalidParameter(format!("{} is invalid: `{}`", key, v))),
+        None => Ok(default),
     }
 }
 
+// 起動時の環境変数を.envより優先する
+// 環境変数で指定されていない値は、reloadで.envの変更を反映するため呼び出すたびにファイルから読み直す
+fn config_var(key: &str) -> Option<String> {
+    PROCESS_ENV.get(key).cloned().or_else(|| {
+        dotenvy::dotenv_iter().ok().and_then(|iter| {
+            iter.filter_map(Result::ok)
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v)
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use domain::service::level_convert::LevelConvert;
     use crate::service::level_convert_impl::LevelConvertImpl;
+    use domain::service::level_convert::LevelConvert;
 
     #[test]
-    fn test_with_level(){
+    fn test_with_level() {
         let a = LevelConvertImpl;
-        assert_eq!(a.to_level_with_remain(0), (1, Some(10)));
-        assert_eq!(a.to_level_with_remain(2), (1, Some(8)));
-        assert_eq!(a.to_level_with_remain(10), (2, Some(18)));
-        assert_eq!(a.to_level_with_remain(7623), (84, Some(76)));
-        assert_eq!(a.to_level_with_remain(8396), (90, Some(142)));
+        a.reload().unwrap();
+        assert_eq!(a.to_level_with_remain(0).unwrap(), (1, Some(10)));
+        assert_eq!(a.to_level_with_remain(2).unwrap(), (1, Some(8)));
+        assert_eq!(a.to_level_with_remain(10).unwrap(), (2, Some(18)));
+        assert_eq!(a.to_level_with_remain(7623).unwrap(), (84, Some(76)));
+        assert_eq!(a.to_level_with_remain(8396).unwrap(), (90, Some(142)));
         // レベルが100以上のケース
-        assert_eq!(a.to_level_with_remain(10000), (100, None));
-        assert_eq!(a.to_level_with_remain(120000), (100, None));
+        assert_eq!(a.to_level_with_remain(10000).unwrap(), (100, None));
+        assert_eq!(a.to_level_with_remain(120000).unwrap(), (100, None));
+        // u32の範囲外の値でも切り捨てられずに最大レベルになる
+        assert_eq!(
+            a.to_level_with_remain(u32::MAX as i64 + 1).unwrap(),
+            (100, None)
+        );
+        assert_eq!(a.to_level_with_remain(i64::MAX).unwrap(), (100, None));
+        // 負の値は0として扱う
+        assert_eq!(a.to_level_with_remain(-1).unwrap(), (1, Some(10)));
+        assert_eq!(a.to_level_with_remain(i64::MIN).unwrap(), (1, Some(10)));
     }
 }
-
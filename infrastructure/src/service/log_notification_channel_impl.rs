@@ -0,0 +1,29 @@
+use std::{future::Future, pin::Pin};
+
+use domain::{
+    entity::notification::Notification,
+    service::{
+        notification_channel::NotificationChannel,
+        service_error::notification_error::NotificationError,
+    },
+};
+
+/// 通知を標準出力に書き出すNotificationChannelの実装
+/// 外部の送信先がない開発環境での動作の確認に使う
+#[derive(Debug, Clone)]
+pub struct LogNotificationChannelImpl;
+
+impl NotificationChannel for LogNotificationChannelImpl {
+    fn send<'a>(
+        &'a self,
+        notification: &'a Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), NotificationError>> + Send + 'a>> {
+        Box::pin(async move {
+            println!(
+                "[notification] {}: {} - {}",
+                notification.user_id.0, notification.title, notification.body
+            );
+            Ok(())
+        })
+    }
+}
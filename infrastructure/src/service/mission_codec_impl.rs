@@ -0,0 +1,182 @@
+use domain::{
+    entity::{
+        daily_mission_input::DailyMissionInput,
+        mission_transfer::{MissionCsvRow, MissionFormat, MissionRecord},
+    },
+    service::{mission_codec::MissionCodec, service_error::mission_codec_error::MissionCodecError},
+};
+
+/// JSON/CSV/YAMLのファイルとミッションを相互に変換する
+/// JSON/YAMLはDailyMissionInputの配列、CSVはヘッダー付きのMissionCsvRowの行として扱う
+#[derive(Debug, Clone)]
+pub struct MissionCodecImpl;
+
+impl MissionCodec for MissionCodecImpl {
+    fn decode(
+        &self,
+        format: MissionFormat,
+        body: &[u8],
+    ) -> Result<Vec<Result<DailyMissionInput, String>>, MissionCodecError> {
+        // 配列として読み込めない場合はファイル全体のエラーとし、要素の変換は行ごとに行う
+        let rows = match format {
+            MissionFormat::Json => serde_json::from_slice::<Vec<serde_json::Value>>(body)
+                .map_err(|e| MissionCodecError::Malformed(e.to_string()))?
+                .into_iter()
+                .map(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+                .collect(),
+            MissionFormat::Yaml => serde_yaml::from_slice::<Vec<serde_yaml::Value>>(body)
+                .map_err(|e| MissionCodecError::Malformed(e.to_string()))?
+                .into_iter()
+                .map(|value| serde_yaml::from_value(value).map_err(|e| e.to_string()))
+                .collect(),
+            MissionFormat::Csv => csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(body)
+                .deserialize::<MissionCsvRow>()
+                .map(|row| {
+                    row.map_err(|e| e.to_string())
+                        .and_then(DailyMissionInput::try_from)
+                })
+                .collect(),
+        };
+        Ok(rows)
+    }
+
+    fn encode(
+        &self,
+        format: MissionFormat,
+        records: &[MissionRecord],
+    ) -> Result<Vec<u8>, MissionCodecError> {
+        let to_err = |e: &dyn std::error::Error| MissionCodecError::Encode(e.to_string());
+        match format {
+            MissionFormat::Json => serde_json::to_vec_pretty(records).map_err(|e| to_err(&e)),
+            MissionFormat::Yaml => serde_yaml::to_string(records)
+                .map(String::into_bytes)
+                .map_err(|e| to_err(&e)),
+            MissionFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(vec![]);
+                for record in records {
+                    writer
+                        .serialize(MissionCsvRow::from(record))
+                        .map_err(|e| to_err(&e))?;
+                }
+                writer.into_inner().map_err(|e| to_err(&e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use domain::{
+        entity::{
+            mission_difficulty::MissionDifficulty,
+            mission_schedule::MissionSchedule,
+            mission_transfer::{MissionFormat, MissionRecord},
+        },
+        service::{
+            mission_codec::MissionCodec, service_error::mission_codec_error::MissionCodecError,
+        },
+    };
+    use sqlx::types::chrono::NaiveDate;
+
+    use super::MissionCodecImpl;
+
+    fn gen_records() -> Vec<MissionRecord> {
+        vec![
+            MissionRecord {
+                title: "read".to_string(),
+                description: Some("30 pages, every day".to_string()),
+                schedule: MissionSchedule::Daily,
+                target_quantity: Some(30),
+                unit: Some("pages".to_string()),
+                difficulty: MissionDifficulty::Hard,
+                exp_weight: None,
+            },
+            MissionRecord {
+                title: "walk".to_string(),
+                description: None,
+                schedule: MissionSchedule::Weekdays {
+                    weekdays: vec!["Mon".parse().unwrap(), "Thu".parse().unwrap()],
+                },
+                target_quantity: None,
+                unit: None,
+                difficulty: MissionDifficulty::Easy,
+                exp_weight: Some(3),
+            },
+            MissionRecord {
+                title: "clean".to_string(),
+                description: None,
+                schedule: MissionSchedule::EveryNDays {
+                    interval: 3,
+                    start_date: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+                },
+                target_quantity: None,
+                unit: None,
+                difficulty: MissionDifficulty::Normal,
+                exp_weight: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let records = gen_records();
+        for format in [MissionFormat::Json, MissionFormat::Csv, MissionFormat::Yaml] {
+            let body = MissionCodecImpl.encode(format, &records).unwrap();
+            let inputs = MissionCodecImpl.decode(format, &body).unwrap();
+            assert_eq!(inputs.len(), records.len(), "{:?}", format);
+            for (input, record) in inputs.into_iter().zip(&records) {
+                let input = input.unwrap();
+                assert_eq!(input.title, record.title, "{:?}", format);
+                assert_eq!(input.description, record.description, "{:?}", format);
+                assert_eq!(input.schedule, record.schedule, "{:?}", format);
+                assert_eq!(input.target_quantity, record.target_quantity);
+                assert_eq!(input.unit, record.unit);
+                assert_eq!(input.difficulty, record.difficulty);
+                assert_eq!(input.exp_weight, record.exp_weight);
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_row_errors() {
+        let csv = "title,schedule,weekdays,difficulty\nread,,,\nwalk,weekdays,Mon;Someday,\nrun,,,impossible\n";
+        let rows = MissionCodecImpl
+            .decode(MissionFormat::Csv, csv.as_bytes())
+            .unwrap();
+        assert_eq!(rows.len(), 3);
+        assert!(rows[0].is_ok());
+        assert!(rows[1].is_err());
+        assert!(rows[2].is_err());
+
+        let json = r#"[{"title": "read"}, {"description": "no title"}]"#;
+        let rows = MissionCodecImpl
+            .decode(MissionFormat::Json, json.as_bytes())
+            .unwrap();
+        assert!(rows[0].is_ok());
+        assert!(rows[1].is_err());
+
+        let yaml = "- title: read\n  schedule:\n    type: timesPerWeek\n    times: 2\n- title: walk\n  schedule:\n    type: hourly\n";
+        let rows = MissionCodecImpl
+            .decode(MissionFormat::Yaml, yaml.as_bytes())
+            .unwrap();
+        assert_eq!(
+            rows[0].as_ref().unwrap().schedule,
+            MissionSchedule::TimesPerWeek { times: 2 }
+        );
+        assert!(rows[1].is_err());
+    }
+
+    #[test]
+    fn test_decode_malformed() {
+        assert!(matches!(
+            MissionCodecImpl.decode(MissionFormat::Json, br#"{"title": "read"}"#),
+            Err(MissionCodecError::Malformed(_))
+        ));
+        assert!(matches!(
+            MissionCodecImpl.decode(MissionFormat::Yaml, b"title: read"),
+            Err(MissionCodecError::Malformed(_))
+        ));
+    }
+}
@@ -1,4 +1,10 @@
+pub mod event_publisher_impl;
+pub(crate) mod http_client;
 pub mod level_convert_impl;
+pub mod log_notification_channel_impl;
+pub mod mission_codec_impl;
 pub mod password_hash_service_impl;
+pub mod push_sender_impl;
 pub mod token_service_impl;
 pub mod uuid_service_impl;
+pub mod webhook_sender_impl;
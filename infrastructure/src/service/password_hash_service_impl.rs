@@ -108,7 +108,7 @@ mod test {
 
         let hash_password = PasswordHashServiceImpl.hash_password(password).await?;
         let verify_result = PasswordHashServiceImpl
-            .verify_password(&wrong_password, &hash_password)
+            .verify_password(wrong_password, &hash_password)
             .await?;
         assert!(!verify_result);
         Ok(())
@@ -0,0 +1,607 @@
+use std::{
+    fs::{exists, read_to_string, OpenOptions},
+    future::Future,
+    io::Write,
+    os::unix::fs::OpenOptionsExt,
+    pin::Pin,
+    sync::{Arc, LazyLock, OnceLock},
+    time::Duration,
+};
+
+use base64::{prelude::BASE64_URL_SAFE_NO_PAD, Engine};
+use domain::{
+    entity::{notification::Notification, push_subscription::PushSubscription},
+    service::{push_sender::PushSender, service_error::push_error::PushError},
+};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use ring::{
+    aead, agreement,
+    error::Unspecified,
+    hkdf,
+    rand::{SecureRandom, SystemRandom},
+    signature::{EcdsaKeyPair, KeyPair, ECDSA_P256_SHA256_FIXED_SIGNING},
+};
+use serde::Serialize;
+use sqlx::types::chrono::Utc;
+use url::Url;
+
+use super::http_client::{self, Destination};
+
+// 環境変数VAPID_PRIVATE_KEYを設定しない場合に、VAPIDの秘密鍵(PKCS#8をbase64urlで表したもの)を保存するファイル
+static KEY_PATH: &str = "../vapid_key.txt";
+
+// 起動時にPushSenderImpl::load_key()で読み込んだVAPIDの鍵
+static VAPID_KEY: OnceLock<Arc<VapidKey>> = OnceLock::new();
+
+// JWTのsubに設定する連絡先(プッシュサービスが問題のあるアプリケーションサーバーに連絡するために使う)
+static VAPID_SUBJECT: LazyLock<String> = LazyLock::new(|| {
+    dotenvy::var("VAPID_SUBJECT").unwrap_or("mailto:admin@example.com".to_string())
+});
+
+// 端末に届かなかった場合にプッシュサービスが保持する秒数
+static PUSH_TTL_SECONDS: u32 = 12 * 3600;
+
+// プッシュサービスへの接続からレスポンスの受信までにかける最大の時間
+static PUSH_TIMEOUT: Duration = Duration::from_secs(15);
+
+// VAPIDのJWTの有効期間(最大24時間)
+static VAPID_EXPIRATION_SECONDS: i64 = 12 * 3600;
+
+// 暗号化したレコードの大きさ(ペイロードは1つのレコードに収める)
+static RECORD_SIZE: u32 = 4096;
+
+/// VAPIDの鍵(P-256)
+#[derive(Debug)]
+pub struct VapidKey {
+    pkcs8: Vec<u8>,
+    public_key: Vec<u8>,
+}
+
+impl VapidKey {
+    /// 新しい鍵を生成する
+    pub fn generate() -> Result<Self, String> {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng)
+            .map_err(|e| e.to_string())?;
+        Self::from_pkcs8(pkcs8.as_ref())
+    }
+
+    /// base64urlで表したPKCS#8から鍵を読み込む
+    pub fn decode(encoded: &str) -> Result<Self, String> {
+        let pkcs8 = BASE64_URL_SAFE_NO_PAD
+            .decode(encoded.trim_end_matches('='))
+            .map_err(|e| e.to_string())?;
+        Self::from_pkcs8(&pkcs8)
+    }
+
+    /// 環境変数VAPID_PRIVATE_KEYの鍵を読み込む
+    /// 設定されていない場合は鍵のファイルを読み、ファイルもない場合は生成して所有者だけが読み書きできるファイルに保存する
+    pub fn load() -> Result<Self, String> {
+        if let Ok(encoded) = dotenvy::var("VAPID_PRIVATE_KEY") {
+            return Self::decode(encoded.trim()).map_err(|e| format!("VAPID_PRIVATE_KEY: {}", e));
+        }
+        let read_error = |e: std::io::Error| format!("{}: {}", KEY_PATH, e);
+        if exists(KEY_PATH).map_err(read_error)? {
+            let encoded = read_to_string(KEY_PATH).map_err(read_error)?;
+            return Self::decode(encoded.trim()).map_err(|e| format!("{}: {}", KEY_PATH, e));
+        }
+        let key = Self::generate()?;
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(KEY_PATH)
+            .map_err(read_error)?;
+        file.write_all(key.encode().as_bytes())
+            .map_err(read_error)?;
+        Ok(key)
+    }
+
+    /// 秘密鍵をbase64urlで表したPKCS#8にする
+    pub fn encode(&self) -> String {
+        BASE64_URL_SAFE_NO_PAD.encode(&self.pkcs8)
+    }
+
+    fn from_pkcs8(pkcs8: &[u8]) -> Result<Self, String> {
+        let key_pair = EcdsaKeyPair::from_pkcs8(
+            &ECDSA_P256_SHA256_FIXED_SIGNING,
+            pkcs8,
+            &SystemRandom::new(),
+        )
+        .map_err(|e| e.to_string())?;
+        Ok(Self {
+            pkcs8: pkcs8.to_vec(),
+            public_key: key_pair.public_key().as_ref().to_vec(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct VapidClaims<'a> {
+    aud: String,
+    exp: i64,
+    sub: &'a str,
+}
+
+/// Web Push(RFC 8030)でメッセージを送るPushSenderの実装
+/// ペイロードはaes128gcm(RFC 8291)で暗号化し、VAPID(RFC 8292)でアプリケーションサーバーを認証する
+/// 送信先はhttpsでグローバルに到達できるアドレスに限る
+#[derive(Debug, Clone)]
+pub struct PushSenderImpl {
+    key: Option<Arc<VapidKey>>,
+    subject: String,
+    destination: Destination,
+}
+
+impl PushSenderImpl {
+    /// VAPIDの鍵を読み込む(起動時に一度だけ呼ぶ)
+    /// 複数のインスタンスで起動する場合は、環境変数VAPID_PRIVATE_KEYに同じ鍵を設定する
+    pub fn load_key() -> Result<(), String> {
+        let key = VapidKey::load()?;
+        let _ = VAPID_KEY.set(Arc::new(key));
+        Ok(())
+    }
+
+    /// 起動時に読み込んだVAPIDの鍵を使う
+    /// 読み込んでいない場合は、公開鍵の取得と送信がエラーになる
+    pub fn new() -> Self {
+        Self {
+            key: VAPID_KEY.get().cloned(),
+            subject: VAPID_SUBJECT.clone(),
+            destination: Destination::Public,
+        }
+    }
+
+    pub fn with_key(key: Arc<VapidKey>, subject: String) -> Self {
+        Self {
+            key: Some(key),
+            subject,
+            destination: Destination::Public,
+        }
+    }
+
+    fn key(&self) -> Result<&VapidKey, PushError> {
+        self.key
+            .as_deref()
+            .ok_or(PushError::Delivery("vapid key is not loaded".to_string()))
+    }
+
+    // endpointのオリジンを対象にしたVAPIDのAuthorizationヘッダー
+    fn authorization(&self, endpoint: &Url) -> Result<String, PushError> {
+        let key = self.key()?;
+        let claims = VapidClaims {
+            aud: endpoint.origin().ascii_serialization(),
+            exp: Utc::now().timestamp() + VAPID_EXPIRATION_SECONDS,
+            sub: &self.subject,
+        };
+        let jwt = encode(
+            &Header::new(Algorithm::ES256),
+            &claims,
+            &EncodingKey::from_ec_der(&key.pkcs8),
+        )
+        .map_err(|e| PushError::Delivery(e.to_string()))?;
+        Ok(format!(
+            "vapid t={}, k={}",
+            jwt,
+            BASE64_URL_SAFE_NO_PAD.encode(&key.public_key)
+        ))
+    }
+}
+
+impl Default for PushSenderImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PushSender for PushSenderImpl {
+    fn public_key(&self) -> Result<String, PushError> {
+        Ok(BASE64_URL_SAFE_NO_PAD.encode(&self.key()?.public_key))
+    }
+
+    fn send<'a>(
+        &'a self,
+        subscription: &'a PushSubscription,
+        notification: &'a Notification,
+    ) -> Pin<Box<dyn Future<Output = Result<(), PushError>> + Send + 'a>> {
+        Box::pin(async move {
+            let invalid = |e: String| PushError::InvalidSubscription(e);
+            let endpoint =
+                Url::parse(&subscription.endpoint).map_err(|e| invalid(e.to_string()))?;
+            let ua_public = decode_key(&subscription.p256dh).map_err(invalid)?;
+            let auth = decode_key(&subscription.auth).map_err(invalid)?;
+            let payload =
+                serde_json::to_vec(notification).map_err(|e| PushError::Delivery(e.to_string()))?;
+            let body = encrypt(&ua_public, &auth, &payload)
+                .map_err(|_| invalid("cannot encrypt payload".to_string()))?;
+            let authorization = self.authorization(&endpoint)?;
+
+            let response = http_client::post(
+                endpoint.as_str(),
+                vec![
+                    ("Authorization", authorization),
+                    ("Content-Encoding", "aes128gcm".to_string()),
+                    ("Content-Type", "application/octet-stream".to_string()),
+                    ("TTL", PUSH_TTL_SECONDS.to_string()),
+                    ("Urgency", "normal".to_string()),
+                ],
+                body,
+                self.destination,
+                PUSH_TIMEOUT,
+            )
+            .await
+            .map_err(PushError::Delivery)?;
+            match response.status {
+                200..=299 => Ok(()),
+                // 購読が解除された、または期限が切れた
+                404 | 410 => Err(PushError::Gone),
+                // 別のVAPIDの鍵で作られた購読は、この鍵では送れない
+                403 => Err(PushError::InvalidSubscription(
+                    "vapid key mismatch".to_string(),
+                )),
+                status => Err(PushError::Delivery(format!(
+                    "push service responded with {}: {}",
+                    status,
+                    String::from_utf8_lossy(&response.body)
+                ))),
+            }
+        })
+    }
+}
+
+fn decode_key(value: &str) -> Result<Vec<u8>, String> {
+    BASE64_URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .map_err(|e| e.to_string())
+}
+
+// HKDFで取り出す鍵の長さ
+struct Len(usize);
+
+impl hkdf::KeyType for Len {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+fn hkdf_sha256(salt: &[u8], ikm: &[u8], info: &[u8], len: usize) -> Result<Vec<u8>, Unspecified> {
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, salt).extract(ikm);
+    let mut out = vec![0; len];
+    prk.expand(&[info], Len(len))?.fill(&mut out)?;
+    Ok(out)
+}
+
+// ECDHの共有の秘密から、コンテンツを暗号化する鍵(CEK)とノンスを導出する(RFC 8291 3.3, 3.4)
+fn derive_key_and_nonce(
+    ecdh_secret: &[u8],
+    auth_secret: &[u8],
+    ua_public: &[u8],
+    as_public: &[u8],
+    salt: &[u8],
+) -> Result<(Vec<u8>, Vec<u8>), Unspecified> {
+    let key_info = [b"WebPush: info\0".as_slice(), ua_public, as_public].concat();
+    let ikm = hkdf_sha256(auth_secret, ecdh_secret, &key_info, 32)?;
+    let cek = hkdf_sha256(salt, &ikm, b"Content-Encoding: aes128gcm\0", 16)?;
+    let nonce = hkdf_sha256(salt, &ikm, b"Content-Encoding: nonce\0", 12)?;
+    Ok((cek, nonce))
+}
+
+// 1つのレコードで暗号化したaes128gcmのボディ(RFC 8188)を作る
+// メッセージごとに新しい鍵ペアを生成し、その公開鍵をヘッダーのkeyidとして送る
+fn encrypt(ua_public: &[u8], auth_secret: &[u8], payload: &[u8]) -> Result<Vec<u8>, Unspecified> {
+    let rng = SystemRandom::new();
+    let as_private = agreement::EphemeralPrivateKey::generate(&agreement::ECDH_P256, &rng)?;
+    let as_public = as_private.compute_public_key()?;
+    let ecdh_secret = agreement::agree_ephemeral(
+        as_private,
+        &agreement::UnparsedPublicKey::new(&agreement::ECDH_P256, ua_public),
+        |secret| secret.to_vec(),
+    )?;
+    let mut salt = [0u8; 16];
+    rng.fill(&mut salt)?;
+    let (cek, nonce) = derive_key_and_nonce(
+        &ecdh_secret,
+        auth_secret,
+        ua_public,
+        as_public.as_ref(),
+        &salt,
+    )?;
+    seal(&cek, &nonce, &salt, as_public.as_ref(), payload)
+}
+
+fn seal(
+    cek: &[u8],
+    nonce: &[u8],
+    salt: &[u8],
+    as_public: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>, Unspecified> {
+    let key = aead::LessSafeKey::new(aead::UnboundKey::new(&aead::AES_128_GCM, cek)?);
+    // 最後のレコードであることを表す区切り(パディングは付けない)
+    let mut record = payload.to_vec();
+    record.push(0x02);
+    key.seal_in_place_append_tag(
+        aead::Nonce::try_assume_unique_for_key(nonce)?,
+        aead::Aad::empty(),
+        &mut record,
+    )?;
+    let mut body = salt.to_vec();
+    body.extend_from_slice(&RECORD_SIZE.to_be_bytes());
+    body.push(as_public.len() as u8);
+    body.extend_from_slice(as_public);
+    body.extend_from_slice(&record);
+    Ok(body)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::{mpsc, Arc},
+        thread,
+    };
+
+    use base64::{prelude::BASE64_URL_SAFE_NO_PAD, Engine};
+    use domain::{
+        entity::{
+            notification::Notification, push_subscription::PushSubscription, user_id::UserId,
+        },
+        service::{push_sender::PushSender, service_error::push_error::PushError},
+    };
+    use ring::{
+        aead, agreement,
+        rand::{SecureRandom, SystemRandom},
+        signature::{UnparsedPublicKey, ECDSA_P256_SHA256_FIXED},
+    };
+    use sqlx::types::chrono::Utc;
+
+    use super::{derive_key_and_nonce, seal, Destination, PushSenderImpl, VapidKey};
+
+    // テストのプッシュサービスはローカルのhttpで待ち受けるため、送信先を制限しない
+    fn local_sender(key: Arc<VapidKey>) -> PushSenderImpl {
+        PushSenderImpl {
+            destination: Destination::Any,
+            ..PushSenderImpl::with_key(key, "mailto:test@example.com".to_string())
+        }
+    }
+
+    fn b64(value: &str) -> Vec<u8> {
+        BASE64_URL_SAFE_NO_PAD.decode(value).unwrap()
+    }
+
+    // 受信する端末として、ボディを復号してペイロードを取り出す
+    fn decrypt(
+        ua_private: agreement::EphemeralPrivateKey,
+        ua_public: &[u8],
+        auth_secret: &[u8],
+        body: &[u8],
+    ) -> Vec<u8> {
+        let salt = &body[..16];
+        assert_eq!(&body[16..20], &4096u32.to_be_bytes());
+        let id_len = body[20] as usize;
+        let as_public = &body[21..21 + id_len];
+        let ecdh_secret = agreement::agree_ephemeral(
+            ua_private,
+            &agreement::UnparsedPublicKey::new(&agreement::ECDH_P256, as_public),
+            |secret| secret.to_vec(),
+        )
+        .unwrap();
+        let (cek, nonce) =
+            derive_key_and_nonce(&ecdh_secret, auth_secret, ua_public, as_public, salt).unwrap();
+        let key = aead::LessSafeKey::new(aead::UnboundKey::new(&aead::AES_128_GCM, &cek).unwrap());
+        let mut record = body[21 + id_len..].to_vec();
+        let plaintext = key
+            .open_in_place(
+                aead::Nonce::try_assume_unique_for_key(&nonce).unwrap(),
+                aead::Aad::empty(),
+                &mut record,
+            )
+            .unwrap();
+        assert_eq!(plaintext.last(), Some(&0x02));
+        plaintext[..plaintext.len() - 1].to_vec()
+    }
+
+    #[test]
+    fn test_rfc8291_example() {
+        // RFC 8291 Appendix A
+        let ua_public = b64("BCVxsr7N_eNgVRqvHtD0zTZsEc6-VV-JvLexhqUzORcxaOzi6-AYWXvTBHm4bjyPjs7Vd8pZGH6SRpkNtoIAiw4");
+        let as_public = b64("BP4z9KsN6nGRTbVYI_c7VJSPQTBtkgcy27mlmlMoZIIgDll6e3vCYLocInmYWAmS6TlzAC8wEqKK6PBru3jl7A8");
+        let auth_secret = b64("BTBZMqHH6r4Tts7J_aSIgg");
+        let salt = b64("DGv6ra1nlYgDCS1FRnbzlw");
+        let ecdh_secret = b64("kyrL1jIIOHEzg3sM2ZWRHDRB62YACZhhSlknJ672kSs");
+
+        let (cek, nonce) =
+            derive_key_and_nonce(&ecdh_secret, &auth_secret, &ua_public, &as_public, &salt)
+                .unwrap();
+        assert_eq!(cek, b64("oIhVW04MRdy2XN9CiKLxTg"));
+        assert_eq!(nonce, b64("4h_95klXJ5E_qnoN"));
+
+        let body = seal(
+            &cek,
+            &nonce,
+            &salt,
+            &as_public,
+            b"When I grow up, I want to be a watermelon",
+        )
+        .unwrap();
+        assert_eq!(
+            BASE64_URL_SAFE_NO_PAD.encode(body),
+            "DGv6ra1nlYgDCS1FRnbzlwAAEABBBP4z9KsN6nGRTbVYI_c7VJSPQTBtkgcy27mlmlMoZIIgDll6e3vCYLocInmYWAmS6TlzAC8wEqKK6PBru3jl7A_yl95bQpu6cVPTpK4Mqgkf1CXztLVBSt2Ks3oZwbuwXPXLWyouBWLVWGNWQexSgSxsj_Qulcy4a-fN"
+        );
+    }
+
+    #[test]
+    fn test_vapid_key_encoding() {
+        let key = VapidKey::generate().unwrap();
+        let decoded = VapidKey::decode(&key.encode()).unwrap();
+        assert_eq!(decoded.public_key, key.public_key);
+        assert_eq!(key.public_key.len(), 65);
+        assert!(VapidKey::decode("not a key").is_err());
+
+        // 鍵を読み込んでいない場合は公開鍵を返さずにエラーにする
+        let sender = PushSenderImpl {
+            key: None,
+            subject: "mailto:test@example.com".to_string(),
+            destination: Destination::Public,
+        };
+        assert!(sender.public_key().is_err());
+        let sender = PushSenderImpl::with_key(Arc::new(key), "mailto:test@example.com".to_string());
+        assert_eq!(
+            sender.public_key(),
+            Ok(BASE64_URL_SAFE_NO_PAD.encode(&decoded.public_key))
+        );
+    }
+
+    // 1回だけリクエストを受け付け、statusを返すプッシュサービス
+    // 受け取ったリクエストのヘッダーとボディを返す
+    fn mock_push_service(status: &'static str) -> (String, mpsc::Receiver<(String, Vec<u8>)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/push/test", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0u8; 4096];
+            // ヘッダーを読み、Content-Lengthの分だけボディを読む
+            let (head, body_start) = loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break (String::from_utf8(request[..i].to_vec()).unwrap(), i + 4);
+                }
+            };
+            let length: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .unwrap()
+                .parse()
+                .unwrap();
+            while request.len() < body_start + length {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            write!(stream, "HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status).unwrap();
+            tx.send((head, request[body_start..].to_vec())).unwrap();
+        });
+        (endpoint, rx)
+    }
+
+    fn gen_subscription(
+        endpoint: String,
+        ua_public: &[u8],
+        auth_secret: &[u8],
+    ) -> PushSubscription {
+        PushSubscription {
+            subscription_id: "test_subscription".to_string(),
+            user_id: UserId("test_user".to_string()),
+            endpoint,
+            p256dh: BASE64_URL_SAFE_NO_PAD.encode(ua_public),
+            auth: BASE64_URL_SAFE_NO_PAD.encode(auth_secret),
+            device_name: None,
+            expires_at: None,
+            created_at: Utc::now().naive_utc(),
+        }
+    }
+
+    fn gen_notification() -> Notification {
+        Notification {
+            user_id: UserId("test_user".to_string()),
+            title: "Mission reminder".to_string(),
+            body: "read".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_to_mock_push_service() {
+        let rng = SystemRandom::new();
+        let ua_private =
+            agreement::EphemeralPrivateKey::generate(&agreement::ECDH_P256, &rng).unwrap();
+        let ua_public = ua_private.compute_public_key().unwrap().as_ref().to_vec();
+        let mut auth_secret = [0u8; 16];
+        rng.fill(&mut auth_secret).unwrap();
+
+        let key = Arc::new(VapidKey::generate().unwrap());
+        let sender = local_sender(key.clone());
+        let (endpoint, rx) = mock_push_service("201 Created");
+        let subscription = gen_subscription(endpoint, &ua_public, &auth_secret);
+        let notification = gen_notification();
+        sender.send(&subscription, &notification).await.unwrap();
+
+        let (head, body) = rx.recv().unwrap();
+        assert!(head.starts_with("POST /push/test HTTP/1.1"));
+        assert!(head.contains("Content-Encoding: aes128gcm"));
+        assert!(head.contains("TTL: "));
+
+        // VAPIDのJWTが公開鍵で検証できる
+        let authorization = head
+            .lines()
+            .find_map(|line| line.strip_prefix("Authorization: vapid t="))
+            .unwrap();
+        let (jwt, public_key) = authorization.split_once(", k=").unwrap();
+        assert_eq!(b64(public_key), key.public_key);
+        let (message, signature) = jwt.rsplit_once('.').unwrap();
+        UnparsedPublicKey::new(&ECDSA_P256_SHA256_FIXED, &key.public_key)
+            .verify(message.as_bytes(), &b64(signature))
+            .unwrap();
+        let claims: serde_json::Value =
+            serde_json::from_slice(&b64(message.split('.').nth(1).unwrap())).unwrap();
+        assert!(claims["aud"]
+            .as_str()
+            .unwrap()
+            .starts_with("http://127.0.0.1:"));
+        assert_eq!(claims["sub"], "mailto:test@example.com");
+
+        // 端末の鍵で復号すると通知のJSONになる
+        let payload = decrypt(ua_private, &ua_public, &auth_secret, &body);
+        let payload: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(payload["title"], "Mission reminder");
+        assert_eq!(payload["body"], "read");
+    }
+
+    #[tokio::test]
+    async fn test_send_errors() {
+        let rng = SystemRandom::new();
+        let ua_private =
+            agreement::EphemeralPrivateKey::generate(&agreement::ECDH_P256, &rng).unwrap();
+        let ua_public = ua_private.compute_public_key().unwrap().as_ref().to_vec();
+        let key = Arc::new(VapidKey::generate().unwrap());
+        let sender = local_sender(key.clone());
+        let notification = gen_notification();
+
+        // 既定では内部のアドレスに送らない
+        let (endpoint, _rx) = mock_push_service("201 Created");
+        let subscription = gen_subscription(endpoint, &ua_public, &[0u8; 16]);
+        assert!(matches!(
+            PushSenderImpl::with_key(key, "mailto:test@example.com".to_string())
+                .send(&subscription, &notification)
+                .await,
+            Err(PushError::Delivery(_))
+        ));
+
+        let (endpoint, _rx) = mock_push_service("410 Gone");
+        let subscription = gen_subscription(endpoint, &ua_public, &[0u8; 16]);
+        assert_eq!(
+            sender.send(&subscription, &notification).await,
+            Err(PushError::Gone)
+        );
+
+        let (endpoint, _rx) = mock_push_service("503 Service Unavailable");
+        let subscription = gen_subscription(endpoint, &ua_public, &[0u8; 16]);
+        assert!(matches!(
+            sender.send(&subscription, &notification).await,
+            Err(PushError::Delivery(_))
+        ));
+
+        // 端末の公開鍵が不正な場合は送信しない
+        let subscription = gen_subscription(
+            "http://127.0.0.1:1/push".to_string(),
+            &[4u8; 10],
+            &[0u8; 16],
+        );
+        assert!(matches!(
+            sender.send(&subscription, &notification).await,
+            Err(PushError::InvalidSubscription(_))
+        ));
+    }
+}
@@ -167,7 +167,7 @@ mod test {
         let token = service.create(claims).expect("failed to create token");
 
         // 別のキーで検証
-        let invalid_key = BASE64_STANDARD.encode(&[1u8; 32]);
+        let invalid_key = BASE64_STANDARD.encode([1u8; 32]);
         let result = decode::<Claims>(
             &token.0,
             &DecodingKey::from_secret(invalid_key.as_bytes()),
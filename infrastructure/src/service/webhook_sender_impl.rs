@@ -0,0 +1,212 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use domain::{
+    entity::webhook::{PendingDelivery, DELIVERY_TIMEOUT_SECONDS},
+    service::{service_error::webhook_error::WebhookError, webhook_sender::WebhookSender},
+};
+use ring::hmac;
+use sqlx::types::chrono::Utc;
+
+use super::http_client::{self, Destination};
+
+// 配信の記録に残すレスポンスのボディの最大の文字数
+static MAX_ERROR_BODY_CHARS: usize = 512;
+
+/// HMAC-SHA256で署名したJSONをPOSTで送るWebhookSenderの実装
+/// 受信した側は`{X-Webhook-Timestamp}.{ボディ}`をWebhookの秘密で署名した値と
+/// X-Webhook-Signatureの`sha256=`以降(16進数)を比較して検証する
+/// 送信先はhttpsでグローバルに到達できるアドレスに限り、リダイレクトには従わない
+#[derive(Debug, Clone)]
+pub struct WebhookSenderImpl {
+    destination: Destination,
+}
+
+impl Default for WebhookSenderImpl {
+    fn default() -> Self {
+        Self {
+            destination: Destination::Public,
+        }
+    }
+}
+
+impl WebhookSender for WebhookSenderImpl {
+    fn send<'a>(
+        &'a self,
+        delivery: &'a PendingDelivery,
+    ) -> Pin<Box<dyn Future<Output = Result<u16, WebhookError>> + Send + 'a>> {
+        Box::pin(async move {
+            // 再試行のたびに送信した時刻で署名し直すため、受信した側は古い署名を拒否できる
+            let timestamp = Utc::now().timestamp();
+            let signature = sign(&delivery.secret, timestamp, &delivery.payload);
+            let response = http_client::post(
+                &delivery.url,
+                vec![
+                    ("Content-Type", "application/json".to_string()),
+                    ("User-Agent", "missions-webhook".to_string()),
+                    ("X-Webhook-Event", delivery.event.as_str().to_string()),
+                    ("X-Webhook-Delivery", delivery.delivery_id.clone()),
+                    ("X-Webhook-Timestamp", timestamp.to_string()),
+                    ("X-Webhook-Signature", format!("sha256={}", signature)),
+                ],
+                delivery.payload.clone().into_bytes(),
+                self.destination,
+                Duration::from_secs(DELIVERY_TIMEOUT_SECONDS),
+            )
+            .await
+            .map_err(WebhookError::Delivery)?;
+            match response.status {
+                status @ 200..=299 => Ok(status),
+                status => Err(WebhookError::Status {
+                    status_code: status,
+                    body: String::from_utf8_lossy(&response.body)
+                        .chars()
+                        .take(MAX_ERROR_BODY_CHARS)
+                        .collect(),
+                }),
+            }
+        })
+    }
+}
+
+// `{timestamp}.{body}`のHMAC-SHA256を16進数で表す
+fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, format!("{}.{}", timestamp, body).as_bytes());
+    tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use domain::{
+        entity::webhook::{PendingDelivery, WebhookEvent},
+        service::{service_error::webhook_error::WebhookError, webhook_sender::WebhookSender},
+    };
+
+    use super::{sign, Destination, WebhookSenderImpl};
+
+    // テストの受信先はローカルのhttpで待ち受けるため、送信先を制限しない
+    const LOCAL_SENDER: WebhookSenderImpl = WebhookSenderImpl {
+        destination: Destination::Any,
+    };
+
+    #[test]
+    fn test_sign() {
+        // 署名の対象は`{timestamp}.{body}`
+        assert_eq!(
+            sign("whsec_test", 1_792_454_400, r#"{"event":"level.up"}"#),
+            "e0f8c8f309f2e2ab6b8c6d53faf14a44b7a59827c99befd81cdcbc56e42e8503"
+        );
+        // タイムスタンプと秘密も署名の対象になる
+        assert_ne!(sign("secret", 1, "{}"), sign("secret", 2, "{}"));
+        assert_ne!(sign("secret", 1, "{}"), sign("other", 1, "{}"));
+    }
+
+    // 1回だけリクエストを受け付け、statusを返す受信先
+    // 受け取ったリクエストのヘッダーとボディを返す
+    fn mock_receiver(status: &'static str) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hooks/test", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0u8; 4096];
+            // ヘッダーを読み、Content-Lengthの分だけボディを読む
+            let (head, body_start) = loop {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+                if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break (String::from_utf8(request[..i].to_vec()).unwrap(), i + 4);
+                }
+            };
+            let length: usize = head
+                .lines()
+                .find_map(|line| line.strip_prefix("Content-Length: "))
+                .unwrap()
+                .parse()
+                .unwrap();
+            while request.len() < body_start + length {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: 4\r\n\r\nnope",
+                status
+            )
+            .unwrap();
+            let body = String::from_utf8(request[body_start..].to_vec()).unwrap();
+            tx.send((head, body)).unwrap();
+        });
+        (url, rx)
+    }
+
+    fn gen_delivery(url: String) -> PendingDelivery {
+        PendingDelivery {
+            id: 1,
+            delivery_id: "test_delivery".to_string(),
+            url,
+            secret: "whsec_test".to_string(),
+            event: WebhookEvent::LevelUp,
+            payload: r#"{"event":"level.up","data":{"newLevel":2}}"#.to_string(),
+            attempts: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_to_mock_receiver() {
+        let (url, rx) = mock_receiver("204 No Content");
+        let delivery = gen_delivery(url);
+        assert_eq!(LOCAL_SENDER.send(&delivery).await, Ok(204));
+
+        let (head, body) = rx.recv().unwrap();
+        let header = |name: &str| {
+            head.lines()
+                .find_map(|line| line.strip_prefix(&format!("{}: ", name)))
+                .unwrap()
+                .to_string()
+        };
+        assert!(head.starts_with("POST /hooks/test HTTP/1.1"));
+        assert_eq!(body, delivery.payload);
+        assert_eq!(header("Content-Type"), "application/json");
+        assert_eq!(header("X-Webhook-Event"), "level.up");
+        assert_eq!(header("X-Webhook-Delivery"), "test_delivery");
+        // 受信した側と同じ方法で署名を検証する
+        let timestamp: i64 = header("X-Webhook-Timestamp").parse().unwrap();
+        assert_eq!(
+            header("X-Webhook-Signature"),
+            format!("sha256={}", sign("whsec_test", timestamp, &body))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_errors() {
+        let (url, _rx) = mock_receiver("500 Internal Server Error");
+        assert_eq!(
+            LOCAL_SENDER.send(&gen_delivery(url)).await,
+            Err(WebhookError::Status {
+                status_code: 500,
+                body: "nope".to_string()
+            })
+        );
+
+        // 接続できない場合はステータスコードのないエラーになる
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hooks/test", listener.local_addr().unwrap());
+        drop(listener);
+        let result = LOCAL_SENDER.send(&gen_delivery(url)).await;
+        assert!(matches!(result, Err(WebhookError::Delivery(_))));
+
+        // 既定では内部のアドレスに送らない
+        let url = "https://127.0.0.1/hooks/test".to_string();
+        let result = WebhookSenderImpl::default().send(&gen_delivery(url)).await;
+        assert!(matches!(result, Err(WebhookError::Delivery(_))));
+    }
+}
@@ -0,0 +1 @@
+CREATE INDEX idx_mission_completed_mission_date ON mission_completed (mission_id, date);
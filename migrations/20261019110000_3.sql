@@ -0,0 +1,26 @@
+-- ユーザーのタイムゾーン(UTCからのオフセット秒)
+-- 既存ユーザーは日本時間とする
+ALTER TABLE users ADD COLUMN utc_offset INT NOT NULL DEFAULT 32400;
+
+CREATE TABLE mission_streak (
+    id              INT AUTO_INCREMENT,
+    mission_id      VARCHAR(64) NOT NULL,
+    current_streak  INT NOT NULL DEFAULT 0,
+    longest_streak  INT NOT NULL DEFAULT 0,
+    last_date       DATE,
+    PRIMARY KEY (id),
+    FOREIGN KEY (mission_id) REFERENCES daily_mission(mission_id) ON DELETE CASCADE,
+    UNIQUE INDEX (mission_id)
+);
+
+-- すべてのミッションを完了した日の連続記録
+CREATE TABLE user_streak (
+    id              INT AUTO_INCREMENT,
+    user_id         VARCHAR(64) NOT NULL,
+    current_streak  INT NOT NULL DEFAULT 0,
+    longest_streak  INT NOT NULL DEFAULT 0,
+    last_date       DATE,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    UNIQUE INDEX (user_id)
+);
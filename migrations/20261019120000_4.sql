@@ -0,0 +1,30 @@
+-- 休暇モード(期間中はストリークが途切れない)
+CREATE TABLE vacation (
+    id          INT AUTO_INCREMENT,
+    user_id     VARCHAR(64) NOT NULL,
+    start_date  DATE NOT NULL,
+    end_date    DATE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    INDEX (user_id, start_date)
+);
+
+-- 所持しているストリークフリーズの数
+CREATE TABLE streak_freeze (
+    id          INT AUTO_INCREMENT,
+    user_id     VARCHAR(64) NOT NULL,
+    available   INT NOT NULL DEFAULT 0,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    UNIQUE INDEX (user_id)
+);
+
+-- ストリークフリーズを消費した日
+CREATE TABLE frozen_day (
+    id          INT AUTO_INCREMENT,
+    user_id     VARCHAR(64) NOT NULL,
+    date        DATE NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    UNIQUE INDEX (user_id, date)
+);
@@ -0,0 +1,10 @@
+-- ミッションの実施スケジュール
+-- schedule_typeごとにschedule_valueの意味が異なる
+--   daily: 未使用
+--   weekdays: 曜日のビットマスク(月曜日 = 1, 日曜日 = 64)
+--   every_n_days: 間隔の日数(schedule_startからの日数で判定する)
+--   times_per_week: ISO週ごとの回数
+ALTER TABLE daily_mission
+    ADD COLUMN schedule_type VARCHAR(16) NOT NULL DEFAULT 'daily',
+    ADD COLUMN schedule_value INT NOT NULL DEFAULT 0,
+    ADD COLUMN schedule_start DATE NULL;
@@ -0,0 +1,16 @@
+-- ミッションの登録上限を変更できるプラン
+-- mission_capacityがNULLの場合は設定(MISSION_CAPACITY)の値を使用する
+CREATE TABLE plan (
+    id                  INT AUTO_INCREMENT,
+    plan_id             VARCHAR(64) NOT NULL,
+    mission_capacity    INT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (plan_id)
+);
+
+-- ユーザーごとの上限はプランより優先する
+ALTER TABLE users
+    ADD COLUMN is_admin BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN plan_id VARCHAR(64) NULL,
+    ADD COLUMN mission_capacity INT NULL,
+    ADD FOREIGN KEY (plan_id) REFERENCES plan(plan_id) ON DELETE SET NULL;
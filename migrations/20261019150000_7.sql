@@ -0,0 +1,18 @@
+-- 数量目標のあるミッション(例: 30ページ読む)
+-- target_quantityがNULLの場合は従来どおり完了/未完了のミッション
+ALTER TABLE daily_mission
+    ADD COLUMN target_quantity INT NULL,
+    ADD COLUMN unit VARCHAR(16) NULL;
+
+-- ミッションの日ごとの進捗の累計
+-- target_quantityは記録時点の目標で、目標を変更しても過去の記録は変わらない
+CREATE TABLE mission_progress (
+    id              INT AUTO_INCREMENT,
+    mission_id      VARCHAR(64) NOT NULL,
+    date            DATE NOT NULL,
+    quantity        INT NOT NULL DEFAULT 0,
+    target_quantity INT NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (mission_id) REFERENCES daily_mission(mission_id) ON DELETE CASCADE,
+    UNIQUE INDEX (mission_id, date)
+);
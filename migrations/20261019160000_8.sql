@@ -0,0 +1,25 @@
+-- 一度だけ達成するクエスト
+-- completed_dateはユーザーのタイムゾーンにおける達成日(未達成の場合はNULL)
+CREATE TABLE quest (
+    id              INT AUTO_INCREMENT,
+    user_id         VARCHAR(64) NOT NULL,
+    quest_id        VARCHAR(64) NOT NULL,
+    title           VARCHAR(255) NOT NULL,
+    descriptions    TEXT,
+    due_date        DATE NULL,
+    completed_date  DATE NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    UNIQUE INDEX (quest_id)
+);
+
+-- クエストのサブタスク(チェックリスト)
+-- idの順に表示する
+CREATE TABLE quest_subtask (
+    id          INT AUTO_INCREMENT,
+    quest_id    VARCHAR(64) NOT NULL,
+    title       VARCHAR(255) NOT NULL,
+    is_complete BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (id),
+    FOREIGN KEY (quest_id) REFERENCES quest(quest_id) ON DELETE CASCADE
+);
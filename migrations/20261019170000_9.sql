@@ -0,0 +1,13 @@
+-- ミッションの難易度と、難易度の代わりに使用する経験値の重み
+ALTER TABLE daily_mission
+    ADD COLUMN difficulty VARCHAR(16) NOT NULL DEFAULT 'normal',
+    ADD COLUMN exp_weight INT NULL;
+
+-- 完了した時刻(ユーザーのタイムゾーン)と付与した経験値
+-- 完了の取り消し時にはexp_awardedの分だけ経験値を戻す
+ALTER TABLE mission_completed
+    ADD COLUMN completed_at DATETIME NULL,
+    ADD COLUMN exp_awarded BIGINT NOT NULL DEFAULT 0;
+
+-- 機能追加前の完了には一律2expを付与していた
+UPDATE mission_completed SET exp_awarded = 2;
@@ -0,0 +1,23 @@
+-- 経験値の増減の台帳(追記のみ)
+-- user_exp.experience_pointsはこの台帳の合計をキャッシュした値になる
+-- ミッションやクエストを削除しても台帳は残すため、mission_idとquest_idには外部キーを設定しない
+CREATE TABLE exp_transactions (
+    id              BIGINT AUTO_INCREMENT,
+    user_id         VARCHAR(64) NOT NULL,
+    amount          BIGINT NOT NULL,
+    reason          VARCHAR(32) NOT NULL,
+    mission_id      VARCHAR(64) NULL,
+    completion_id   INT NULL,
+    quest_id        VARCHAR(64) NULL,
+    created_at      DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    INDEX (user_id, id)
+);
+
+-- 台帳の導入前の経験値は開始残高として記録する
+INSERT INTO exp_transactions
+(user_id, amount, reason, created_at)
+SELECT user_id, experience_points, 'openingBalance', UTC_TIMESTAMP()
+FROM user_exp
+WHERE experience_points <> 0;
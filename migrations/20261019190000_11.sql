@@ -0,0 +1,37 @@
+-- 実績の定義
+-- metricの値がthreshold以上になった時に解除され、bonus_expの経験値を付与する
+-- metric: totalCompletions(ミッションの累計完了数) / missionStreak(ミッションの最長連続達成日数)
+--         allClearStreak(すべてのミッションを完了した日の最長連続日数) / level(レベル)
+CREATE TABLE achievements (
+    id              INT AUTO_INCREMENT,
+    achievement_id  VARCHAR(64) NOT NULL,
+    title           VARCHAR(255) NOT NULL,
+    description     VARCHAR(255) NOT NULL,
+    metric          VARCHAR(32) NOT NULL,
+    threshold       INT NOT NULL,
+    bonus_exp       BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (achievement_id)
+);
+
+-- ユーザーごとの解除済みの実績
+CREATE TABLE user_achievements (
+    user_id         VARCHAR(64) NOT NULL,
+    achievement_id  VARCHAR(64) NOT NULL,
+    unlocked_at     DATETIME NOT NULL,
+    PRIMARY KEY (user_id, achievement_id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    FOREIGN KEY (achievement_id) REFERENCES achievements(achievement_id) ON DELETE CASCADE
+);
+
+INSERT INTO achievements
+(achievement_id, title, description, metric, threshold, bonus_exp)
+VALUES
+('first_completion', 'First Step', 'Complete a mission for the first time', 'totalCompletions', 1, 5),
+('streak_7', 'One Week Streak', 'Complete a mission 7 days in a row', 'missionStreak', 7, 10),
+('completions_100', 'Centurion', 'Complete missions 100 times in total', 'totalCompletions', 100, 50),
+('level_50', 'Halfway There', 'Reach level 50', 'level', 50, 0),
+('all_clear_30', 'Perfect Month', 'Complete all missions 30 days in a row', 'allClearStreak', 30, 100);
+
+-- 実績のボーナス経験値を台帳に記録する
+ALTER TABLE exp_transactions ADD COLUMN achievement_id VARCHAR(64) NULL;
@@ -0,0 +1,10 @@
+-- すべてのミッションを完了した日に付与したボーナス経験値
+-- 主キーにより1日1回だけ付与され、完了を取り消した場合は行を削除して経験値を戻す
+CREATE TABLE all_clear_bonus (
+    user_id         VARCHAR(64) NOT NULL,
+    date            DATE NOT NULL,
+    exp_awarded     BIGINT NOT NULL,
+    created_at      DATETIME NOT NULL,
+    PRIMARY KEY (user_id, date),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
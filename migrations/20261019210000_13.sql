@@ -0,0 +1,23 @@
+-- ランキングとフレンド申請の公開設定(オプトアウト)
+ALTER TABLE users
+    ADD COLUMN show_on_global_leaderboard BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN show_on_friend_leaderboard BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN allow_friend_requests BOOLEAN NOT NULL DEFAULT TRUE;
+
+-- フレンド関係
+-- status: pending(user_idからfriend_idへの申請中) / accepted(フレンド) / blocked(user_idがfriend_idをブロック)
+-- acceptedは両方向の2行で保存し、フレンドの一覧をuser_idだけで引けるようにする
+CREATE TABLE friendships (
+    user_id     VARCHAR(64) NOT NULL,
+    friend_id   VARCHAR(64) NOT NULL,
+    status      VARCHAR(16) NOT NULL,
+    created_at  DATETIME NOT NULL,
+    PRIMARY KEY (user_id, friend_id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    FOREIGN KEY (friend_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    INDEX (friend_id, status)
+);
+
+-- ランキングの集計用
+ALTER TABLE user_exp ADD INDEX (experience_points);
+ALTER TABLE exp_transactions ADD INDEX (created_at, user_id);
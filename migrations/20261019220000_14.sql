@@ -0,0 +1,76 @@
+-- グループ(GROUPSは予約語のためuser_groupsとする)
+CREATE TABLE user_groups (
+    id          INT AUTO_INCREMENT,
+    group_id    VARCHAR(64) NOT NULL,
+    name        VARCHAR(255) NOT NULL,
+    created_at  DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (group_id)
+);
+
+-- グループのメンバー
+-- role: owner(作成者、1グループに1人) / admin(招待とミッションの管理ができる) / member
+CREATE TABLE group_members (
+    group_id    VARCHAR(64) NOT NULL,
+    user_id     VARCHAR(64) NOT NULL,
+    role        VARCHAR(16) NOT NULL,
+    joined_at   DATETIME NOT NULL,
+    PRIMARY KEY (group_id, user_id),
+    FOREIGN KEY (group_id) REFERENCES user_groups(group_id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    INDEX (user_id)
+);
+
+-- グループへの招待
+CREATE TABLE group_invites (
+    group_id    VARCHAR(64) NOT NULL,
+    user_id     VARCHAR(64) NOT NULL,
+    invited_by  VARCHAR(64) NOT NULL,
+    created_at  DATETIME NOT NULL,
+    PRIMARY KEY (group_id, user_id),
+    FOREIGN KEY (group_id) REFERENCES user_groups(group_id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    FOREIGN KEY (invited_by) REFERENCES users(user_id) ON DELETE CASCADE,
+    INDEX (user_id)
+);
+
+-- グループで共有するミッション
+-- 個人のdaily_missionとは別のテーブルのため、個人のミッションの登録上限には含まれない
+CREATE TABLE group_missions (
+    id              INT AUTO_INCREMENT,
+    mission_id      VARCHAR(64) NOT NULL,
+    group_id        VARCHAR(64) NOT NULL,
+    title           VARCHAR(255) NOT NULL,
+    descriptions    TEXT,
+    bonus_exp       BIGINT NOT NULL,
+    created_at      DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (group_id) REFERENCES user_groups(group_id) ON DELETE CASCADE,
+    UNIQUE INDEX (mission_id),
+    INDEX (group_id)
+);
+
+-- メンバーごとのグループミッションの完了記録
+-- dateは完了したメンバーのタイムゾーンの日付
+CREATE TABLE group_mission_completions (
+    mission_id      VARCHAR(64) NOT NULL,
+    user_id         VARCHAR(64) NOT NULL,
+    date            DATE NOT NULL,
+    exp_awarded     BIGINT NOT NULL,
+    completed_at    DATETIME NOT NULL,
+    PRIMARY KEY (mission_id, date, user_id),
+    FOREIGN KEY (mission_id) REFERENCES group_missions(mission_id) ON DELETE CASCADE,
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+-- 全員が完了した日のボーナスの配布記録(1日1回)
+CREATE TABLE group_mission_bonus (
+    mission_id      VARCHAR(64) NOT NULL,
+    date            DATE NOT NULL,
+    exp_awarded     BIGINT NOT NULL,
+    created_at      DATETIME NOT NULL,
+    PRIMARY KEY (mission_id, date),
+    FOREIGN KEY (mission_id) REFERENCES group_missions(mission_id) ON DELETE CASCADE
+);
+
+ALTER TABLE exp_transactions ADD COLUMN group_mission_id VARCHAR(64) NULL;
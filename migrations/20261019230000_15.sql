@@ -0,0 +1,35 @@
+-- ミッションのテンプレート
+-- is_curated: TRUE(管理者が管理するカタログのテンプレート) / FALSE(ユーザーが共有コードで公開したテンプレート)
+-- 共有したテンプレートは公開した時点のミッションの内容を保存し、元のミッションを変更しても変わらない
+CREATE TABLE mission_templates (
+    id              INT AUTO_INCREMENT,
+    template_id     VARCHAR(64) NOT NULL,
+    is_curated      BOOLEAN NOT NULL,
+    author_id       VARCHAR(64) NULL,
+    share_code      VARCHAR(16) NULL,
+    title           VARCHAR(255) NOT NULL,
+    descriptions    TEXT,
+    schedule_type   VARCHAR(16) NOT NULL DEFAULT 'daily',
+    schedule_value  INT NOT NULL DEFAULT 0,
+    schedule_start  DATE NULL,
+    target_quantity INT NULL,
+    unit            VARCHAR(16) NULL,
+    difficulty      VARCHAR(16) NOT NULL DEFAULT 'normal',
+    exp_weight      INT NULL,
+    created_at      DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (template_id),
+    UNIQUE INDEX (share_code),
+    FOREIGN KEY (author_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    INDEX (is_curated),
+    INDEX (author_id)
+);
+
+INSERT INTO mission_templates
+(template_id, is_curated, title, descriptions, schedule_type, schedule_value, target_quantity, unit, difficulty, created_at)
+VALUES
+('read_30_pages', TRUE, 'Read 30 pages', 'Read a book a little every day', 'daily', 0, 30, 'pages', 'normal', UTC_TIMESTAMP()),
+('morning_walk', TRUE, 'Morning walk', 'Take a walk before starting the day', 'daily', 0, NULL, NULL, 'easy', UTC_TIMESTAMP()),
+('workout', TRUE, 'Workout', 'Exercise three times a week', 'times_per_week', 3, NULL, NULL, 'hard', UTC_TIMESTAMP()),
+('study_english', TRUE, 'Study English', 'Study English on weekdays', 'weekdays', 31, 20, 'minutes', 'normal', UTC_TIMESTAMP()),
+('clean_room', TRUE, 'Clean the room', 'Clean up the room on weekends', 'weekdays', 96, NULL, NULL, 'easy', UTC_TIMESTAMP());
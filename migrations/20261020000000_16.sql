@@ -0,0 +1,17 @@
+-- カレンダーアプリから購読するiCalendarフィードのURL
+-- feed_tokenを知っていれば認証なしでフィードを取得できるため、再発行すると古いURLは使えなくなる
+CREATE TABLE calendar_feeds (
+    id          INT AUTO_INCREMENT,
+    user_id     VARCHAR(64) NOT NULL,
+    feed_token  VARCHAR(64) NOT NULL,
+    created_at  DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (user_id),
+    UNIQUE INDEX (feed_token),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+-- ミッションを登録した日時(UTC)
+-- フィードの繰り返しの開始日に使う。既存のミッションはこのマイグレーションの実行日時になる
+ALTER TABLE daily_mission
+    ADD COLUMN created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP;
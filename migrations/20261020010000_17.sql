@@ -0,0 +1,42 @@
+-- リマインダーの時刻(ユーザーのタイムゾーン)
+-- reminder_time: その日に未完了のミッションを通知する時刻
+-- summary_time: その日のミッションの状況をまとめて通知する時刻
+ALTER TABLE daily_mission
+    ADD COLUMN reminder_time TIME NULL;
+
+ALTER TABLE users
+    ADD COLUMN summary_time TIME NULL;
+
+-- バックグラウンドで実行するジョブ
+-- 再起動しても失われないようDBに保存し、job_keyで同じジョブの重複を防ぐ
+-- status: pending(実行待ち) / running(実行中) / done(完了) / skipped(実行不要) / failed(再試行の上限に達した)
+-- run_atはUTC、local_dateはユーザーのタイムゾーンにおけるジョブの対象の日付
+CREATE TABLE scheduled_jobs (
+    id          BIGINT AUTO_INCREMENT,
+    job_key     VARCHAR(191) NOT NULL,
+    kind        VARCHAR(16) NOT NULL,
+    user_id     VARCHAR(64) NOT NULL,
+    mission_id  VARCHAR(64) NULL,
+    local_date  DATE NOT NULL,
+    run_at      DATETIME NOT NULL,
+    status      VARCHAR(16) NOT NULL DEFAULT 'pending',
+    attempts    INT NOT NULL DEFAULT 0,
+    last_error  TEXT NULL,
+    locked_by   VARCHAR(64) NULL,
+    created_at  DATETIME NOT NULL,
+    updated_at  DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (job_key),
+    INDEX (status, run_at),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE,
+    FOREIGN KEY (mission_id) REFERENCES daily_mission(mission_id) ON DELETE CASCADE
+);
+
+-- 複数のインスタンスで起動した場合に、ジョブを実行するインスタンスを1つに決めるためのリース
+-- expires_atを過ぎるまで更新されなかった場合は他のインスタンスが引き継ぐ
+CREATE TABLE job_leases (
+    name        VARCHAR(64) NOT NULL,
+    holder      VARCHAR(64) NOT NULL,
+    expires_at  DATETIME NOT NULL,
+    PRIMARY KEY (name)
+);
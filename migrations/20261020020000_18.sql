@@ -0,0 +1,20 @@
+-- Web Pushの購読(ブラウザ/端末ごと)
+-- endpointはプッシュサービスのURLで、同じ端末で購読し直した場合は上書きする
+-- p256dh, authはペイロードの暗号化(RFC 8291)に使う端末の公開鍵と認証用の秘密(base64url)
+-- expires_atはブラウザが通知した購読の有効期限(UTC)
+CREATE TABLE push_subscriptions (
+    id               BIGINT AUTO_INCREMENT,
+    subscription_id  VARCHAR(64) NOT NULL,
+    user_id          VARCHAR(64) NOT NULL,
+    endpoint         VARCHAR(512) NOT NULL,
+    p256dh           VARCHAR(128) NOT NULL,
+    auth             VARCHAR(64) NOT NULL,
+    device_name      VARCHAR(50) NULL,
+    expires_at       DATETIME NULL,
+    created_at       DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (subscription_id),
+    UNIQUE INDEX (endpoint),
+    INDEX (user_id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
@@ -0,0 +1,43 @@
+-- ユーザーが登録したWebhook
+-- events: 購読するイベント(mission.completedなど)のカンマ区切り
+-- secret: 配信のペイロードにHMAC-SHA256で署名する秘密
+CREATE TABLE webhooks (
+    id          BIGINT AUTO_INCREMENT,
+    webhook_id  VARCHAR(64) NOT NULL,
+    user_id     VARCHAR(64) NOT NULL,
+    url         VARCHAR(512) NOT NULL,
+    secret      VARCHAR(128) NOT NULL,
+    events      VARCHAR(255) NOT NULL,
+    is_active   BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at  DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (webhook_id),
+    INDEX (user_id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+-- Webhookの配信のキューと記録
+-- 再起動しても失われないようDBに保存し、失敗した場合はnext_attempt_atを延ばして再試行する
+-- status: pending(送信待ち) / running(送信中) / succeeded(成功) / failed(再試行の上限に達した)
+-- next_attempt_at, delivered_atはUTC
+CREATE TABLE webhook_deliveries (
+    id                BIGINT AUTO_INCREMENT,
+    delivery_id       VARCHAR(64) NOT NULL,
+    webhook_id        VARCHAR(64) NOT NULL,
+    event             VARCHAR(32) NOT NULL,
+    payload           TEXT NOT NULL,
+    status            VARCHAR(16) NOT NULL DEFAULT 'pending',
+    attempts          INT NOT NULL DEFAULT 0,
+    next_attempt_at   DATETIME NOT NULL,
+    last_status_code  INT NULL,
+    last_error        TEXT NULL,
+    locked_by         VARCHAR(64) NULL,
+    delivered_at      DATETIME NULL,
+    created_at        DATETIME NOT NULL,
+    updated_at        DATETIME NOT NULL,
+    PRIMARY KEY (id),
+    UNIQUE INDEX (delivery_id),
+    INDEX (status, next_attempt_at),
+    INDEX (webhook_id, id),
+    FOREIGN KEY (webhook_id) REFERENCES webhooks(webhook_id) ON DELETE CASCADE
+);
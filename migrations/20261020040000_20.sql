@@ -0,0 +1,39 @@
+-- ユーザーの日ごとの集計(統計の取得用)
+-- dateはユーザーのタイムゾーンにおける日付で、完了と経験値の記録と同じトランザクションで加算する
+-- ミッションを削除しても完了の記録とは異なり集計は残る
+CREATE TABLE user_daily_stats (
+    user_id         VARCHAR(64) NOT NULL,
+    date            DATE NOT NULL,
+    completions     INT NOT NULL DEFAULT 0,
+    exp_gained      BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, date),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+-- 既存の完了の記録を集計する(mission_completed.dateはユーザーのタイムゾーンの日付)
+INSERT INTO user_daily_stats
+(user_id, date, completions)
+SELECT daily_mission.user_id, mission_completed.date, COUNT(*)
+FROM mission_completed
+INNER JOIN daily_mission
+ON daily_mission.mission_id = mission_completed.mission_id
+GROUP BY daily_mission.user_id, mission_completed.date;
+
+-- 既存の経験値の台帳を集計する(created_atはUTCのため、現在のタイムゾーンで日付に変換する)
+-- 台帳の導入前の経験値(開始残高)は獲得した日が分からないため含めない
+INSERT INTO user_daily_stats
+(user_id, date, exp_gained)
+SELECT user_id, date, exp_gained
+FROM (
+    SELECT
+    exp_transactions.user_id,
+    DATE(DATE_ADD(exp_transactions.created_at, INTERVAL users.utc_offset SECOND)) AS date,
+    SUM(exp_transactions.amount) AS exp_gained
+    FROM exp_transactions
+    INNER JOIN users
+    ON users.user_id = exp_transactions.user_id
+    WHERE exp_transactions.reason <> 'openingBalance'
+    GROUP BY exp_transactions.user_id, date
+) AS daily
+ON DUPLICATE KEY UPDATE
+exp_gained = daily.exp_gained;
@@ -0,0 +1,30 @@
+-- 同じミッションを同じ日に重複して完了できないようにする
+-- 日ごとの集計から重複した分の完了の数を差し引く
+UPDATE user_daily_stats
+INNER JOIN (
+    SELECT
+    daily_mission.user_id,
+    mission_completed.date,
+    COUNT(*) - COUNT(DISTINCT mission_completed.mission_id) AS duplicates
+    FROM mission_completed
+    INNER JOIN daily_mission
+    ON daily_mission.mission_id = mission_completed.mission_id
+    GROUP BY daily_mission.user_id, mission_completed.date
+) AS duplicated
+ON duplicated.user_id = user_daily_stats.user_id
+AND duplicated.date = user_daily_stats.date
+SET user_daily_stats.completions = GREATEST(user_daily_stats.completions - duplicated.duplicates, 0)
+WHERE duplicated.duplicates > 0;
+
+-- 最初の完了の記録を残して重複した記録を削除する
+DELETE newer
+FROM mission_completed AS newer
+INNER JOIN mission_completed AS older
+ON older.mission_id = newer.mission_id
+AND older.date = newer.date
+AND older.id < newer.id;
+
+ALTER TABLE mission_completed
+ADD UNIQUE INDEX uq_mission_completed_mission_date (mission_id, date);
+
+DROP INDEX idx_mission_completed_mission_date ON mission_completed;
@@ -0,0 +1,5 @@
+-- レベルアップの通知をレベルが上がったトランザクションでジョブとして登録するため、通知する内容を保存する
+-- レベルアップ以外のジョブではNULL
+ALTER TABLE scheduled_jobs
+    ADD COLUMN new_level INT NULL,
+    ADD COLUMN total_exp BIGINT NULL;
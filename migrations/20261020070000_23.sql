@@ -0,0 +1,10 @@
+-- ストリークフリーズを付与した日(すべてのミッションを完了した日、ユーザーのタイムゾーン)
+-- 完了を取り消した時に、その日に実際に付与したフリーズだけを取り除くために使う
+-- 上限に達していて付与しなかった日は記録しない。このマイグレーションより前に付与したフリーズは取り除かれない
+CREATE TABLE streak_freeze_earned (
+    user_id     VARCHAR(64) NOT NULL,
+    date        DATE NOT NULL,
+    created_at  DATETIME NOT NULL,
+    PRIMARY KEY (user_id, date),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
@@ -0,0 +1,25 @@
+-- ユーザーの週ごとの獲得経験値(週間ランキング用)
+-- week_startはユーザーのタイムゾーンにおける週の月曜日で、user_daily_statsと同じトランザクションで加算する
+CREATE TABLE user_weekly_exp (
+    user_id         VARCHAR(64) NOT NULL,
+    week_start      DATE NOT NULL,
+    exp_gained      BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, week_start),
+    INDEX idx_user_weekly_exp_score (week_start, exp_gained DESC, user_id),
+    FOREIGN KEY (user_id) REFERENCES users(user_id) ON DELETE CASCADE
+);
+
+-- 既存の日ごとの集計から週ごとの獲得経験値を集計する
+INSERT INTO user_weekly_exp
+(user_id, week_start, exp_gained)
+SELECT user_id, DATE_SUB(date, INTERVAL WEEKDAY(date) DAY) AS week_start, SUM(exp_gained)
+FROM user_daily_stats
+GROUP BY user_id, week_start
+HAVING SUM(exp_gained) <> 0;
+
+-- ランキングを(score DESC, user_id)の順にインデックスで辿れるようにする
+ALTER TABLE user_exp
+ADD INDEX idx_user_exp_score (experience_points DESC, user_id);
+
+ALTER TABLE user_streak
+ADD INDEX idx_user_streak_score (current_streak DESC, user_id);
@@ -0,0 +1,13 @@
+-- ミッションの前回の達成日を含むISO週の達成日数
+-- 完了と取り消しのたびにcurrent_streak, longest_streakと同じトランザクションで更新し、読み込み時は再集計しない
+ALTER TABLE mission_streak ADD COLUMN week_count INT NOT NULL DEFAULT 0;
+
+UPDATE mission_streak
+SET week_count = (
+    SELECT COUNT(*)
+    FROM mission_completed
+    WHERE mission_completed.mission_id = mission_streak.mission_id
+    AND mission_completed.date <= mission_streak.last_date
+    AND mission_completed.date >= DATE_SUB(mission_streak.last_date, INTERVAL WEEKDAY(mission_streak.last_date) DAY)
+)
+WHERE last_date IS NOT NULL;
@@ -0,0 +1,28 @@
+-- ユーザーのタイムゾーンをIANAのタイムゾーン名で保存する
+-- 今日の日付は日時ごとのオフセットで求めるため、夏時間の切り替えも反映される
+-- utc_offsetは複数のユーザーをまとめて扱うSQLのための現在のオフセットとして残し、バックグラウンドジョブで更新する
+ALTER TABLE users ADD COLUMN time_zone VARCHAR(64) NOT NULL DEFAULT 'Asia/Tokyo';
+
+-- 既存のユーザーは固定のオフセットと同じオフセットのタイムゾーンにする
+-- 1時間単位のオフセットはEtc/GMT(符号が逆になる)、それ以外は同じオフセットの代表的なタイムゾーンにする
+UPDATE users
+SET time_zone = CASE
+    WHEN utc_offset = 32400 THEN 'Asia/Tokyo'
+    WHEN utc_offset = 0 THEN 'UTC'
+    WHEN utc_offset % 3600 = 0 AND utc_offset > 0 THEN CONCAT('Etc/GMT-', utc_offset DIV 3600)
+    WHEN utc_offset % 3600 = 0 AND utc_offset < 0 THEN CONCAT('Etc/GMT+', -utc_offset DIV 3600)
+    WHEN utc_offset = 12600 THEN 'Asia/Tehran'
+    WHEN utc_offset = 16200 THEN 'Asia/Kabul'
+    WHEN utc_offset = 19800 THEN 'Asia/Kolkata'
+    WHEN utc_offset = 20700 THEN 'Asia/Kathmandu'
+    WHEN utc_offset = 23400 THEN 'Asia/Yangon'
+    WHEN utc_offset = 31500 THEN 'Australia/Eucla'
+    WHEN utc_offset = 34200 THEN 'Australia/Darwin'
+    WHEN utc_offset = 37800 THEN 'Australia/Lord_Howe'
+    WHEN utc_offset = 45900 THEN 'Pacific/Chatham'
+    WHEN utc_offset = -12600 THEN 'America/St_Johns'
+    WHEN utc_offset = -34200 THEN 'Pacific/Marquesas'
+    ELSE 'Asia/Tokyo'
+END;
+
+CREATE INDEX idx_users_time_zone ON users (time_zone);
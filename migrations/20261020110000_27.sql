@@ -0,0 +1,3 @@
+-- 今日の完了を取り消したかどうか
+-- 完了を取り消しても進捗の累計は履歴として残し、目標に達したままの進捗で再び完了にならないようにする
+ALTER TABLE mission_progress ADD COLUMN completion_undone BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,11 @@
+-- ミッションを指定しない完了の履歴をユーザーごとに日付順で辿れるよう、完了の記録にユーザーIDを持たせる
+ALTER TABLE mission_completed ADD COLUMN user_id VARCHAR(64) NULL AFTER mission_id;
+
+UPDATE mission_completed
+INNER JOIN daily_mission
+ON daily_mission.mission_id = mission_completed.mission_id
+SET mission_completed.user_id = daily_mission.user_id;
+
+ALTER TABLE mission_completed MODIFY COLUMN user_id VARCHAR(64) NOT NULL;
+
+CREATE INDEX idx_mission_completed_user_date ON mission_completed (user_id, date, id);